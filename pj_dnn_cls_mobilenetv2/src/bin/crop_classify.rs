@@ -0,0 +1,52 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::core;
+
+use pj_dnn_cls_mobilenetv2::classifier::classifier::Classifier;
+use pj_dnn_cls_mobilenetv2::pipeline::pipeline::CropClassifier;
+use pj_dnn_det_vehicle_detection_0200::detection_engine::detection_engine::DetectionEngine;
+use vision_core::bounding_box::BoundingBox;
+use vision_core::cli::{Args, Parser};
+use vision_core::config::FromModelConfig;
+use vision_core::detector::Detector;
+use vision_core::error::Error;
+use vision_core::helper_cv::ColorGenerator;
+use vision_core::runner::{run_with, draw_bounding_box_list, AppResult};
+
+/////////////////////////////////////////////////////////////////
+/* Detect vehicles with vehicle-detection-0200, then classify each vehicle with MobileNetV2 */
+/* Usage (see --help for all the options. The options are for the detector) */
+/*   cargo run --bin crop_classify */
+/*   cargo run --bin crop_classify -- --image ../resource/dashcam_00.jpg --output result.jpg */
+/*   cargo run --bin crop_classify -- --dir ../resource --output result --headless */
+const DEFAULT_IMAGE_FILENAME: &str = "../resource/dashcam_01.jpg";
+const CROP_MARGIN: f32 = 0.1;
+const BATCH_SIZE: usize = 8;
+
+/////////////////////////////////////////////////////////////////
+fn main() -> AppResult {
+    let args = Args::parse();
+
+    /* Create the detector (first stage) and the classifier (second stage) */
+    let mut detector: Box<dyn Detector> = Box::new(args.engine_builder::<DetectionEngine>()?.build()?);
+    let mut crop_classifier = CropClassifier::new(Classifier::new()?, CROP_MARGIN, BATCH_SIZE);
+
+    let color_generator = ColorGenerator::new(20, 30);
+    run_with(&mut |mat: &mut core::Mat, text_thickness: i32| {
+        let detection_list = crop_classifier.process(detector.as_mut(), mat)?;
+        let bbox_list: Vec::<BoundingBox> = detection_list.iter().map(|detection| BoundingBox { label: detection.label(), ..detection.bbox.clone() }).collect();
+        draw_bounding_box_list(mat, &bbox_list, &color_generator, text_thickness).map_err(Error::Postprocess)?;
+        Ok(detection_list.len())
+    }, &args, DEFAULT_IMAGE_FILENAME)
+}
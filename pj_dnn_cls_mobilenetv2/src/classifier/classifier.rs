@@ -0,0 +1,238 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::{prelude::*, core, dnn};
+
+use vision_core::bounding_box::{NmsMethod, NmsMode};
+use vision_core::config::{ModelConfig, FromModelConfig};
+use vision_core::dnn_helper::{read_net, read_label_file, output_to_vec, normalize, output_blob_names};
+use vision_core::error::{Error, Result};
+use vision_core::preprocess::{resize, ResizeMode};
+
+
+/////////////////////////////////////////////////////////////////
+#[derive(Debug, Clone, PartialEq)]
+pub struct Classification {
+    pub class_id: i32,
+    pub label: String,
+    pub probability: f32,
+}
+
+
+/////////////////////////////////////////////////////////////////
+pub struct Classifier {
+    config: ModelConfig,
+    net: dnn::Net,
+    out_blob_names: core::Vector::<String>,
+    normalize_mean: core::Scalar,
+    normalize_norm: core::Scalar,
+    label_list: Vec::<String>,
+}
+
+impl Classifier {
+    /* Model Parameters */
+    const MODEL_NAME: &'static str = "MobileNetV2";
+    const MODEL_FILENAME: &'static str = "../resource/model/mobilenetv2-1.0.onnx";
+    const LABEL_FILENAME: &'static str = "../resource/model/imagenet_labels.txt";
+    const MODEL_NORMALIZE_MEAN: (f64, f64, f64) = (0.485, 0.456, 0.406);
+    const MODEL_NORMALIZE_NORM: (f64, f64, f64) = (0.229, 0.224, 0.225);
+    const MODEL_WIDTH: i32 = 224;
+    const MODEL_HEIGHT: i32 = 224;
+    const MODEL_NAME_INPUT_0: &'static str = "data";
+    const MODEL_NAME_OUTPUT_0: &'static str = "mobilenetv20_output_flatten0_reshape0";   // logits: [batch, num_class]
+    const MODEL_NUMBER_OF_CLASS: usize = 1000;
+
+    /* Other Parameters */
+    const SCORE_THRESHOLD: f32 = 0.0;
+
+    pub fn config(&self) -> &ModelConfig {
+        &self.config
+    }
+
+    pub fn input_size(&self) -> core::Size {
+        core::Size { width: self.config.input_width, height: self.config.input_height }
+    }
+
+    /* Labels indexed by class id */
+    pub fn label_list(&self) -> &[String] {
+        &self.label_list
+    }
+
+    pub fn model_name(&self) -> &str {
+        Self::MODEL_NAME
+    }
+
+    /* Top-k classes in descending order of the probability. Classes whose probability < score_threshold are not included */
+    pub fn process(&mut self, mat: &core::Mat, top_k: usize) -> Result<Vec::<Classification>> {
+        Ok(self.process_batch(std::slice::from_ref(mat), top_k)?.pop().unwrap_or_default())
+    }
+
+    /* Same as process, but runs the images at once. The result is in the order of mat_list */
+    pub fn process_batch(&mut self, mat_list: &[core::Mat], top_k: usize) -> Result<Vec::<Vec::<Classification>>> {
+        if mat_list.is_empty() {
+            return Ok(vec![]);
+        }
+
+        /* Pre Process */
+        let mut mat_normalized_list = core::Vector::<core::Mat>::new();
+        for mat in mat_list {
+            let (mat_resized, _) = resize(mat, self.input_size(), self.config.resize_mode, self.config.pad_value)?;
+            mat_normalized_list.push(normalize(&mat_resized, &self.normalize_mean, &self.normalize_norm)?);
+        }
+        let mat_blob = dnn::blob_from_images(&mat_normalized_list, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).map_err(Error::Preprocess)?;
+
+        /* Feed input data */
+        self.net.set_input(&mat_blob, &self.config.input_name, 1.0, core::Scalar::default()).map_err(Error::Inference)?;
+
+        /* Run inference */
+        let mut output_blobs = core::Vector::<core::Mat>::new();
+        self.net.forward(&mut output_blobs, &self.out_blob_names).map_err(Error::Inference)?;
+
+        /* Retrieve output ([batch, num_class]) */
+        let output_data = output_blobs.get(0).map_err(Error::Inference)?;
+        let output_data = output_to_vec(&output_data, &self.config.output_names[0], mat_list.len() * Self::MODEL_NUMBER_OF_CLASS)?;
+
+        Ok(decode(&output_data, Self::MODEL_NUMBER_OF_CLASS, top_k, self.config.score_threshold, &self.label_list))
+    }
+}
+
+/* Probability of each class. The max logit is subtracted first to avoid overflow in exp */
+pub fn softmax(logit_list: &[f32]) -> Vec::<f32> {
+    let max = logit_list.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    let exp_list: Vec::<f32> = logit_list.iter().map(|logit| (logit - max).exp()).collect();
+    let sum: f32 = exp_list.iter().sum();
+    exp_list.iter().map(|v| v / sum).collect()
+}
+
+/* Indices of the top-k values in descending order. The smaller index comes first on a tie */
+pub fn top_k(value_list: &[f32], k: usize) -> Vec::<usize> {
+    let mut index_list: Vec::<usize> = (0 .. value_list.len()).collect();
+    index_list.sort_by(|a, b| value_list[*b].partial_cmp(&value_list[*a]).unwrap_or(std::cmp::Ordering::Equal));
+    index_list.truncate(k);
+    index_list
+}
+
+/* Decode logits ([batch, num_class]) into top-k classes of each image */
+pub fn decode(logits: &[f32], num_class: usize, k: usize, score_threshold: f32, label_list: &[String]) -> Vec::<Vec::<Classification>> {
+    logits.chunks_exact(num_class).map(|logit_list| {
+        let probability_list = softmax(logit_list);
+        top_k(&probability_list, k).into_iter()
+            .filter(|class_id| probability_list[*class_id] >= score_threshold)
+            .map(|class_id| Classification {
+                class_id: class_id as i32,
+                label: label_list.get(class_id).cloned().unwrap_or_else(|| class_id.to_string()),
+                probability: probability_list[class_id],
+            })
+            .collect()
+    }).collect()
+}
+
+impl FromModelConfig for Classifier {
+    fn default_config() -> ModelConfig {
+        ModelConfig {
+            model_filename: Self::MODEL_FILENAME.to_string(),
+            label_filename: Some(Self::LABEL_FILENAME.to_string()),
+            input_width: Self::MODEL_WIDTH,
+            input_height: Self::MODEL_HEIGHT,
+            input_name: Self::MODEL_NAME_INPUT_0.to_string(),
+            output_names: vec![Self::MODEL_NAME_OUTPUT_0.to_string()],
+            normalize_mean: Self::MODEL_NORMALIZE_MEAN,
+            normalize_norm: Self::MODEL_NORMALIZE_NORM,
+            resize_mode: ResizeMode::Stretch,
+            pad_value: (0.0, 0.0, 0.0),
+            score_threshold: Self::SCORE_THRESHOLD,
+            /* Not used (see unused_keys) */
+            score_class_threshold: Self::SCORE_THRESHOLD,
+            nms_iou_threshold: 0.0,
+            nms_mode: NmsMode::ClassAgnostic,
+            nms_method: NmsMethod::Hard,
+            soft_nms_sigma: 0.5,
+            max_detections: None,
+            prior_box: None,
+        }
+    }
+
+    fn unused_keys() -> &'static [&'static str] {
+        &["score_class_threshold", "nms_iou_threshold", "nms_mode", "nms_method", "soft_nms_sigma", "max_detections", "prior_box"]
+    }
+
+    fn from_config(config: ModelConfig) -> Result<Self> {
+        if config.output_names.len() != 1 {
+            return Err(Error::Config(format!("{} requires 1 output name", Self::MODEL_NAME)));
+        }
+        let label_filename = config.label_filename.clone()
+            .ok_or_else(|| Error::Config(format!("{} requires label_filename", Self::MODEL_NAME)))?;
+
+        /* Load model */
+        let net = read_net(&config.model_filename)?;
+
+        /* Set output names */
+        let out_blob_names = output_blob_names(&config.output_names);
+
+        /* Initialize normalize param */
+        let normalize_mean = core::Scalar::from(config.normalize_mean);
+        let normalize_norm = core::Scalar::from(config.normalize_norm);
+
+        /* Read label */
+        let label_list = read_label_file(&label_filename, Self::MODEL_NUMBER_OF_CLASS)?;
+
+        Ok(Classifier {
+            config: config,
+            net: net,
+            out_blob_names: out_blob_names,
+            normalize_mean: normalize_mean,
+            normalize_norm: normalize_norm,
+            label_list: label_list
+        })
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn softmax_top_k() {
+        let probability_list = softmax(&[1.0, 3.0, 2.0, 3.0]);
+        assert!((probability_list.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+        assert!(probability_list[1] > probability_list[2] && probability_list[2] > probability_list[0]);
+        assert_eq!(top_k(&probability_list, 3), vec![1, 3, 2]);
+        assert_eq!(top_k(&probability_list, 10).len(), 4);
+
+        /* Large logits don't overflow */
+        let probability_list = softmax(&[1000.0, 1000.0]);
+        assert_eq!(probability_list, vec![0.5, 0.5]);
+    }
+
+    #[test]
+    fn decode_batch() {
+        let label_list = vec!["cat".to_string(), "dog".to_string(), "bird".to_string()];
+        let logits = vec![
+            -5.0, -1.0, -3.0,       /* all negative: dog */
+            2.0, -20.0, 2.0_f32.ln() + 2.0,     /* bird (2/3), cat (1/3) */
+        ];
+        let result = decode(&logits, 3, 2, 0.0, &label_list);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0][0].class_id, 1);
+        assert_eq!(result[0][0].label, "dog");
+        assert_eq!(result[0].len(), 2);
+        assert_eq!(result[1].iter().map(|c| c.label.as_str()).collect::<Vec::<_>>(), vec!["bird", "cat"]);
+        assert!((result[1][0].probability - 2.0 / 3.0).abs() < 1e-4);
+
+        /* Threshold */
+        let result = decode(&logits, 3, 3, 0.5, &label_list);
+        assert_eq!(result[1].len(), 1);
+    }
+}
@@ -0,0 +1,98 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::core;
+
+use vision_core::bounding_box::BoundingBox;
+use vision_core::detector::Detector;
+use vision_core::error::Result;
+use vision_core::preprocess::process_crop_batch;
+
+use crate::classifier::classifier::{Classification, Classifier};
+
+
+/////////////////////////////////////////////////////////////////
+/* Detection with the result of the second stage classification */
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassifiedDetection {
+    pub bbox: BoundingBox,
+    pub classification: Option<Classification>,     /* None if the crop is empty (e.g. the box is out of the image) */
+}
+
+impl ClassifiedDetection {
+    /* e.g. "Car / sports car (0.82)" */
+    pub fn label(&self) -> String {
+        match &self.classification {
+            Some(classification) => format!("{} / {} ({:.2})", self.bbox.label, classification.label, classification.probability),
+            None => self.bbox.label.clone(),
+        }
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+/* Two-stage pipeline: detect objects, then classify the crop of each object */
+pub struct CropClassifier {
+    classifier: Classifier,
+    margin: f32,
+    batch_size: usize,
+}
+
+impl CropClassifier {
+    /* margin is the ratio to the box size added to each side of the crop (e.g. 0.1 makes the crop 1.2x) */
+    /* batch_size is the max number of crops given to the classifier at once */
+    pub fn new(classifier: Classifier, margin: f32, batch_size: usize) -> Self {
+        CropClassifier {
+            classifier: classifier,
+            margin: margin.max(0.0),
+            batch_size: batch_size.max(1),
+        }
+    }
+
+    pub fn classifier(&mut self) -> &mut Classifier {
+        &mut self.classifier
+    }
+
+    /* Run the detector, then classify each detected object */
+    pub fn process(&mut self, detector: &mut dyn Detector, mat: &core::Mat) -> Result<Vec::<ClassifiedDetection>> {
+        let bbox_list = detector.process(mat)?;
+        self.classify(mat, bbox_list)
+    }
+
+    /* Classify the crop of each box. The result is in the order of bbox_list */
+    pub fn classify(&mut self, mat: &core::Mat, bbox_list: Vec::<BoundingBox>) -> Result<Vec::<ClassifiedDetection>> {
+        /* Classify the crops in batches, and attach the top-1 class to each detection */
+        let classification_list = process_crop_batch(mat, &bbox_list, self.margin, self.batch_size, |mat_list| self.classifier.process_batch(mat_list, 1))?;
+        let classification_list = classification_list.into_iter().map(|result| result.and_then(|mut result| result.pop()));
+
+        Ok(bbox_list.into_iter().zip(classification_list)
+            .map(|(bbox, classification)| ClassifiedDetection { bbox: bbox, classification: classification })
+            .collect())
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_of_detection() {
+        let bbox = BoundingBox { class_id: 0, label: "Car".to_string(), score: 0.9, x: 0.0, y: 0.0, w: 10.0, h: 10.0 };
+        let detection = ClassifiedDetection { bbox: bbox.clone(), classification: None };
+        assert_eq!(detection.label(), "Car");
+        let detection = ClassifiedDetection { bbox: bbox, classification: Some(Classification { class_id: 817, label: "sports car".to_string(), probability: 0.824 }) };
+        assert_eq!(detection.label(), "Car / sports car (0.82)");
+    }
+}
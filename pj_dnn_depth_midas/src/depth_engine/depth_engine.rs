@@ -0,0 +1,280 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::{prelude::*, core, dnn};
+
+use vision_core::bounding_box::{BoundingBox, NmsMethod, NmsMode};
+use vision_core::config::{ModelConfig, FromModelConfig};
+use vision_core::dnn_helper::{read_net, output_to_vec, output_shape, normalize, output_blob_names};
+use vision_core::error::{Error, Result};
+use vision_core::preprocess::{resize, ResizeMode, ResizeTransform};
+
+
+/////////////////////////////////////////////////////////////////
+/* Relative depth of each pixel in the original image */
+/* MiDaS outputs relative inverse depth, so the value is normalized into [0.0, 1.0] where 1.0 is the nearest in the frame */
+pub struct DepthResult {
+    pub width: usize,
+    pub height: usize,
+    pub depth_list: Vec::<f32>,     /* row major */
+}
+
+impl DepthResult {
+    /* CV_32FC1 of the original image size */
+    pub fn to_mat(&self) -> Result<core::Mat> {
+        if self.depth_list.len() != self.width * self.height {
+            return Err(Error::OutputShape { name: "depth".to_string(), expected: self.width * self.height, actual: self.depth_list.len() });
+        }
+        let mut mat = core::Mat::new_rows_cols_with_default(self.height as i32, self.width as i32, core::CV_32FC1, core::Scalar::default()).map_err(Error::Postprocess)?;
+        if !self.depth_list.is_empty() {
+            let ptr = mat.ptr_mut(0).map_err(Error::Postprocess)?;
+            unsafe {std::ptr::copy_nonoverlapping(self.depth_list.as_ptr(), ptr as *mut f32, self.depth_list.len())};
+        }
+        Ok(mat)
+    }
+
+    /* Median of the depth in bbox. None if bbox is out of the image */
+    pub fn median_in_box(&self, bbox: &BoundingBox) -> Option<f32> {
+        median_depth(&self.depth_list, self.width, self.height, bbox)
+    }
+
+    /* Relative distance in the frame (0.0 is the nearest, 1.0 is the farthest). This is not metric */
+    pub fn relative_distance(&self, bbox: &BoundingBox) -> Option<f32> {
+        self.median_in_box(bbox).map(|depth| 1.0 - depth)
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+pub struct DepthEngine {
+    config: ModelConfig,
+    net: dnn::Net,
+    out_blob_names: core::Vector::<String>,
+    normalize_mean: core::Scalar,
+    normalize_norm: core::Scalar,
+}
+
+impl DepthEngine {
+    /* Model Parameters */
+    const MODEL_NAME: &'static str = "MiDaS-small";
+    const MODEL_FILENAME: &'static str = "../resource/model/midas_v21_small_256.onnx";
+    const MODEL_NORMALIZE_MEAN: (f64, f64, f64) = (0.485, 0.456, 0.406);
+    const MODEL_NORMALIZE_NORM: (f64, f64, f64) = (0.229, 0.224, 0.225);
+    const MODEL_WIDTH: i32 = 256;
+    const MODEL_HEIGHT: i32 = 256;
+    const MODEL_NAME_INPUT_0: &'static str = "input";
+    const MODEL_NAME_OUTPUT_0: &'static str = "output";     // relative inverse depth: [1, height, width] or [1, 1, height, width]
+
+    pub fn config(&self) -> &ModelConfig {
+        &self.config
+    }
+
+    pub fn input_size(&self) -> core::Size {
+        core::Size { width: self.config.input_width, height: self.config.input_height }
+    }
+
+    pub fn model_name(&self) -> &str {
+        Self::MODEL_NAME
+    }
+
+    pub fn process(&mut self, mat: &core::Mat) -> Result<DepthResult> {
+        /* Pre Process */
+        let (mat_resized, transform) = resize(mat, self.input_size(), self.config.resize_mode, self.config.pad_value)?;
+        let mat_normalized = normalize(&mat_resized, &self.normalize_mean, &self.normalize_norm)?;
+        let mat_blob = dnn::blob_from_image(&mat_normalized, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).map_err(Error::Preprocess)?;
+
+        /* Feed input data */
+        self.net.set_input(&mat_blob, &self.config.input_name, 1.0, core::Scalar::default()).map_err(Error::Inference)?;
+
+        /* Run inference */
+        let mut output_blobs = core::Vector::<core::Mat>::new();
+        self.net.forward(&mut output_blobs, &self.out_blob_names).map_err(Error::Inference)?;
+
+        /* Retrieve output ([1, height, width] or [1, 1, height, width]) */
+        let output_data = output_blobs.get(0).map_err(Error::Inference)?;
+        let shape = output_shape(&output_data);
+        if shape.len() < 2 {
+            return Err(Error::OutputShape { name: self.config.output_names[0].clone(), expected: 3, actual: shape.len() });
+        }
+        let (output_height, output_width) = (shape[shape.len() - 2], shape[shape.len() - 1]);
+        if output_width == 0 || output_height == 0 {
+            /* The depth map is the same size as the model input */
+            let expected = (self.config.input_width * self.config.input_height) as usize;
+            return Err(Error::OutputShape { name: self.config.output_names[0].clone(), expected: expected, actual: 0 });
+        }
+        let output_data = output_to_vec(&output_data, &self.config.output_names[0], output_width * output_height)?;
+
+        /* Normalize in the output size, then map it into the original image */
+        let depth_list = normalize_depth(&output_data);
+        let scale_x = output_width as f32 / self.config.input_width as f32;
+        let scale_y = output_height as f32 / self.config.input_height as f32;
+        let (width, height) = (mat.cols() as usize, mat.rows() as usize);
+        let depth_list = map_to_original(&depth_list, output_width, output_height, width, height, &transform, scale_x, scale_y);
+
+        Ok(DepthResult {
+            width: width,
+            height: height,
+            depth_list: depth_list,
+        })
+    }
+}
+
+/* Min-max normalization into [0.0, 1.0]. All 0.0 if the values are flat */
+pub fn normalize_depth(depth_list: &[f32]) -> Vec::<f32> {
+    let min = depth_list.iter().cloned().fold(f32::INFINITY, f32::min);
+    let max = depth_list.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    let range = max - min;
+    if range.is_nan() || range <= f32::EPSILON {
+        return vec![0.0; depth_list.len()];
+    }
+    depth_list.iter().map(|depth| (depth - min) / range).collect()
+}
+
+/* Nearest neighbor. (x, y) in the original image -> transform -> * scale -> (x, y) in the depth map */
+/* The depth map (width x height) must not be empty */
+pub fn map_to_original(depth_list: &[f32], width: usize, height: usize, original_width: usize, original_height: usize, transform: &ResizeTransform, scale_x: f32, scale_y: f32) -> Vec::<f32> {
+    let mut depth_list_original = Vec::<f32>::with_capacity(original_width * original_height);
+    for y in 0 .. original_height {
+        for x in 0 .. original_width {
+            let (model_x, model_y) = transform.to_model_point(x as f32 + 0.5, y as f32 + 0.5);
+            let map_x = ((model_x * scale_x).max(0.0) as usize).min(width - 1);
+            let map_y = ((model_y * scale_y).max(0.0) as usize).min(height - 1);
+            depth_list_original.push(depth_list[map_y * width + map_x]);
+        }
+    }
+    depth_list_original
+}
+
+/* Median of the depth in bbox (clipped into the image). The median is robust to the background around the object */
+pub fn median_depth(depth_list: &[f32], width: usize, height: usize, bbox: &BoundingBox) -> Option<f32> {
+    let (x0, y0, x1, y1) = bbox.corners();
+    let clip = |v: f32, max: usize| (v.round().max(0.0) as usize).min(max);
+    let (x0, y0, x1, y1) = (clip(x0, width), clip(y0, height), clip(x1, width), clip(y1, height));
+    if x0 >= x1 || y0 >= y1 {
+        return None;
+    }
+
+    let mut value_list = Vec::<f32>::with_capacity((x1 - x0) * (y1 - y0));
+    for y in y0 .. y1 {
+        value_list.extend_from_slice(&depth_list[y * width + x0 .. y * width + x1]);
+    }
+    value_list.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let num = value_list.len();
+    Some((value_list[(num - 1) / 2] + value_list[num / 2]) / 2.0)
+}
+
+impl FromModelConfig for DepthEngine {
+    fn default_config() -> ModelConfig {
+        ModelConfig {
+            model_filename: Self::MODEL_FILENAME.to_string(),
+            label_filename: None,
+            input_width: Self::MODEL_WIDTH,
+            input_height: Self::MODEL_HEIGHT,
+            input_name: Self::MODEL_NAME_INPUT_0.to_string(),
+            output_names: vec![Self::MODEL_NAME_OUTPUT_0.to_string()],
+            normalize_mean: Self::MODEL_NORMALIZE_MEAN,
+            normalize_norm: Self::MODEL_NORMALIZE_NORM,
+            resize_mode: ResizeMode::Stretch,
+            pad_value: (0.0, 0.0, 0.0),
+            /* Not used (see unused_keys) */
+            score_threshold: 0.0,
+            score_class_threshold: 0.0,
+            nms_iou_threshold: 0.0,
+            nms_mode: NmsMode::ClassAgnostic,
+            nms_method: NmsMethod::Hard,
+            soft_nms_sigma: 0.5,
+            max_detections: None,
+            prior_box: None,
+        }
+    }
+
+    fn unused_keys() -> &'static [&'static str] {
+        &["label_filename", "score_threshold", "score_class_threshold", "nms_iou_threshold", "nms_mode", "nms_method", "soft_nms_sigma", "max_detections", "prior_box"]
+    }
+
+    fn from_config(config: ModelConfig) -> Result<Self> {
+        if config.output_names.len() != 1 {
+            return Err(Error::Config(format!("{} requires 1 output name", Self::MODEL_NAME)));
+        }
+
+        /* Load model */
+        let net = read_net(&config.model_filename)?;
+
+        /* Set output names */
+        let out_blob_names = output_blob_names(&config.output_names);
+
+        /* Initialize normalize param */
+        let normalize_mean = core::Scalar::from(config.normalize_mean);
+        let normalize_norm = core::Scalar::from(config.normalize_norm);
+
+        Ok(DepthEngine {
+            config: config,
+            net: net,
+            out_blob_names: out_blob_names,
+            normalize_mean: normalize_mean,
+            normalize_norm: normalize_norm,
+        })
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_and_map() {
+        /* 2x2 depth map of 4x4 input (output stride = 2), for 8x4 image stretched */
+        let depth_list = normalize_depth(&[10.0, 20.0, 30.0, 50.0]);
+        assert_eq!(depth_list, vec![0.0, 0.25, 0.5, 1.0]);
+        assert_eq!(normalize_depth(&[3.0, 3.0]), vec![0.0, 0.0]);
+
+        let transform = ResizeTransform { scale_x: 0.5, scale_y: 1.0, offset_x: 0.0, offset_y: 0.0 };
+        let depth_list_original = map_to_original(&depth_list, 2, 2, 8, 4, &transform, 0.5, 0.5);
+        assert_eq!(&depth_list_original[0 .. 8], &[0.0, 0.0, 0.0, 0.0, 0.25, 0.25, 0.25, 0.25]);
+        assert_eq!(&depth_list_original[24 .. 32], &[0.5, 0.5, 0.5, 0.5, 1.0, 1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn median_in_box() {
+        /* 4x3 image. The object (0.8) is in the right half with a bit of background (0.1) */
+        let result = DepthResult {
+            width: 4,
+            height: 3,
+            depth_list: vec![
+                0.1, 0.1, 0.8, 0.8,
+                0.1, 0.1, 0.8, 0.8,
+                0.1, 0.1, 0.1, 0.8,
+            ],
+        };
+        let bbox = BoundingBox { class_id: 0, label: "car".to_string(), score: 1.0, x: 2.0, y: 0.0, w: 2.0, h: 3.0 };
+        assert_eq!(result.median_in_box(&bbox), Some(0.8));
+        assert!((result.relative_distance(&bbox).unwrap() - 0.2).abs() < 1e-6);
+
+        /* Clipped into the image, and even number of pixels */
+        let bbox = BoundingBox { x: 1.0, y: 2.0, w: 10.0, h: 10.0, ..bbox };
+        assert_eq!(result.median_in_box(&bbox), Some(0.1));
+        let bbox = BoundingBox { x: 1.0, y: 0.0, w: 2.0, h: 1.0, ..bbox };
+        assert_eq!(result.median_in_box(&bbox), Some((0.1 + 0.8) / 2.0));
+
+        let bbox = BoundingBox { x: 10.0, y: 0.0, w: 2.0, h: 2.0, ..bbox };
+        assert_eq!(result.median_in_box(&bbox), None);
+    }
+
+    #[test]
+    fn to_mat_size_mismatch() {
+        let result = DepthResult { width: 4, height: 3, depth_list: vec![0.0; 11] };
+        assert!(result.to_mat().is_err());
+    }
+}
@@ -0,0 +1,48 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::{core, imgproc};
+
+use pj_dnn_depth_midas::depth_engine::depth_engine::DepthEngine;
+use vision_core::cli::{Args, Parser};
+use vision_core::error::Error;
+use vision_core::helper_cv::colorize_depth_map;
+use vision_core::runner::{run_with, AppResult};
+
+/////////////////////////////////////////////////////////////////
+/* Usage (see --help for all the options) */
+/*   cargo run */
+/*   cargo run -- --image ../resource/dashcam_01.jpg --output result.jpg */
+/*   cargo run -- --video ../resource/Megamind.avi --output result.mp4 */
+/*   cargo run -- --dir ../resource --output result --headless */
+const DEFAULT_IMAGE_FILENAME: &str = "../resource/dashcam_00.jpg";
+const DEPTH_MAP_ALPHA: f64 = 0.6;
+
+/////////////////////////////////////////////////////////////////
+fn main() -> AppResult {
+    let args = Args::parse();
+
+    /* Create depth engine. The options (--model, --input-width, etc.) are for this engine */
+    let mut engine = args.engine_builder::<DepthEngine>()?.build()?;
+
+    run_with(&mut |mat: &mut core::Mat, _text_thickness: i32| {
+        let result = engine.process(mat)?;
+
+        /* Blend the colormapped depth */
+        let mat_depth = colorize_depth_map(&result.to_mat()?, imgproc::COLORMAP_INFERNO).map_err(Error::Postprocess)?;
+        let mut mat_blended = core::Mat::default();
+        core::add_weighted(mat, 1.0 - DEPTH_MAP_ALPHA, &mat_depth, DEPTH_MAP_ALPHA, 0.0, &mut mat_blended, -1).map_err(Error::Postprocess)?;
+        *mat = mat_blended;
+        Ok(0)
+    }, &args, DEFAULT_IMAGE_FILENAME)
+}
@@ -11,16 +11,19 @@ limitations under the License.
 ==============================================================================*/
 
 /////////////////////////////////////////////////////////////////
-use opencv::{prelude::*, core, imgproc, dnn};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use opencv::{prelude::*, core, dnn};
 
-// use crate::detection_engine::boundnig_box::BoundingBox;
-use super::boundnig_box::{BoundingBox, nms};
+use vision_core::bounding_box::{BoundingBox, NmsMethod, NmsMode, apply_nms};
+use vision_core::config::{ModelConfig, FromModelConfig};
+use vision_core::detector::Detector;
+use vision_core::dnn_helper::{read_net, read_label_file, output_to_vec, normalize, output_blob_names};
+use vision_core::error::{Error, Result};
+use vision_core::preprocess::{resize, ResizeMode};
 
 
 /////////////////////////////////////////////////////////////////
 pub struct DetectionEngine {
+    config: ModelConfig,
     net: dnn::Net,
     out_blob_names: core::Vector::<String>,
     normalize_mean: core::Scalar,
@@ -30,6 +33,7 @@ pub struct DetectionEngine {
 
 impl DetectionEngine {
     /* Model Parameters */
+    const MODEL_NAME: &'static str = "CenterNet";
     const MODEL_FILENAME: &'static str = "../resource/model/ctdet_coco_dlav0_384.onnx";
     const LABEL_FILENAME: &'static str = "../resource/model/label_coco_80.txt";
     const MODEL_NORMALIZE_MEAN: (f64, f64, f64) = (0.485, 0.456, 0.406);
@@ -41,95 +45,127 @@ impl DetectionEngine {
     const MODEL_NAME_OUTPUT_1: &'static str = "511";
     const MODEL_NAME_OUTPUT_2: &'static str = "514";
     const MODEL_HM_SCALE: i32 = 4;
-    const MODEL_HM_HEIGHT: i32  = Self::MODEL_WIDTH / Self::MODEL_HM_SCALE;
-    const MODEL_HM_WIDTH: i32   = Self::MODEL_HEIGHT / Self::MODEL_HM_SCALE;
     const MODEL_HM_CHANNEL: i32 = 80;
 
     /* Other Parameters */
     const SCORE_THRESHOLD: f32 = 0.4;
     const NMS_IOU_THRESHOLD: f32 = 0.6;
     
-    pub fn new() -> Self {
+    pub fn config(&self) -> &ModelConfig {
+        &self.config
+    }
+
+    fn sigmoid(x: f32) -> f32 {
+        if x >= 0.0 {
+            return 1.0 / (1.0 + (-x).exp());
+        } else {
+            return x.exp() / (1.0 + x.exp());   /* to aovid overflow */
+        }
+    }
+
+    fn logit(x: f32) -> f32 {
+        if x <= 0.0 {
+            return std::f32::MIN;
+        } else if x >= 1.0 {
+            return std::f32::MAX;
+        } else {
+            return (x / (1.0 - x)).ln();
+        }
+    }
+}
+
+impl FromModelConfig for DetectionEngine {
+    fn default_config() -> ModelConfig {
+        ModelConfig {
+            model_filename: Self::MODEL_FILENAME.to_string(),
+            label_filename: Some(Self::LABEL_FILENAME.to_string()),
+            input_width: Self::MODEL_WIDTH,
+            input_height: Self::MODEL_HEIGHT,
+            input_name: Self::MODEL_NAME_INPUT_0.to_string(),
+            output_names: vec![Self::MODEL_NAME_OUTPUT_0.to_string(), Self::MODEL_NAME_OUTPUT_1.to_string(), Self::MODEL_NAME_OUTPUT_2.to_string()],
+            normalize_mean: Self::MODEL_NORMALIZE_MEAN,
+            normalize_norm: Self::MODEL_NORMALIZE_NORM,
+            resize_mode: ResizeMode::Stretch,
+            pad_value: (0.0, 0.0, 0.0),
+            score_threshold: Self::SCORE_THRESHOLD,
+            score_class_threshold: Self::SCORE_THRESHOLD,
+            nms_iou_threshold: Self::NMS_IOU_THRESHOLD,
+            nms_mode: NmsMode::ClassAware,
+            nms_method: NmsMethod::Hard,
+            soft_nms_sigma: 0.5,
+            max_detections: None,
+            prior_box: None,
+        }
+    }
+
+    fn from_config(config: ModelConfig) -> Result<Self> {
+        if config.output_names.len() != 3 {
+            return Err(Error::Config(format!("{} requires 3 output names (heatmap, reg_xy, reg_wh)", Self::MODEL_NAME)));
+        }
+        let label_filename = config.label_filename.clone()
+            .ok_or_else(|| Error::Config(format!("{} requires label_filename", Self::MODEL_NAME)))?;
+
         /* Load model */
-        let mut net = dnn::read_net_from_onnx(Self::MODEL_FILENAME).unwrap();
-        net.set_preferable_backend(dnn::DNN_BACKEND_OPENCV).unwrap();
-        net.set_preferable_target(dnn::DNN_TARGET_CPU).unwrap();
-        // net.set_preferable_target(dnn::DNN_TARGET_OPENCL).unwrap();
+        let net = read_net(&config.model_filename)?;
 
         /* Set output names */
-        let mut out_blob_names = core::Vector::<String>::new();
-        out_blob_names.push(Self::MODEL_NAME_OUTPUT_0);
-        out_blob_names.push(Self::MODEL_NAME_OUTPUT_1);
-        out_blob_names.push(Self::MODEL_NAME_OUTPUT_2);
+        let out_blob_names = output_blob_names(&config.output_names);
 
         /* Initialize normalize param */
-        let normalize_mean = core::Scalar::from(Self::MODEL_NORMALIZE_MEAN);
-        let normalize_norm = core::Scalar::from(Self::MODEL_NORMALIZE_NORM);
+        let normalize_mean = core::Scalar::from(config.normalize_mean);
+        let normalize_norm = core::Scalar::from(config.normalize_norm);
 
         /* Read label */
-        let mut label_list = Vec::<String>::new();
-        let label_file = File::open(Self::LABEL_FILENAME).unwrap();
-        let reader = BufReader::new(label_file);
-        for (_, line) in reader.lines().enumerate() {
-            label_list.push(line.unwrap());
-        }
-        // println!("{:?}", label_list);
+        let label_list = read_label_file(&label_filename, Self::MODEL_HM_CHANNEL as usize)?;
         
-        DetectionEngine {
+        Ok(DetectionEngine {
+            config: config,
             net: net,
             out_blob_names: out_blob_names,
             normalize_mean: normalize_mean,
             normalize_norm: normalize_norm,
             label_list: label_list
-        }
-    }
-
-    fn normalize(&self, mat: &mut core::Mat) -> core::Mat {
-        let mut mat_normalized = Mat::default();
-        let mut mat_normalized_sub = Mat::default();
-        let mut mat_normalized_div = Mat::default();
-        mat.convert_to(&mut mat_normalized, core::CV_32FC3, 1.0 / 255.0, 0.0).unwrap();
-        core::subtract(&mat_normalized, &self.normalize_mean, &mut mat_normalized_sub, &core::no_array(), -1).unwrap();
-        core::divide2(&mat_normalized_sub, &self.normalize_norm, &mut mat_normalized_div, 1.0, -1).unwrap();
-        mat_normalized_div
+        })
     }
+}
 
-    pub fn process(&mut self, mat: &core::Mat) -> Vec::<BoundingBox> {
+impl Detector for DetectionEngine {
+    fn process(&mut self, mat: &core::Mat) -> Result<Vec::<BoundingBox>> {
         /* Pre Process */
-        let mut mat_resized = Mat::default();
-        imgproc::resize(mat, &mut mat_resized, core::Size { width: Self::MODEL_WIDTH, height: Self::MODEL_HEIGHT }, 0.0, 0.0, imgproc::INTER_LINEAR).unwrap();
-        let mat_normalized = self.normalize(&mut mat_resized);
-        let mat_blob = dnn::blob_from_image(&mat_normalized, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).unwrap();
+        let (mat_resized, transform) = resize(mat, self.input_size(), self.config.resize_mode, self.config.pad_value)?;
+        let mat_normalized = normalize(&mat_resized, &self.normalize_mean, &self.normalize_norm)?;
+        let mat_blob = dnn::blob_from_image(&mat_normalized, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).map_err(Error::Preprocess)?;
 
         /* Feed input data */
-        self.net.set_input(&mat_blob, Self::MODEL_NAME_INPUT_0, 1.0, core::Scalar::default()).unwrap();
+        self.net.set_input(&mat_blob, &self.config.input_name, 1.0, core::Scalar::default()).map_err(Error::Inference)?;
 
         /* Run inference */
         let mut output_blobs = core::Vector::<core::Mat>::new();
-        self.net.forward(&mut output_blobs, &self.out_blob_names).unwrap();
+        self.net.forward(&mut output_blobs, &self.out_blob_names).map_err(Error::Inference)?;
 
         /* Retrieve output */
-        let hm_list = &output_blobs.to_vec()[0];
-        let reg_xy_list = &output_blobs.to_vec()[1];
-        let reg_wh_list = &output_blobs.to_vec()[2];
+        let hm_list = output_blobs.get(0).map_err(Error::Inference)?;
+        let reg_xy_list = output_blobs.get(1).map_err(Error::Inference)?;
+        let reg_wh_list = output_blobs.get(2).map_err(Error::Inference)?;
 
         /* Convert the output to vector */
-        let hm_list = unsafe {std::slice::from_raw_parts(hm_list.ptr(0).unwrap() as *mut f32, hm_list.total()).to_vec()};
-        let reg_xy_list = unsafe {std::slice::from_raw_parts(reg_xy_list.ptr(0).unwrap() as *mut f32, reg_xy_list.total()).to_vec()};
-        let reg_wh_list = unsafe {std::slice::from_raw_parts(reg_wh_list.ptr(0).unwrap() as *mut f32, reg_wh_list.total()).to_vec()};
+        let hm_width = self.config.input_width / Self::MODEL_HM_SCALE;
+        let hm_height = self.config.input_height / Self::MODEL_HM_SCALE;
+        let hm_size = (hm_width * hm_height) as usize;
+        let hm_list = output_to_vec(&hm_list, &self.config.output_names[0], hm_size * Self::MODEL_HM_CHANNEL as usize)?;
+        let reg_xy_list = output_to_vec(&reg_xy_list, &self.config.output_names[1], hm_size * 2)?;
+        let reg_wh_list = output_to_vec(&reg_wh_list, &self.config.output_names[2], hm_size * 2)?;
         //  println!("{}, {}", hm_list[10], hm_list.len());
         
         /* Decode bbox */
         let mut bbox_list = Vec::<BoundingBox>::new();
-        let threshold_score_logit: f32 = Self::logit(Self::SCORE_THRESHOLD);
-        let scale_w: f32 = mat.cols() as f32 / Self::MODEL_WIDTH as f32;
-        let scale_h: f32 = mat.rows() as f32 / Self::MODEL_HEIGHT as f32;
-        for hm_y in 0 .. Self::MODEL_HM_HEIGHT {
-            for hm_x in 0 .. Self::MODEL_HM_WIDTH {
+        let threshold_score_logit: f32 = Self::logit(self.config.score_threshold);
+        for hm_y in 0 .. hm_height {
+            for hm_x in 0 .. hm_width {
                 let mut max_class_id = -1;
                 let mut max_score_logit: f32 = std::f32::MIN;
                 for class_id in 0 .. Self::MODEL_HM_CHANNEL {
-                    let index_score: usize = (hm_x + Self::MODEL_HM_WIDTH * hm_y + Self::MODEL_HM_WIDTH * Self::MODEL_HM_HEIGHT * class_id) as usize;
+                    let index_score: usize = (hm_x + hm_width * hm_y + hm_width * hm_height * class_id) as usize;
                     let score_logit = hm_list[index_score];
                     // max_score_logit = max_score_logit.max(score_logit);
                     if score_logit > max_score_logit {
@@ -138,8 +174,8 @@ impl DetectionEngine {
                     }
                 }
                 if max_score_logit > threshold_score_logit && max_class_id >= 0 {
-                    let index_x: usize = (Self::MODEL_HM_WIDTH * hm_y + hm_x) as usize;
-                    let index_y: usize = index_x + (Self::MODEL_HM_HEIGHT * Self::MODEL_HM_WIDTH) as usize;
+                    let index_x: usize = (hm_width * hm_y + hm_x) as usize;
+                    let index_y: usize = index_x + (hm_height * hm_width) as usize;
                     let width = reg_wh_list[index_x];
                     let height = reg_wh_list[index_y];
                     let cx = hm_x as f32 + reg_xy_list[index_x];  /* no need to add +0.5f according to sample code */
@@ -147,14 +183,16 @@ impl DetectionEngine {
                     let x0 = cx - width / 2.0;
                     let y0 = cy - height / 2.0;
                     // println!("{}, {}, {}, {}", x0, y0, width, height);
+                    let hm_scale = Self::MODEL_HM_SCALE as f32;
+                    let (x, y, w, h) = transform.to_original_rect(x0 * hm_scale, y0 * hm_scale, width * hm_scale, height * hm_scale);
                     let bbox = BoundingBox{
                         class_id: max_class_id,
                         label: self.label_list[max_class_id as usize].clone(),
                         score: Self::sigmoid(max_score_logit),
-                        x: (x0 * Self::MODEL_HM_SCALE as f32 * scale_w) as i32,
-                        y: (y0 * Self::MODEL_HM_SCALE as f32 * scale_h) as i32,
-                        w: (width * Self::MODEL_HM_SCALE as f32 * scale_w) as i32,
-                        h: (height * Self::MODEL_HM_SCALE as f32 * scale_h) as i32,
+                        x: x,
+                        y: y,
+                        w: w,
+                        h: h,
                     };
                     bbox_list.push(bbox);
                 }
@@ -162,27 +200,28 @@ impl DetectionEngine {
         }
 
         /* NMS */
-        let bbox_nms_list = nms(&mut bbox_list, Self::NMS_IOU_THRESHOLD);
+        let bbox_nms_list = apply_nms(&mut bbox_list, &self.config.nms_param(self.config.score_threshold));
 
-        bbox_nms_list
+        Ok(bbox_nms_list)
     }
 
-    fn sigmoid(x: f32) -> f32 {
-        if x >= 0.0 {
-            return 1.0 / (1.0 + (-x).exp());
-        } else {
-            return x.exp() / (1.0 + x.exp());   /* to aovid overflow */
-        }
+    fn input_size(&self) -> core::Size {
+        core::Size { width: self.config.input_width, height: self.config.input_height }
     }
 
-    fn logit(x: f32) -> f32 {
-        if x <= 0.0 {
-            return std::f32::MIN;
-        } else if x >= 1.0 {
-            return std::f32::MAX;
-        } else {
-            return (x / (1.0 - x)).ln();
-        }
+    fn label_list(&self) -> &[String] {
+        &self.label_list
     }
-}
 
+    fn model_name(&self) -> &str {
+        Self::MODEL_NAME
+    }
+
+    fn score_threshold(&self) -> f32 {
+        self.config.score_threshold
+    }
+
+    fn set_score_threshold(&mut self, threshold: f32) {
+        self.config.score_threshold = threshold;
+    }
+}
@@ -11,102 +11,26 @@ limitations under the License.
 ==============================================================================*/
 
 /////////////////////////////////////////////////////////////////
-#[allow(unused_imports)]
-use opencv::{prelude::*, core, highgui, imgcodecs, imgproc, videoio};
-
-mod detection_engine;
-use detection_engine::detection_engine::DetectionEngine;
-mod helper_cv;
-use helper_cv::*;
+use pj_dnn_det_centernet::detection_engine::detection_engine::DetectionEngine;
+use vision_core::cli::{Args, Parser};
+use vision_core::detector::Detector;
+use vision_core::runner::{run, AppResult};
 
 /////////////////////////////////////////////////////////////////
-#[allow(dead_code)]
-enum CaptureSource<'a> {
-    Camera(i32),
-    Video(&'a str),
-}
+/* Usage (see --help for all the options) */
+/*   cargo run */
+/*   cargo run -- --image ../resource/dog.jpg --output result.jpg */
+/*   cargo run -- --video ../resource/Megamind.avi --output result.mp4 */
+/*   cargo run -- --camera 0 */
+/*   cargo run -- --dir ../resource --output result --headless */
+const DEFAULT_IMAGE_FILENAME: &str = "../resource/dog.jpg";
 
 /////////////////////////////////////////////////////////////////
-fn main() {
-    test_with_single_image("../resource/dog.jpg");
-    // test_with_cap(CaptureSource::Camera(0));
-    // test_with_cap(CaptureSource::Video("../resource/Megamind.avi"));
-}
-
-#[allow(dead_code)]
-fn test_with_single_image(image_filename: &str) {
-    /* Create detection engine */
-    let mut engine = DetectionEngine::new();
-
-    /* Read input image */
-    let mut mat_org = imgcodecs::imread(image_filename, imgcodecs::IMREAD_COLOR).unwrap();
-
-    /* Run detection */
-    let bbox_list = engine.process(&mat_org);
-
-    /* Other */
-    let color_generator = ColorGenerator::new(20, 30);
-
-    /* Draw bounding box */
-    for bbox in bbox_list {
-        let color = color_generator.get(bbox.class_id);
-        imgproc::rectangle(&mut mat_org, core::Rect::new(bbox.x, bbox.y, bbox.w, bbox.h), color, 2, imgproc::LINE_8, 0).unwrap();
-        draw_text(&mut mat_org, &bbox.label, core::Point::new(bbox.x, bbox.y - 20), 0.6, 1, core::Scalar::new(255., 255., 255., 255.), color, true); 
-    }
-
-    highgui::imshow("result", &mat_org).unwrap();
-
-    highgui::wait_key(-1).unwrap();
-}
-
-#[allow(dead_code)]
-fn test_with_cap(capture_source: CaptureSource) {
-    /* Open capture */
-    let mut cap = match capture_source {
-        CaptureSource::Camera(id) => videoio::VideoCapture::new(id, videoio::CAP_ANY).unwrap(),
-        CaptureSource::Video(filename) => videoio::VideoCapture::from_file(filename, videoio::CAP_ANY).unwrap(),
-    };
-    if !videoio::VideoCapture::is_opened(&cap).unwrap() {
-        panic!("Unable to open capture");
-    }
+fn main() -> AppResult {
+    let args = Args::parse();
 
     /* Create detection engine */
-    let mut engine = DetectionEngine::new();
-
-    let color_generator = ColorGenerator::new(20, 30);
-
-    let mut t_all_previous = std::time::Instant::now();
-    loop {
-        /* Read image */
-        let mut mat_org = Mat::default();
-        cap.read(&mut mat_org).unwrap();
-        if mat_org.empty() {
-            break;
-        }
-
-        /* Run detection */
-        let t_detection_start = std::time::Instant::now();
-        let bbox_list = engine.process(&mat_org);
-        let t_detection = t_detection_start.elapsed();
-
-        /* Draw bounding box */
-        for bbox in bbox_list {
-            let color = color_generator.get(bbox.class_id);
-            imgproc::rectangle(&mut mat_org, core::Rect::new(bbox.x, bbox.y, bbox.w, bbox.h), color, 2, imgproc::LINE_8, 0).unwrap();
-            draw_text(&mut mat_org, &bbox.label, core::Point::new(bbox.x, bbox.y - 20), 0.6, 2, core::Scalar::new(255., 255., 255., 255.), color, true); 
-        }
-
-        /* Calculate processing time */
-        let t_all = t_all_previous.elapsed();
-        t_all_previous = std::time::Instant::now();
-        let text = format!("FPS = {:5.1}, Process = {:.1} ms", 1.0 / t_all.as_secs_f32(), t_detection.as_secs_f32() * 1000.0);
-        draw_text(&mut mat_org, &text, core::Point::new(0, 0), 0.8, 2, core::Scalar::new(255., 0., 0., 255.), core::Scalar::new(200., 200., 200., 255.), true); 
+    let mut engine: Box<dyn Detector> = Box::new(args.engine_builder::<DetectionEngine>()?.build()?);
 
-        /* Display the result image */
-        highgui::imshow("result", &mat_org).unwrap();
-        let key = highgui::wait_key(1).unwrap() as u8 as char;
-        if key == 'q' || key as u8 == 27 {
-            break;
-        }
-    }
+    run(engine.as_mut(), &args, DEFAULT_IMAGE_FILENAME)
 }
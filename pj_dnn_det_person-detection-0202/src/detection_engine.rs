@@ -11,4 +11,3 @@ limitations under the License.
 ==============================================================================*/
 
 pub mod detection_engine;
-pub mod boundnig_box;
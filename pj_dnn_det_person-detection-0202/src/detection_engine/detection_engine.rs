@@ -11,23 +11,32 @@ limitations under the License.
 ==============================================================================*/
 
 /////////////////////////////////////////////////////////////////
-use opencv::{prelude::*, core, imgproc, dnn};
+use opencv::{prelude::*, core, dnn};
 
-// use crate::detection_engine::boundnig_box::BoundingBox;
-use super::boundnig_box::{BoundingBox, nms};
+use vision_core::bounding_box::{BoundingBox, NmsMethod, NmsMode, apply_nms};
+use vision_core::config::{ModelConfig, FromModelConfig};
+use vision_core::detector::Detector;
+use vision_core::dnn_helper::{read_net, read_label_file, output_to_vec, output_blob_names};
+use vision_core::error::{Error, Result};
+use vision_core::preprocess::{resize, ResizeMode};
+use vision_core::prior_box::{PriorBoxConfig, PriorBoxLayer, PriorBoxDecoder};
 
 
 /////////////////////////////////////////////////////////////////
 pub struct DetectionEngine {
+    config: ModelConfig,
     net: dnn::Net,
     out_blob_names: core::Vector::<String>,
     normalize_mean: core::Scalar,
     normalize_norm: core::Scalar,
+    label_list: Vec::<String>,
+    prior_decoder: PriorBoxDecoder,
 }
 
 
 impl DetectionEngine {
     /* Model Parameters */
+    const MODEL_NAME: &'static str = "person-detection-0202";
     const MODEL_FILENAME: &'static str = "../resource/model/person-detection-0202_512x512.onnx";
     const MODEL_NORMALIZE_MEAN: (f64, f64, f64) = (0.0, 0.0, 0.0);
     const MODEL_NORMALIZE_NORM: (f64, f64, f64) = (1.0, 1.0, 1.0);
@@ -36,5513 +45,158 @@ impl DetectionEngine {
     const MODEL_NAME_INPUT_0: &'static str = "image";
     const MODEL_NAME_OUTPUT_0: &'static str = "tf.identity";
     const MODEL_NAME_OUTPUT_1: &'static str = "tf.identity_1";
-    const MODEL_NUM_PRIOR: i32 = 21504 / 4;
     const MODEL_NUM_CLASS: i32 = 2;
+    const MODEL_LABEL: &'static str = "Person";
 
-    /* Retrieved from 0.npy */
-    /*
-import numpy as np
-prior = np.load('0.npy')[0]
-prio_box = prior[0]
-prio_box1 = prior[1]
-for i in range(0, int(prio_box.shape[0] / 4)):
-  print(str(prio_box[i * 4 + 0]) + ", " + str(prio_box[i * 4 + 1]) + ", " + str(prio_box[i * 4 + 2]) + ", " + str(prio_box[i * 4 + 3]))
-  # print(str(prio_box1[i * 4 + 0]) + ", " + str(prio_box1[i * 4 + 1]) + ", " + str(prio_box1[i * 4 + 2]) + ", " + str(prio_box1[i * 4 + 3]))
-  */
+    /* Prior boxes (see default_prior_box) */
     const PRIOR_VARIANCE: (f32, f32, f32, f32) = (0.1, 0.1, 0.2, 0.2);
-    const PRIOR_BBOX: [f32; 4 * Self::MODEL_NUM_PRIOR as usize] = [
-        0.0078125, -0.0078125, 0.021484375, 0.0390625,
-        0.0, -0.033203125, 0.03125, 0.064453125,
-        -0.005859375, -0.0625, 0.037109375, 0.09375,
-        -0.013671875, -0.095703125, 0.044921875, 0.12695312,
-        0.0390625, -0.0078125, 0.052734375, 0.0390625,
-        0.029296875, -0.033203125, 0.0625, 0.064453125,
-        0.0234375, -0.0625, 0.068359375, 0.09375,
-        0.015625, -0.095703125, 0.076171875, 0.12695312,
-        0.0703125, -0.0078125, 0.083984375, 0.0390625,
-        0.060546875, -0.033203125, 0.09375, 0.064453125,
-        0.0546875, -0.0625, 0.099609375, 0.09375,
-        0.046875, -0.095703125, 0.107421875, 0.12695312,
-        0.1015625, -0.0078125, 0.115234375, 0.0390625,
-        0.091796875, -0.033203125, 0.125, 0.064453125,
-        0.0859375, -0.0625, 0.13085938, 0.09375,
-        0.078125, -0.095703125, 0.13867188, 0.12695312,
-        0.1328125, -0.0078125, 0.14648438, 0.0390625,
-        0.123046875, -0.033203125, 0.15625, 0.064453125,
-        0.1171875, -0.0625, 0.16210938, 0.09375,
-        0.109375, -0.095703125, 0.16992188, 0.12695312,
-        0.1640625, -0.0078125, 0.17773438, 0.0390625,
-        0.15429688, -0.033203125, 0.1875, 0.064453125,
-        0.1484375, -0.0625, 0.19335938, 0.09375,
-        0.140625, -0.095703125, 0.20117188, 0.12695312,
-        0.1953125, -0.0078125, 0.20898438, 0.0390625,
-        0.18554688, -0.033203125, 0.21875, 0.064453125,
-        0.1796875, -0.0625, 0.22460938, 0.09375,
-        0.171875, -0.095703125, 0.23242188, 0.12695312,
-        0.2265625, -0.0078125, 0.24023438, 0.0390625,
-        0.21679688, -0.033203125, 0.25, 0.064453125,
-        0.2109375, -0.0625, 0.25585938, 0.09375,
-        0.203125, -0.095703125, 0.26367188, 0.12695312,
-        0.2578125, -0.0078125, 0.27148438, 0.0390625,
-        0.24804688, -0.033203125, 0.28125, 0.064453125,
-        0.2421875, -0.0625, 0.28710938, 0.09375,
-        0.234375, -0.095703125, 0.29492188, 0.12695312,
-        0.2890625, -0.0078125, 0.30273438, 0.0390625,
-        0.27929688, -0.033203125, 0.3125, 0.064453125,
-        0.2734375, -0.0625, 0.31835938, 0.09375,
-        0.265625, -0.095703125, 0.32617188, 0.12695312,
-        0.3203125, -0.0078125, 0.33398438, 0.0390625,
-        0.31054688, -0.033203125, 0.34375, 0.064453125,
-        0.3046875, -0.0625, 0.34960938, 0.09375,
-        0.296875, -0.095703125, 0.35742188, 0.12695312,
-        0.3515625, -0.0078125, 0.36523438, 0.0390625,
-        0.34179688, -0.033203125, 0.375, 0.064453125,
-        0.3359375, -0.0625, 0.38085938, 0.09375,
-        0.328125, -0.095703125, 0.38867188, 0.12695312,
-        0.3828125, -0.0078125, 0.39648438, 0.0390625,
-        0.37304688, -0.033203125, 0.40625, 0.064453125,
-        0.3671875, -0.0625, 0.41210938, 0.09375,
-        0.359375, -0.095703125, 0.41992188, 0.12695312,
-        0.4140625, -0.0078125, 0.42773438, 0.0390625,
-        0.40429688, -0.033203125, 0.4375, 0.064453125,
-        0.3984375, -0.0625, 0.44335938, 0.09375,
-        0.390625, -0.095703125, 0.45117188, 0.12695312,
-        0.4453125, -0.0078125, 0.45898438, 0.0390625,
-        0.43554688, -0.033203125, 0.46875, 0.064453125,
-        0.4296875, -0.0625, 0.47460938, 0.09375,
-        0.421875, -0.095703125, 0.48242188, 0.12695312,
-        0.4765625, -0.0078125, 0.49023438, 0.0390625,
-        0.46679688, -0.033203125, 0.5, 0.064453125,
-        0.4609375, -0.0625, 0.5058594, 0.09375,
-        0.453125, -0.095703125, 0.5136719, 0.12695312,
-        0.5078125, -0.0078125, 0.5214844, 0.0390625,
-        0.49804688, -0.033203125, 0.53125, 0.064453125,
-        0.4921875, -0.0625, 0.5371094, 0.09375,
-        0.484375, -0.095703125, 0.5449219, 0.12695312,
-        0.5390625, -0.0078125, 0.5527344, 0.0390625,
-        0.5292969, -0.033203125, 0.5625, 0.064453125,
-        0.5234375, -0.0625, 0.5683594, 0.09375,
-        0.515625, -0.095703125, 0.5761719, 0.12695312,
-        0.5703125, -0.0078125, 0.5839844, 0.0390625,
-        0.5605469, -0.033203125, 0.59375, 0.064453125,
-        0.5546875, -0.0625, 0.5996094, 0.09375,
-        0.546875, -0.095703125, 0.6074219, 0.12695312,
-        0.6015625, -0.0078125, 0.6152344, 0.0390625,
-        0.5917969, -0.033203125, 0.625, 0.064453125,
-        0.5859375, -0.0625, 0.6308594, 0.09375,
-        0.578125, -0.095703125, 0.6386719, 0.12695312,
-        0.6328125, -0.0078125, 0.6464844, 0.0390625,
-        0.6230469, -0.033203125, 0.65625, 0.064453125,
-        0.6171875, -0.0625, 0.6621094, 0.09375,
-        0.609375, -0.095703125, 0.6699219, 0.12695312,
-        0.6640625, -0.0078125, 0.6777344, 0.0390625,
-        0.6542969, -0.033203125, 0.6875, 0.064453125,
-        0.6484375, -0.0625, 0.6933594, 0.09375,
-        0.640625, -0.095703125, 0.7011719, 0.12695312,
-        0.6953125, -0.0078125, 0.7089844, 0.0390625,
-        0.6855469, -0.033203125, 0.71875, 0.064453125,
-        0.6796875, -0.0625, 0.7246094, 0.09375,
-        0.671875, -0.095703125, 0.7324219, 0.12695312,
-        0.7265625, -0.0078125, 0.7402344, 0.0390625,
-        0.7167969, -0.033203125, 0.75, 0.064453125,
-        0.7109375, -0.0625, 0.7558594, 0.09375,
-        0.703125, -0.095703125, 0.7636719, 0.12695312,
-        0.7578125, -0.0078125, 0.7714844, 0.0390625,
-        0.7480469, -0.033203125, 0.78125, 0.064453125,
-        0.7421875, -0.0625, 0.7871094, 0.09375,
-        0.734375, -0.095703125, 0.7949219, 0.12695312,
-        0.7890625, -0.0078125, 0.8027344, 0.0390625,
-        0.7792969, -0.033203125, 0.8125, 0.064453125,
-        0.7734375, -0.0625, 0.8183594, 0.09375,
-        0.765625, -0.095703125, 0.8261719, 0.12695312,
-        0.8203125, -0.0078125, 0.8339844, 0.0390625,
-        0.8105469, -0.033203125, 0.84375, 0.064453125,
-        0.8046875, -0.0625, 0.8496094, 0.09375,
-        0.796875, -0.095703125, 0.8574219, 0.12695312,
-        0.8515625, -0.0078125, 0.8652344, 0.0390625,
-        0.8417969, -0.033203125, 0.875, 0.064453125,
-        0.8359375, -0.0625, 0.8808594, 0.09375,
-        0.828125, -0.095703125, 0.8886719, 0.12695312,
-        0.8828125, -0.0078125, 0.8964844, 0.0390625,
-        0.8730469, -0.033203125, 0.90625, 0.064453125,
-        0.8671875, -0.0625, 0.9121094, 0.09375,
-        0.859375, -0.095703125, 0.9199219, 0.12695312,
-        0.9140625, -0.0078125, 0.9277344, 0.0390625,
-        0.9042969, -0.033203125, 0.9375, 0.064453125,
-        0.8984375, -0.0625, 0.9433594, 0.09375,
-        0.890625, -0.095703125, 0.9511719, 0.12695312,
-        0.9453125, -0.0078125, 0.9589844, 0.0390625,
-        0.9355469, -0.033203125, 0.96875, 0.064453125,
-        0.9296875, -0.0625, 0.9746094, 0.09375,
-        0.921875, -0.095703125, 0.9824219, 0.12695312,
-        0.9765625, -0.0078125, 0.9902344, 0.0390625,
-        0.9667969, -0.033203125, 1.0, 0.064453125,
-        0.9609375, -0.0625, 1.0058594, 0.09375,
-        0.953125, -0.095703125, 1.0136719, 0.12695312,
-        0.0078125, 0.021484375, 0.021484375, 0.0703125,
-        0.0, -0.001953125, 0.03125, 0.095703125,
-        -0.005859375, -0.03125, 0.037109375, 0.125,
-        -0.013671875, -0.064453125, 0.044921875, 0.15820312,
-        0.0390625, 0.021484375, 0.052734375, 0.0703125,
-        0.029296875, -0.001953125, 0.0625, 0.095703125,
-        0.0234375, -0.03125, 0.068359375, 0.125,
-        0.015625, -0.064453125, 0.076171875, 0.15820312,
-        0.0703125, 0.021484375, 0.083984375, 0.0703125,
-        0.060546875, -0.001953125, 0.09375, 0.095703125,
-        0.0546875, -0.03125, 0.099609375, 0.125,
-        0.046875, -0.064453125, 0.107421875, 0.15820312,
-        0.1015625, 0.021484375, 0.115234375, 0.0703125,
-        0.091796875, -0.001953125, 0.125, 0.095703125,
-        0.0859375, -0.03125, 0.13085938, 0.125,
-        0.078125, -0.064453125, 0.13867188, 0.15820312,
-        0.1328125, 0.021484375, 0.14648438, 0.0703125,
-        0.123046875, -0.001953125, 0.15625, 0.095703125,
-        0.1171875, -0.03125, 0.16210938, 0.125,
-        0.109375, -0.064453125, 0.16992188, 0.15820312,
-        0.1640625, 0.021484375, 0.17773438, 0.0703125,
-        0.15429688, -0.001953125, 0.1875, 0.095703125,
-        0.1484375, -0.03125, 0.19335938, 0.125,
-        0.140625, -0.064453125, 0.20117188, 0.15820312,
-        0.1953125, 0.021484375, 0.20898438, 0.0703125,
-        0.18554688, -0.001953125, 0.21875, 0.095703125,
-        0.1796875, -0.03125, 0.22460938, 0.125,
-        0.171875, -0.064453125, 0.23242188, 0.15820312,
-        0.2265625, 0.021484375, 0.24023438, 0.0703125,
-        0.21679688, -0.001953125, 0.25, 0.095703125,
-        0.2109375, -0.03125, 0.25585938, 0.125,
-        0.203125, -0.064453125, 0.26367188, 0.15820312,
-        0.2578125, 0.021484375, 0.27148438, 0.0703125,
-        0.24804688, -0.001953125, 0.28125, 0.095703125,
-        0.2421875, -0.03125, 0.28710938, 0.125,
-        0.234375, -0.064453125, 0.29492188, 0.15820312,
-        0.2890625, 0.021484375, 0.30273438, 0.0703125,
-        0.27929688, -0.001953125, 0.3125, 0.095703125,
-        0.2734375, -0.03125, 0.31835938, 0.125,
-        0.265625, -0.064453125, 0.32617188, 0.15820312,
-        0.3203125, 0.021484375, 0.33398438, 0.0703125,
-        0.31054688, -0.001953125, 0.34375, 0.095703125,
-        0.3046875, -0.03125, 0.34960938, 0.125,
-        0.296875, -0.064453125, 0.35742188, 0.15820312,
-        0.3515625, 0.021484375, 0.36523438, 0.0703125,
-        0.34179688, -0.001953125, 0.375, 0.095703125,
-        0.3359375, -0.03125, 0.38085938, 0.125,
-        0.328125, -0.064453125, 0.38867188, 0.15820312,
-        0.3828125, 0.021484375, 0.39648438, 0.0703125,
-        0.37304688, -0.001953125, 0.40625, 0.095703125,
-        0.3671875, -0.03125, 0.41210938, 0.125,
-        0.359375, -0.064453125, 0.41992188, 0.15820312,
-        0.4140625, 0.021484375, 0.42773438, 0.0703125,
-        0.40429688, -0.001953125, 0.4375, 0.095703125,
-        0.3984375, -0.03125, 0.44335938, 0.125,
-        0.390625, -0.064453125, 0.45117188, 0.15820312,
-        0.4453125, 0.021484375, 0.45898438, 0.0703125,
-        0.43554688, -0.001953125, 0.46875, 0.095703125,
-        0.4296875, -0.03125, 0.47460938, 0.125,
-        0.421875, -0.064453125, 0.48242188, 0.15820312,
-        0.4765625, 0.021484375, 0.49023438, 0.0703125,
-        0.46679688, -0.001953125, 0.5, 0.095703125,
-        0.4609375, -0.03125, 0.5058594, 0.125,
-        0.453125, -0.064453125, 0.5136719, 0.15820312,
-        0.5078125, 0.021484375, 0.5214844, 0.0703125,
-        0.49804688, -0.001953125, 0.53125, 0.095703125,
-        0.4921875, -0.03125, 0.5371094, 0.125,
-        0.484375, -0.064453125, 0.5449219, 0.15820312,
-        0.5390625, 0.021484375, 0.5527344, 0.0703125,
-        0.5292969, -0.001953125, 0.5625, 0.095703125,
-        0.5234375, -0.03125, 0.5683594, 0.125,
-        0.515625, -0.064453125, 0.5761719, 0.15820312,
-        0.5703125, 0.021484375, 0.5839844, 0.0703125,
-        0.5605469, -0.001953125, 0.59375, 0.095703125,
-        0.5546875, -0.03125, 0.5996094, 0.125,
-        0.546875, -0.064453125, 0.6074219, 0.15820312,
-        0.6015625, 0.021484375, 0.6152344, 0.0703125,
-        0.5917969, -0.001953125, 0.625, 0.095703125,
-        0.5859375, -0.03125, 0.6308594, 0.125,
-        0.578125, -0.064453125, 0.6386719, 0.15820312,
-        0.6328125, 0.021484375, 0.6464844, 0.0703125,
-        0.6230469, -0.001953125, 0.65625, 0.095703125,
-        0.6171875, -0.03125, 0.6621094, 0.125,
-        0.609375, -0.064453125, 0.6699219, 0.15820312,
-        0.6640625, 0.021484375, 0.6777344, 0.0703125,
-        0.6542969, -0.001953125, 0.6875, 0.095703125,
-        0.6484375, -0.03125, 0.6933594, 0.125,
-        0.640625, -0.064453125, 0.7011719, 0.15820312,
-        0.6953125, 0.021484375, 0.7089844, 0.0703125,
-        0.6855469, -0.001953125, 0.71875, 0.095703125,
-        0.6796875, -0.03125, 0.7246094, 0.125,
-        0.671875, -0.064453125, 0.7324219, 0.15820312,
-        0.7265625, 0.021484375, 0.7402344, 0.0703125,
-        0.7167969, -0.001953125, 0.75, 0.095703125,
-        0.7109375, -0.03125, 0.7558594, 0.125,
-        0.703125, -0.064453125, 0.7636719, 0.15820312,
-        0.7578125, 0.021484375, 0.7714844, 0.0703125,
-        0.7480469, -0.001953125, 0.78125, 0.095703125,
-        0.7421875, -0.03125, 0.7871094, 0.125,
-        0.734375, -0.064453125, 0.7949219, 0.15820312,
-        0.7890625, 0.021484375, 0.8027344, 0.0703125,
-        0.7792969, -0.001953125, 0.8125, 0.095703125,
-        0.7734375, -0.03125, 0.8183594, 0.125,
-        0.765625, -0.064453125, 0.8261719, 0.15820312,
-        0.8203125, 0.021484375, 0.8339844, 0.0703125,
-        0.8105469, -0.001953125, 0.84375, 0.095703125,
-        0.8046875, -0.03125, 0.8496094, 0.125,
-        0.796875, -0.064453125, 0.8574219, 0.15820312,
-        0.8515625, 0.021484375, 0.8652344, 0.0703125,
-        0.8417969, -0.001953125, 0.875, 0.095703125,
-        0.8359375, -0.03125, 0.8808594, 0.125,
-        0.828125, -0.064453125, 0.8886719, 0.15820312,
-        0.8828125, 0.021484375, 0.8964844, 0.0703125,
-        0.8730469, -0.001953125, 0.90625, 0.095703125,
-        0.8671875, -0.03125, 0.9121094, 0.125,
-        0.859375, -0.064453125, 0.9199219, 0.15820312,
-        0.9140625, 0.021484375, 0.9277344, 0.0703125,
-        0.9042969, -0.001953125, 0.9375, 0.095703125,
-        0.8984375, -0.03125, 0.9433594, 0.125,
-        0.890625, -0.064453125, 0.9511719, 0.15820312,
-        0.9453125, 0.021484375, 0.9589844, 0.0703125,
-        0.9355469, -0.001953125, 0.96875, 0.095703125,
-        0.9296875, -0.03125, 0.9746094, 0.125,
-        0.921875, -0.064453125, 0.9824219, 0.15820312,
-        0.9765625, 0.021484375, 0.9902344, 0.0703125,
-        0.9667969, -0.001953125, 1.0, 0.095703125,
-        0.9609375, -0.03125, 1.0058594, 0.125,
-        0.953125, -0.064453125, 1.0136719, 0.15820312,
-        0.0078125, 0.052734375, 0.021484375, 0.1015625,
-        0.0, 0.02734375, 0.03125, 0.12695312,
-        -0.005859375, 0.0, 0.037109375, 0.15625,
-        -0.013671875, -0.033203125, 0.044921875, 0.18945312,
-        0.0390625, 0.052734375, 0.052734375, 0.1015625,
-        0.029296875, 0.02734375, 0.0625, 0.12695312,
-        0.0234375, 0.0, 0.068359375, 0.15625,
-        0.015625, -0.033203125, 0.076171875, 0.18945312,
-        0.0703125, 0.052734375, 0.083984375, 0.1015625,
-        0.060546875, 0.02734375, 0.09375, 0.12695312,
-        0.0546875, 0.0, 0.099609375, 0.15625,
-        0.046875, -0.033203125, 0.107421875, 0.18945312,
-        0.1015625, 0.052734375, 0.115234375, 0.1015625,
-        0.091796875, 0.02734375, 0.125, 0.12695312,
-        0.0859375, 0.0, 0.13085938, 0.15625,
-        0.078125, -0.033203125, 0.13867188, 0.18945312,
-        0.1328125, 0.052734375, 0.14648438, 0.1015625,
-        0.123046875, 0.02734375, 0.15625, 0.12695312,
-        0.1171875, 0.0, 0.16210938, 0.15625,
-        0.109375, -0.033203125, 0.16992188, 0.18945312,
-        0.1640625, 0.052734375, 0.17773438, 0.1015625,
-        0.15429688, 0.02734375, 0.1875, 0.12695312,
-        0.1484375, 0.0, 0.19335938, 0.15625,
-        0.140625, -0.033203125, 0.20117188, 0.18945312,
-        0.1953125, 0.052734375, 0.20898438, 0.1015625,
-        0.18554688, 0.02734375, 0.21875, 0.12695312,
-        0.1796875, 0.0, 0.22460938, 0.15625,
-        0.171875, -0.033203125, 0.23242188, 0.18945312,
-        0.2265625, 0.052734375, 0.24023438, 0.1015625,
-        0.21679688, 0.02734375, 0.25, 0.12695312,
-        0.2109375, 0.0, 0.25585938, 0.15625,
-        0.203125, -0.033203125, 0.26367188, 0.18945312,
-        0.2578125, 0.052734375, 0.27148438, 0.1015625,
-        0.24804688, 0.02734375, 0.28125, 0.12695312,
-        0.2421875, 0.0, 0.28710938, 0.15625,
-        0.234375, -0.033203125, 0.29492188, 0.18945312,
-        0.2890625, 0.052734375, 0.30273438, 0.1015625,
-        0.27929688, 0.02734375, 0.3125, 0.12695312,
-        0.2734375, 0.0, 0.31835938, 0.15625,
-        0.265625, -0.033203125, 0.32617188, 0.18945312,
-        0.3203125, 0.052734375, 0.33398438, 0.1015625,
-        0.31054688, 0.02734375, 0.34375, 0.12695312,
-        0.3046875, 0.0, 0.34960938, 0.15625,
-        0.296875, -0.033203125, 0.35742188, 0.18945312,
-        0.3515625, 0.052734375, 0.36523438, 0.1015625,
-        0.34179688, 0.02734375, 0.375, 0.12695312,
-        0.3359375, 0.0, 0.38085938, 0.15625,
-        0.328125, -0.033203125, 0.38867188, 0.18945312,
-        0.3828125, 0.052734375, 0.39648438, 0.1015625,
-        0.37304688, 0.02734375, 0.40625, 0.12695312,
-        0.3671875, 0.0, 0.41210938, 0.15625,
-        0.359375, -0.033203125, 0.41992188, 0.18945312,
-        0.4140625, 0.052734375, 0.42773438, 0.1015625,
-        0.40429688, 0.02734375, 0.4375, 0.12695312,
-        0.3984375, 0.0, 0.44335938, 0.15625,
-        0.390625, -0.033203125, 0.45117188, 0.18945312,
-        0.4453125, 0.052734375, 0.45898438, 0.1015625,
-        0.43554688, 0.02734375, 0.46875, 0.12695312,
-        0.4296875, 0.0, 0.47460938, 0.15625,
-        0.421875, -0.033203125, 0.48242188, 0.18945312,
-        0.4765625, 0.052734375, 0.49023438, 0.1015625,
-        0.46679688, 0.02734375, 0.5, 0.12695312,
-        0.4609375, 0.0, 0.5058594, 0.15625,
-        0.453125, -0.033203125, 0.5136719, 0.18945312,
-        0.5078125, 0.052734375, 0.5214844, 0.1015625,
-        0.49804688, 0.02734375, 0.53125, 0.12695312,
-        0.4921875, 0.0, 0.5371094, 0.15625,
-        0.484375, -0.033203125, 0.5449219, 0.18945312,
-        0.5390625, 0.052734375, 0.5527344, 0.1015625,
-        0.5292969, 0.02734375, 0.5625, 0.12695312,
-        0.5234375, 0.0, 0.5683594, 0.15625,
-        0.515625, -0.033203125, 0.5761719, 0.18945312,
-        0.5703125, 0.052734375, 0.5839844, 0.1015625,
-        0.5605469, 0.02734375, 0.59375, 0.12695312,
-        0.5546875, 0.0, 0.5996094, 0.15625,
-        0.546875, -0.033203125, 0.6074219, 0.18945312,
-        0.6015625, 0.052734375, 0.6152344, 0.1015625,
-        0.5917969, 0.02734375, 0.625, 0.12695312,
-        0.5859375, 0.0, 0.6308594, 0.15625,
-        0.578125, -0.033203125, 0.6386719, 0.18945312,
-        0.6328125, 0.052734375, 0.6464844, 0.1015625,
-        0.6230469, 0.02734375, 0.65625, 0.12695312,
-        0.6171875, 0.0, 0.6621094, 0.15625,
-        0.609375, -0.033203125, 0.6699219, 0.18945312,
-        0.6640625, 0.052734375, 0.6777344, 0.1015625,
-        0.6542969, 0.02734375, 0.6875, 0.12695312,
-        0.6484375, 0.0, 0.6933594, 0.15625,
-        0.640625, -0.033203125, 0.7011719, 0.18945312,
-        0.6953125, 0.052734375, 0.7089844, 0.1015625,
-        0.6855469, 0.02734375, 0.71875, 0.12695312,
-        0.6796875, 0.0, 0.7246094, 0.15625,
-        0.671875, -0.033203125, 0.7324219, 0.18945312,
-        0.7265625, 0.052734375, 0.7402344, 0.1015625,
-        0.7167969, 0.02734375, 0.75, 0.12695312,
-        0.7109375, 0.0, 0.7558594, 0.15625,
-        0.703125, -0.033203125, 0.7636719, 0.18945312,
-        0.7578125, 0.052734375, 0.7714844, 0.1015625,
-        0.7480469, 0.02734375, 0.78125, 0.12695312,
-        0.7421875, 0.0, 0.7871094, 0.15625,
-        0.734375, -0.033203125, 0.7949219, 0.18945312,
-        0.7890625, 0.052734375, 0.8027344, 0.1015625,
-        0.7792969, 0.02734375, 0.8125, 0.12695312,
-        0.7734375, 0.0, 0.8183594, 0.15625,
-        0.765625, -0.033203125, 0.8261719, 0.18945312,
-        0.8203125, 0.052734375, 0.8339844, 0.1015625,
-        0.8105469, 0.02734375, 0.84375, 0.12695312,
-        0.8046875, 0.0, 0.8496094, 0.15625,
-        0.796875, -0.033203125, 0.8574219, 0.18945312,
-        0.8515625, 0.052734375, 0.8652344, 0.1015625,
-        0.8417969, 0.02734375, 0.875, 0.12695312,
-        0.8359375, 0.0, 0.8808594, 0.15625,
-        0.828125, -0.033203125, 0.8886719, 0.18945312,
-        0.8828125, 0.052734375, 0.8964844, 0.1015625,
-        0.8730469, 0.02734375, 0.90625, 0.12695312,
-        0.8671875, 0.0, 0.9121094, 0.15625,
-        0.859375, -0.033203125, 0.9199219, 0.18945312,
-        0.9140625, 0.052734375, 0.9277344, 0.1015625,
-        0.9042969, 0.02734375, 0.9375, 0.12695312,
-        0.8984375, 0.0, 0.9433594, 0.15625,
-        0.890625, -0.033203125, 0.9511719, 0.18945312,
-        0.9453125, 0.052734375, 0.9589844, 0.1015625,
-        0.9355469, 0.02734375, 0.96875, 0.12695312,
-        0.9296875, 0.0, 0.9746094, 0.15625,
-        0.921875, -0.033203125, 0.9824219, 0.18945312,
-        0.9765625, 0.052734375, 0.9902344, 0.1015625,
-        0.9667969, 0.02734375, 1.0, 0.12695312,
-        0.9609375, 0.0, 1.0058594, 0.15625,
-        0.953125, -0.033203125, 1.0136719, 0.18945312,
-        0.0078125, 0.083984375, 0.021484375, 0.1328125,
-        0.0, 0.05859375, 0.03125, 0.15820312,
-        -0.005859375, 0.029296875, 0.037109375, 0.1875,
-        -0.013671875, -0.001953125, 0.044921875, 0.22070312,
-        0.0390625, 0.083984375, 0.052734375, 0.1328125,
-        0.029296875, 0.05859375, 0.0625, 0.15820312,
-        0.0234375, 0.029296875, 0.068359375, 0.1875,
-        0.015625, -0.001953125, 0.076171875, 0.22070312,
-        0.0703125, 0.083984375, 0.083984375, 0.1328125,
-        0.060546875, 0.05859375, 0.09375, 0.15820312,
-        0.0546875, 0.029296875, 0.099609375, 0.1875,
-        0.046875, -0.001953125, 0.107421875, 0.22070312,
-        0.1015625, 0.083984375, 0.115234375, 0.1328125,
-        0.091796875, 0.05859375, 0.125, 0.15820312,
-        0.0859375, 0.029296875, 0.13085938, 0.1875,
-        0.078125, -0.001953125, 0.13867188, 0.22070312,
-        0.1328125, 0.083984375, 0.14648438, 0.1328125,
-        0.123046875, 0.05859375, 0.15625, 0.15820312,
-        0.1171875, 0.029296875, 0.16210938, 0.1875,
-        0.109375, -0.001953125, 0.16992188, 0.22070312,
-        0.1640625, 0.083984375, 0.17773438, 0.1328125,
-        0.15429688, 0.05859375, 0.1875, 0.15820312,
-        0.1484375, 0.029296875, 0.19335938, 0.1875,
-        0.140625, -0.001953125, 0.20117188, 0.22070312,
-        0.1953125, 0.083984375, 0.20898438, 0.1328125,
-        0.18554688, 0.05859375, 0.21875, 0.15820312,
-        0.1796875, 0.029296875, 0.22460938, 0.1875,
-        0.171875, -0.001953125, 0.23242188, 0.22070312,
-        0.2265625, 0.083984375, 0.24023438, 0.1328125,
-        0.21679688, 0.05859375, 0.25, 0.15820312,
-        0.2109375, 0.029296875, 0.25585938, 0.1875,
-        0.203125, -0.001953125, 0.26367188, 0.22070312,
-        0.2578125, 0.083984375, 0.27148438, 0.1328125,
-        0.24804688, 0.05859375, 0.28125, 0.15820312,
-        0.2421875, 0.029296875, 0.28710938, 0.1875,
-        0.234375, -0.001953125, 0.29492188, 0.22070312,
-        0.2890625, 0.083984375, 0.30273438, 0.1328125,
-        0.27929688, 0.05859375, 0.3125, 0.15820312,
-        0.2734375, 0.029296875, 0.31835938, 0.1875,
-        0.265625, -0.001953125, 0.32617188, 0.22070312,
-        0.3203125, 0.083984375, 0.33398438, 0.1328125,
-        0.31054688, 0.05859375, 0.34375, 0.15820312,
-        0.3046875, 0.029296875, 0.34960938, 0.1875,
-        0.296875, -0.001953125, 0.35742188, 0.22070312,
-        0.3515625, 0.083984375, 0.36523438, 0.1328125,
-        0.34179688, 0.05859375, 0.375, 0.15820312,
-        0.3359375, 0.029296875, 0.38085938, 0.1875,
-        0.328125, -0.001953125, 0.38867188, 0.22070312,
-        0.3828125, 0.083984375, 0.39648438, 0.1328125,
-        0.37304688, 0.05859375, 0.40625, 0.15820312,
-        0.3671875, 0.029296875, 0.41210938, 0.1875,
-        0.359375, -0.001953125, 0.41992188, 0.22070312,
-        0.4140625, 0.083984375, 0.42773438, 0.1328125,
-        0.40429688, 0.05859375, 0.4375, 0.15820312,
-        0.3984375, 0.029296875, 0.44335938, 0.1875,
-        0.390625, -0.001953125, 0.45117188, 0.22070312,
-        0.4453125, 0.083984375, 0.45898438, 0.1328125,
-        0.43554688, 0.05859375, 0.46875, 0.15820312,
-        0.4296875, 0.029296875, 0.47460938, 0.1875,
-        0.421875, -0.001953125, 0.48242188, 0.22070312,
-        0.4765625, 0.083984375, 0.49023438, 0.1328125,
-        0.46679688, 0.05859375, 0.5, 0.15820312,
-        0.4609375, 0.029296875, 0.5058594, 0.1875,
-        0.453125, -0.001953125, 0.5136719, 0.22070312,
-        0.5078125, 0.083984375, 0.5214844, 0.1328125,
-        0.49804688, 0.05859375, 0.53125, 0.15820312,
-        0.4921875, 0.029296875, 0.5371094, 0.1875,
-        0.484375, -0.001953125, 0.5449219, 0.22070312,
-        0.5390625, 0.083984375, 0.5527344, 0.1328125,
-        0.5292969, 0.05859375, 0.5625, 0.15820312,
-        0.5234375, 0.029296875, 0.5683594, 0.1875,
-        0.515625, -0.001953125, 0.5761719, 0.22070312,
-        0.5703125, 0.083984375, 0.5839844, 0.1328125,
-        0.5605469, 0.05859375, 0.59375, 0.15820312,
-        0.5546875, 0.029296875, 0.5996094, 0.1875,
-        0.546875, -0.001953125, 0.6074219, 0.22070312,
-        0.6015625, 0.083984375, 0.6152344, 0.1328125,
-        0.5917969, 0.05859375, 0.625, 0.15820312,
-        0.5859375, 0.029296875, 0.6308594, 0.1875,
-        0.578125, -0.001953125, 0.6386719, 0.22070312,
-        0.6328125, 0.083984375, 0.6464844, 0.1328125,
-        0.6230469, 0.05859375, 0.65625, 0.15820312,
-        0.6171875, 0.029296875, 0.6621094, 0.1875,
-        0.609375, -0.001953125, 0.6699219, 0.22070312,
-        0.6640625, 0.083984375, 0.6777344, 0.1328125,
-        0.6542969, 0.05859375, 0.6875, 0.15820312,
-        0.6484375, 0.029296875, 0.6933594, 0.1875,
-        0.640625, -0.001953125, 0.7011719, 0.22070312,
-        0.6953125, 0.083984375, 0.7089844, 0.1328125,
-        0.6855469, 0.05859375, 0.71875, 0.15820312,
-        0.6796875, 0.029296875, 0.7246094, 0.1875,
-        0.671875, -0.001953125, 0.7324219, 0.22070312,
-        0.7265625, 0.083984375, 0.7402344, 0.1328125,
-        0.7167969, 0.05859375, 0.75, 0.15820312,
-        0.7109375, 0.029296875, 0.7558594, 0.1875,
-        0.703125, -0.001953125, 0.7636719, 0.22070312,
-        0.7578125, 0.083984375, 0.7714844, 0.1328125,
-        0.7480469, 0.05859375, 0.78125, 0.15820312,
-        0.7421875, 0.029296875, 0.7871094, 0.1875,
-        0.734375, -0.001953125, 0.7949219, 0.22070312,
-        0.7890625, 0.083984375, 0.8027344, 0.1328125,
-        0.7792969, 0.05859375, 0.8125, 0.15820312,
-        0.7734375, 0.029296875, 0.8183594, 0.1875,
-        0.765625, -0.001953125, 0.8261719, 0.22070312,
-        0.8203125, 0.083984375, 0.8339844, 0.1328125,
-        0.8105469, 0.05859375, 0.84375, 0.15820312,
-        0.8046875, 0.029296875, 0.8496094, 0.1875,
-        0.796875, -0.001953125, 0.8574219, 0.22070312,
-        0.8515625, 0.083984375, 0.8652344, 0.1328125,
-        0.8417969, 0.05859375, 0.875, 0.15820312,
-        0.8359375, 0.029296875, 0.8808594, 0.1875,
-        0.828125, -0.001953125, 0.8886719, 0.22070312,
-        0.8828125, 0.083984375, 0.8964844, 0.1328125,
-        0.8730469, 0.05859375, 0.90625, 0.15820312,
-        0.8671875, 0.029296875, 0.9121094, 0.1875,
-        0.859375, -0.001953125, 0.9199219, 0.22070312,
-        0.9140625, 0.083984375, 0.9277344, 0.1328125,
-        0.9042969, 0.05859375, 0.9375, 0.15820312,
-        0.8984375, 0.029296875, 0.9433594, 0.1875,
-        0.890625, -0.001953125, 0.9511719, 0.22070312,
-        0.9453125, 0.083984375, 0.9589844, 0.1328125,
-        0.9355469, 0.05859375, 0.96875, 0.15820312,
-        0.9296875, 0.029296875, 0.9746094, 0.1875,
-        0.921875, -0.001953125, 0.9824219, 0.22070312,
-        0.9765625, 0.083984375, 0.9902344, 0.1328125,
-        0.9667969, 0.05859375, 1.0, 0.15820312,
-        0.9609375, 0.029296875, 1.0058594, 0.1875,
-        0.953125, -0.001953125, 1.0136719, 0.22070312,
-        0.0078125, 0.115234375, 0.021484375, 0.1640625,
-        0.0, 0.08984375, 0.03125, 0.18945312,
-        -0.005859375, 0.060546875, 0.037109375, 0.21875,
-        -0.013671875, 0.02734375, 0.044921875, 0.25195312,
-        0.0390625, 0.115234375, 0.052734375, 0.1640625,
-        0.029296875, 0.08984375, 0.0625, 0.18945312,
-        0.0234375, 0.060546875, 0.068359375, 0.21875,
-        0.015625, 0.02734375, 0.076171875, 0.25195312,
-        0.0703125, 0.115234375, 0.083984375, 0.1640625,
-        0.060546875, 0.08984375, 0.09375, 0.18945312,
-        0.0546875, 0.060546875, 0.099609375, 0.21875,
-        0.046875, 0.02734375, 0.107421875, 0.25195312,
-        0.1015625, 0.115234375, 0.115234375, 0.1640625,
-        0.091796875, 0.08984375, 0.125, 0.18945312,
-        0.0859375, 0.060546875, 0.13085938, 0.21875,
-        0.078125, 0.02734375, 0.13867188, 0.25195312,
-        0.1328125, 0.115234375, 0.14648438, 0.1640625,
-        0.123046875, 0.08984375, 0.15625, 0.18945312,
-        0.1171875, 0.060546875, 0.16210938, 0.21875,
-        0.109375, 0.02734375, 0.16992188, 0.25195312,
-        0.1640625, 0.115234375, 0.17773438, 0.1640625,
-        0.15429688, 0.08984375, 0.1875, 0.18945312,
-        0.1484375, 0.060546875, 0.19335938, 0.21875,
-        0.140625, 0.02734375, 0.20117188, 0.25195312,
-        0.1953125, 0.115234375, 0.20898438, 0.1640625,
-        0.18554688, 0.08984375, 0.21875, 0.18945312,
-        0.1796875, 0.060546875, 0.22460938, 0.21875,
-        0.171875, 0.02734375, 0.23242188, 0.25195312,
-        0.2265625, 0.115234375, 0.24023438, 0.1640625,
-        0.21679688, 0.08984375, 0.25, 0.18945312,
-        0.2109375, 0.060546875, 0.25585938, 0.21875,
-        0.203125, 0.02734375, 0.26367188, 0.25195312,
-        0.2578125, 0.115234375, 0.27148438, 0.1640625,
-        0.24804688, 0.08984375, 0.28125, 0.18945312,
-        0.2421875, 0.060546875, 0.28710938, 0.21875,
-        0.234375, 0.02734375, 0.29492188, 0.25195312,
-        0.2890625, 0.115234375, 0.30273438, 0.1640625,
-        0.27929688, 0.08984375, 0.3125, 0.18945312,
-        0.2734375, 0.060546875, 0.31835938, 0.21875,
-        0.265625, 0.02734375, 0.32617188, 0.25195312,
-        0.3203125, 0.115234375, 0.33398438, 0.1640625,
-        0.31054688, 0.08984375, 0.34375, 0.18945312,
-        0.3046875, 0.060546875, 0.34960938, 0.21875,
-        0.296875, 0.02734375, 0.35742188, 0.25195312,
-        0.3515625, 0.115234375, 0.36523438, 0.1640625,
-        0.34179688, 0.08984375, 0.375, 0.18945312,
-        0.3359375, 0.060546875, 0.38085938, 0.21875,
-        0.328125, 0.02734375, 0.38867188, 0.25195312,
-        0.3828125, 0.115234375, 0.39648438, 0.1640625,
-        0.37304688, 0.08984375, 0.40625, 0.18945312,
-        0.3671875, 0.060546875, 0.41210938, 0.21875,
-        0.359375, 0.02734375, 0.41992188, 0.25195312,
-        0.4140625, 0.115234375, 0.42773438, 0.1640625,
-        0.40429688, 0.08984375, 0.4375, 0.18945312,
-        0.3984375, 0.060546875, 0.44335938, 0.21875,
-        0.390625, 0.02734375, 0.45117188, 0.25195312,
-        0.4453125, 0.115234375, 0.45898438, 0.1640625,
-        0.43554688, 0.08984375, 0.46875, 0.18945312,
-        0.4296875, 0.060546875, 0.47460938, 0.21875,
-        0.421875, 0.02734375, 0.48242188, 0.25195312,
-        0.4765625, 0.115234375, 0.49023438, 0.1640625,
-        0.46679688, 0.08984375, 0.5, 0.18945312,
-        0.4609375, 0.060546875, 0.5058594, 0.21875,
-        0.453125, 0.02734375, 0.5136719, 0.25195312,
-        0.5078125, 0.115234375, 0.5214844, 0.1640625,
-        0.49804688, 0.08984375, 0.53125, 0.18945312,
-        0.4921875, 0.060546875, 0.5371094, 0.21875,
-        0.484375, 0.02734375, 0.5449219, 0.25195312,
-        0.5390625, 0.115234375, 0.5527344, 0.1640625,
-        0.5292969, 0.08984375, 0.5625, 0.18945312,
-        0.5234375, 0.060546875, 0.5683594, 0.21875,
-        0.515625, 0.02734375, 0.5761719, 0.25195312,
-        0.5703125, 0.115234375, 0.5839844, 0.1640625,
-        0.5605469, 0.08984375, 0.59375, 0.18945312,
-        0.5546875, 0.060546875, 0.5996094, 0.21875,
-        0.546875, 0.02734375, 0.6074219, 0.25195312,
-        0.6015625, 0.115234375, 0.6152344, 0.1640625,
-        0.5917969, 0.08984375, 0.625, 0.18945312,
-        0.5859375, 0.060546875, 0.6308594, 0.21875,
-        0.578125, 0.02734375, 0.6386719, 0.25195312,
-        0.6328125, 0.115234375, 0.6464844, 0.1640625,
-        0.6230469, 0.08984375, 0.65625, 0.18945312,
-        0.6171875, 0.060546875, 0.6621094, 0.21875,
-        0.609375, 0.02734375, 0.6699219, 0.25195312,
-        0.6640625, 0.115234375, 0.6777344, 0.1640625,
-        0.6542969, 0.08984375, 0.6875, 0.18945312,
-        0.6484375, 0.060546875, 0.6933594, 0.21875,
-        0.640625, 0.02734375, 0.7011719, 0.25195312,
-        0.6953125, 0.115234375, 0.7089844, 0.1640625,
-        0.6855469, 0.08984375, 0.71875, 0.18945312,
-        0.6796875, 0.060546875, 0.7246094, 0.21875,
-        0.671875, 0.02734375, 0.7324219, 0.25195312,
-        0.7265625, 0.115234375, 0.7402344, 0.1640625,
-        0.7167969, 0.08984375, 0.75, 0.18945312,
-        0.7109375, 0.060546875, 0.7558594, 0.21875,
-        0.703125, 0.02734375, 0.7636719, 0.25195312,
-        0.7578125, 0.115234375, 0.7714844, 0.1640625,
-        0.7480469, 0.08984375, 0.78125, 0.18945312,
-        0.7421875, 0.060546875, 0.7871094, 0.21875,
-        0.734375, 0.02734375, 0.7949219, 0.25195312,
-        0.7890625, 0.115234375, 0.8027344, 0.1640625,
-        0.7792969, 0.08984375, 0.8125, 0.18945312,
-        0.7734375, 0.060546875, 0.8183594, 0.21875,
-        0.765625, 0.02734375, 0.8261719, 0.25195312,
-        0.8203125, 0.115234375, 0.8339844, 0.1640625,
-        0.8105469, 0.08984375, 0.84375, 0.18945312,
-        0.8046875, 0.060546875, 0.8496094, 0.21875,
-        0.796875, 0.02734375, 0.8574219, 0.25195312,
-        0.8515625, 0.115234375, 0.8652344, 0.1640625,
-        0.8417969, 0.08984375, 0.875, 0.18945312,
-        0.8359375, 0.060546875, 0.8808594, 0.21875,
-        0.828125, 0.02734375, 0.8886719, 0.25195312,
-        0.8828125, 0.115234375, 0.8964844, 0.1640625,
-        0.8730469, 0.08984375, 0.90625, 0.18945312,
-        0.8671875, 0.060546875, 0.9121094, 0.21875,
-        0.859375, 0.02734375, 0.9199219, 0.25195312,
-        0.9140625, 0.115234375, 0.9277344, 0.1640625,
-        0.9042969, 0.08984375, 0.9375, 0.18945312,
-        0.8984375, 0.060546875, 0.9433594, 0.21875,
-        0.890625, 0.02734375, 0.9511719, 0.25195312,
-        0.9453125, 0.115234375, 0.9589844, 0.1640625,
-        0.9355469, 0.08984375, 0.96875, 0.18945312,
-        0.9296875, 0.060546875, 0.9746094, 0.21875,
-        0.921875, 0.02734375, 0.9824219, 0.25195312,
-        0.9765625, 0.115234375, 0.9902344, 0.1640625,
-        0.9667969, 0.08984375, 1.0, 0.18945312,
-        0.9609375, 0.060546875, 1.0058594, 0.21875,
-        0.953125, 0.02734375, 1.0136719, 0.25195312,
-        0.0078125, 0.14648438, 0.021484375, 0.1953125,
-        0.0, 0.12109375, 0.03125, 0.22070312,
-        -0.005859375, 0.091796875, 0.037109375, 0.25,
-        -0.013671875, 0.05859375, 0.044921875, 0.28320312,
-        0.0390625, 0.14648438, 0.052734375, 0.1953125,
-        0.029296875, 0.12109375, 0.0625, 0.22070312,
-        0.0234375, 0.091796875, 0.068359375, 0.25,
-        0.015625, 0.05859375, 0.076171875, 0.28320312,
-        0.0703125, 0.14648438, 0.083984375, 0.1953125,
-        0.060546875, 0.12109375, 0.09375, 0.22070312,
-        0.0546875, 0.091796875, 0.099609375, 0.25,
-        0.046875, 0.05859375, 0.107421875, 0.28320312,
-        0.1015625, 0.14648438, 0.115234375, 0.1953125,
-        0.091796875, 0.12109375, 0.125, 0.22070312,
-        0.0859375, 0.091796875, 0.13085938, 0.25,
-        0.078125, 0.05859375, 0.13867188, 0.28320312,
-        0.1328125, 0.14648438, 0.14648438, 0.1953125,
-        0.123046875, 0.12109375, 0.15625, 0.22070312,
-        0.1171875, 0.091796875, 0.16210938, 0.25,
-        0.109375, 0.05859375, 0.16992188, 0.28320312,
-        0.1640625, 0.14648438, 0.17773438, 0.1953125,
-        0.15429688, 0.12109375, 0.1875, 0.22070312,
-        0.1484375, 0.091796875, 0.19335938, 0.25,
-        0.140625, 0.05859375, 0.20117188, 0.28320312,
-        0.1953125, 0.14648438, 0.20898438, 0.1953125,
-        0.18554688, 0.12109375, 0.21875, 0.22070312,
-        0.1796875, 0.091796875, 0.22460938, 0.25,
-        0.171875, 0.05859375, 0.23242188, 0.28320312,
-        0.2265625, 0.14648438, 0.24023438, 0.1953125,
-        0.21679688, 0.12109375, 0.25, 0.22070312,
-        0.2109375, 0.091796875, 0.25585938, 0.25,
-        0.203125, 0.05859375, 0.26367188, 0.28320312,
-        0.2578125, 0.14648438, 0.27148438, 0.1953125,
-        0.24804688, 0.12109375, 0.28125, 0.22070312,
-        0.2421875, 0.091796875, 0.28710938, 0.25,
-        0.234375, 0.05859375, 0.29492188, 0.28320312,
-        0.2890625, 0.14648438, 0.30273438, 0.1953125,
-        0.27929688, 0.12109375, 0.3125, 0.22070312,
-        0.2734375, 0.091796875, 0.31835938, 0.25,
-        0.265625, 0.05859375, 0.32617188, 0.28320312,
-        0.3203125, 0.14648438, 0.33398438, 0.1953125,
-        0.31054688, 0.12109375, 0.34375, 0.22070312,
-        0.3046875, 0.091796875, 0.34960938, 0.25,
-        0.296875, 0.05859375, 0.35742188, 0.28320312,
-        0.3515625, 0.14648438, 0.36523438, 0.1953125,
-        0.34179688, 0.12109375, 0.375, 0.22070312,
-        0.3359375, 0.091796875, 0.38085938, 0.25,
-        0.328125, 0.05859375, 0.38867188, 0.28320312,
-        0.3828125, 0.14648438, 0.39648438, 0.1953125,
-        0.37304688, 0.12109375, 0.40625, 0.22070312,
-        0.3671875, 0.091796875, 0.41210938, 0.25,
-        0.359375, 0.05859375, 0.41992188, 0.28320312,
-        0.4140625, 0.14648438, 0.42773438, 0.1953125,
-        0.40429688, 0.12109375, 0.4375, 0.22070312,
-        0.3984375, 0.091796875, 0.44335938, 0.25,
-        0.390625, 0.05859375, 0.45117188, 0.28320312,
-        0.4453125, 0.14648438, 0.45898438, 0.1953125,
-        0.43554688, 0.12109375, 0.46875, 0.22070312,
-        0.4296875, 0.091796875, 0.47460938, 0.25,
-        0.421875, 0.05859375, 0.48242188, 0.28320312,
-        0.4765625, 0.14648438, 0.49023438, 0.1953125,
-        0.46679688, 0.12109375, 0.5, 0.22070312,
-        0.4609375, 0.091796875, 0.5058594, 0.25,
-        0.453125, 0.05859375, 0.5136719, 0.28320312,
-        0.5078125, 0.14648438, 0.5214844, 0.1953125,
-        0.49804688, 0.12109375, 0.53125, 0.22070312,
-        0.4921875, 0.091796875, 0.5371094, 0.25,
-        0.484375, 0.05859375, 0.5449219, 0.28320312,
-        0.5390625, 0.14648438, 0.5527344, 0.1953125,
-        0.5292969, 0.12109375, 0.5625, 0.22070312,
-        0.5234375, 0.091796875, 0.5683594, 0.25,
-        0.515625, 0.05859375, 0.5761719, 0.28320312,
-        0.5703125, 0.14648438, 0.5839844, 0.1953125,
-        0.5605469, 0.12109375, 0.59375, 0.22070312,
-        0.5546875, 0.091796875, 0.5996094, 0.25,
-        0.546875, 0.05859375, 0.6074219, 0.28320312,
-        0.6015625, 0.14648438, 0.6152344, 0.1953125,
-        0.5917969, 0.12109375, 0.625, 0.22070312,
-        0.5859375, 0.091796875, 0.6308594, 0.25,
-        0.578125, 0.05859375, 0.6386719, 0.28320312,
-        0.6328125, 0.14648438, 0.6464844, 0.1953125,
-        0.6230469, 0.12109375, 0.65625, 0.22070312,
-        0.6171875, 0.091796875, 0.6621094, 0.25,
-        0.609375, 0.05859375, 0.6699219, 0.28320312,
-        0.6640625, 0.14648438, 0.6777344, 0.1953125,
-        0.6542969, 0.12109375, 0.6875, 0.22070312,
-        0.6484375, 0.091796875, 0.6933594, 0.25,
-        0.640625, 0.05859375, 0.7011719, 0.28320312,
-        0.6953125, 0.14648438, 0.7089844, 0.1953125,
-        0.6855469, 0.12109375, 0.71875, 0.22070312,
-        0.6796875, 0.091796875, 0.7246094, 0.25,
-        0.671875, 0.05859375, 0.7324219, 0.28320312,
-        0.7265625, 0.14648438, 0.7402344, 0.1953125,
-        0.7167969, 0.12109375, 0.75, 0.22070312,
-        0.7109375, 0.091796875, 0.7558594, 0.25,
-        0.703125, 0.05859375, 0.7636719, 0.28320312,
-        0.7578125, 0.14648438, 0.7714844, 0.1953125,
-        0.7480469, 0.12109375, 0.78125, 0.22070312,
-        0.7421875, 0.091796875, 0.7871094, 0.25,
-        0.734375, 0.05859375, 0.7949219, 0.28320312,
-        0.7890625, 0.14648438, 0.8027344, 0.1953125,
-        0.7792969, 0.12109375, 0.8125, 0.22070312,
-        0.7734375, 0.091796875, 0.8183594, 0.25,
-        0.765625, 0.05859375, 0.8261719, 0.28320312,
-        0.8203125, 0.14648438, 0.8339844, 0.1953125,
-        0.8105469, 0.12109375, 0.84375, 0.22070312,
-        0.8046875, 0.091796875, 0.8496094, 0.25,
-        0.796875, 0.05859375, 0.8574219, 0.28320312,
-        0.8515625, 0.14648438, 0.8652344, 0.1953125,
-        0.8417969, 0.12109375, 0.875, 0.22070312,
-        0.8359375, 0.091796875, 0.8808594, 0.25,
-        0.828125, 0.05859375, 0.8886719, 0.28320312,
-        0.8828125, 0.14648438, 0.8964844, 0.1953125,
-        0.8730469, 0.12109375, 0.90625, 0.22070312,
-        0.8671875, 0.091796875, 0.9121094, 0.25,
-        0.859375, 0.05859375, 0.9199219, 0.28320312,
-        0.9140625, 0.14648438, 0.9277344, 0.1953125,
-        0.9042969, 0.12109375, 0.9375, 0.22070312,
-        0.8984375, 0.091796875, 0.9433594, 0.25,
-        0.890625, 0.05859375, 0.9511719, 0.28320312,
-        0.9453125, 0.14648438, 0.9589844, 0.1953125,
-        0.9355469, 0.12109375, 0.96875, 0.22070312,
-        0.9296875, 0.091796875, 0.9746094, 0.25,
-        0.921875, 0.05859375, 0.9824219, 0.28320312,
-        0.9765625, 0.14648438, 0.9902344, 0.1953125,
-        0.9667969, 0.12109375, 1.0, 0.22070312,
-        0.9609375, 0.091796875, 1.0058594, 0.25,
-        0.953125, 0.05859375, 1.0136719, 0.28320312,
-        0.0078125, 0.17773438, 0.021484375, 0.2265625,
-        0.0, 0.15234375, 0.03125, 0.25195312,
-        -0.005859375, 0.123046875, 0.037109375, 0.28125,
-        -0.013671875, 0.08984375, 0.044921875, 0.31445312,
-        0.0390625, 0.17773438, 0.052734375, 0.2265625,
-        0.029296875, 0.15234375, 0.0625, 0.25195312,
-        0.0234375, 0.123046875, 0.068359375, 0.28125,
-        0.015625, 0.08984375, 0.076171875, 0.31445312,
-        0.0703125, 0.17773438, 0.083984375, 0.2265625,
-        0.060546875, 0.15234375, 0.09375, 0.25195312,
-        0.0546875, 0.123046875, 0.099609375, 0.28125,
-        0.046875, 0.08984375, 0.107421875, 0.31445312,
-        0.1015625, 0.17773438, 0.115234375, 0.2265625,
-        0.091796875, 0.15234375, 0.125, 0.25195312,
-        0.0859375, 0.123046875, 0.13085938, 0.28125,
-        0.078125, 0.08984375, 0.13867188, 0.31445312,
-        0.1328125, 0.17773438, 0.14648438, 0.2265625,
-        0.123046875, 0.15234375, 0.15625, 0.25195312,
-        0.1171875, 0.123046875, 0.16210938, 0.28125,
-        0.109375, 0.08984375, 0.16992188, 0.31445312,
-        0.1640625, 0.17773438, 0.17773438, 0.2265625,
-        0.15429688, 0.15234375, 0.1875, 0.25195312,
-        0.1484375, 0.123046875, 0.19335938, 0.28125,
-        0.140625, 0.08984375, 0.20117188, 0.31445312,
-        0.1953125, 0.17773438, 0.20898438, 0.2265625,
-        0.18554688, 0.15234375, 0.21875, 0.25195312,
-        0.1796875, 0.123046875, 0.22460938, 0.28125,
-        0.171875, 0.08984375, 0.23242188, 0.31445312,
-        0.2265625, 0.17773438, 0.24023438, 0.2265625,
-        0.21679688, 0.15234375, 0.25, 0.25195312,
-        0.2109375, 0.123046875, 0.25585938, 0.28125,
-        0.203125, 0.08984375, 0.26367188, 0.31445312,
-        0.2578125, 0.17773438, 0.27148438, 0.2265625,
-        0.24804688, 0.15234375, 0.28125, 0.25195312,
-        0.2421875, 0.123046875, 0.28710938, 0.28125,
-        0.234375, 0.08984375, 0.29492188, 0.31445312,
-        0.2890625, 0.17773438, 0.30273438, 0.2265625,
-        0.27929688, 0.15234375, 0.3125, 0.25195312,
-        0.2734375, 0.123046875, 0.31835938, 0.28125,
-        0.265625, 0.08984375, 0.32617188, 0.31445312,
-        0.3203125, 0.17773438, 0.33398438, 0.2265625,
-        0.31054688, 0.15234375, 0.34375, 0.25195312,
-        0.3046875, 0.123046875, 0.34960938, 0.28125,
-        0.296875, 0.08984375, 0.35742188, 0.31445312,
-        0.3515625, 0.17773438, 0.36523438, 0.2265625,
-        0.34179688, 0.15234375, 0.375, 0.25195312,
-        0.3359375, 0.123046875, 0.38085938, 0.28125,
-        0.328125, 0.08984375, 0.38867188, 0.31445312,
-        0.3828125, 0.17773438, 0.39648438, 0.2265625,
-        0.37304688, 0.15234375, 0.40625, 0.25195312,
-        0.3671875, 0.123046875, 0.41210938, 0.28125,
-        0.359375, 0.08984375, 0.41992188, 0.31445312,
-        0.4140625, 0.17773438, 0.42773438, 0.2265625,
-        0.40429688, 0.15234375, 0.4375, 0.25195312,
-        0.3984375, 0.123046875, 0.44335938, 0.28125,
-        0.390625, 0.08984375, 0.45117188, 0.31445312,
-        0.4453125, 0.17773438, 0.45898438, 0.2265625,
-        0.43554688, 0.15234375, 0.46875, 0.25195312,
-        0.4296875, 0.123046875, 0.47460938, 0.28125,
-        0.421875, 0.08984375, 0.48242188, 0.31445312,
-        0.4765625, 0.17773438, 0.49023438, 0.2265625,
-        0.46679688, 0.15234375, 0.5, 0.25195312,
-        0.4609375, 0.123046875, 0.5058594, 0.28125,
-        0.453125, 0.08984375, 0.5136719, 0.31445312,
-        0.5078125, 0.17773438, 0.5214844, 0.2265625,
-        0.49804688, 0.15234375, 0.53125, 0.25195312,
-        0.4921875, 0.123046875, 0.5371094, 0.28125,
-        0.484375, 0.08984375, 0.5449219, 0.31445312,
-        0.5390625, 0.17773438, 0.5527344, 0.2265625,
-        0.5292969, 0.15234375, 0.5625, 0.25195312,
-        0.5234375, 0.123046875, 0.5683594, 0.28125,
-        0.515625, 0.08984375, 0.5761719, 0.31445312,
-        0.5703125, 0.17773438, 0.5839844, 0.2265625,
-        0.5605469, 0.15234375, 0.59375, 0.25195312,
-        0.5546875, 0.123046875, 0.5996094, 0.28125,
-        0.546875, 0.08984375, 0.6074219, 0.31445312,
-        0.6015625, 0.17773438, 0.6152344, 0.2265625,
-        0.5917969, 0.15234375, 0.625, 0.25195312,
-        0.5859375, 0.123046875, 0.6308594, 0.28125,
-        0.578125, 0.08984375, 0.6386719, 0.31445312,
-        0.6328125, 0.17773438, 0.6464844, 0.2265625,
-        0.6230469, 0.15234375, 0.65625, 0.25195312,
-        0.6171875, 0.123046875, 0.6621094, 0.28125,
-        0.609375, 0.08984375, 0.6699219, 0.31445312,
-        0.6640625, 0.17773438, 0.6777344, 0.2265625,
-        0.6542969, 0.15234375, 0.6875, 0.25195312,
-        0.6484375, 0.123046875, 0.6933594, 0.28125,
-        0.640625, 0.08984375, 0.7011719, 0.31445312,
-        0.6953125, 0.17773438, 0.7089844, 0.2265625,
-        0.6855469, 0.15234375, 0.71875, 0.25195312,
-        0.6796875, 0.123046875, 0.7246094, 0.28125,
-        0.671875, 0.08984375, 0.7324219, 0.31445312,
-        0.7265625, 0.17773438, 0.7402344, 0.2265625,
-        0.7167969, 0.15234375, 0.75, 0.25195312,
-        0.7109375, 0.123046875, 0.7558594, 0.28125,
-        0.703125, 0.08984375, 0.7636719, 0.31445312,
-        0.7578125, 0.17773438, 0.7714844, 0.2265625,
-        0.7480469, 0.15234375, 0.78125, 0.25195312,
-        0.7421875, 0.123046875, 0.7871094, 0.28125,
-        0.734375, 0.08984375, 0.7949219, 0.31445312,
-        0.7890625, 0.17773438, 0.8027344, 0.2265625,
-        0.7792969, 0.15234375, 0.8125, 0.25195312,
-        0.7734375, 0.123046875, 0.8183594, 0.28125,
-        0.765625, 0.08984375, 0.8261719, 0.31445312,
-        0.8203125, 0.17773438, 0.8339844, 0.2265625,
-        0.8105469, 0.15234375, 0.84375, 0.25195312,
-        0.8046875, 0.123046875, 0.8496094, 0.28125,
-        0.796875, 0.08984375, 0.8574219, 0.31445312,
-        0.8515625, 0.17773438, 0.8652344, 0.2265625,
-        0.8417969, 0.15234375, 0.875, 0.25195312,
-        0.8359375, 0.123046875, 0.8808594, 0.28125,
-        0.828125, 0.08984375, 0.8886719, 0.31445312,
-        0.8828125, 0.17773438, 0.8964844, 0.2265625,
-        0.8730469, 0.15234375, 0.90625, 0.25195312,
-        0.8671875, 0.123046875, 0.9121094, 0.28125,
-        0.859375, 0.08984375, 0.9199219, 0.31445312,
-        0.9140625, 0.17773438, 0.9277344, 0.2265625,
-        0.9042969, 0.15234375, 0.9375, 0.25195312,
-        0.8984375, 0.123046875, 0.9433594, 0.28125,
-        0.890625, 0.08984375, 0.9511719, 0.31445312,
-        0.9453125, 0.17773438, 0.9589844, 0.2265625,
-        0.9355469, 0.15234375, 0.96875, 0.25195312,
-        0.9296875, 0.123046875, 0.9746094, 0.28125,
-        0.921875, 0.08984375, 0.9824219, 0.31445312,
-        0.9765625, 0.17773438, 0.9902344, 0.2265625,
-        0.9667969, 0.15234375, 1.0, 0.25195312,
-        0.9609375, 0.123046875, 1.0058594, 0.28125,
-        0.953125, 0.08984375, 1.0136719, 0.31445312,
-        0.0078125, 0.20898438, 0.021484375, 0.2578125,
-        0.0, 0.18359375, 0.03125, 0.28320312,
-        -0.005859375, 0.15429688, 0.037109375, 0.3125,
-        -0.013671875, 0.12109375, 0.044921875, 0.34570312,
-        0.0390625, 0.20898438, 0.052734375, 0.2578125,
-        0.029296875, 0.18359375, 0.0625, 0.28320312,
-        0.0234375, 0.15429688, 0.068359375, 0.3125,
-        0.015625, 0.12109375, 0.076171875, 0.34570312,
-        0.0703125, 0.20898438, 0.083984375, 0.2578125,
-        0.060546875, 0.18359375, 0.09375, 0.28320312,
-        0.0546875, 0.15429688, 0.099609375, 0.3125,
-        0.046875, 0.12109375, 0.107421875, 0.34570312,
-        0.1015625, 0.20898438, 0.115234375, 0.2578125,
-        0.091796875, 0.18359375, 0.125, 0.28320312,
-        0.0859375, 0.15429688, 0.13085938, 0.3125,
-        0.078125, 0.12109375, 0.13867188, 0.34570312,
-        0.1328125, 0.20898438, 0.14648438, 0.2578125,
-        0.123046875, 0.18359375, 0.15625, 0.28320312,
-        0.1171875, 0.15429688, 0.16210938, 0.3125,
-        0.109375, 0.12109375, 0.16992188, 0.34570312,
-        0.1640625, 0.20898438, 0.17773438, 0.2578125,
-        0.15429688, 0.18359375, 0.1875, 0.28320312,
-        0.1484375, 0.15429688, 0.19335938, 0.3125,
-        0.140625, 0.12109375, 0.20117188, 0.34570312,
-        0.1953125, 0.20898438, 0.20898438, 0.2578125,
-        0.18554688, 0.18359375, 0.21875, 0.28320312,
-        0.1796875, 0.15429688, 0.22460938, 0.3125,
-        0.171875, 0.12109375, 0.23242188, 0.34570312,
-        0.2265625, 0.20898438, 0.24023438, 0.2578125,
-        0.21679688, 0.18359375, 0.25, 0.28320312,
-        0.2109375, 0.15429688, 0.25585938, 0.3125,
-        0.203125, 0.12109375, 0.26367188, 0.34570312,
-        0.2578125, 0.20898438, 0.27148438, 0.2578125,
-        0.24804688, 0.18359375, 0.28125, 0.28320312,
-        0.2421875, 0.15429688, 0.28710938, 0.3125,
-        0.234375, 0.12109375, 0.29492188, 0.34570312,
-        0.2890625, 0.20898438, 0.30273438, 0.2578125,
-        0.27929688, 0.18359375, 0.3125, 0.28320312,
-        0.2734375, 0.15429688, 0.31835938, 0.3125,
-        0.265625, 0.12109375, 0.32617188, 0.34570312,
-        0.3203125, 0.20898438, 0.33398438, 0.2578125,
-        0.31054688, 0.18359375, 0.34375, 0.28320312,
-        0.3046875, 0.15429688, 0.34960938, 0.3125,
-        0.296875, 0.12109375, 0.35742188, 0.34570312,
-        0.3515625, 0.20898438, 0.36523438, 0.2578125,
-        0.34179688, 0.18359375, 0.375, 0.28320312,
-        0.3359375, 0.15429688, 0.38085938, 0.3125,
-        0.328125, 0.12109375, 0.38867188, 0.34570312,
-        0.3828125, 0.20898438, 0.39648438, 0.2578125,
-        0.37304688, 0.18359375, 0.40625, 0.28320312,
-        0.3671875, 0.15429688, 0.41210938, 0.3125,
-        0.359375, 0.12109375, 0.41992188, 0.34570312,
-        0.4140625, 0.20898438, 0.42773438, 0.2578125,
-        0.40429688, 0.18359375, 0.4375, 0.28320312,
-        0.3984375, 0.15429688, 0.44335938, 0.3125,
-        0.390625, 0.12109375, 0.45117188, 0.34570312,
-        0.4453125, 0.20898438, 0.45898438, 0.2578125,
-        0.43554688, 0.18359375, 0.46875, 0.28320312,
-        0.4296875, 0.15429688, 0.47460938, 0.3125,
-        0.421875, 0.12109375, 0.48242188, 0.34570312,
-        0.4765625, 0.20898438, 0.49023438, 0.2578125,
-        0.46679688, 0.18359375, 0.5, 0.28320312,
-        0.4609375, 0.15429688, 0.5058594, 0.3125,
-        0.453125, 0.12109375, 0.5136719, 0.34570312,
-        0.5078125, 0.20898438, 0.5214844, 0.2578125,
-        0.49804688, 0.18359375, 0.53125, 0.28320312,
-        0.4921875, 0.15429688, 0.5371094, 0.3125,
-        0.484375, 0.12109375, 0.5449219, 0.34570312,
-        0.5390625, 0.20898438, 0.5527344, 0.2578125,
-        0.5292969, 0.18359375, 0.5625, 0.28320312,
-        0.5234375, 0.15429688, 0.5683594, 0.3125,
-        0.515625, 0.12109375, 0.5761719, 0.34570312,
-        0.5703125, 0.20898438, 0.5839844, 0.2578125,
-        0.5605469, 0.18359375, 0.59375, 0.28320312,
-        0.5546875, 0.15429688, 0.5996094, 0.3125,
-        0.546875, 0.12109375, 0.6074219, 0.34570312,
-        0.6015625, 0.20898438, 0.6152344, 0.2578125,
-        0.5917969, 0.18359375, 0.625, 0.28320312,
-        0.5859375, 0.15429688, 0.6308594, 0.3125,
-        0.578125, 0.12109375, 0.6386719, 0.34570312,
-        0.6328125, 0.20898438, 0.6464844, 0.2578125,
-        0.6230469, 0.18359375, 0.65625, 0.28320312,
-        0.6171875, 0.15429688, 0.6621094, 0.3125,
-        0.609375, 0.12109375, 0.6699219, 0.34570312,
-        0.6640625, 0.20898438, 0.6777344, 0.2578125,
-        0.6542969, 0.18359375, 0.6875, 0.28320312,
-        0.6484375, 0.15429688, 0.6933594, 0.3125,
-        0.640625, 0.12109375, 0.7011719, 0.34570312,
-        0.6953125, 0.20898438, 0.7089844, 0.2578125,
-        0.6855469, 0.18359375, 0.71875, 0.28320312,
-        0.6796875, 0.15429688, 0.7246094, 0.3125,
-        0.671875, 0.12109375, 0.7324219, 0.34570312,
-        0.7265625, 0.20898438, 0.7402344, 0.2578125,
-        0.7167969, 0.18359375, 0.75, 0.28320312,
-        0.7109375, 0.15429688, 0.7558594, 0.3125,
-        0.703125, 0.12109375, 0.7636719, 0.34570312,
-        0.7578125, 0.20898438, 0.7714844, 0.2578125,
-        0.7480469, 0.18359375, 0.78125, 0.28320312,
-        0.7421875, 0.15429688, 0.7871094, 0.3125,
-        0.734375, 0.12109375, 0.7949219, 0.34570312,
-        0.7890625, 0.20898438, 0.8027344, 0.2578125,
-        0.7792969, 0.18359375, 0.8125, 0.28320312,
-        0.7734375, 0.15429688, 0.8183594, 0.3125,
-        0.765625, 0.12109375, 0.8261719, 0.34570312,
-        0.8203125, 0.20898438, 0.8339844, 0.2578125,
-        0.8105469, 0.18359375, 0.84375, 0.28320312,
-        0.8046875, 0.15429688, 0.8496094, 0.3125,
-        0.796875, 0.12109375, 0.8574219, 0.34570312,
-        0.8515625, 0.20898438, 0.8652344, 0.2578125,
-        0.8417969, 0.18359375, 0.875, 0.28320312,
-        0.8359375, 0.15429688, 0.8808594, 0.3125,
-        0.828125, 0.12109375, 0.8886719, 0.34570312,
-        0.8828125, 0.20898438, 0.8964844, 0.2578125,
-        0.8730469, 0.18359375, 0.90625, 0.28320312,
-        0.8671875, 0.15429688, 0.9121094, 0.3125,
-        0.859375, 0.12109375, 0.9199219, 0.34570312,
-        0.9140625, 0.20898438, 0.9277344, 0.2578125,
-        0.9042969, 0.18359375, 0.9375, 0.28320312,
-        0.8984375, 0.15429688, 0.9433594, 0.3125,
-        0.890625, 0.12109375, 0.9511719, 0.34570312,
-        0.9453125, 0.20898438, 0.9589844, 0.2578125,
-        0.9355469, 0.18359375, 0.96875, 0.28320312,
-        0.9296875, 0.15429688, 0.9746094, 0.3125,
-        0.921875, 0.12109375, 0.9824219, 0.34570312,
-        0.9765625, 0.20898438, 0.9902344, 0.2578125,
-        0.9667969, 0.18359375, 1.0, 0.28320312,
-        0.9609375, 0.15429688, 1.0058594, 0.3125,
-        0.953125, 0.12109375, 1.0136719, 0.34570312,
-        0.0078125, 0.24023438, 0.021484375, 0.2890625,
-        0.0, 0.21484375, 0.03125, 0.31445312,
-        -0.005859375, 0.18554688, 0.037109375, 0.34375,
-        -0.013671875, 0.15234375, 0.044921875, 0.37695312,
-        0.0390625, 0.24023438, 0.052734375, 0.2890625,
-        0.029296875, 0.21484375, 0.0625, 0.31445312,
-        0.0234375, 0.18554688, 0.068359375, 0.34375,
-        0.015625, 0.15234375, 0.076171875, 0.37695312,
-        0.0703125, 0.24023438, 0.083984375, 0.2890625,
-        0.060546875, 0.21484375, 0.09375, 0.31445312,
-        0.0546875, 0.18554688, 0.099609375, 0.34375,
-        0.046875, 0.15234375, 0.107421875, 0.37695312,
-        0.1015625, 0.24023438, 0.115234375, 0.2890625,
-        0.091796875, 0.21484375, 0.125, 0.31445312,
-        0.0859375, 0.18554688, 0.13085938, 0.34375,
-        0.078125, 0.15234375, 0.13867188, 0.37695312,
-        0.1328125, 0.24023438, 0.14648438, 0.2890625,
-        0.123046875, 0.21484375, 0.15625, 0.31445312,
-        0.1171875, 0.18554688, 0.16210938, 0.34375,
-        0.109375, 0.15234375, 0.16992188, 0.37695312,
-        0.1640625, 0.24023438, 0.17773438, 0.2890625,
-        0.15429688, 0.21484375, 0.1875, 0.31445312,
-        0.1484375, 0.18554688, 0.19335938, 0.34375,
-        0.140625, 0.15234375, 0.20117188, 0.37695312,
-        0.1953125, 0.24023438, 0.20898438, 0.2890625,
-        0.18554688, 0.21484375, 0.21875, 0.31445312,
-        0.1796875, 0.18554688, 0.22460938, 0.34375,
-        0.171875, 0.15234375, 0.23242188, 0.37695312,
-        0.2265625, 0.24023438, 0.24023438, 0.2890625,
-        0.21679688, 0.21484375, 0.25, 0.31445312,
-        0.2109375, 0.18554688, 0.25585938, 0.34375,
-        0.203125, 0.15234375, 0.26367188, 0.37695312,
-        0.2578125, 0.24023438, 0.27148438, 0.2890625,
-        0.24804688, 0.21484375, 0.28125, 0.31445312,
-        0.2421875, 0.18554688, 0.28710938, 0.34375,
-        0.234375, 0.15234375, 0.29492188, 0.37695312,
-        0.2890625, 0.24023438, 0.30273438, 0.2890625,
-        0.27929688, 0.21484375, 0.3125, 0.31445312,
-        0.2734375, 0.18554688, 0.31835938, 0.34375,
-        0.265625, 0.15234375, 0.32617188, 0.37695312,
-        0.3203125, 0.24023438, 0.33398438, 0.2890625,
-        0.31054688, 0.21484375, 0.34375, 0.31445312,
-        0.3046875, 0.18554688, 0.34960938, 0.34375,
-        0.296875, 0.15234375, 0.35742188, 0.37695312,
-        0.3515625, 0.24023438, 0.36523438, 0.2890625,
-        0.34179688, 0.21484375, 0.375, 0.31445312,
-        0.3359375, 0.18554688, 0.38085938, 0.34375,
-        0.328125, 0.15234375, 0.38867188, 0.37695312,
-        0.3828125, 0.24023438, 0.39648438, 0.2890625,
-        0.37304688, 0.21484375, 0.40625, 0.31445312,
-        0.3671875, 0.18554688, 0.41210938, 0.34375,
-        0.359375, 0.15234375, 0.41992188, 0.37695312,
-        0.4140625, 0.24023438, 0.42773438, 0.2890625,
-        0.40429688, 0.21484375, 0.4375, 0.31445312,
-        0.3984375, 0.18554688, 0.44335938, 0.34375,
-        0.390625, 0.15234375, 0.45117188, 0.37695312,
-        0.4453125, 0.24023438, 0.45898438, 0.2890625,
-        0.43554688, 0.21484375, 0.46875, 0.31445312,
-        0.4296875, 0.18554688, 0.47460938, 0.34375,
-        0.421875, 0.15234375, 0.48242188, 0.37695312,
-        0.4765625, 0.24023438, 0.49023438, 0.2890625,
-        0.46679688, 0.21484375, 0.5, 0.31445312,
-        0.4609375, 0.18554688, 0.5058594, 0.34375,
-        0.453125, 0.15234375, 0.5136719, 0.37695312,
-        0.5078125, 0.24023438, 0.5214844, 0.2890625,
-        0.49804688, 0.21484375, 0.53125, 0.31445312,
-        0.4921875, 0.18554688, 0.5371094, 0.34375,
-        0.484375, 0.15234375, 0.5449219, 0.37695312,
-        0.5390625, 0.24023438, 0.5527344, 0.2890625,
-        0.5292969, 0.21484375, 0.5625, 0.31445312,
-        0.5234375, 0.18554688, 0.5683594, 0.34375,
-        0.515625, 0.15234375, 0.5761719, 0.37695312,
-        0.5703125, 0.24023438, 0.5839844, 0.2890625,
-        0.5605469, 0.21484375, 0.59375, 0.31445312,
-        0.5546875, 0.18554688, 0.5996094, 0.34375,
-        0.546875, 0.15234375, 0.6074219, 0.37695312,
-        0.6015625, 0.24023438, 0.6152344, 0.2890625,
-        0.5917969, 0.21484375, 0.625, 0.31445312,
-        0.5859375, 0.18554688, 0.6308594, 0.34375,
-        0.578125, 0.15234375, 0.6386719, 0.37695312,
-        0.6328125, 0.24023438, 0.6464844, 0.2890625,
-        0.6230469, 0.21484375, 0.65625, 0.31445312,
-        0.6171875, 0.18554688, 0.6621094, 0.34375,
-        0.609375, 0.15234375, 0.6699219, 0.37695312,
-        0.6640625, 0.24023438, 0.6777344, 0.2890625,
-        0.6542969, 0.21484375, 0.6875, 0.31445312,
-        0.6484375, 0.18554688, 0.6933594, 0.34375,
-        0.640625, 0.15234375, 0.7011719, 0.37695312,
-        0.6953125, 0.24023438, 0.7089844, 0.2890625,
-        0.6855469, 0.21484375, 0.71875, 0.31445312,
-        0.6796875, 0.18554688, 0.7246094, 0.34375,
-        0.671875, 0.15234375, 0.7324219, 0.37695312,
-        0.7265625, 0.24023438, 0.7402344, 0.2890625,
-        0.7167969, 0.21484375, 0.75, 0.31445312,
-        0.7109375, 0.18554688, 0.7558594, 0.34375,
-        0.703125, 0.15234375, 0.7636719, 0.37695312,
-        0.7578125, 0.24023438, 0.7714844, 0.2890625,
-        0.7480469, 0.21484375, 0.78125, 0.31445312,
-        0.7421875, 0.18554688, 0.7871094, 0.34375,
-        0.734375, 0.15234375, 0.7949219, 0.37695312,
-        0.7890625, 0.24023438, 0.8027344, 0.2890625,
-        0.7792969, 0.21484375, 0.8125, 0.31445312,
-        0.7734375, 0.18554688, 0.8183594, 0.34375,
-        0.765625, 0.15234375, 0.8261719, 0.37695312,
-        0.8203125, 0.24023438, 0.8339844, 0.2890625,
-        0.8105469, 0.21484375, 0.84375, 0.31445312,
-        0.8046875, 0.18554688, 0.8496094, 0.34375,
-        0.796875, 0.15234375, 0.8574219, 0.37695312,
-        0.8515625, 0.24023438, 0.8652344, 0.2890625,
-        0.8417969, 0.21484375, 0.875, 0.31445312,
-        0.8359375, 0.18554688, 0.8808594, 0.34375,
-        0.828125, 0.15234375, 0.8886719, 0.37695312,
-        0.8828125, 0.24023438, 0.8964844, 0.2890625,
-        0.8730469, 0.21484375, 0.90625, 0.31445312,
-        0.8671875, 0.18554688, 0.9121094, 0.34375,
-        0.859375, 0.15234375, 0.9199219, 0.37695312,
-        0.9140625, 0.24023438, 0.9277344, 0.2890625,
-        0.9042969, 0.21484375, 0.9375, 0.31445312,
-        0.8984375, 0.18554688, 0.9433594, 0.34375,
-        0.890625, 0.15234375, 0.9511719, 0.37695312,
-        0.9453125, 0.24023438, 0.9589844, 0.2890625,
-        0.9355469, 0.21484375, 0.96875, 0.31445312,
-        0.9296875, 0.18554688, 0.9746094, 0.34375,
-        0.921875, 0.15234375, 0.9824219, 0.37695312,
-        0.9765625, 0.24023438, 0.9902344, 0.2890625,
-        0.9667969, 0.21484375, 1.0, 0.31445312,
-        0.9609375, 0.18554688, 1.0058594, 0.34375,
-        0.953125, 0.15234375, 1.0136719, 0.37695312,
-        0.0078125, 0.27148438, 0.021484375, 0.3203125,
-        0.0, 0.24609375, 0.03125, 0.34570312,
-        -0.005859375, 0.21679688, 0.037109375, 0.375,
-        -0.013671875, 0.18359375, 0.044921875, 0.40820312,
-        0.0390625, 0.27148438, 0.052734375, 0.3203125,
-        0.029296875, 0.24609375, 0.0625, 0.34570312,
-        0.0234375, 0.21679688, 0.068359375, 0.375,
-        0.015625, 0.18359375, 0.076171875, 0.40820312,
-        0.0703125, 0.27148438, 0.083984375, 0.3203125,
-        0.060546875, 0.24609375, 0.09375, 0.34570312,
-        0.0546875, 0.21679688, 0.099609375, 0.375,
-        0.046875, 0.18359375, 0.107421875, 0.40820312,
-        0.1015625, 0.27148438, 0.115234375, 0.3203125,
-        0.091796875, 0.24609375, 0.125, 0.34570312,
-        0.0859375, 0.21679688, 0.13085938, 0.375,
-        0.078125, 0.18359375, 0.13867188, 0.40820312,
-        0.1328125, 0.27148438, 0.14648438, 0.3203125,
-        0.123046875, 0.24609375, 0.15625, 0.34570312,
-        0.1171875, 0.21679688, 0.16210938, 0.375,
-        0.109375, 0.18359375, 0.16992188, 0.40820312,
-        0.1640625, 0.27148438, 0.17773438, 0.3203125,
-        0.15429688, 0.24609375, 0.1875, 0.34570312,
-        0.1484375, 0.21679688, 0.19335938, 0.375,
-        0.140625, 0.18359375, 0.20117188, 0.40820312,
-        0.1953125, 0.27148438, 0.20898438, 0.3203125,
-        0.18554688, 0.24609375, 0.21875, 0.34570312,
-        0.1796875, 0.21679688, 0.22460938, 0.375,
-        0.171875, 0.18359375, 0.23242188, 0.40820312,
-        0.2265625, 0.27148438, 0.24023438, 0.3203125,
-        0.21679688, 0.24609375, 0.25, 0.34570312,
-        0.2109375, 0.21679688, 0.25585938, 0.375,
-        0.203125, 0.18359375, 0.26367188, 0.40820312,
-        0.2578125, 0.27148438, 0.27148438, 0.3203125,
-        0.24804688, 0.24609375, 0.28125, 0.34570312,
-        0.2421875, 0.21679688, 0.28710938, 0.375,
-        0.234375, 0.18359375, 0.29492188, 0.40820312,
-        0.2890625, 0.27148438, 0.30273438, 0.3203125,
-        0.27929688, 0.24609375, 0.3125, 0.34570312,
-        0.2734375, 0.21679688, 0.31835938, 0.375,
-        0.265625, 0.18359375, 0.32617188, 0.40820312,
-        0.3203125, 0.27148438, 0.33398438, 0.3203125,
-        0.31054688, 0.24609375, 0.34375, 0.34570312,
-        0.3046875, 0.21679688, 0.34960938, 0.375,
-        0.296875, 0.18359375, 0.35742188, 0.40820312,
-        0.3515625, 0.27148438, 0.36523438, 0.3203125,
-        0.34179688, 0.24609375, 0.375, 0.34570312,
-        0.3359375, 0.21679688, 0.38085938, 0.375,
-        0.328125, 0.18359375, 0.38867188, 0.40820312,
-        0.3828125, 0.27148438, 0.39648438, 0.3203125,
-        0.37304688, 0.24609375, 0.40625, 0.34570312,
-        0.3671875, 0.21679688, 0.41210938, 0.375,
-        0.359375, 0.18359375, 0.41992188, 0.40820312,
-        0.4140625, 0.27148438, 0.42773438, 0.3203125,
-        0.40429688, 0.24609375, 0.4375, 0.34570312,
-        0.3984375, 0.21679688, 0.44335938, 0.375,
-        0.390625, 0.18359375, 0.45117188, 0.40820312,
-        0.4453125, 0.27148438, 0.45898438, 0.3203125,
-        0.43554688, 0.24609375, 0.46875, 0.34570312,
-        0.4296875, 0.21679688, 0.47460938, 0.375,
-        0.421875, 0.18359375, 0.48242188, 0.40820312,
-        0.4765625, 0.27148438, 0.49023438, 0.3203125,
-        0.46679688, 0.24609375, 0.5, 0.34570312,
-        0.4609375, 0.21679688, 0.5058594, 0.375,
-        0.453125, 0.18359375, 0.5136719, 0.40820312,
-        0.5078125, 0.27148438, 0.5214844, 0.3203125,
-        0.49804688, 0.24609375, 0.53125, 0.34570312,
-        0.4921875, 0.21679688, 0.5371094, 0.375,
-        0.484375, 0.18359375, 0.5449219, 0.40820312,
-        0.5390625, 0.27148438, 0.5527344, 0.3203125,
-        0.5292969, 0.24609375, 0.5625, 0.34570312,
-        0.5234375, 0.21679688, 0.5683594, 0.375,
-        0.515625, 0.18359375, 0.5761719, 0.40820312,
-        0.5703125, 0.27148438, 0.5839844, 0.3203125,
-        0.5605469, 0.24609375, 0.59375, 0.34570312,
-        0.5546875, 0.21679688, 0.5996094, 0.375,
-        0.546875, 0.18359375, 0.6074219, 0.40820312,
-        0.6015625, 0.27148438, 0.6152344, 0.3203125,
-        0.5917969, 0.24609375, 0.625, 0.34570312,
-        0.5859375, 0.21679688, 0.6308594, 0.375,
-        0.578125, 0.18359375, 0.6386719, 0.40820312,
-        0.6328125, 0.27148438, 0.6464844, 0.3203125,
-        0.6230469, 0.24609375, 0.65625, 0.34570312,
-        0.6171875, 0.21679688, 0.6621094, 0.375,
-        0.609375, 0.18359375, 0.6699219, 0.40820312,
-        0.6640625, 0.27148438, 0.6777344, 0.3203125,
-        0.6542969, 0.24609375, 0.6875, 0.34570312,
-        0.6484375, 0.21679688, 0.6933594, 0.375,
-        0.640625, 0.18359375, 0.7011719, 0.40820312,
-        0.6953125, 0.27148438, 0.7089844, 0.3203125,
-        0.6855469, 0.24609375, 0.71875, 0.34570312,
-        0.6796875, 0.21679688, 0.7246094, 0.375,
-        0.671875, 0.18359375, 0.7324219, 0.40820312,
-        0.7265625, 0.27148438, 0.7402344, 0.3203125,
-        0.7167969, 0.24609375, 0.75, 0.34570312,
-        0.7109375, 0.21679688, 0.7558594, 0.375,
-        0.703125, 0.18359375, 0.7636719, 0.40820312,
-        0.7578125, 0.27148438, 0.7714844, 0.3203125,
-        0.7480469, 0.24609375, 0.78125, 0.34570312,
-        0.7421875, 0.21679688, 0.7871094, 0.375,
-        0.734375, 0.18359375, 0.7949219, 0.40820312,
-        0.7890625, 0.27148438, 0.8027344, 0.3203125,
-        0.7792969, 0.24609375, 0.8125, 0.34570312,
-        0.7734375, 0.21679688, 0.8183594, 0.375,
-        0.765625, 0.18359375, 0.8261719, 0.40820312,
-        0.8203125, 0.27148438, 0.8339844, 0.3203125,
-        0.8105469, 0.24609375, 0.84375, 0.34570312,
-        0.8046875, 0.21679688, 0.8496094, 0.375,
-        0.796875, 0.18359375, 0.8574219, 0.40820312,
-        0.8515625, 0.27148438, 0.8652344, 0.3203125,
-        0.8417969, 0.24609375, 0.875, 0.34570312,
-        0.8359375, 0.21679688, 0.8808594, 0.375,
-        0.828125, 0.18359375, 0.8886719, 0.40820312,
-        0.8828125, 0.27148438, 0.8964844, 0.3203125,
-        0.8730469, 0.24609375, 0.90625, 0.34570312,
-        0.8671875, 0.21679688, 0.9121094, 0.375,
-        0.859375, 0.18359375, 0.9199219, 0.40820312,
-        0.9140625, 0.27148438, 0.9277344, 0.3203125,
-        0.9042969, 0.24609375, 0.9375, 0.34570312,
-        0.8984375, 0.21679688, 0.9433594, 0.375,
-        0.890625, 0.18359375, 0.9511719, 0.40820312,
-        0.9453125, 0.27148438, 0.9589844, 0.3203125,
-        0.9355469, 0.24609375, 0.96875, 0.34570312,
-        0.9296875, 0.21679688, 0.9746094, 0.375,
-        0.921875, 0.18359375, 0.9824219, 0.40820312,
-        0.9765625, 0.27148438, 0.9902344, 0.3203125,
-        0.9667969, 0.24609375, 1.0, 0.34570312,
-        0.9609375, 0.21679688, 1.0058594, 0.375,
-        0.953125, 0.18359375, 1.0136719, 0.40820312,
-        0.0078125, 0.30273438, 0.021484375, 0.3515625,
-        0.0, 0.27734375, 0.03125, 0.37695312,
-        -0.005859375, 0.24804688, 0.037109375, 0.40625,
-        -0.013671875, 0.21484375, 0.044921875, 0.43945312,
-        0.0390625, 0.30273438, 0.052734375, 0.3515625,
-        0.029296875, 0.27734375, 0.0625, 0.37695312,
-        0.0234375, 0.24804688, 0.068359375, 0.40625,
-        0.015625, 0.21484375, 0.076171875, 0.43945312,
-        0.0703125, 0.30273438, 0.083984375, 0.3515625,
-        0.060546875, 0.27734375, 0.09375, 0.37695312,
-        0.0546875, 0.24804688, 0.099609375, 0.40625,
-        0.046875, 0.21484375, 0.107421875, 0.43945312,
-        0.1015625, 0.30273438, 0.115234375, 0.3515625,
-        0.091796875, 0.27734375, 0.125, 0.37695312,
-        0.0859375, 0.24804688, 0.13085938, 0.40625,
-        0.078125, 0.21484375, 0.13867188, 0.43945312,
-        0.1328125, 0.30273438, 0.14648438, 0.3515625,
-        0.123046875, 0.27734375, 0.15625, 0.37695312,
-        0.1171875, 0.24804688, 0.16210938, 0.40625,
-        0.109375, 0.21484375, 0.16992188, 0.43945312,
-        0.1640625, 0.30273438, 0.17773438, 0.3515625,
-        0.15429688, 0.27734375, 0.1875, 0.37695312,
-        0.1484375, 0.24804688, 0.19335938, 0.40625,
-        0.140625, 0.21484375, 0.20117188, 0.43945312,
-        0.1953125, 0.30273438, 0.20898438, 0.3515625,
-        0.18554688, 0.27734375, 0.21875, 0.37695312,
-        0.1796875, 0.24804688, 0.22460938, 0.40625,
-        0.171875, 0.21484375, 0.23242188, 0.43945312,
-        0.2265625, 0.30273438, 0.24023438, 0.3515625,
-        0.21679688, 0.27734375, 0.25, 0.37695312,
-        0.2109375, 0.24804688, 0.25585938, 0.40625,
-        0.203125, 0.21484375, 0.26367188, 0.43945312,
-        0.2578125, 0.30273438, 0.27148438, 0.3515625,
-        0.24804688, 0.27734375, 0.28125, 0.37695312,
-        0.2421875, 0.24804688, 0.28710938, 0.40625,
-        0.234375, 0.21484375, 0.29492188, 0.43945312,
-        0.2890625, 0.30273438, 0.30273438, 0.3515625,
-        0.27929688, 0.27734375, 0.3125, 0.37695312,
-        0.2734375, 0.24804688, 0.31835938, 0.40625,
-        0.265625, 0.21484375, 0.32617188, 0.43945312,
-        0.3203125, 0.30273438, 0.33398438, 0.3515625,
-        0.31054688, 0.27734375, 0.34375, 0.37695312,
-        0.3046875, 0.24804688, 0.34960938, 0.40625,
-        0.296875, 0.21484375, 0.35742188, 0.43945312,
-        0.3515625, 0.30273438, 0.36523438, 0.3515625,
-        0.34179688, 0.27734375, 0.375, 0.37695312,
-        0.3359375, 0.24804688, 0.38085938, 0.40625,
-        0.328125, 0.21484375, 0.38867188, 0.43945312,
-        0.3828125, 0.30273438, 0.39648438, 0.3515625,
-        0.37304688, 0.27734375, 0.40625, 0.37695312,
-        0.3671875, 0.24804688, 0.41210938, 0.40625,
-        0.359375, 0.21484375, 0.41992188, 0.43945312,
-        0.4140625, 0.30273438, 0.42773438, 0.3515625,
-        0.40429688, 0.27734375, 0.4375, 0.37695312,
-        0.3984375, 0.24804688, 0.44335938, 0.40625,
-        0.390625, 0.21484375, 0.45117188, 0.43945312,
-        0.4453125, 0.30273438, 0.45898438, 0.3515625,
-        0.43554688, 0.27734375, 0.46875, 0.37695312,
-        0.4296875, 0.24804688, 0.47460938, 0.40625,
-        0.421875, 0.21484375, 0.48242188, 0.43945312,
-        0.4765625, 0.30273438, 0.49023438, 0.3515625,
-        0.46679688, 0.27734375, 0.5, 0.37695312,
-        0.4609375, 0.24804688, 0.5058594, 0.40625,
-        0.453125, 0.21484375, 0.5136719, 0.43945312,
-        0.5078125, 0.30273438, 0.5214844, 0.3515625,
-        0.49804688, 0.27734375, 0.53125, 0.37695312,
-        0.4921875, 0.24804688, 0.5371094, 0.40625,
-        0.484375, 0.21484375, 0.5449219, 0.43945312,
-        0.5390625, 0.30273438, 0.5527344, 0.3515625,
-        0.5292969, 0.27734375, 0.5625, 0.37695312,
-        0.5234375, 0.24804688, 0.5683594, 0.40625,
-        0.515625, 0.21484375, 0.5761719, 0.43945312,
-        0.5703125, 0.30273438, 0.5839844, 0.3515625,
-        0.5605469, 0.27734375, 0.59375, 0.37695312,
-        0.5546875, 0.24804688, 0.5996094, 0.40625,
-        0.546875, 0.21484375, 0.6074219, 0.43945312,
-        0.6015625, 0.30273438, 0.6152344, 0.3515625,
-        0.5917969, 0.27734375, 0.625, 0.37695312,
-        0.5859375, 0.24804688, 0.6308594, 0.40625,
-        0.578125, 0.21484375, 0.6386719, 0.43945312,
-        0.6328125, 0.30273438, 0.6464844, 0.3515625,
-        0.6230469, 0.27734375, 0.65625, 0.37695312,
-        0.6171875, 0.24804688, 0.6621094, 0.40625,
-        0.609375, 0.21484375, 0.6699219, 0.43945312,
-        0.6640625, 0.30273438, 0.6777344, 0.3515625,
-        0.6542969, 0.27734375, 0.6875, 0.37695312,
-        0.6484375, 0.24804688, 0.6933594, 0.40625,
-        0.640625, 0.21484375, 0.7011719, 0.43945312,
-        0.6953125, 0.30273438, 0.7089844, 0.3515625,
-        0.6855469, 0.27734375, 0.71875, 0.37695312,
-        0.6796875, 0.24804688, 0.7246094, 0.40625,
-        0.671875, 0.21484375, 0.7324219, 0.43945312,
-        0.7265625, 0.30273438, 0.7402344, 0.3515625,
-        0.7167969, 0.27734375, 0.75, 0.37695312,
-        0.7109375, 0.24804688, 0.7558594, 0.40625,
-        0.703125, 0.21484375, 0.7636719, 0.43945312,
-        0.7578125, 0.30273438, 0.7714844, 0.3515625,
-        0.7480469, 0.27734375, 0.78125, 0.37695312,
-        0.7421875, 0.24804688, 0.7871094, 0.40625,
-        0.734375, 0.21484375, 0.7949219, 0.43945312,
-        0.7890625, 0.30273438, 0.8027344, 0.3515625,
-        0.7792969, 0.27734375, 0.8125, 0.37695312,
-        0.7734375, 0.24804688, 0.8183594, 0.40625,
-        0.765625, 0.21484375, 0.8261719, 0.43945312,
-        0.8203125, 0.30273438, 0.8339844, 0.3515625,
-        0.8105469, 0.27734375, 0.84375, 0.37695312,
-        0.8046875, 0.24804688, 0.8496094, 0.40625,
-        0.796875, 0.21484375, 0.8574219, 0.43945312,
-        0.8515625, 0.30273438, 0.8652344, 0.3515625,
-        0.8417969, 0.27734375, 0.875, 0.37695312,
-        0.8359375, 0.24804688, 0.8808594, 0.40625,
-        0.828125, 0.21484375, 0.8886719, 0.43945312,
-        0.8828125, 0.30273438, 0.8964844, 0.3515625,
-        0.8730469, 0.27734375, 0.90625, 0.37695312,
-        0.8671875, 0.24804688, 0.9121094, 0.40625,
-        0.859375, 0.21484375, 0.9199219, 0.43945312,
-        0.9140625, 0.30273438, 0.9277344, 0.3515625,
-        0.9042969, 0.27734375, 0.9375, 0.37695312,
-        0.8984375, 0.24804688, 0.9433594, 0.40625,
-        0.890625, 0.21484375, 0.9511719, 0.43945312,
-        0.9453125, 0.30273438, 0.9589844, 0.3515625,
-        0.9355469, 0.27734375, 0.96875, 0.37695312,
-        0.9296875, 0.24804688, 0.9746094, 0.40625,
-        0.921875, 0.21484375, 0.9824219, 0.43945312,
-        0.9765625, 0.30273438, 0.9902344, 0.3515625,
-        0.9667969, 0.27734375, 1.0, 0.37695312,
-        0.9609375, 0.24804688, 1.0058594, 0.40625,
-        0.953125, 0.21484375, 1.0136719, 0.43945312,
-        0.0078125, 0.33398438, 0.021484375, 0.3828125,
-        0.0, 0.30859375, 0.03125, 0.40820312,
-        -0.005859375, 0.27929688, 0.037109375, 0.4375,
-        -0.013671875, 0.24609375, 0.044921875, 0.47070312,
-        0.0390625, 0.33398438, 0.052734375, 0.3828125,
-        0.029296875, 0.30859375, 0.0625, 0.40820312,
-        0.0234375, 0.27929688, 0.068359375, 0.4375,
-        0.015625, 0.24609375, 0.076171875, 0.47070312,
-        0.0703125, 0.33398438, 0.083984375, 0.3828125,
-        0.060546875, 0.30859375, 0.09375, 0.40820312,
-        0.0546875, 0.27929688, 0.099609375, 0.4375,
-        0.046875, 0.24609375, 0.107421875, 0.47070312,
-        0.1015625, 0.33398438, 0.115234375, 0.3828125,
-        0.091796875, 0.30859375, 0.125, 0.40820312,
-        0.0859375, 0.27929688, 0.13085938, 0.4375,
-        0.078125, 0.24609375, 0.13867188, 0.47070312,
-        0.1328125, 0.33398438, 0.14648438, 0.3828125,
-        0.123046875, 0.30859375, 0.15625, 0.40820312,
-        0.1171875, 0.27929688, 0.16210938, 0.4375,
-        0.109375, 0.24609375, 0.16992188, 0.47070312,
-        0.1640625, 0.33398438, 0.17773438, 0.3828125,
-        0.15429688, 0.30859375, 0.1875, 0.40820312,
-        0.1484375, 0.27929688, 0.19335938, 0.4375,
-        0.140625, 0.24609375, 0.20117188, 0.47070312,
-        0.1953125, 0.33398438, 0.20898438, 0.3828125,
-        0.18554688, 0.30859375, 0.21875, 0.40820312,
-        0.1796875, 0.27929688, 0.22460938, 0.4375,
-        0.171875, 0.24609375, 0.23242188, 0.47070312,
-        0.2265625, 0.33398438, 0.24023438, 0.3828125,
-        0.21679688, 0.30859375, 0.25, 0.40820312,
-        0.2109375, 0.27929688, 0.25585938, 0.4375,
-        0.203125, 0.24609375, 0.26367188, 0.47070312,
-        0.2578125, 0.33398438, 0.27148438, 0.3828125,
-        0.24804688, 0.30859375, 0.28125, 0.40820312,
-        0.2421875, 0.27929688, 0.28710938, 0.4375,
-        0.234375, 0.24609375, 0.29492188, 0.47070312,
-        0.2890625, 0.33398438, 0.30273438, 0.3828125,
-        0.27929688, 0.30859375, 0.3125, 0.40820312,
-        0.2734375, 0.27929688, 0.31835938, 0.4375,
-        0.265625, 0.24609375, 0.32617188, 0.47070312,
-        0.3203125, 0.33398438, 0.33398438, 0.3828125,
-        0.31054688, 0.30859375, 0.34375, 0.40820312,
-        0.3046875, 0.27929688, 0.34960938, 0.4375,
-        0.296875, 0.24609375, 0.35742188, 0.47070312,
-        0.3515625, 0.33398438, 0.36523438, 0.3828125,
-        0.34179688, 0.30859375, 0.375, 0.40820312,
-        0.3359375, 0.27929688, 0.38085938, 0.4375,
-        0.328125, 0.24609375, 0.38867188, 0.47070312,
-        0.3828125, 0.33398438, 0.39648438, 0.3828125,
-        0.37304688, 0.30859375, 0.40625, 0.40820312,
-        0.3671875, 0.27929688, 0.41210938, 0.4375,
-        0.359375, 0.24609375, 0.41992188, 0.47070312,
-        0.4140625, 0.33398438, 0.42773438, 0.3828125,
-        0.40429688, 0.30859375, 0.4375, 0.40820312,
-        0.3984375, 0.27929688, 0.44335938, 0.4375,
-        0.390625, 0.24609375, 0.45117188, 0.47070312,
-        0.4453125, 0.33398438, 0.45898438, 0.3828125,
-        0.43554688, 0.30859375, 0.46875, 0.40820312,
-        0.4296875, 0.27929688, 0.47460938, 0.4375,
-        0.421875, 0.24609375, 0.48242188, 0.47070312,
-        0.4765625, 0.33398438, 0.49023438, 0.3828125,
-        0.46679688, 0.30859375, 0.5, 0.40820312,
-        0.4609375, 0.27929688, 0.5058594, 0.4375,
-        0.453125, 0.24609375, 0.5136719, 0.47070312,
-        0.5078125, 0.33398438, 0.5214844, 0.3828125,
-        0.49804688, 0.30859375, 0.53125, 0.40820312,
-        0.4921875, 0.27929688, 0.5371094, 0.4375,
-        0.484375, 0.24609375, 0.5449219, 0.47070312,
-        0.5390625, 0.33398438, 0.5527344, 0.3828125,
-        0.5292969, 0.30859375, 0.5625, 0.40820312,
-        0.5234375, 0.27929688, 0.5683594, 0.4375,
-        0.515625, 0.24609375, 0.5761719, 0.47070312,
-        0.5703125, 0.33398438, 0.5839844, 0.3828125,
-        0.5605469, 0.30859375, 0.59375, 0.40820312,
-        0.5546875, 0.27929688, 0.5996094, 0.4375,
-        0.546875, 0.24609375, 0.6074219, 0.47070312,
-        0.6015625, 0.33398438, 0.6152344, 0.3828125,
-        0.5917969, 0.30859375, 0.625, 0.40820312,
-        0.5859375, 0.27929688, 0.6308594, 0.4375,
-        0.578125, 0.24609375, 0.6386719, 0.47070312,
-        0.6328125, 0.33398438, 0.6464844, 0.3828125,
-        0.6230469, 0.30859375, 0.65625, 0.40820312,
-        0.6171875, 0.27929688, 0.6621094, 0.4375,
-        0.609375, 0.24609375, 0.6699219, 0.47070312,
-        0.6640625, 0.33398438, 0.6777344, 0.3828125,
-        0.6542969, 0.30859375, 0.6875, 0.40820312,
-        0.6484375, 0.27929688, 0.6933594, 0.4375,
-        0.640625, 0.24609375, 0.7011719, 0.47070312,
-        0.6953125, 0.33398438, 0.7089844, 0.3828125,
-        0.6855469, 0.30859375, 0.71875, 0.40820312,
-        0.6796875, 0.27929688, 0.7246094, 0.4375,
-        0.671875, 0.24609375, 0.7324219, 0.47070312,
-        0.7265625, 0.33398438, 0.7402344, 0.3828125,
-        0.7167969, 0.30859375, 0.75, 0.40820312,
-        0.7109375, 0.27929688, 0.7558594, 0.4375,
-        0.703125, 0.24609375, 0.7636719, 0.47070312,
-        0.7578125, 0.33398438, 0.7714844, 0.3828125,
-        0.7480469, 0.30859375, 0.78125, 0.40820312,
-        0.7421875, 0.27929688, 0.7871094, 0.4375,
-        0.734375, 0.24609375, 0.7949219, 0.47070312,
-        0.7890625, 0.33398438, 0.8027344, 0.3828125,
-        0.7792969, 0.30859375, 0.8125, 0.40820312,
-        0.7734375, 0.27929688, 0.8183594, 0.4375,
-        0.765625, 0.24609375, 0.8261719, 0.47070312,
-        0.8203125, 0.33398438, 0.8339844, 0.3828125,
-        0.8105469, 0.30859375, 0.84375, 0.40820312,
-        0.8046875, 0.27929688, 0.8496094, 0.4375,
-        0.796875, 0.24609375, 0.8574219, 0.47070312,
-        0.8515625, 0.33398438, 0.8652344, 0.3828125,
-        0.8417969, 0.30859375, 0.875, 0.40820312,
-        0.8359375, 0.27929688, 0.8808594, 0.4375,
-        0.828125, 0.24609375, 0.8886719, 0.47070312,
-        0.8828125, 0.33398438, 0.8964844, 0.3828125,
-        0.8730469, 0.30859375, 0.90625, 0.40820312,
-        0.8671875, 0.27929688, 0.9121094, 0.4375,
-        0.859375, 0.24609375, 0.9199219, 0.47070312,
-        0.9140625, 0.33398438, 0.9277344, 0.3828125,
-        0.9042969, 0.30859375, 0.9375, 0.40820312,
-        0.8984375, 0.27929688, 0.9433594, 0.4375,
-        0.890625, 0.24609375, 0.9511719, 0.47070312,
-        0.9453125, 0.33398438, 0.9589844, 0.3828125,
-        0.9355469, 0.30859375, 0.96875, 0.40820312,
-        0.9296875, 0.27929688, 0.9746094, 0.4375,
-        0.921875, 0.24609375, 0.9824219, 0.47070312,
-        0.9765625, 0.33398438, 0.9902344, 0.3828125,
-        0.9667969, 0.30859375, 1.0, 0.40820312,
-        0.9609375, 0.27929688, 1.0058594, 0.4375,
-        0.953125, 0.24609375, 1.0136719, 0.47070312,
-        0.0078125, 0.36523438, 0.021484375, 0.4140625,
-        0.0, 0.33984375, 0.03125, 0.43945312,
-        -0.005859375, 0.31054688, 0.037109375, 0.46875,
-        -0.013671875, 0.27734375, 0.044921875, 0.5019531,
-        0.0390625, 0.36523438, 0.052734375, 0.4140625,
-        0.029296875, 0.33984375, 0.0625, 0.43945312,
-        0.0234375, 0.31054688, 0.068359375, 0.46875,
-        0.015625, 0.27734375, 0.076171875, 0.5019531,
-        0.0703125, 0.36523438, 0.083984375, 0.4140625,
-        0.060546875, 0.33984375, 0.09375, 0.43945312,
-        0.0546875, 0.31054688, 0.099609375, 0.46875,
-        0.046875, 0.27734375, 0.107421875, 0.5019531,
-        0.1015625, 0.36523438, 0.115234375, 0.4140625,
-        0.091796875, 0.33984375, 0.125, 0.43945312,
-        0.0859375, 0.31054688, 0.13085938, 0.46875,
-        0.078125, 0.27734375, 0.13867188, 0.5019531,
-        0.1328125, 0.36523438, 0.14648438, 0.4140625,
-        0.123046875, 0.33984375, 0.15625, 0.43945312,
-        0.1171875, 0.31054688, 0.16210938, 0.46875,
-        0.109375, 0.27734375, 0.16992188, 0.5019531,
-        0.1640625, 0.36523438, 0.17773438, 0.4140625,
-        0.15429688, 0.33984375, 0.1875, 0.43945312,
-        0.1484375, 0.31054688, 0.19335938, 0.46875,
-        0.140625, 0.27734375, 0.20117188, 0.5019531,
-        0.1953125, 0.36523438, 0.20898438, 0.4140625,
-        0.18554688, 0.33984375, 0.21875, 0.43945312,
-        0.1796875, 0.31054688, 0.22460938, 0.46875,
-        0.171875, 0.27734375, 0.23242188, 0.5019531,
-        0.2265625, 0.36523438, 0.24023438, 0.4140625,
-        0.21679688, 0.33984375, 0.25, 0.43945312,
-        0.2109375, 0.31054688, 0.25585938, 0.46875,
-        0.203125, 0.27734375, 0.26367188, 0.5019531,
-        0.2578125, 0.36523438, 0.27148438, 0.4140625,
-        0.24804688, 0.33984375, 0.28125, 0.43945312,
-        0.2421875, 0.31054688, 0.28710938, 0.46875,
-        0.234375, 0.27734375, 0.29492188, 0.5019531,
-        0.2890625, 0.36523438, 0.30273438, 0.4140625,
-        0.27929688, 0.33984375, 0.3125, 0.43945312,
-        0.2734375, 0.31054688, 0.31835938, 0.46875,
-        0.265625, 0.27734375, 0.32617188, 0.5019531,
-        0.3203125, 0.36523438, 0.33398438, 0.4140625,
-        0.31054688, 0.33984375, 0.34375, 0.43945312,
-        0.3046875, 0.31054688, 0.34960938, 0.46875,
-        0.296875, 0.27734375, 0.35742188, 0.5019531,
-        0.3515625, 0.36523438, 0.36523438, 0.4140625,
-        0.34179688, 0.33984375, 0.375, 0.43945312,
-        0.3359375, 0.31054688, 0.38085938, 0.46875,
-        0.328125, 0.27734375, 0.38867188, 0.5019531,
-        0.3828125, 0.36523438, 0.39648438, 0.4140625,
-        0.37304688, 0.33984375, 0.40625, 0.43945312,
-        0.3671875, 0.31054688, 0.41210938, 0.46875,
-        0.359375, 0.27734375, 0.41992188, 0.5019531,
-        0.4140625, 0.36523438, 0.42773438, 0.4140625,
-        0.40429688, 0.33984375, 0.4375, 0.43945312,
-        0.3984375, 0.31054688, 0.44335938, 0.46875,
-        0.390625, 0.27734375, 0.45117188, 0.5019531,
-        0.4453125, 0.36523438, 0.45898438, 0.4140625,
-        0.43554688, 0.33984375, 0.46875, 0.43945312,
-        0.4296875, 0.31054688, 0.47460938, 0.46875,
-        0.421875, 0.27734375, 0.48242188, 0.5019531,
-        0.4765625, 0.36523438, 0.49023438, 0.4140625,
-        0.46679688, 0.33984375, 0.5, 0.43945312,
-        0.4609375, 0.31054688, 0.5058594, 0.46875,
-        0.453125, 0.27734375, 0.5136719, 0.5019531,
-        0.5078125, 0.36523438, 0.5214844, 0.4140625,
-        0.49804688, 0.33984375, 0.53125, 0.43945312,
-        0.4921875, 0.31054688, 0.5371094, 0.46875,
-        0.484375, 0.27734375, 0.5449219, 0.5019531,
-        0.5390625, 0.36523438, 0.5527344, 0.4140625,
-        0.5292969, 0.33984375, 0.5625, 0.43945312,
-        0.5234375, 0.31054688, 0.5683594, 0.46875,
-        0.515625, 0.27734375, 0.5761719, 0.5019531,
-        0.5703125, 0.36523438, 0.5839844, 0.4140625,
-        0.5605469, 0.33984375, 0.59375, 0.43945312,
-        0.5546875, 0.31054688, 0.5996094, 0.46875,
-        0.546875, 0.27734375, 0.6074219, 0.5019531,
-        0.6015625, 0.36523438, 0.6152344, 0.4140625,
-        0.5917969, 0.33984375, 0.625, 0.43945312,
-        0.5859375, 0.31054688, 0.6308594, 0.46875,
-        0.578125, 0.27734375, 0.6386719, 0.5019531,
-        0.6328125, 0.36523438, 0.6464844, 0.4140625,
-        0.6230469, 0.33984375, 0.65625, 0.43945312,
-        0.6171875, 0.31054688, 0.6621094, 0.46875,
-        0.609375, 0.27734375, 0.6699219, 0.5019531,
-        0.6640625, 0.36523438, 0.6777344, 0.4140625,
-        0.6542969, 0.33984375, 0.6875, 0.43945312,
-        0.6484375, 0.31054688, 0.6933594, 0.46875,
-        0.640625, 0.27734375, 0.7011719, 0.5019531,
-        0.6953125, 0.36523438, 0.7089844, 0.4140625,
-        0.6855469, 0.33984375, 0.71875, 0.43945312,
-        0.6796875, 0.31054688, 0.7246094, 0.46875,
-        0.671875, 0.27734375, 0.7324219, 0.5019531,
-        0.7265625, 0.36523438, 0.7402344, 0.4140625,
-        0.7167969, 0.33984375, 0.75, 0.43945312,
-        0.7109375, 0.31054688, 0.7558594, 0.46875,
-        0.703125, 0.27734375, 0.7636719, 0.5019531,
-        0.7578125, 0.36523438, 0.7714844, 0.4140625,
-        0.7480469, 0.33984375, 0.78125, 0.43945312,
-        0.7421875, 0.31054688, 0.7871094, 0.46875,
-        0.734375, 0.27734375, 0.7949219, 0.5019531,
-        0.7890625, 0.36523438, 0.8027344, 0.4140625,
-        0.7792969, 0.33984375, 0.8125, 0.43945312,
-        0.7734375, 0.31054688, 0.8183594, 0.46875,
-        0.765625, 0.27734375, 0.8261719, 0.5019531,
-        0.8203125, 0.36523438, 0.8339844, 0.4140625,
-        0.8105469, 0.33984375, 0.84375, 0.43945312,
-        0.8046875, 0.31054688, 0.8496094, 0.46875,
-        0.796875, 0.27734375, 0.8574219, 0.5019531,
-        0.8515625, 0.36523438, 0.8652344, 0.4140625,
-        0.8417969, 0.33984375, 0.875, 0.43945312,
-        0.8359375, 0.31054688, 0.8808594, 0.46875,
-        0.828125, 0.27734375, 0.8886719, 0.5019531,
-        0.8828125, 0.36523438, 0.8964844, 0.4140625,
-        0.8730469, 0.33984375, 0.90625, 0.43945312,
-        0.8671875, 0.31054688, 0.9121094, 0.46875,
-        0.859375, 0.27734375, 0.9199219, 0.5019531,
-        0.9140625, 0.36523438, 0.9277344, 0.4140625,
-        0.9042969, 0.33984375, 0.9375, 0.43945312,
-        0.8984375, 0.31054688, 0.9433594, 0.46875,
-        0.890625, 0.27734375, 0.9511719, 0.5019531,
-        0.9453125, 0.36523438, 0.9589844, 0.4140625,
-        0.9355469, 0.33984375, 0.96875, 0.43945312,
-        0.9296875, 0.31054688, 0.9746094, 0.46875,
-        0.921875, 0.27734375, 0.9824219, 0.5019531,
-        0.9765625, 0.36523438, 0.9902344, 0.4140625,
-        0.9667969, 0.33984375, 1.0, 0.43945312,
-        0.9609375, 0.31054688, 1.0058594, 0.46875,
-        0.953125, 0.27734375, 1.0136719, 0.5019531,
-        0.0078125, 0.39648438, 0.021484375, 0.4453125,
-        0.0, 0.37109375, 0.03125, 0.47070312,
-        -0.005859375, 0.34179688, 0.037109375, 0.5,
-        -0.013671875, 0.30859375, 0.044921875, 0.5332031,
-        0.0390625, 0.39648438, 0.052734375, 0.4453125,
-        0.029296875, 0.37109375, 0.0625, 0.47070312,
-        0.0234375, 0.34179688, 0.068359375, 0.5,
-        0.015625, 0.30859375, 0.076171875, 0.5332031,
-        0.0703125, 0.39648438, 0.083984375, 0.4453125,
-        0.060546875, 0.37109375, 0.09375, 0.47070312,
-        0.0546875, 0.34179688, 0.099609375, 0.5,
-        0.046875, 0.30859375, 0.107421875, 0.5332031,
-        0.1015625, 0.39648438, 0.115234375, 0.4453125,
-        0.091796875, 0.37109375, 0.125, 0.47070312,
-        0.0859375, 0.34179688, 0.13085938, 0.5,
-        0.078125, 0.30859375, 0.13867188, 0.5332031,
-        0.1328125, 0.39648438, 0.14648438, 0.4453125,
-        0.123046875, 0.37109375, 0.15625, 0.47070312,
-        0.1171875, 0.34179688, 0.16210938, 0.5,
-        0.109375, 0.30859375, 0.16992188, 0.5332031,
-        0.1640625, 0.39648438, 0.17773438, 0.4453125,
-        0.15429688, 0.37109375, 0.1875, 0.47070312,
-        0.1484375, 0.34179688, 0.19335938, 0.5,
-        0.140625, 0.30859375, 0.20117188, 0.5332031,
-        0.1953125, 0.39648438, 0.20898438, 0.4453125,
-        0.18554688, 0.37109375, 0.21875, 0.47070312,
-        0.1796875, 0.34179688, 0.22460938, 0.5,
-        0.171875, 0.30859375, 0.23242188, 0.5332031,
-        0.2265625, 0.39648438, 0.24023438, 0.4453125,
-        0.21679688, 0.37109375, 0.25, 0.47070312,
-        0.2109375, 0.34179688, 0.25585938, 0.5,
-        0.203125, 0.30859375, 0.26367188, 0.5332031,
-        0.2578125, 0.39648438, 0.27148438, 0.4453125,
-        0.24804688, 0.37109375, 0.28125, 0.47070312,
-        0.2421875, 0.34179688, 0.28710938, 0.5,
-        0.234375, 0.30859375, 0.29492188, 0.5332031,
-        0.2890625, 0.39648438, 0.30273438, 0.4453125,
-        0.27929688, 0.37109375, 0.3125, 0.47070312,
-        0.2734375, 0.34179688, 0.31835938, 0.5,
-        0.265625, 0.30859375, 0.32617188, 0.5332031,
-        0.3203125, 0.39648438, 0.33398438, 0.4453125,
-        0.31054688, 0.37109375, 0.34375, 0.47070312,
-        0.3046875, 0.34179688, 0.34960938, 0.5,
-        0.296875, 0.30859375, 0.35742188, 0.5332031,
-        0.3515625, 0.39648438, 0.36523438, 0.4453125,
-        0.34179688, 0.37109375, 0.375, 0.47070312,
-        0.3359375, 0.34179688, 0.38085938, 0.5,
-        0.328125, 0.30859375, 0.38867188, 0.5332031,
-        0.3828125, 0.39648438, 0.39648438, 0.4453125,
-        0.37304688, 0.37109375, 0.40625, 0.47070312,
-        0.3671875, 0.34179688, 0.41210938, 0.5,
-        0.359375, 0.30859375, 0.41992188, 0.5332031,
-        0.4140625, 0.39648438, 0.42773438, 0.4453125,
-        0.40429688, 0.37109375, 0.4375, 0.47070312,
-        0.3984375, 0.34179688, 0.44335938, 0.5,
-        0.390625, 0.30859375, 0.45117188, 0.5332031,
-        0.4453125, 0.39648438, 0.45898438, 0.4453125,
-        0.43554688, 0.37109375, 0.46875, 0.47070312,
-        0.4296875, 0.34179688, 0.47460938, 0.5,
-        0.421875, 0.30859375, 0.48242188, 0.5332031,
-        0.4765625, 0.39648438, 0.49023438, 0.4453125,
-        0.46679688, 0.37109375, 0.5, 0.47070312,
-        0.4609375, 0.34179688, 0.5058594, 0.5,
-        0.453125, 0.30859375, 0.5136719, 0.5332031,
-        0.5078125, 0.39648438, 0.5214844, 0.4453125,
-        0.49804688, 0.37109375, 0.53125, 0.47070312,
-        0.4921875, 0.34179688, 0.5371094, 0.5,
-        0.484375, 0.30859375, 0.5449219, 0.5332031,
-        0.5390625, 0.39648438, 0.5527344, 0.4453125,
-        0.5292969, 0.37109375, 0.5625, 0.47070312,
-        0.5234375, 0.34179688, 0.5683594, 0.5,
-        0.515625, 0.30859375, 0.5761719, 0.5332031,
-        0.5703125, 0.39648438, 0.5839844, 0.4453125,
-        0.5605469, 0.37109375, 0.59375, 0.47070312,
-        0.5546875, 0.34179688, 0.5996094, 0.5,
-        0.546875, 0.30859375, 0.6074219, 0.5332031,
-        0.6015625, 0.39648438, 0.6152344, 0.4453125,
-        0.5917969, 0.37109375, 0.625, 0.47070312,
-        0.5859375, 0.34179688, 0.6308594, 0.5,
-        0.578125, 0.30859375, 0.6386719, 0.5332031,
-        0.6328125, 0.39648438, 0.6464844, 0.4453125,
-        0.6230469, 0.37109375, 0.65625, 0.47070312,
-        0.6171875, 0.34179688, 0.6621094, 0.5,
-        0.609375, 0.30859375, 0.6699219, 0.5332031,
-        0.6640625, 0.39648438, 0.6777344, 0.4453125,
-        0.6542969, 0.37109375, 0.6875, 0.47070312,
-        0.6484375, 0.34179688, 0.6933594, 0.5,
-        0.640625, 0.30859375, 0.7011719, 0.5332031,
-        0.6953125, 0.39648438, 0.7089844, 0.4453125,
-        0.6855469, 0.37109375, 0.71875, 0.47070312,
-        0.6796875, 0.34179688, 0.7246094, 0.5,
-        0.671875, 0.30859375, 0.7324219, 0.5332031,
-        0.7265625, 0.39648438, 0.7402344, 0.4453125,
-        0.7167969, 0.37109375, 0.75, 0.47070312,
-        0.7109375, 0.34179688, 0.7558594, 0.5,
-        0.703125, 0.30859375, 0.7636719, 0.5332031,
-        0.7578125, 0.39648438, 0.7714844, 0.4453125,
-        0.7480469, 0.37109375, 0.78125, 0.47070312,
-        0.7421875, 0.34179688, 0.7871094, 0.5,
-        0.734375, 0.30859375, 0.7949219, 0.5332031,
-        0.7890625, 0.39648438, 0.8027344, 0.4453125,
-        0.7792969, 0.37109375, 0.8125, 0.47070312,
-        0.7734375, 0.34179688, 0.8183594, 0.5,
-        0.765625, 0.30859375, 0.8261719, 0.5332031,
-        0.8203125, 0.39648438, 0.8339844, 0.4453125,
-        0.8105469, 0.37109375, 0.84375, 0.47070312,
-        0.8046875, 0.34179688, 0.8496094, 0.5,
-        0.796875, 0.30859375, 0.8574219, 0.5332031,
-        0.8515625, 0.39648438, 0.8652344, 0.4453125,
-        0.8417969, 0.37109375, 0.875, 0.47070312,
-        0.8359375, 0.34179688, 0.8808594, 0.5,
-        0.828125, 0.30859375, 0.8886719, 0.5332031,
-        0.8828125, 0.39648438, 0.8964844, 0.4453125,
-        0.8730469, 0.37109375, 0.90625, 0.47070312,
-        0.8671875, 0.34179688, 0.9121094, 0.5,
-        0.859375, 0.30859375, 0.9199219, 0.5332031,
-        0.9140625, 0.39648438, 0.9277344, 0.4453125,
-        0.9042969, 0.37109375, 0.9375, 0.47070312,
-        0.8984375, 0.34179688, 0.9433594, 0.5,
-        0.890625, 0.30859375, 0.9511719, 0.5332031,
-        0.9453125, 0.39648438, 0.9589844, 0.4453125,
-        0.9355469, 0.37109375, 0.96875, 0.47070312,
-        0.9296875, 0.34179688, 0.9746094, 0.5,
-        0.921875, 0.30859375, 0.9824219, 0.5332031,
-        0.9765625, 0.39648438, 0.9902344, 0.4453125,
-        0.9667969, 0.37109375, 1.0, 0.47070312,
-        0.9609375, 0.34179688, 1.0058594, 0.5,
-        0.953125, 0.30859375, 1.0136719, 0.5332031,
-        0.0078125, 0.42773438, 0.021484375, 0.4765625,
-        0.0, 0.40234375, 0.03125, 0.5019531,
-        -0.005859375, 0.37304688, 0.037109375, 0.53125,
-        -0.013671875, 0.33984375, 0.044921875, 0.5644531,
-        0.0390625, 0.42773438, 0.052734375, 0.4765625,
-        0.029296875, 0.40234375, 0.0625, 0.5019531,
-        0.0234375, 0.37304688, 0.068359375, 0.53125,
-        0.015625, 0.33984375, 0.076171875, 0.5644531,
-        0.0703125, 0.42773438, 0.083984375, 0.4765625,
-        0.060546875, 0.40234375, 0.09375, 0.5019531,
-        0.0546875, 0.37304688, 0.099609375, 0.53125,
-        0.046875, 0.33984375, 0.107421875, 0.5644531,
-        0.1015625, 0.42773438, 0.115234375, 0.4765625,
-        0.091796875, 0.40234375, 0.125, 0.5019531,
-        0.0859375, 0.37304688, 0.13085938, 0.53125,
-        0.078125, 0.33984375, 0.13867188, 0.5644531,
-        0.1328125, 0.42773438, 0.14648438, 0.4765625,
-        0.123046875, 0.40234375, 0.15625, 0.5019531,
-        0.1171875, 0.37304688, 0.16210938, 0.53125,
-        0.109375, 0.33984375, 0.16992188, 0.5644531,
-        0.1640625, 0.42773438, 0.17773438, 0.4765625,
-        0.15429688, 0.40234375, 0.1875, 0.5019531,
-        0.1484375, 0.37304688, 0.19335938, 0.53125,
-        0.140625, 0.33984375, 0.20117188, 0.5644531,
-        0.1953125, 0.42773438, 0.20898438, 0.4765625,
-        0.18554688, 0.40234375, 0.21875, 0.5019531,
-        0.1796875, 0.37304688, 0.22460938, 0.53125,
-        0.171875, 0.33984375, 0.23242188, 0.5644531,
-        0.2265625, 0.42773438, 0.24023438, 0.4765625,
-        0.21679688, 0.40234375, 0.25, 0.5019531,
-        0.2109375, 0.37304688, 0.25585938, 0.53125,
-        0.203125, 0.33984375, 0.26367188, 0.5644531,
-        0.2578125, 0.42773438, 0.27148438, 0.4765625,
-        0.24804688, 0.40234375, 0.28125, 0.5019531,
-        0.2421875, 0.37304688, 0.28710938, 0.53125,
-        0.234375, 0.33984375, 0.29492188, 0.5644531,
-        0.2890625, 0.42773438, 0.30273438, 0.4765625,
-        0.27929688, 0.40234375, 0.3125, 0.5019531,
-        0.2734375, 0.37304688, 0.31835938, 0.53125,
-        0.265625, 0.33984375, 0.32617188, 0.5644531,
-        0.3203125, 0.42773438, 0.33398438, 0.4765625,
-        0.31054688, 0.40234375, 0.34375, 0.5019531,
-        0.3046875, 0.37304688, 0.34960938, 0.53125,
-        0.296875, 0.33984375, 0.35742188, 0.5644531,
-        0.3515625, 0.42773438, 0.36523438, 0.4765625,
-        0.34179688, 0.40234375, 0.375, 0.5019531,
-        0.3359375, 0.37304688, 0.38085938, 0.53125,
-        0.328125, 0.33984375, 0.38867188, 0.5644531,
-        0.3828125, 0.42773438, 0.39648438, 0.4765625,
-        0.37304688, 0.40234375, 0.40625, 0.5019531,
-        0.3671875, 0.37304688, 0.41210938, 0.53125,
-        0.359375, 0.33984375, 0.41992188, 0.5644531,
-        0.4140625, 0.42773438, 0.42773438, 0.4765625,
-        0.40429688, 0.40234375, 0.4375, 0.5019531,
-        0.3984375, 0.37304688, 0.44335938, 0.53125,
-        0.390625, 0.33984375, 0.45117188, 0.5644531,
-        0.4453125, 0.42773438, 0.45898438, 0.4765625,
-        0.43554688, 0.40234375, 0.46875, 0.5019531,
-        0.4296875, 0.37304688, 0.47460938, 0.53125,
-        0.421875, 0.33984375, 0.48242188, 0.5644531,
-        0.4765625, 0.42773438, 0.49023438, 0.4765625,
-        0.46679688, 0.40234375, 0.5, 0.5019531,
-        0.4609375, 0.37304688, 0.5058594, 0.53125,
-        0.453125, 0.33984375, 0.5136719, 0.5644531,
-        0.5078125, 0.42773438, 0.5214844, 0.4765625,
-        0.49804688, 0.40234375, 0.53125, 0.5019531,
-        0.4921875, 0.37304688, 0.5371094, 0.53125,
-        0.484375, 0.33984375, 0.5449219, 0.5644531,
-        0.5390625, 0.42773438, 0.5527344, 0.4765625,
-        0.5292969, 0.40234375, 0.5625, 0.5019531,
-        0.5234375, 0.37304688, 0.5683594, 0.53125,
-        0.515625, 0.33984375, 0.5761719, 0.5644531,
-        0.5703125, 0.42773438, 0.5839844, 0.4765625,
-        0.5605469, 0.40234375, 0.59375, 0.5019531,
-        0.5546875, 0.37304688, 0.5996094, 0.53125,
-        0.546875, 0.33984375, 0.6074219, 0.5644531,
-        0.6015625, 0.42773438, 0.6152344, 0.4765625,
-        0.5917969, 0.40234375, 0.625, 0.5019531,
-        0.5859375, 0.37304688, 0.6308594, 0.53125,
-        0.578125, 0.33984375, 0.6386719, 0.5644531,
-        0.6328125, 0.42773438, 0.6464844, 0.4765625,
-        0.6230469, 0.40234375, 0.65625, 0.5019531,
-        0.6171875, 0.37304688, 0.6621094, 0.53125,
-        0.609375, 0.33984375, 0.6699219, 0.5644531,
-        0.6640625, 0.42773438, 0.6777344, 0.4765625,
-        0.6542969, 0.40234375, 0.6875, 0.5019531,
-        0.6484375, 0.37304688, 0.6933594, 0.53125,
-        0.640625, 0.33984375, 0.7011719, 0.5644531,
-        0.6953125, 0.42773438, 0.7089844, 0.4765625,
-        0.6855469, 0.40234375, 0.71875, 0.5019531,
-        0.6796875, 0.37304688, 0.7246094, 0.53125,
-        0.671875, 0.33984375, 0.7324219, 0.5644531,
-        0.7265625, 0.42773438, 0.7402344, 0.4765625,
-        0.7167969, 0.40234375, 0.75, 0.5019531,
-        0.7109375, 0.37304688, 0.7558594, 0.53125,
-        0.703125, 0.33984375, 0.7636719, 0.5644531,
-        0.7578125, 0.42773438, 0.7714844, 0.4765625,
-        0.7480469, 0.40234375, 0.78125, 0.5019531,
-        0.7421875, 0.37304688, 0.7871094, 0.53125,
-        0.734375, 0.33984375, 0.7949219, 0.5644531,
-        0.7890625, 0.42773438, 0.8027344, 0.4765625,
-        0.7792969, 0.40234375, 0.8125, 0.5019531,
-        0.7734375, 0.37304688, 0.8183594, 0.53125,
-        0.765625, 0.33984375, 0.8261719, 0.5644531,
-        0.8203125, 0.42773438, 0.8339844, 0.4765625,
-        0.8105469, 0.40234375, 0.84375, 0.5019531,
-        0.8046875, 0.37304688, 0.8496094, 0.53125,
-        0.796875, 0.33984375, 0.8574219, 0.5644531,
-        0.8515625, 0.42773438, 0.8652344, 0.4765625,
-        0.8417969, 0.40234375, 0.875, 0.5019531,
-        0.8359375, 0.37304688, 0.8808594, 0.53125,
-        0.828125, 0.33984375, 0.8886719, 0.5644531,
-        0.8828125, 0.42773438, 0.8964844, 0.4765625,
-        0.8730469, 0.40234375, 0.90625, 0.5019531,
-        0.8671875, 0.37304688, 0.9121094, 0.53125,
-        0.859375, 0.33984375, 0.9199219, 0.5644531,
-        0.9140625, 0.42773438, 0.9277344, 0.4765625,
-        0.9042969, 0.40234375, 0.9375, 0.5019531,
-        0.8984375, 0.37304688, 0.9433594, 0.53125,
-        0.890625, 0.33984375, 0.9511719, 0.5644531,
-        0.9453125, 0.42773438, 0.9589844, 0.4765625,
-        0.9355469, 0.40234375, 0.96875, 0.5019531,
-        0.9296875, 0.37304688, 0.9746094, 0.53125,
-        0.921875, 0.33984375, 0.9824219, 0.5644531,
-        0.9765625, 0.42773438, 0.9902344, 0.4765625,
-        0.9667969, 0.40234375, 1.0, 0.5019531,
-        0.9609375, 0.37304688, 1.0058594, 0.53125,
-        0.953125, 0.33984375, 1.0136719, 0.5644531,
-        0.0078125, 0.45898438, 0.021484375, 0.5078125,
-        0.0, 0.43359375, 0.03125, 0.5332031,
-        -0.005859375, 0.40429688, 0.037109375, 0.5625,
-        -0.013671875, 0.37109375, 0.044921875, 0.5957031,
-        0.0390625, 0.45898438, 0.052734375, 0.5078125,
-        0.029296875, 0.43359375, 0.0625, 0.5332031,
-        0.0234375, 0.40429688, 0.068359375, 0.5625,
-        0.015625, 0.37109375, 0.076171875, 0.5957031,
-        0.0703125, 0.45898438, 0.083984375, 0.5078125,
-        0.060546875, 0.43359375, 0.09375, 0.5332031,
-        0.0546875, 0.40429688, 0.099609375, 0.5625,
-        0.046875, 0.37109375, 0.107421875, 0.5957031,
-        0.1015625, 0.45898438, 0.115234375, 0.5078125,
-        0.091796875, 0.43359375, 0.125, 0.5332031,
-        0.0859375, 0.40429688, 0.13085938, 0.5625,
-        0.078125, 0.37109375, 0.13867188, 0.5957031,
-        0.1328125, 0.45898438, 0.14648438, 0.5078125,
-        0.123046875, 0.43359375, 0.15625, 0.5332031,
-        0.1171875, 0.40429688, 0.16210938, 0.5625,
-        0.109375, 0.37109375, 0.16992188, 0.5957031,
-        0.1640625, 0.45898438, 0.17773438, 0.5078125,
-        0.15429688, 0.43359375, 0.1875, 0.5332031,
-        0.1484375, 0.40429688, 0.19335938, 0.5625,
-        0.140625, 0.37109375, 0.20117188, 0.5957031,
-        0.1953125, 0.45898438, 0.20898438, 0.5078125,
-        0.18554688, 0.43359375, 0.21875, 0.5332031,
-        0.1796875, 0.40429688, 0.22460938, 0.5625,
-        0.171875, 0.37109375, 0.23242188, 0.5957031,
-        0.2265625, 0.45898438, 0.24023438, 0.5078125,
-        0.21679688, 0.43359375, 0.25, 0.5332031,
-        0.2109375, 0.40429688, 0.25585938, 0.5625,
-        0.203125, 0.37109375, 0.26367188, 0.5957031,
-        0.2578125, 0.45898438, 0.27148438, 0.5078125,
-        0.24804688, 0.43359375, 0.28125, 0.5332031,
-        0.2421875, 0.40429688, 0.28710938, 0.5625,
-        0.234375, 0.37109375, 0.29492188, 0.5957031,
-        0.2890625, 0.45898438, 0.30273438, 0.5078125,
-        0.27929688, 0.43359375, 0.3125, 0.5332031,
-        0.2734375, 0.40429688, 0.31835938, 0.5625,
-        0.265625, 0.37109375, 0.32617188, 0.5957031,
-        0.3203125, 0.45898438, 0.33398438, 0.5078125,
-        0.31054688, 0.43359375, 0.34375, 0.5332031,
-        0.3046875, 0.40429688, 0.34960938, 0.5625,
-        0.296875, 0.37109375, 0.35742188, 0.5957031,
-        0.3515625, 0.45898438, 0.36523438, 0.5078125,
-        0.34179688, 0.43359375, 0.375, 0.5332031,
-        0.3359375, 0.40429688, 0.38085938, 0.5625,
-        0.328125, 0.37109375, 0.38867188, 0.5957031,
-        0.3828125, 0.45898438, 0.39648438, 0.5078125,
-        0.37304688, 0.43359375, 0.40625, 0.5332031,
-        0.3671875, 0.40429688, 0.41210938, 0.5625,
-        0.359375, 0.37109375, 0.41992188, 0.5957031,
-        0.4140625, 0.45898438, 0.42773438, 0.5078125,
-        0.40429688, 0.43359375, 0.4375, 0.5332031,
-        0.3984375, 0.40429688, 0.44335938, 0.5625,
-        0.390625, 0.37109375, 0.45117188, 0.5957031,
-        0.4453125, 0.45898438, 0.45898438, 0.5078125,
-        0.43554688, 0.43359375, 0.46875, 0.5332031,
-        0.4296875, 0.40429688, 0.47460938, 0.5625,
-        0.421875, 0.37109375, 0.48242188, 0.5957031,
-        0.4765625, 0.45898438, 0.49023438, 0.5078125,
-        0.46679688, 0.43359375, 0.5, 0.5332031,
-        0.4609375, 0.40429688, 0.5058594, 0.5625,
-        0.453125, 0.37109375, 0.5136719, 0.5957031,
-        0.5078125, 0.45898438, 0.5214844, 0.5078125,
-        0.49804688, 0.43359375, 0.53125, 0.5332031,
-        0.4921875, 0.40429688, 0.5371094, 0.5625,
-        0.484375, 0.37109375, 0.5449219, 0.5957031,
-        0.5390625, 0.45898438, 0.5527344, 0.5078125,
-        0.5292969, 0.43359375, 0.5625, 0.5332031,
-        0.5234375, 0.40429688, 0.5683594, 0.5625,
-        0.515625, 0.37109375, 0.5761719, 0.5957031,
-        0.5703125, 0.45898438, 0.5839844, 0.5078125,
-        0.5605469, 0.43359375, 0.59375, 0.5332031,
-        0.5546875, 0.40429688, 0.5996094, 0.5625,
-        0.546875, 0.37109375, 0.6074219, 0.5957031,
-        0.6015625, 0.45898438, 0.6152344, 0.5078125,
-        0.5917969, 0.43359375, 0.625, 0.5332031,
-        0.5859375, 0.40429688, 0.6308594, 0.5625,
-        0.578125, 0.37109375, 0.6386719, 0.5957031,
-        0.6328125, 0.45898438, 0.6464844, 0.5078125,
-        0.6230469, 0.43359375, 0.65625, 0.5332031,
-        0.6171875, 0.40429688, 0.6621094, 0.5625,
-        0.609375, 0.37109375, 0.6699219, 0.5957031,
-        0.6640625, 0.45898438, 0.6777344, 0.5078125,
-        0.6542969, 0.43359375, 0.6875, 0.5332031,
-        0.6484375, 0.40429688, 0.6933594, 0.5625,
-        0.640625, 0.37109375, 0.7011719, 0.5957031,
-        0.6953125, 0.45898438, 0.7089844, 0.5078125,
-        0.6855469, 0.43359375, 0.71875, 0.5332031,
-        0.6796875, 0.40429688, 0.7246094, 0.5625,
-        0.671875, 0.37109375, 0.7324219, 0.5957031,
-        0.7265625, 0.45898438, 0.7402344, 0.5078125,
-        0.7167969, 0.43359375, 0.75, 0.5332031,
-        0.7109375, 0.40429688, 0.7558594, 0.5625,
-        0.703125, 0.37109375, 0.7636719, 0.5957031,
-        0.7578125, 0.45898438, 0.7714844, 0.5078125,
-        0.7480469, 0.43359375, 0.78125, 0.5332031,
-        0.7421875, 0.40429688, 0.7871094, 0.5625,
-        0.734375, 0.37109375, 0.7949219, 0.5957031,
-        0.7890625, 0.45898438, 0.8027344, 0.5078125,
-        0.7792969, 0.43359375, 0.8125, 0.5332031,
-        0.7734375, 0.40429688, 0.8183594, 0.5625,
-        0.765625, 0.37109375, 0.8261719, 0.5957031,
-        0.8203125, 0.45898438, 0.8339844, 0.5078125,
-        0.8105469, 0.43359375, 0.84375, 0.5332031,
-        0.8046875, 0.40429688, 0.8496094, 0.5625,
-        0.796875, 0.37109375, 0.8574219, 0.5957031,
-        0.8515625, 0.45898438, 0.8652344, 0.5078125,
-        0.8417969, 0.43359375, 0.875, 0.5332031,
-        0.8359375, 0.40429688, 0.8808594, 0.5625,
-        0.828125, 0.37109375, 0.8886719, 0.5957031,
-        0.8828125, 0.45898438, 0.8964844, 0.5078125,
-        0.8730469, 0.43359375, 0.90625, 0.5332031,
-        0.8671875, 0.40429688, 0.9121094, 0.5625,
-        0.859375, 0.37109375, 0.9199219, 0.5957031,
-        0.9140625, 0.45898438, 0.9277344, 0.5078125,
-        0.9042969, 0.43359375, 0.9375, 0.5332031,
-        0.8984375, 0.40429688, 0.9433594, 0.5625,
-        0.890625, 0.37109375, 0.9511719, 0.5957031,
-        0.9453125, 0.45898438, 0.9589844, 0.5078125,
-        0.9355469, 0.43359375, 0.96875, 0.5332031,
-        0.9296875, 0.40429688, 0.9746094, 0.5625,
-        0.921875, 0.37109375, 0.9824219, 0.5957031,
-        0.9765625, 0.45898438, 0.9902344, 0.5078125,
-        0.9667969, 0.43359375, 1.0, 0.5332031,
-        0.9609375, 0.40429688, 1.0058594, 0.5625,
-        0.953125, 0.37109375, 1.0136719, 0.5957031,
-        0.0078125, 0.49023438, 0.021484375, 0.5390625,
-        0.0, 0.46484375, 0.03125, 0.5644531,
-        -0.005859375, 0.43554688, 0.037109375, 0.59375,
-        -0.013671875, 0.40234375, 0.044921875, 0.6269531,
-        0.0390625, 0.49023438, 0.052734375, 0.5390625,
-        0.029296875, 0.46484375, 0.0625, 0.5644531,
-        0.0234375, 0.43554688, 0.068359375, 0.59375,
-        0.015625, 0.40234375, 0.076171875, 0.6269531,
-        0.0703125, 0.49023438, 0.083984375, 0.5390625,
-        0.060546875, 0.46484375, 0.09375, 0.5644531,
-        0.0546875, 0.43554688, 0.099609375, 0.59375,
-        0.046875, 0.40234375, 0.107421875, 0.6269531,
-        0.1015625, 0.49023438, 0.115234375, 0.5390625,
-        0.091796875, 0.46484375, 0.125, 0.5644531,
-        0.0859375, 0.43554688, 0.13085938, 0.59375,
-        0.078125, 0.40234375, 0.13867188, 0.6269531,
-        0.1328125, 0.49023438, 0.14648438, 0.5390625,
-        0.123046875, 0.46484375, 0.15625, 0.5644531,
-        0.1171875, 0.43554688, 0.16210938, 0.59375,
-        0.109375, 0.40234375, 0.16992188, 0.6269531,
-        0.1640625, 0.49023438, 0.17773438, 0.5390625,
-        0.15429688, 0.46484375, 0.1875, 0.5644531,
-        0.1484375, 0.43554688, 0.19335938, 0.59375,
-        0.140625, 0.40234375, 0.20117188, 0.6269531,
-        0.1953125, 0.49023438, 0.20898438, 0.5390625,
-        0.18554688, 0.46484375, 0.21875, 0.5644531,
-        0.1796875, 0.43554688, 0.22460938, 0.59375,
-        0.171875, 0.40234375, 0.23242188, 0.6269531,
-        0.2265625, 0.49023438, 0.24023438, 0.5390625,
-        0.21679688, 0.46484375, 0.25, 0.5644531,
-        0.2109375, 0.43554688, 0.25585938, 0.59375,
-        0.203125, 0.40234375, 0.26367188, 0.6269531,
-        0.2578125, 0.49023438, 0.27148438, 0.5390625,
-        0.24804688, 0.46484375, 0.28125, 0.5644531,
-        0.2421875, 0.43554688, 0.28710938, 0.59375,
-        0.234375, 0.40234375, 0.29492188, 0.6269531,
-        0.2890625, 0.49023438, 0.30273438, 0.5390625,
-        0.27929688, 0.46484375, 0.3125, 0.5644531,
-        0.2734375, 0.43554688, 0.31835938, 0.59375,
-        0.265625, 0.40234375, 0.32617188, 0.6269531,
-        0.3203125, 0.49023438, 0.33398438, 0.5390625,
-        0.31054688, 0.46484375, 0.34375, 0.5644531,
-        0.3046875, 0.43554688, 0.34960938, 0.59375,
-        0.296875, 0.40234375, 0.35742188, 0.6269531,
-        0.3515625, 0.49023438, 0.36523438, 0.5390625,
-        0.34179688, 0.46484375, 0.375, 0.5644531,
-        0.3359375, 0.43554688, 0.38085938, 0.59375,
-        0.328125, 0.40234375, 0.38867188, 0.6269531,
-        0.3828125, 0.49023438, 0.39648438, 0.5390625,
-        0.37304688, 0.46484375, 0.40625, 0.5644531,
-        0.3671875, 0.43554688, 0.41210938, 0.59375,
-        0.359375, 0.40234375, 0.41992188, 0.6269531,
-        0.4140625, 0.49023438, 0.42773438, 0.5390625,
-        0.40429688, 0.46484375, 0.4375, 0.5644531,
-        0.3984375, 0.43554688, 0.44335938, 0.59375,
-        0.390625, 0.40234375, 0.45117188, 0.6269531,
-        0.4453125, 0.49023438, 0.45898438, 0.5390625,
-        0.43554688, 0.46484375, 0.46875, 0.5644531,
-        0.4296875, 0.43554688, 0.47460938, 0.59375,
-        0.421875, 0.40234375, 0.48242188, 0.6269531,
-        0.4765625, 0.49023438, 0.49023438, 0.5390625,
-        0.46679688, 0.46484375, 0.5, 0.5644531,
-        0.4609375, 0.43554688, 0.5058594, 0.59375,
-        0.453125, 0.40234375, 0.5136719, 0.6269531,
-        0.5078125, 0.49023438, 0.5214844, 0.5390625,
-        0.49804688, 0.46484375, 0.53125, 0.5644531,
-        0.4921875, 0.43554688, 0.5371094, 0.59375,
-        0.484375, 0.40234375, 0.5449219, 0.6269531,
-        0.5390625, 0.49023438, 0.5527344, 0.5390625,
-        0.5292969, 0.46484375, 0.5625, 0.5644531,
-        0.5234375, 0.43554688, 0.5683594, 0.59375,
-        0.515625, 0.40234375, 0.5761719, 0.6269531,
-        0.5703125, 0.49023438, 0.5839844, 0.5390625,
-        0.5605469, 0.46484375, 0.59375, 0.5644531,
-        0.5546875, 0.43554688, 0.5996094, 0.59375,
-        0.546875, 0.40234375, 0.6074219, 0.6269531,
-        0.6015625, 0.49023438, 0.6152344, 0.5390625,
-        0.5917969, 0.46484375, 0.625, 0.5644531,
-        0.5859375, 0.43554688, 0.6308594, 0.59375,
-        0.578125, 0.40234375, 0.6386719, 0.6269531,
-        0.6328125, 0.49023438, 0.6464844, 0.5390625,
-        0.6230469, 0.46484375, 0.65625, 0.5644531,
-        0.6171875, 0.43554688, 0.6621094, 0.59375,
-        0.609375, 0.40234375, 0.6699219, 0.6269531,
-        0.6640625, 0.49023438, 0.6777344, 0.5390625,
-        0.6542969, 0.46484375, 0.6875, 0.5644531,
-        0.6484375, 0.43554688, 0.6933594, 0.59375,
-        0.640625, 0.40234375, 0.7011719, 0.6269531,
-        0.6953125, 0.49023438, 0.7089844, 0.5390625,
-        0.6855469, 0.46484375, 0.71875, 0.5644531,
-        0.6796875, 0.43554688, 0.7246094, 0.59375,
-        0.671875, 0.40234375, 0.7324219, 0.6269531,
-        0.7265625, 0.49023438, 0.7402344, 0.5390625,
-        0.7167969, 0.46484375, 0.75, 0.5644531,
-        0.7109375, 0.43554688, 0.7558594, 0.59375,
-        0.703125, 0.40234375, 0.7636719, 0.6269531,
-        0.7578125, 0.49023438, 0.7714844, 0.5390625,
-        0.7480469, 0.46484375, 0.78125, 0.5644531,
-        0.7421875, 0.43554688, 0.7871094, 0.59375,
-        0.734375, 0.40234375, 0.7949219, 0.6269531,
-        0.7890625, 0.49023438, 0.8027344, 0.5390625,
-        0.7792969, 0.46484375, 0.8125, 0.5644531,
-        0.7734375, 0.43554688, 0.8183594, 0.59375,
-        0.765625, 0.40234375, 0.8261719, 0.6269531,
-        0.8203125, 0.49023438, 0.8339844, 0.5390625,
-        0.8105469, 0.46484375, 0.84375, 0.5644531,
-        0.8046875, 0.43554688, 0.8496094, 0.59375,
-        0.796875, 0.40234375, 0.8574219, 0.6269531,
-        0.8515625, 0.49023438, 0.8652344, 0.5390625,
-        0.8417969, 0.46484375, 0.875, 0.5644531,
-        0.8359375, 0.43554688, 0.8808594, 0.59375,
-        0.828125, 0.40234375, 0.8886719, 0.6269531,
-        0.8828125, 0.49023438, 0.8964844, 0.5390625,
-        0.8730469, 0.46484375, 0.90625, 0.5644531,
-        0.8671875, 0.43554688, 0.9121094, 0.59375,
-        0.859375, 0.40234375, 0.9199219, 0.6269531,
-        0.9140625, 0.49023438, 0.9277344, 0.5390625,
-        0.9042969, 0.46484375, 0.9375, 0.5644531,
-        0.8984375, 0.43554688, 0.9433594, 0.59375,
-        0.890625, 0.40234375, 0.9511719, 0.6269531,
-        0.9453125, 0.49023438, 0.9589844, 0.5390625,
-        0.9355469, 0.46484375, 0.96875, 0.5644531,
-        0.9296875, 0.43554688, 0.9746094, 0.59375,
-        0.921875, 0.40234375, 0.9824219, 0.6269531,
-        0.9765625, 0.49023438, 0.9902344, 0.5390625,
-        0.9667969, 0.46484375, 1.0, 0.5644531,
-        0.9609375, 0.43554688, 1.0058594, 0.59375,
-        0.953125, 0.40234375, 1.0136719, 0.6269531,
-        0.0078125, 0.5214844, 0.021484375, 0.5703125,
-        0.0, 0.49609375, 0.03125, 0.5957031,
-        -0.005859375, 0.46679688, 0.037109375, 0.625,
-        -0.013671875, 0.43359375, 0.044921875, 0.6582031,
-        0.0390625, 0.5214844, 0.052734375, 0.5703125,
-        0.029296875, 0.49609375, 0.0625, 0.5957031,
-        0.0234375, 0.46679688, 0.068359375, 0.625,
-        0.015625, 0.43359375, 0.076171875, 0.6582031,
-        0.0703125, 0.5214844, 0.083984375, 0.5703125,
-        0.060546875, 0.49609375, 0.09375, 0.5957031,
-        0.0546875, 0.46679688, 0.099609375, 0.625,
-        0.046875, 0.43359375, 0.107421875, 0.6582031,
-        0.1015625, 0.5214844, 0.115234375, 0.5703125,
-        0.091796875, 0.49609375, 0.125, 0.5957031,
-        0.0859375, 0.46679688, 0.13085938, 0.625,
-        0.078125, 0.43359375, 0.13867188, 0.6582031,
-        0.1328125, 0.5214844, 0.14648438, 0.5703125,
-        0.123046875, 0.49609375, 0.15625, 0.5957031,
-        0.1171875, 0.46679688, 0.16210938, 0.625,
-        0.109375, 0.43359375, 0.16992188, 0.6582031,
-        0.1640625, 0.5214844, 0.17773438, 0.5703125,
-        0.15429688, 0.49609375, 0.1875, 0.5957031,
-        0.1484375, 0.46679688, 0.19335938, 0.625,
-        0.140625, 0.43359375, 0.20117188, 0.6582031,
-        0.1953125, 0.5214844, 0.20898438, 0.5703125,
-        0.18554688, 0.49609375, 0.21875, 0.5957031,
-        0.1796875, 0.46679688, 0.22460938, 0.625,
-        0.171875, 0.43359375, 0.23242188, 0.6582031,
-        0.2265625, 0.5214844, 0.24023438, 0.5703125,
-        0.21679688, 0.49609375, 0.25, 0.5957031,
-        0.2109375, 0.46679688, 0.25585938, 0.625,
-        0.203125, 0.43359375, 0.26367188, 0.6582031,
-        0.2578125, 0.5214844, 0.27148438, 0.5703125,
-        0.24804688, 0.49609375, 0.28125, 0.5957031,
-        0.2421875, 0.46679688, 0.28710938, 0.625,
-        0.234375, 0.43359375, 0.29492188, 0.6582031,
-        0.2890625, 0.5214844, 0.30273438, 0.5703125,
-        0.27929688, 0.49609375, 0.3125, 0.5957031,
-        0.2734375, 0.46679688, 0.31835938, 0.625,
-        0.265625, 0.43359375, 0.32617188, 0.6582031,
-        0.3203125, 0.5214844, 0.33398438, 0.5703125,
-        0.31054688, 0.49609375, 0.34375, 0.5957031,
-        0.3046875, 0.46679688, 0.34960938, 0.625,
-        0.296875, 0.43359375, 0.35742188, 0.6582031,
-        0.3515625, 0.5214844, 0.36523438, 0.5703125,
-        0.34179688, 0.49609375, 0.375, 0.5957031,
-        0.3359375, 0.46679688, 0.38085938, 0.625,
-        0.328125, 0.43359375, 0.38867188, 0.6582031,
-        0.3828125, 0.5214844, 0.39648438, 0.5703125,
-        0.37304688, 0.49609375, 0.40625, 0.5957031,
-        0.3671875, 0.46679688, 0.41210938, 0.625,
-        0.359375, 0.43359375, 0.41992188, 0.6582031,
-        0.4140625, 0.5214844, 0.42773438, 0.5703125,
-        0.40429688, 0.49609375, 0.4375, 0.5957031,
-        0.3984375, 0.46679688, 0.44335938, 0.625,
-        0.390625, 0.43359375, 0.45117188, 0.6582031,
-        0.4453125, 0.5214844, 0.45898438, 0.5703125,
-        0.43554688, 0.49609375, 0.46875, 0.5957031,
-        0.4296875, 0.46679688, 0.47460938, 0.625,
-        0.421875, 0.43359375, 0.48242188, 0.6582031,
-        0.4765625, 0.5214844, 0.49023438, 0.5703125,
-        0.46679688, 0.49609375, 0.5, 0.5957031,
-        0.4609375, 0.46679688, 0.5058594, 0.625,
-        0.453125, 0.43359375, 0.5136719, 0.6582031,
-        0.5078125, 0.5214844, 0.5214844, 0.5703125,
-        0.49804688, 0.49609375, 0.53125, 0.5957031,
-        0.4921875, 0.46679688, 0.5371094, 0.625,
-        0.484375, 0.43359375, 0.5449219, 0.6582031,
-        0.5390625, 0.5214844, 0.5527344, 0.5703125,
-        0.5292969, 0.49609375, 0.5625, 0.5957031,
-        0.5234375, 0.46679688, 0.5683594, 0.625,
-        0.515625, 0.43359375, 0.5761719, 0.6582031,
-        0.5703125, 0.5214844, 0.5839844, 0.5703125,
-        0.5605469, 0.49609375, 0.59375, 0.5957031,
-        0.5546875, 0.46679688, 0.5996094, 0.625,
-        0.546875, 0.43359375, 0.6074219, 0.6582031,
-        0.6015625, 0.5214844, 0.6152344, 0.5703125,
-        0.5917969, 0.49609375, 0.625, 0.5957031,
-        0.5859375, 0.46679688, 0.6308594, 0.625,
-        0.578125, 0.43359375, 0.6386719, 0.6582031,
-        0.6328125, 0.5214844, 0.6464844, 0.5703125,
-        0.6230469, 0.49609375, 0.65625, 0.5957031,
-        0.6171875, 0.46679688, 0.6621094, 0.625,
-        0.609375, 0.43359375, 0.6699219, 0.6582031,
-        0.6640625, 0.5214844, 0.6777344, 0.5703125,
-        0.6542969, 0.49609375, 0.6875, 0.5957031,
-        0.6484375, 0.46679688, 0.6933594, 0.625,
-        0.640625, 0.43359375, 0.7011719, 0.6582031,
-        0.6953125, 0.5214844, 0.7089844, 0.5703125,
-        0.6855469, 0.49609375, 0.71875, 0.5957031,
-        0.6796875, 0.46679688, 0.7246094, 0.625,
-        0.671875, 0.43359375, 0.7324219, 0.6582031,
-        0.7265625, 0.5214844, 0.7402344, 0.5703125,
-        0.7167969, 0.49609375, 0.75, 0.5957031,
-        0.7109375, 0.46679688, 0.7558594, 0.625,
-        0.703125, 0.43359375, 0.7636719, 0.6582031,
-        0.7578125, 0.5214844, 0.7714844, 0.5703125,
-        0.7480469, 0.49609375, 0.78125, 0.5957031,
-        0.7421875, 0.46679688, 0.7871094, 0.625,
-        0.734375, 0.43359375, 0.7949219, 0.6582031,
-        0.7890625, 0.5214844, 0.8027344, 0.5703125,
-        0.7792969, 0.49609375, 0.8125, 0.5957031,
-        0.7734375, 0.46679688, 0.8183594, 0.625,
-        0.765625, 0.43359375, 0.8261719, 0.6582031,
-        0.8203125, 0.5214844, 0.8339844, 0.5703125,
-        0.8105469, 0.49609375, 0.84375, 0.5957031,
-        0.8046875, 0.46679688, 0.8496094, 0.625,
-        0.796875, 0.43359375, 0.8574219, 0.6582031,
-        0.8515625, 0.5214844, 0.8652344, 0.5703125,
-        0.8417969, 0.49609375, 0.875, 0.5957031,
-        0.8359375, 0.46679688, 0.8808594, 0.625,
-        0.828125, 0.43359375, 0.8886719, 0.6582031,
-        0.8828125, 0.5214844, 0.8964844, 0.5703125,
-        0.8730469, 0.49609375, 0.90625, 0.5957031,
-        0.8671875, 0.46679688, 0.9121094, 0.625,
-        0.859375, 0.43359375, 0.9199219, 0.6582031,
-        0.9140625, 0.5214844, 0.9277344, 0.5703125,
-        0.9042969, 0.49609375, 0.9375, 0.5957031,
-        0.8984375, 0.46679688, 0.9433594, 0.625,
-        0.890625, 0.43359375, 0.9511719, 0.6582031,
-        0.9453125, 0.5214844, 0.9589844, 0.5703125,
-        0.9355469, 0.49609375, 0.96875, 0.5957031,
-        0.9296875, 0.46679688, 0.9746094, 0.625,
-        0.921875, 0.43359375, 0.9824219, 0.6582031,
-        0.9765625, 0.5214844, 0.9902344, 0.5703125,
-        0.9667969, 0.49609375, 1.0, 0.5957031,
-        0.9609375, 0.46679688, 1.0058594, 0.625,
-        0.953125, 0.43359375, 1.0136719, 0.6582031,
-        0.0078125, 0.5527344, 0.021484375, 0.6015625,
-        0.0, 0.52734375, 0.03125, 0.6269531,
-        -0.005859375, 0.49804688, 0.037109375, 0.65625,
-        -0.013671875, 0.46484375, 0.044921875, 0.6894531,
-        0.0390625, 0.5527344, 0.052734375, 0.6015625,
-        0.029296875, 0.52734375, 0.0625, 0.6269531,
-        0.0234375, 0.49804688, 0.068359375, 0.65625,
-        0.015625, 0.46484375, 0.076171875, 0.6894531,
-        0.0703125, 0.5527344, 0.083984375, 0.6015625,
-        0.060546875, 0.52734375, 0.09375, 0.6269531,
-        0.0546875, 0.49804688, 0.099609375, 0.65625,
-        0.046875, 0.46484375, 0.107421875, 0.6894531,
-        0.1015625, 0.5527344, 0.115234375, 0.6015625,
-        0.091796875, 0.52734375, 0.125, 0.6269531,
-        0.0859375, 0.49804688, 0.13085938, 0.65625,
-        0.078125, 0.46484375, 0.13867188, 0.6894531,
-        0.1328125, 0.5527344, 0.14648438, 0.6015625,
-        0.123046875, 0.52734375, 0.15625, 0.6269531,
-        0.1171875, 0.49804688, 0.16210938, 0.65625,
-        0.109375, 0.46484375, 0.16992188, 0.6894531,
-        0.1640625, 0.5527344, 0.17773438, 0.6015625,
-        0.15429688, 0.52734375, 0.1875, 0.6269531,
-        0.1484375, 0.49804688, 0.19335938, 0.65625,
-        0.140625, 0.46484375, 0.20117188, 0.6894531,
-        0.1953125, 0.5527344, 0.20898438, 0.6015625,
-        0.18554688, 0.52734375, 0.21875, 0.6269531,
-        0.1796875, 0.49804688, 0.22460938, 0.65625,
-        0.171875, 0.46484375, 0.23242188, 0.6894531,
-        0.2265625, 0.5527344, 0.24023438, 0.6015625,
-        0.21679688, 0.52734375, 0.25, 0.6269531,
-        0.2109375, 0.49804688, 0.25585938, 0.65625,
-        0.203125, 0.46484375, 0.26367188, 0.6894531,
-        0.2578125, 0.5527344, 0.27148438, 0.6015625,
-        0.24804688, 0.52734375, 0.28125, 0.6269531,
-        0.2421875, 0.49804688, 0.28710938, 0.65625,
-        0.234375, 0.46484375, 0.29492188, 0.6894531,
-        0.2890625, 0.5527344, 0.30273438, 0.6015625,
-        0.27929688, 0.52734375, 0.3125, 0.6269531,
-        0.2734375, 0.49804688, 0.31835938, 0.65625,
-        0.265625, 0.46484375, 0.32617188, 0.6894531,
-        0.3203125, 0.5527344, 0.33398438, 0.6015625,
-        0.31054688, 0.52734375, 0.34375, 0.6269531,
-        0.3046875, 0.49804688, 0.34960938, 0.65625,
-        0.296875, 0.46484375, 0.35742188, 0.6894531,
-        0.3515625, 0.5527344, 0.36523438, 0.6015625,
-        0.34179688, 0.52734375, 0.375, 0.6269531,
-        0.3359375, 0.49804688, 0.38085938, 0.65625,
-        0.328125, 0.46484375, 0.38867188, 0.6894531,
-        0.3828125, 0.5527344, 0.39648438, 0.6015625,
-        0.37304688, 0.52734375, 0.40625, 0.6269531,
-        0.3671875, 0.49804688, 0.41210938, 0.65625,
-        0.359375, 0.46484375, 0.41992188, 0.6894531,
-        0.4140625, 0.5527344, 0.42773438, 0.6015625,
-        0.40429688, 0.52734375, 0.4375, 0.6269531,
-        0.3984375, 0.49804688, 0.44335938, 0.65625,
-        0.390625, 0.46484375, 0.45117188, 0.6894531,
-        0.4453125, 0.5527344, 0.45898438, 0.6015625,
-        0.43554688, 0.52734375, 0.46875, 0.6269531,
-        0.4296875, 0.49804688, 0.47460938, 0.65625,
-        0.421875, 0.46484375, 0.48242188, 0.6894531,
-        0.4765625, 0.5527344, 0.49023438, 0.6015625,
-        0.46679688, 0.52734375, 0.5, 0.6269531,
-        0.4609375, 0.49804688, 0.5058594, 0.65625,
-        0.453125, 0.46484375, 0.5136719, 0.6894531,
-        0.5078125, 0.5527344, 0.5214844, 0.6015625,
-        0.49804688, 0.52734375, 0.53125, 0.6269531,
-        0.4921875, 0.49804688, 0.5371094, 0.65625,
-        0.484375, 0.46484375, 0.5449219, 0.6894531,
-        0.5390625, 0.5527344, 0.5527344, 0.6015625,
-        0.5292969, 0.52734375, 0.5625, 0.6269531,
-        0.5234375, 0.49804688, 0.5683594, 0.65625,
-        0.515625, 0.46484375, 0.5761719, 0.6894531,
-        0.5703125, 0.5527344, 0.5839844, 0.6015625,
-        0.5605469, 0.52734375, 0.59375, 0.6269531,
-        0.5546875, 0.49804688, 0.5996094, 0.65625,
-        0.546875, 0.46484375, 0.6074219, 0.6894531,
-        0.6015625, 0.5527344, 0.6152344, 0.6015625,
-        0.5917969, 0.52734375, 0.625, 0.6269531,
-        0.5859375, 0.49804688, 0.6308594, 0.65625,
-        0.578125, 0.46484375, 0.6386719, 0.6894531,
-        0.6328125, 0.5527344, 0.6464844, 0.6015625,
-        0.6230469, 0.52734375, 0.65625, 0.6269531,
-        0.6171875, 0.49804688, 0.6621094, 0.65625,
-        0.609375, 0.46484375, 0.6699219, 0.6894531,
-        0.6640625, 0.5527344, 0.6777344, 0.6015625,
-        0.6542969, 0.52734375, 0.6875, 0.6269531,
-        0.6484375, 0.49804688, 0.6933594, 0.65625,
-        0.640625, 0.46484375, 0.7011719, 0.6894531,
-        0.6953125, 0.5527344, 0.7089844, 0.6015625,
-        0.6855469, 0.52734375, 0.71875, 0.6269531,
-        0.6796875, 0.49804688, 0.7246094, 0.65625,
-        0.671875, 0.46484375, 0.7324219, 0.6894531,
-        0.7265625, 0.5527344, 0.7402344, 0.6015625,
-        0.7167969, 0.52734375, 0.75, 0.6269531,
-        0.7109375, 0.49804688, 0.7558594, 0.65625,
-        0.703125, 0.46484375, 0.7636719, 0.6894531,
-        0.7578125, 0.5527344, 0.7714844, 0.6015625,
-        0.7480469, 0.52734375, 0.78125, 0.6269531,
-        0.7421875, 0.49804688, 0.7871094, 0.65625,
-        0.734375, 0.46484375, 0.7949219, 0.6894531,
-        0.7890625, 0.5527344, 0.8027344, 0.6015625,
-        0.7792969, 0.52734375, 0.8125, 0.6269531,
-        0.7734375, 0.49804688, 0.8183594, 0.65625,
-        0.765625, 0.46484375, 0.8261719, 0.6894531,
-        0.8203125, 0.5527344, 0.8339844, 0.6015625,
-        0.8105469, 0.52734375, 0.84375, 0.6269531,
-        0.8046875, 0.49804688, 0.8496094, 0.65625,
-        0.796875, 0.46484375, 0.8574219, 0.6894531,
-        0.8515625, 0.5527344, 0.8652344, 0.6015625,
-        0.8417969, 0.52734375, 0.875, 0.6269531,
-        0.8359375, 0.49804688, 0.8808594, 0.65625,
-        0.828125, 0.46484375, 0.8886719, 0.6894531,
-        0.8828125, 0.5527344, 0.8964844, 0.6015625,
-        0.8730469, 0.52734375, 0.90625, 0.6269531,
-        0.8671875, 0.49804688, 0.9121094, 0.65625,
-        0.859375, 0.46484375, 0.9199219, 0.6894531,
-        0.9140625, 0.5527344, 0.9277344, 0.6015625,
-        0.9042969, 0.52734375, 0.9375, 0.6269531,
-        0.8984375, 0.49804688, 0.9433594, 0.65625,
-        0.890625, 0.46484375, 0.9511719, 0.6894531,
-        0.9453125, 0.5527344, 0.9589844, 0.6015625,
-        0.9355469, 0.52734375, 0.96875, 0.6269531,
-        0.9296875, 0.49804688, 0.9746094, 0.65625,
-        0.921875, 0.46484375, 0.9824219, 0.6894531,
-        0.9765625, 0.5527344, 0.9902344, 0.6015625,
-        0.9667969, 0.52734375, 1.0, 0.6269531,
-        0.9609375, 0.49804688, 1.0058594, 0.65625,
-        0.953125, 0.46484375, 1.0136719, 0.6894531,
-        0.0078125, 0.5839844, 0.021484375, 0.6328125,
-        0.0, 0.55859375, 0.03125, 0.6582031,
-        -0.005859375, 0.5292969, 0.037109375, 0.6875,
-        -0.013671875, 0.49609375, 0.044921875, 0.7207031,
-        0.0390625, 0.5839844, 0.052734375, 0.6328125,
-        0.029296875, 0.55859375, 0.0625, 0.6582031,
-        0.0234375, 0.5292969, 0.068359375, 0.6875,
-        0.015625, 0.49609375, 0.076171875, 0.7207031,
-        0.0703125, 0.5839844, 0.083984375, 0.6328125,
-        0.060546875, 0.55859375, 0.09375, 0.6582031,
-        0.0546875, 0.5292969, 0.099609375, 0.6875,
-        0.046875, 0.49609375, 0.107421875, 0.7207031,
-        0.1015625, 0.5839844, 0.115234375, 0.6328125,
-        0.091796875, 0.55859375, 0.125, 0.6582031,
-        0.0859375, 0.5292969, 0.13085938, 0.6875,
-        0.078125, 0.49609375, 0.13867188, 0.7207031,
-        0.1328125, 0.5839844, 0.14648438, 0.6328125,
-        0.123046875, 0.55859375, 0.15625, 0.6582031,
-        0.1171875, 0.5292969, 0.16210938, 0.6875,
-        0.109375, 0.49609375, 0.16992188, 0.7207031,
-        0.1640625, 0.5839844, 0.17773438, 0.6328125,
-        0.15429688, 0.55859375, 0.1875, 0.6582031,
-        0.1484375, 0.5292969, 0.19335938, 0.6875,
-        0.140625, 0.49609375, 0.20117188, 0.7207031,
-        0.1953125, 0.5839844, 0.20898438, 0.6328125,
-        0.18554688, 0.55859375, 0.21875, 0.6582031,
-        0.1796875, 0.5292969, 0.22460938, 0.6875,
-        0.171875, 0.49609375, 0.23242188, 0.7207031,
-        0.2265625, 0.5839844, 0.24023438, 0.6328125,
-        0.21679688, 0.55859375, 0.25, 0.6582031,
-        0.2109375, 0.5292969, 0.25585938, 0.6875,
-        0.203125, 0.49609375, 0.26367188, 0.7207031,
-        0.2578125, 0.5839844, 0.27148438, 0.6328125,
-        0.24804688, 0.55859375, 0.28125, 0.6582031,
-        0.2421875, 0.5292969, 0.28710938, 0.6875,
-        0.234375, 0.49609375, 0.29492188, 0.7207031,
-        0.2890625, 0.5839844, 0.30273438, 0.6328125,
-        0.27929688, 0.55859375, 0.3125, 0.6582031,
-        0.2734375, 0.5292969, 0.31835938, 0.6875,
-        0.265625, 0.49609375, 0.32617188, 0.7207031,
-        0.3203125, 0.5839844, 0.33398438, 0.6328125,
-        0.31054688, 0.55859375, 0.34375, 0.6582031,
-        0.3046875, 0.5292969, 0.34960938, 0.6875,
-        0.296875, 0.49609375, 0.35742188, 0.7207031,
-        0.3515625, 0.5839844, 0.36523438, 0.6328125,
-        0.34179688, 0.55859375, 0.375, 0.6582031,
-        0.3359375, 0.5292969, 0.38085938, 0.6875,
-        0.328125, 0.49609375, 0.38867188, 0.7207031,
-        0.3828125, 0.5839844, 0.39648438, 0.6328125,
-        0.37304688, 0.55859375, 0.40625, 0.6582031,
-        0.3671875, 0.5292969, 0.41210938, 0.6875,
-        0.359375, 0.49609375, 0.41992188, 0.7207031,
-        0.4140625, 0.5839844, 0.42773438, 0.6328125,
-        0.40429688, 0.55859375, 0.4375, 0.6582031,
-        0.3984375, 0.5292969, 0.44335938, 0.6875,
-        0.390625, 0.49609375, 0.45117188, 0.7207031,
-        0.4453125, 0.5839844, 0.45898438, 0.6328125,
-        0.43554688, 0.55859375, 0.46875, 0.6582031,
-        0.4296875, 0.5292969, 0.47460938, 0.6875,
-        0.421875, 0.49609375, 0.48242188, 0.7207031,
-        0.4765625, 0.5839844, 0.49023438, 0.6328125,
-        0.46679688, 0.55859375, 0.5, 0.6582031,
-        0.4609375, 0.5292969, 0.5058594, 0.6875,
-        0.453125, 0.49609375, 0.5136719, 0.7207031,
-        0.5078125, 0.5839844, 0.5214844, 0.6328125,
-        0.49804688, 0.55859375, 0.53125, 0.6582031,
-        0.4921875, 0.5292969, 0.5371094, 0.6875,
-        0.484375, 0.49609375, 0.5449219, 0.7207031,
-        0.5390625, 0.5839844, 0.5527344, 0.6328125,
-        0.5292969, 0.55859375, 0.5625, 0.6582031,
-        0.5234375, 0.5292969, 0.5683594, 0.6875,
-        0.515625, 0.49609375, 0.5761719, 0.7207031,
-        0.5703125, 0.5839844, 0.5839844, 0.6328125,
-        0.5605469, 0.55859375, 0.59375, 0.6582031,
-        0.5546875, 0.5292969, 0.5996094, 0.6875,
-        0.546875, 0.49609375, 0.6074219, 0.7207031,
-        0.6015625, 0.5839844, 0.6152344, 0.6328125,
-        0.5917969, 0.55859375, 0.625, 0.6582031,
-        0.5859375, 0.5292969, 0.6308594, 0.6875,
-        0.578125, 0.49609375, 0.6386719, 0.7207031,
-        0.6328125, 0.5839844, 0.6464844, 0.6328125,
-        0.6230469, 0.55859375, 0.65625, 0.6582031,
-        0.6171875, 0.5292969, 0.6621094, 0.6875,
-        0.609375, 0.49609375, 0.6699219, 0.7207031,
-        0.6640625, 0.5839844, 0.6777344, 0.6328125,
-        0.6542969, 0.55859375, 0.6875, 0.6582031,
-        0.6484375, 0.5292969, 0.6933594, 0.6875,
-        0.640625, 0.49609375, 0.7011719, 0.7207031,
-        0.6953125, 0.5839844, 0.7089844, 0.6328125,
-        0.6855469, 0.55859375, 0.71875, 0.6582031,
-        0.6796875, 0.5292969, 0.7246094, 0.6875,
-        0.671875, 0.49609375, 0.7324219, 0.7207031,
-        0.7265625, 0.5839844, 0.7402344, 0.6328125,
-        0.7167969, 0.55859375, 0.75, 0.6582031,
-        0.7109375, 0.5292969, 0.7558594, 0.6875,
-        0.703125, 0.49609375, 0.7636719, 0.7207031,
-        0.7578125, 0.5839844, 0.7714844, 0.6328125,
-        0.7480469, 0.55859375, 0.78125, 0.6582031,
-        0.7421875, 0.5292969, 0.7871094, 0.6875,
-        0.734375, 0.49609375, 0.7949219, 0.7207031,
-        0.7890625, 0.5839844, 0.8027344, 0.6328125,
-        0.7792969, 0.55859375, 0.8125, 0.6582031,
-        0.7734375, 0.5292969, 0.8183594, 0.6875,
-        0.765625, 0.49609375, 0.8261719, 0.7207031,
-        0.8203125, 0.5839844, 0.8339844, 0.6328125,
-        0.8105469, 0.55859375, 0.84375, 0.6582031,
-        0.8046875, 0.5292969, 0.8496094, 0.6875,
-        0.796875, 0.49609375, 0.8574219, 0.7207031,
-        0.8515625, 0.5839844, 0.8652344, 0.6328125,
-        0.8417969, 0.55859375, 0.875, 0.6582031,
-        0.8359375, 0.5292969, 0.8808594, 0.6875,
-        0.828125, 0.49609375, 0.8886719, 0.7207031,
-        0.8828125, 0.5839844, 0.8964844, 0.6328125,
-        0.8730469, 0.55859375, 0.90625, 0.6582031,
-        0.8671875, 0.5292969, 0.9121094, 0.6875,
-        0.859375, 0.49609375, 0.9199219, 0.7207031,
-        0.9140625, 0.5839844, 0.9277344, 0.6328125,
-        0.9042969, 0.55859375, 0.9375, 0.6582031,
-        0.8984375, 0.5292969, 0.9433594, 0.6875,
-        0.890625, 0.49609375, 0.9511719, 0.7207031,
-        0.9453125, 0.5839844, 0.9589844, 0.6328125,
-        0.9355469, 0.55859375, 0.96875, 0.6582031,
-        0.9296875, 0.5292969, 0.9746094, 0.6875,
-        0.921875, 0.49609375, 0.9824219, 0.7207031,
-        0.9765625, 0.5839844, 0.9902344, 0.6328125,
-        0.9667969, 0.55859375, 1.0, 0.6582031,
-        0.9609375, 0.5292969, 1.0058594, 0.6875,
-        0.953125, 0.49609375, 1.0136719, 0.7207031,
-        0.0078125, 0.6152344, 0.021484375, 0.6640625,
-        0.0, 0.58984375, 0.03125, 0.6894531,
-        -0.005859375, 0.5605469, 0.037109375, 0.71875,
-        -0.013671875, 0.52734375, 0.044921875, 0.7519531,
-        0.0390625, 0.6152344, 0.052734375, 0.6640625,
-        0.029296875, 0.58984375, 0.0625, 0.6894531,
-        0.0234375, 0.5605469, 0.068359375, 0.71875,
-        0.015625, 0.52734375, 0.076171875, 0.7519531,
-        0.0703125, 0.6152344, 0.083984375, 0.6640625,
-        0.060546875, 0.58984375, 0.09375, 0.6894531,
-        0.0546875, 0.5605469, 0.099609375, 0.71875,
-        0.046875, 0.52734375, 0.107421875, 0.7519531,
-        0.1015625, 0.6152344, 0.115234375, 0.6640625,
-        0.091796875, 0.58984375, 0.125, 0.6894531,
-        0.0859375, 0.5605469, 0.13085938, 0.71875,
-        0.078125, 0.52734375, 0.13867188, 0.7519531,
-        0.1328125, 0.6152344, 0.14648438, 0.6640625,
-        0.123046875, 0.58984375, 0.15625, 0.6894531,
-        0.1171875, 0.5605469, 0.16210938, 0.71875,
-        0.109375, 0.52734375, 0.16992188, 0.7519531,
-        0.1640625, 0.6152344, 0.17773438, 0.6640625,
-        0.15429688, 0.58984375, 0.1875, 0.6894531,
-        0.1484375, 0.5605469, 0.19335938, 0.71875,
-        0.140625, 0.52734375, 0.20117188, 0.7519531,
-        0.1953125, 0.6152344, 0.20898438, 0.6640625,
-        0.18554688, 0.58984375, 0.21875, 0.6894531,
-        0.1796875, 0.5605469, 0.22460938, 0.71875,
-        0.171875, 0.52734375, 0.23242188, 0.7519531,
-        0.2265625, 0.6152344, 0.24023438, 0.6640625,
-        0.21679688, 0.58984375, 0.25, 0.6894531,
-        0.2109375, 0.5605469, 0.25585938, 0.71875,
-        0.203125, 0.52734375, 0.26367188, 0.7519531,
-        0.2578125, 0.6152344, 0.27148438, 0.6640625,
-        0.24804688, 0.58984375, 0.28125, 0.6894531,
-        0.2421875, 0.5605469, 0.28710938, 0.71875,
-        0.234375, 0.52734375, 0.29492188, 0.7519531,
-        0.2890625, 0.6152344, 0.30273438, 0.6640625,
-        0.27929688, 0.58984375, 0.3125, 0.6894531,
-        0.2734375, 0.5605469, 0.31835938, 0.71875,
-        0.265625, 0.52734375, 0.32617188, 0.7519531,
-        0.3203125, 0.6152344, 0.33398438, 0.6640625,
-        0.31054688, 0.58984375, 0.34375, 0.6894531,
-        0.3046875, 0.5605469, 0.34960938, 0.71875,
-        0.296875, 0.52734375, 0.35742188, 0.7519531,
-        0.3515625, 0.6152344, 0.36523438, 0.6640625,
-        0.34179688, 0.58984375, 0.375, 0.6894531,
-        0.3359375, 0.5605469, 0.38085938, 0.71875,
-        0.328125, 0.52734375, 0.38867188, 0.7519531,
-        0.3828125, 0.6152344, 0.39648438, 0.6640625,
-        0.37304688, 0.58984375, 0.40625, 0.6894531,
-        0.3671875, 0.5605469, 0.41210938, 0.71875,
-        0.359375, 0.52734375, 0.41992188, 0.7519531,
-        0.4140625, 0.6152344, 0.42773438, 0.6640625,
-        0.40429688, 0.58984375, 0.4375, 0.6894531,
-        0.3984375, 0.5605469, 0.44335938, 0.71875,
-        0.390625, 0.52734375, 0.45117188, 0.7519531,
-        0.4453125, 0.6152344, 0.45898438, 0.6640625,
-        0.43554688, 0.58984375, 0.46875, 0.6894531,
-        0.4296875, 0.5605469, 0.47460938, 0.71875,
-        0.421875, 0.52734375, 0.48242188, 0.7519531,
-        0.4765625, 0.6152344, 0.49023438, 0.6640625,
-        0.46679688, 0.58984375, 0.5, 0.6894531,
-        0.4609375, 0.5605469, 0.5058594, 0.71875,
-        0.453125, 0.52734375, 0.5136719, 0.7519531,
-        0.5078125, 0.6152344, 0.5214844, 0.6640625,
-        0.49804688, 0.58984375, 0.53125, 0.6894531,
-        0.4921875, 0.5605469, 0.5371094, 0.71875,
-        0.484375, 0.52734375, 0.5449219, 0.7519531,
-        0.5390625, 0.6152344, 0.5527344, 0.6640625,
-        0.5292969, 0.58984375, 0.5625, 0.6894531,
-        0.5234375, 0.5605469, 0.5683594, 0.71875,
-        0.515625, 0.52734375, 0.5761719, 0.7519531,
-        0.5703125, 0.6152344, 0.5839844, 0.6640625,
-        0.5605469, 0.58984375, 0.59375, 0.6894531,
-        0.5546875, 0.5605469, 0.5996094, 0.71875,
-        0.546875, 0.52734375, 0.6074219, 0.7519531,
-        0.6015625, 0.6152344, 0.6152344, 0.6640625,
-        0.5917969, 0.58984375, 0.625, 0.6894531,
-        0.5859375, 0.5605469, 0.6308594, 0.71875,
-        0.578125, 0.52734375, 0.6386719, 0.7519531,
-        0.6328125, 0.6152344, 0.6464844, 0.6640625,
-        0.6230469, 0.58984375, 0.65625, 0.6894531,
-        0.6171875, 0.5605469, 0.6621094, 0.71875,
-        0.609375, 0.52734375, 0.6699219, 0.7519531,
-        0.6640625, 0.6152344, 0.6777344, 0.6640625,
-        0.6542969, 0.58984375, 0.6875, 0.6894531,
-        0.6484375, 0.5605469, 0.6933594, 0.71875,
-        0.640625, 0.52734375, 0.7011719, 0.7519531,
-        0.6953125, 0.6152344, 0.7089844, 0.6640625,
-        0.6855469, 0.58984375, 0.71875, 0.6894531,
-        0.6796875, 0.5605469, 0.7246094, 0.71875,
-        0.671875, 0.52734375, 0.7324219, 0.7519531,
-        0.7265625, 0.6152344, 0.7402344, 0.6640625,
-        0.7167969, 0.58984375, 0.75, 0.6894531,
-        0.7109375, 0.5605469, 0.7558594, 0.71875,
-        0.703125, 0.52734375, 0.7636719, 0.7519531,
-        0.7578125, 0.6152344, 0.7714844, 0.6640625,
-        0.7480469, 0.58984375, 0.78125, 0.6894531,
-        0.7421875, 0.5605469, 0.7871094, 0.71875,
-        0.734375, 0.52734375, 0.7949219, 0.7519531,
-        0.7890625, 0.6152344, 0.8027344, 0.6640625,
-        0.7792969, 0.58984375, 0.8125, 0.6894531,
-        0.7734375, 0.5605469, 0.8183594, 0.71875,
-        0.765625, 0.52734375, 0.8261719, 0.7519531,
-        0.8203125, 0.6152344, 0.8339844, 0.6640625,
-        0.8105469, 0.58984375, 0.84375, 0.6894531,
-        0.8046875, 0.5605469, 0.8496094, 0.71875,
-        0.796875, 0.52734375, 0.8574219, 0.7519531,
-        0.8515625, 0.6152344, 0.8652344, 0.6640625,
-        0.8417969, 0.58984375, 0.875, 0.6894531,
-        0.8359375, 0.5605469, 0.8808594, 0.71875,
-        0.828125, 0.52734375, 0.8886719, 0.7519531,
-        0.8828125, 0.6152344, 0.8964844, 0.6640625,
-        0.8730469, 0.58984375, 0.90625, 0.6894531,
-        0.8671875, 0.5605469, 0.9121094, 0.71875,
-        0.859375, 0.52734375, 0.9199219, 0.7519531,
-        0.9140625, 0.6152344, 0.9277344, 0.6640625,
-        0.9042969, 0.58984375, 0.9375, 0.6894531,
-        0.8984375, 0.5605469, 0.9433594, 0.71875,
-        0.890625, 0.52734375, 0.9511719, 0.7519531,
-        0.9453125, 0.6152344, 0.9589844, 0.6640625,
-        0.9355469, 0.58984375, 0.96875, 0.6894531,
-        0.9296875, 0.5605469, 0.9746094, 0.71875,
-        0.921875, 0.52734375, 0.9824219, 0.7519531,
-        0.9765625, 0.6152344, 0.9902344, 0.6640625,
-        0.9667969, 0.58984375, 1.0, 0.6894531,
-        0.9609375, 0.5605469, 1.0058594, 0.71875,
-        0.953125, 0.52734375, 1.0136719, 0.7519531,
-        0.0078125, 0.6464844, 0.021484375, 0.6953125,
-        0.0, 0.62109375, 0.03125, 0.7207031,
-        -0.005859375, 0.5917969, 0.037109375, 0.75,
-        -0.013671875, 0.55859375, 0.044921875, 0.7832031,
-        0.0390625, 0.6464844, 0.052734375, 0.6953125,
-        0.029296875, 0.62109375, 0.0625, 0.7207031,
-        0.0234375, 0.5917969, 0.068359375, 0.75,
-        0.015625, 0.55859375, 0.076171875, 0.7832031,
-        0.0703125, 0.6464844, 0.083984375, 0.6953125,
-        0.060546875, 0.62109375, 0.09375, 0.7207031,
-        0.0546875, 0.5917969, 0.099609375, 0.75,
-        0.046875, 0.55859375, 0.107421875, 0.7832031,
-        0.1015625, 0.6464844, 0.115234375, 0.6953125,
-        0.091796875, 0.62109375, 0.125, 0.7207031,
-        0.0859375, 0.5917969, 0.13085938, 0.75,
-        0.078125, 0.55859375, 0.13867188, 0.7832031,
-        0.1328125, 0.6464844, 0.14648438, 0.6953125,
-        0.123046875, 0.62109375, 0.15625, 0.7207031,
-        0.1171875, 0.5917969, 0.16210938, 0.75,
-        0.109375, 0.55859375, 0.16992188, 0.7832031,
-        0.1640625, 0.6464844, 0.17773438, 0.6953125,
-        0.15429688, 0.62109375, 0.1875, 0.7207031,
-        0.1484375, 0.5917969, 0.19335938, 0.75,
-        0.140625, 0.55859375, 0.20117188, 0.7832031,
-        0.1953125, 0.6464844, 0.20898438, 0.6953125,
-        0.18554688, 0.62109375, 0.21875, 0.7207031,
-        0.1796875, 0.5917969, 0.22460938, 0.75,
-        0.171875, 0.55859375, 0.23242188, 0.7832031,
-        0.2265625, 0.6464844, 0.24023438, 0.6953125,
-        0.21679688, 0.62109375, 0.25, 0.7207031,
-        0.2109375, 0.5917969, 0.25585938, 0.75,
-        0.203125, 0.55859375, 0.26367188, 0.7832031,
-        0.2578125, 0.6464844, 0.27148438, 0.6953125,
-        0.24804688, 0.62109375, 0.28125, 0.7207031,
-        0.2421875, 0.5917969, 0.28710938, 0.75,
-        0.234375, 0.55859375, 0.29492188, 0.7832031,
-        0.2890625, 0.6464844, 0.30273438, 0.6953125,
-        0.27929688, 0.62109375, 0.3125, 0.7207031,
-        0.2734375, 0.5917969, 0.31835938, 0.75,
-        0.265625, 0.55859375, 0.32617188, 0.7832031,
-        0.3203125, 0.6464844, 0.33398438, 0.6953125,
-        0.31054688, 0.62109375, 0.34375, 0.7207031,
-        0.3046875, 0.5917969, 0.34960938, 0.75,
-        0.296875, 0.55859375, 0.35742188, 0.7832031,
-        0.3515625, 0.6464844, 0.36523438, 0.6953125,
-        0.34179688, 0.62109375, 0.375, 0.7207031,
-        0.3359375, 0.5917969, 0.38085938, 0.75,
-        0.328125, 0.55859375, 0.38867188, 0.7832031,
-        0.3828125, 0.6464844, 0.39648438, 0.6953125,
-        0.37304688, 0.62109375, 0.40625, 0.7207031,
-        0.3671875, 0.5917969, 0.41210938, 0.75,
-        0.359375, 0.55859375, 0.41992188, 0.7832031,
-        0.4140625, 0.6464844, 0.42773438, 0.6953125,
-        0.40429688, 0.62109375, 0.4375, 0.7207031,
-        0.3984375, 0.5917969, 0.44335938, 0.75,
-        0.390625, 0.55859375, 0.45117188, 0.7832031,
-        0.4453125, 0.6464844, 0.45898438, 0.6953125,
-        0.43554688, 0.62109375, 0.46875, 0.7207031,
-        0.4296875, 0.5917969, 0.47460938, 0.75,
-        0.421875, 0.55859375, 0.48242188, 0.7832031,
-        0.4765625, 0.6464844, 0.49023438, 0.6953125,
-        0.46679688, 0.62109375, 0.5, 0.7207031,
-        0.4609375, 0.5917969, 0.5058594, 0.75,
-        0.453125, 0.55859375, 0.5136719, 0.7832031,
-        0.5078125, 0.6464844, 0.5214844, 0.6953125,
-        0.49804688, 0.62109375, 0.53125, 0.7207031,
-        0.4921875, 0.5917969, 0.5371094, 0.75,
-        0.484375, 0.55859375, 0.5449219, 0.7832031,
-        0.5390625, 0.6464844, 0.5527344, 0.6953125,
-        0.5292969, 0.62109375, 0.5625, 0.7207031,
-        0.5234375, 0.5917969, 0.5683594, 0.75,
-        0.515625, 0.55859375, 0.5761719, 0.7832031,
-        0.5703125, 0.6464844, 0.5839844, 0.6953125,
-        0.5605469, 0.62109375, 0.59375, 0.7207031,
-        0.5546875, 0.5917969, 0.5996094, 0.75,
-        0.546875, 0.55859375, 0.6074219, 0.7832031,
-        0.6015625, 0.6464844, 0.6152344, 0.6953125,
-        0.5917969, 0.62109375, 0.625, 0.7207031,
-        0.5859375, 0.5917969, 0.6308594, 0.75,
-        0.578125, 0.55859375, 0.6386719, 0.7832031,
-        0.6328125, 0.6464844, 0.6464844, 0.6953125,
-        0.6230469, 0.62109375, 0.65625, 0.7207031,
-        0.6171875, 0.5917969, 0.6621094, 0.75,
-        0.609375, 0.55859375, 0.6699219, 0.7832031,
-        0.6640625, 0.6464844, 0.6777344, 0.6953125,
-        0.6542969, 0.62109375, 0.6875, 0.7207031,
-        0.6484375, 0.5917969, 0.6933594, 0.75,
-        0.640625, 0.55859375, 0.7011719, 0.7832031,
-        0.6953125, 0.6464844, 0.7089844, 0.6953125,
-        0.6855469, 0.62109375, 0.71875, 0.7207031,
-        0.6796875, 0.5917969, 0.7246094, 0.75,
-        0.671875, 0.55859375, 0.7324219, 0.7832031,
-        0.7265625, 0.6464844, 0.7402344, 0.6953125,
-        0.7167969, 0.62109375, 0.75, 0.7207031,
-        0.7109375, 0.5917969, 0.7558594, 0.75,
-        0.703125, 0.55859375, 0.7636719, 0.7832031,
-        0.7578125, 0.6464844, 0.7714844, 0.6953125,
-        0.7480469, 0.62109375, 0.78125, 0.7207031,
-        0.7421875, 0.5917969, 0.7871094, 0.75,
-        0.734375, 0.55859375, 0.7949219, 0.7832031,
-        0.7890625, 0.6464844, 0.8027344, 0.6953125,
-        0.7792969, 0.62109375, 0.8125, 0.7207031,
-        0.7734375, 0.5917969, 0.8183594, 0.75,
-        0.765625, 0.55859375, 0.8261719, 0.7832031,
-        0.8203125, 0.6464844, 0.8339844, 0.6953125,
-        0.8105469, 0.62109375, 0.84375, 0.7207031,
-        0.8046875, 0.5917969, 0.8496094, 0.75,
-        0.796875, 0.55859375, 0.8574219, 0.7832031,
-        0.8515625, 0.6464844, 0.8652344, 0.6953125,
-        0.8417969, 0.62109375, 0.875, 0.7207031,
-        0.8359375, 0.5917969, 0.8808594, 0.75,
-        0.828125, 0.55859375, 0.8886719, 0.7832031,
-        0.8828125, 0.6464844, 0.8964844, 0.6953125,
-        0.8730469, 0.62109375, 0.90625, 0.7207031,
-        0.8671875, 0.5917969, 0.9121094, 0.75,
-        0.859375, 0.55859375, 0.9199219, 0.7832031,
-        0.9140625, 0.6464844, 0.9277344, 0.6953125,
-        0.9042969, 0.62109375, 0.9375, 0.7207031,
-        0.8984375, 0.5917969, 0.9433594, 0.75,
-        0.890625, 0.55859375, 0.9511719, 0.7832031,
-        0.9453125, 0.6464844, 0.9589844, 0.6953125,
-        0.9355469, 0.62109375, 0.96875, 0.7207031,
-        0.9296875, 0.5917969, 0.9746094, 0.75,
-        0.921875, 0.55859375, 0.9824219, 0.7832031,
-        0.9765625, 0.6464844, 0.9902344, 0.6953125,
-        0.9667969, 0.62109375, 1.0, 0.7207031,
-        0.9609375, 0.5917969, 1.0058594, 0.75,
-        0.953125, 0.55859375, 1.0136719, 0.7832031,
-        0.0078125, 0.6777344, 0.021484375, 0.7265625,
-        0.0, 0.65234375, 0.03125, 0.7519531,
-        -0.005859375, 0.6230469, 0.037109375, 0.78125,
-        -0.013671875, 0.58984375, 0.044921875, 0.8144531,
-        0.0390625, 0.6777344, 0.052734375, 0.7265625,
-        0.029296875, 0.65234375, 0.0625, 0.7519531,
-        0.0234375, 0.6230469, 0.068359375, 0.78125,
-        0.015625, 0.58984375, 0.076171875, 0.8144531,
-        0.0703125, 0.6777344, 0.083984375, 0.7265625,
-        0.060546875, 0.65234375, 0.09375, 0.7519531,
-        0.0546875, 0.6230469, 0.099609375, 0.78125,
-        0.046875, 0.58984375, 0.107421875, 0.8144531,
-        0.1015625, 0.6777344, 0.115234375, 0.7265625,
-        0.091796875, 0.65234375, 0.125, 0.7519531,
-        0.0859375, 0.6230469, 0.13085938, 0.78125,
-        0.078125, 0.58984375, 0.13867188, 0.8144531,
-        0.1328125, 0.6777344, 0.14648438, 0.7265625,
-        0.123046875, 0.65234375, 0.15625, 0.7519531,
-        0.1171875, 0.6230469, 0.16210938, 0.78125,
-        0.109375, 0.58984375, 0.16992188, 0.8144531,
-        0.1640625, 0.6777344, 0.17773438, 0.7265625,
-        0.15429688, 0.65234375, 0.1875, 0.7519531,
-        0.1484375, 0.6230469, 0.19335938, 0.78125,
-        0.140625, 0.58984375, 0.20117188, 0.8144531,
-        0.1953125, 0.6777344, 0.20898438, 0.7265625,
-        0.18554688, 0.65234375, 0.21875, 0.7519531,
-        0.1796875, 0.6230469, 0.22460938, 0.78125,
-        0.171875, 0.58984375, 0.23242188, 0.8144531,
-        0.2265625, 0.6777344, 0.24023438, 0.7265625,
-        0.21679688, 0.65234375, 0.25, 0.7519531,
-        0.2109375, 0.6230469, 0.25585938, 0.78125,
-        0.203125, 0.58984375, 0.26367188, 0.8144531,
-        0.2578125, 0.6777344, 0.27148438, 0.7265625,
-        0.24804688, 0.65234375, 0.28125, 0.7519531,
-        0.2421875, 0.6230469, 0.28710938, 0.78125,
-        0.234375, 0.58984375, 0.29492188, 0.8144531,
-        0.2890625, 0.6777344, 0.30273438, 0.7265625,
-        0.27929688, 0.65234375, 0.3125, 0.7519531,
-        0.2734375, 0.6230469, 0.31835938, 0.78125,
-        0.265625, 0.58984375, 0.32617188, 0.8144531,
-        0.3203125, 0.6777344, 0.33398438, 0.7265625,
-        0.31054688, 0.65234375, 0.34375, 0.7519531,
-        0.3046875, 0.6230469, 0.34960938, 0.78125,
-        0.296875, 0.58984375, 0.35742188, 0.8144531,
-        0.3515625, 0.6777344, 0.36523438, 0.7265625,
-        0.34179688, 0.65234375, 0.375, 0.7519531,
-        0.3359375, 0.6230469, 0.38085938, 0.78125,
-        0.328125, 0.58984375, 0.38867188, 0.8144531,
-        0.3828125, 0.6777344, 0.39648438, 0.7265625,
-        0.37304688, 0.65234375, 0.40625, 0.7519531,
-        0.3671875, 0.6230469, 0.41210938, 0.78125,
-        0.359375, 0.58984375, 0.41992188, 0.8144531,
-        0.4140625, 0.6777344, 0.42773438, 0.7265625,
-        0.40429688, 0.65234375, 0.4375, 0.7519531,
-        0.3984375, 0.6230469, 0.44335938, 0.78125,
-        0.390625, 0.58984375, 0.45117188, 0.8144531,
-        0.4453125, 0.6777344, 0.45898438, 0.7265625,
-        0.43554688, 0.65234375, 0.46875, 0.7519531,
-        0.4296875, 0.6230469, 0.47460938, 0.78125,
-        0.421875, 0.58984375, 0.48242188, 0.8144531,
-        0.4765625, 0.6777344, 0.49023438, 0.7265625,
-        0.46679688, 0.65234375, 0.5, 0.7519531,
-        0.4609375, 0.6230469, 0.5058594, 0.78125,
-        0.453125, 0.58984375, 0.5136719, 0.8144531,
-        0.5078125, 0.6777344, 0.5214844, 0.7265625,
-        0.49804688, 0.65234375, 0.53125, 0.7519531,
-        0.4921875, 0.6230469, 0.5371094, 0.78125,
-        0.484375, 0.58984375, 0.5449219, 0.8144531,
-        0.5390625, 0.6777344, 0.5527344, 0.7265625,
-        0.5292969, 0.65234375, 0.5625, 0.7519531,
-        0.5234375, 0.6230469, 0.5683594, 0.78125,
-        0.515625, 0.58984375, 0.5761719, 0.8144531,
-        0.5703125, 0.6777344, 0.5839844, 0.7265625,
-        0.5605469, 0.65234375, 0.59375, 0.7519531,
-        0.5546875, 0.6230469, 0.5996094, 0.78125,
-        0.546875, 0.58984375, 0.6074219, 0.8144531,
-        0.6015625, 0.6777344, 0.6152344, 0.7265625,
-        0.5917969, 0.65234375, 0.625, 0.7519531,
-        0.5859375, 0.6230469, 0.6308594, 0.78125,
-        0.578125, 0.58984375, 0.6386719, 0.8144531,
-        0.6328125, 0.6777344, 0.6464844, 0.7265625,
-        0.6230469, 0.65234375, 0.65625, 0.7519531,
-        0.6171875, 0.6230469, 0.6621094, 0.78125,
-        0.609375, 0.58984375, 0.6699219, 0.8144531,
-        0.6640625, 0.6777344, 0.6777344, 0.7265625,
-        0.6542969, 0.65234375, 0.6875, 0.7519531,
-        0.6484375, 0.6230469, 0.6933594, 0.78125,
-        0.640625, 0.58984375, 0.7011719, 0.8144531,
-        0.6953125, 0.6777344, 0.7089844, 0.7265625,
-        0.6855469, 0.65234375, 0.71875, 0.7519531,
-        0.6796875, 0.6230469, 0.7246094, 0.78125,
-        0.671875, 0.58984375, 0.7324219, 0.8144531,
-        0.7265625, 0.6777344, 0.7402344, 0.7265625,
-        0.7167969, 0.65234375, 0.75, 0.7519531,
-        0.7109375, 0.6230469, 0.7558594, 0.78125,
-        0.703125, 0.58984375, 0.7636719, 0.8144531,
-        0.7578125, 0.6777344, 0.7714844, 0.7265625,
-        0.7480469, 0.65234375, 0.78125, 0.7519531,
-        0.7421875, 0.6230469, 0.7871094, 0.78125,
-        0.734375, 0.58984375, 0.7949219, 0.8144531,
-        0.7890625, 0.6777344, 0.8027344, 0.7265625,
-        0.7792969, 0.65234375, 0.8125, 0.7519531,
-        0.7734375, 0.6230469, 0.8183594, 0.78125,
-        0.765625, 0.58984375, 0.8261719, 0.8144531,
-        0.8203125, 0.6777344, 0.8339844, 0.7265625,
-        0.8105469, 0.65234375, 0.84375, 0.7519531,
-        0.8046875, 0.6230469, 0.8496094, 0.78125,
-        0.796875, 0.58984375, 0.8574219, 0.8144531,
-        0.8515625, 0.6777344, 0.8652344, 0.7265625,
-        0.8417969, 0.65234375, 0.875, 0.7519531,
-        0.8359375, 0.6230469, 0.8808594, 0.78125,
-        0.828125, 0.58984375, 0.8886719, 0.8144531,
-        0.8828125, 0.6777344, 0.8964844, 0.7265625,
-        0.8730469, 0.65234375, 0.90625, 0.7519531,
-        0.8671875, 0.6230469, 0.9121094, 0.78125,
-        0.859375, 0.58984375, 0.9199219, 0.8144531,
-        0.9140625, 0.6777344, 0.9277344, 0.7265625,
-        0.9042969, 0.65234375, 0.9375, 0.7519531,
-        0.8984375, 0.6230469, 0.9433594, 0.78125,
-        0.890625, 0.58984375, 0.9511719, 0.8144531,
-        0.9453125, 0.6777344, 0.9589844, 0.7265625,
-        0.9355469, 0.65234375, 0.96875, 0.7519531,
-        0.9296875, 0.6230469, 0.9746094, 0.78125,
-        0.921875, 0.58984375, 0.9824219, 0.8144531,
-        0.9765625, 0.6777344, 0.9902344, 0.7265625,
-        0.9667969, 0.65234375, 1.0, 0.7519531,
-        0.9609375, 0.6230469, 1.0058594, 0.78125,
-        0.953125, 0.58984375, 1.0136719, 0.8144531,
-        0.0078125, 0.7089844, 0.021484375, 0.7578125,
-        0.0, 0.68359375, 0.03125, 0.7832031,
-        -0.005859375, 0.6542969, 0.037109375, 0.8125,
-        -0.013671875, 0.62109375, 0.044921875, 0.8457031,
-        0.0390625, 0.7089844, 0.052734375, 0.7578125,
-        0.029296875, 0.68359375, 0.0625, 0.7832031,
-        0.0234375, 0.6542969, 0.068359375, 0.8125,
-        0.015625, 0.62109375, 0.076171875, 0.8457031,
-        0.0703125, 0.7089844, 0.083984375, 0.7578125,
-        0.060546875, 0.68359375, 0.09375, 0.7832031,
-        0.0546875, 0.6542969, 0.099609375, 0.8125,
-        0.046875, 0.62109375, 0.107421875, 0.8457031,
-        0.1015625, 0.7089844, 0.115234375, 0.7578125,
-        0.091796875, 0.68359375, 0.125, 0.7832031,
-        0.0859375, 0.6542969, 0.13085938, 0.8125,
-        0.078125, 0.62109375, 0.13867188, 0.8457031,
-        0.1328125, 0.7089844, 0.14648438, 0.7578125,
-        0.123046875, 0.68359375, 0.15625, 0.7832031,
-        0.1171875, 0.6542969, 0.16210938, 0.8125,
-        0.109375, 0.62109375, 0.16992188, 0.8457031,
-        0.1640625, 0.7089844, 0.17773438, 0.7578125,
-        0.15429688, 0.68359375, 0.1875, 0.7832031,
-        0.1484375, 0.6542969, 0.19335938, 0.8125,
-        0.140625, 0.62109375, 0.20117188, 0.8457031,
-        0.1953125, 0.7089844, 0.20898438, 0.7578125,
-        0.18554688, 0.68359375, 0.21875, 0.7832031,
-        0.1796875, 0.6542969, 0.22460938, 0.8125,
-        0.171875, 0.62109375, 0.23242188, 0.8457031,
-        0.2265625, 0.7089844, 0.24023438, 0.7578125,
-        0.21679688, 0.68359375, 0.25, 0.7832031,
-        0.2109375, 0.6542969, 0.25585938, 0.8125,
-        0.203125, 0.62109375, 0.26367188, 0.8457031,
-        0.2578125, 0.7089844, 0.27148438, 0.7578125,
-        0.24804688, 0.68359375, 0.28125, 0.7832031,
-        0.2421875, 0.6542969, 0.28710938, 0.8125,
-        0.234375, 0.62109375, 0.29492188, 0.8457031,
-        0.2890625, 0.7089844, 0.30273438, 0.7578125,
-        0.27929688, 0.68359375, 0.3125, 0.7832031,
-        0.2734375, 0.6542969, 0.31835938, 0.8125,
-        0.265625, 0.62109375, 0.32617188, 0.8457031,
-        0.3203125, 0.7089844, 0.33398438, 0.7578125,
-        0.31054688, 0.68359375, 0.34375, 0.7832031,
-        0.3046875, 0.6542969, 0.34960938, 0.8125,
-        0.296875, 0.62109375, 0.35742188, 0.8457031,
-        0.3515625, 0.7089844, 0.36523438, 0.7578125,
-        0.34179688, 0.68359375, 0.375, 0.7832031,
-        0.3359375, 0.6542969, 0.38085938, 0.8125,
-        0.328125, 0.62109375, 0.38867188, 0.8457031,
-        0.3828125, 0.7089844, 0.39648438, 0.7578125,
-        0.37304688, 0.68359375, 0.40625, 0.7832031,
-        0.3671875, 0.6542969, 0.41210938, 0.8125,
-        0.359375, 0.62109375, 0.41992188, 0.8457031,
-        0.4140625, 0.7089844, 0.42773438, 0.7578125,
-        0.40429688, 0.68359375, 0.4375, 0.7832031,
-        0.3984375, 0.6542969, 0.44335938, 0.8125,
-        0.390625, 0.62109375, 0.45117188, 0.8457031,
-        0.4453125, 0.7089844, 0.45898438, 0.7578125,
-        0.43554688, 0.68359375, 0.46875, 0.7832031,
-        0.4296875, 0.6542969, 0.47460938, 0.8125,
-        0.421875, 0.62109375, 0.48242188, 0.8457031,
-        0.4765625, 0.7089844, 0.49023438, 0.7578125,
-        0.46679688, 0.68359375, 0.5, 0.7832031,
-        0.4609375, 0.6542969, 0.5058594, 0.8125,
-        0.453125, 0.62109375, 0.5136719, 0.8457031,
-        0.5078125, 0.7089844, 0.5214844, 0.7578125,
-        0.49804688, 0.68359375, 0.53125, 0.7832031,
-        0.4921875, 0.6542969, 0.5371094, 0.8125,
-        0.484375, 0.62109375, 0.5449219, 0.8457031,
-        0.5390625, 0.7089844, 0.5527344, 0.7578125,
-        0.5292969, 0.68359375, 0.5625, 0.7832031,
-        0.5234375, 0.6542969, 0.5683594, 0.8125,
-        0.515625, 0.62109375, 0.5761719, 0.8457031,
-        0.5703125, 0.7089844, 0.5839844, 0.7578125,
-        0.5605469, 0.68359375, 0.59375, 0.7832031,
-        0.5546875, 0.6542969, 0.5996094, 0.8125,
-        0.546875, 0.62109375, 0.6074219, 0.8457031,
-        0.6015625, 0.7089844, 0.6152344, 0.7578125,
-        0.5917969, 0.68359375, 0.625, 0.7832031,
-        0.5859375, 0.6542969, 0.6308594, 0.8125,
-        0.578125, 0.62109375, 0.6386719, 0.8457031,
-        0.6328125, 0.7089844, 0.6464844, 0.7578125,
-        0.6230469, 0.68359375, 0.65625, 0.7832031,
-        0.6171875, 0.6542969, 0.6621094, 0.8125,
-        0.609375, 0.62109375, 0.6699219, 0.8457031,
-        0.6640625, 0.7089844, 0.6777344, 0.7578125,
-        0.6542969, 0.68359375, 0.6875, 0.7832031,
-        0.6484375, 0.6542969, 0.6933594, 0.8125,
-        0.640625, 0.62109375, 0.7011719, 0.8457031,
-        0.6953125, 0.7089844, 0.7089844, 0.7578125,
-        0.6855469, 0.68359375, 0.71875, 0.7832031,
-        0.6796875, 0.6542969, 0.7246094, 0.8125,
-        0.671875, 0.62109375, 0.7324219, 0.8457031,
-        0.7265625, 0.7089844, 0.7402344, 0.7578125,
-        0.7167969, 0.68359375, 0.75, 0.7832031,
-        0.7109375, 0.6542969, 0.7558594, 0.8125,
-        0.703125, 0.62109375, 0.7636719, 0.8457031,
-        0.7578125, 0.7089844, 0.7714844, 0.7578125,
-        0.7480469, 0.68359375, 0.78125, 0.7832031,
-        0.7421875, 0.6542969, 0.7871094, 0.8125,
-        0.734375, 0.62109375, 0.7949219, 0.8457031,
-        0.7890625, 0.7089844, 0.8027344, 0.7578125,
-        0.7792969, 0.68359375, 0.8125, 0.7832031,
-        0.7734375, 0.6542969, 0.8183594, 0.8125,
-        0.765625, 0.62109375, 0.8261719, 0.8457031,
-        0.8203125, 0.7089844, 0.8339844, 0.7578125,
-        0.8105469, 0.68359375, 0.84375, 0.7832031,
-        0.8046875, 0.6542969, 0.8496094, 0.8125,
-        0.796875, 0.62109375, 0.8574219, 0.8457031,
-        0.8515625, 0.7089844, 0.8652344, 0.7578125,
-        0.8417969, 0.68359375, 0.875, 0.7832031,
-        0.8359375, 0.6542969, 0.8808594, 0.8125,
-        0.828125, 0.62109375, 0.8886719, 0.8457031,
-        0.8828125, 0.7089844, 0.8964844, 0.7578125,
-        0.8730469, 0.68359375, 0.90625, 0.7832031,
-        0.8671875, 0.6542969, 0.9121094, 0.8125,
-        0.859375, 0.62109375, 0.9199219, 0.8457031,
-        0.9140625, 0.7089844, 0.9277344, 0.7578125,
-        0.9042969, 0.68359375, 0.9375, 0.7832031,
-        0.8984375, 0.6542969, 0.9433594, 0.8125,
-        0.890625, 0.62109375, 0.9511719, 0.8457031,
-        0.9453125, 0.7089844, 0.9589844, 0.7578125,
-        0.9355469, 0.68359375, 0.96875, 0.7832031,
-        0.9296875, 0.6542969, 0.9746094, 0.8125,
-        0.921875, 0.62109375, 0.9824219, 0.8457031,
-        0.9765625, 0.7089844, 0.9902344, 0.7578125,
-        0.9667969, 0.68359375, 1.0, 0.7832031,
-        0.9609375, 0.6542969, 1.0058594, 0.8125,
-        0.953125, 0.62109375, 1.0136719, 0.8457031,
-        0.0078125, 0.7402344, 0.021484375, 0.7890625,
-        0.0, 0.71484375, 0.03125, 0.8144531,
-        -0.005859375, 0.6855469, 0.037109375, 0.84375,
-        -0.013671875, 0.65234375, 0.044921875, 0.8769531,
-        0.0390625, 0.7402344, 0.052734375, 0.7890625,
-        0.029296875, 0.71484375, 0.0625, 0.8144531,
-        0.0234375, 0.6855469, 0.068359375, 0.84375,
-        0.015625, 0.65234375, 0.076171875, 0.8769531,
-        0.0703125, 0.7402344, 0.083984375, 0.7890625,
-        0.060546875, 0.71484375, 0.09375, 0.8144531,
-        0.0546875, 0.6855469, 0.099609375, 0.84375,
-        0.046875, 0.65234375, 0.107421875, 0.8769531,
-        0.1015625, 0.7402344, 0.115234375, 0.7890625,
-        0.091796875, 0.71484375, 0.125, 0.8144531,
-        0.0859375, 0.6855469, 0.13085938, 0.84375,
-        0.078125, 0.65234375, 0.13867188, 0.8769531,
-        0.1328125, 0.7402344, 0.14648438, 0.7890625,
-        0.123046875, 0.71484375, 0.15625, 0.8144531,
-        0.1171875, 0.6855469, 0.16210938, 0.84375,
-        0.109375, 0.65234375, 0.16992188, 0.8769531,
-        0.1640625, 0.7402344, 0.17773438, 0.7890625,
-        0.15429688, 0.71484375, 0.1875, 0.8144531,
-        0.1484375, 0.6855469, 0.19335938, 0.84375,
-        0.140625, 0.65234375, 0.20117188, 0.8769531,
-        0.1953125, 0.7402344, 0.20898438, 0.7890625,
-        0.18554688, 0.71484375, 0.21875, 0.8144531,
-        0.1796875, 0.6855469, 0.22460938, 0.84375,
-        0.171875, 0.65234375, 0.23242188, 0.8769531,
-        0.2265625, 0.7402344, 0.24023438, 0.7890625,
-        0.21679688, 0.71484375, 0.25, 0.8144531,
-        0.2109375, 0.6855469, 0.25585938, 0.84375,
-        0.203125, 0.65234375, 0.26367188, 0.8769531,
-        0.2578125, 0.7402344, 0.27148438, 0.7890625,
-        0.24804688, 0.71484375, 0.28125, 0.8144531,
-        0.2421875, 0.6855469, 0.28710938, 0.84375,
-        0.234375, 0.65234375, 0.29492188, 0.8769531,
-        0.2890625, 0.7402344, 0.30273438, 0.7890625,
-        0.27929688, 0.71484375, 0.3125, 0.8144531,
-        0.2734375, 0.6855469, 0.31835938, 0.84375,
-        0.265625, 0.65234375, 0.32617188, 0.8769531,
-        0.3203125, 0.7402344, 0.33398438, 0.7890625,
-        0.31054688, 0.71484375, 0.34375, 0.8144531,
-        0.3046875, 0.6855469, 0.34960938, 0.84375,
-        0.296875, 0.65234375, 0.35742188, 0.8769531,
-        0.3515625, 0.7402344, 0.36523438, 0.7890625,
-        0.34179688, 0.71484375, 0.375, 0.8144531,
-        0.3359375, 0.6855469, 0.38085938, 0.84375,
-        0.328125, 0.65234375, 0.38867188, 0.8769531,
-        0.3828125, 0.7402344, 0.39648438, 0.7890625,
-        0.37304688, 0.71484375, 0.40625, 0.8144531,
-        0.3671875, 0.6855469, 0.41210938, 0.84375,
-        0.359375, 0.65234375, 0.41992188, 0.8769531,
-        0.4140625, 0.7402344, 0.42773438, 0.7890625,
-        0.40429688, 0.71484375, 0.4375, 0.8144531,
-        0.3984375, 0.6855469, 0.44335938, 0.84375,
-        0.390625, 0.65234375, 0.45117188, 0.8769531,
-        0.4453125, 0.7402344, 0.45898438, 0.7890625,
-        0.43554688, 0.71484375, 0.46875, 0.8144531,
-        0.4296875, 0.6855469, 0.47460938, 0.84375,
-        0.421875, 0.65234375, 0.48242188, 0.8769531,
-        0.4765625, 0.7402344, 0.49023438, 0.7890625,
-        0.46679688, 0.71484375, 0.5, 0.8144531,
-        0.4609375, 0.6855469, 0.5058594, 0.84375,
-        0.453125, 0.65234375, 0.5136719, 0.8769531,
-        0.5078125, 0.7402344, 0.5214844, 0.7890625,
-        0.49804688, 0.71484375, 0.53125, 0.8144531,
-        0.4921875, 0.6855469, 0.5371094, 0.84375,
-        0.484375, 0.65234375, 0.5449219, 0.8769531,
-        0.5390625, 0.7402344, 0.5527344, 0.7890625,
-        0.5292969, 0.71484375, 0.5625, 0.8144531,
-        0.5234375, 0.6855469, 0.5683594, 0.84375,
-        0.515625, 0.65234375, 0.5761719, 0.8769531,
-        0.5703125, 0.7402344, 0.5839844, 0.7890625,
-        0.5605469, 0.71484375, 0.59375, 0.8144531,
-        0.5546875, 0.6855469, 0.5996094, 0.84375,
-        0.546875, 0.65234375, 0.6074219, 0.8769531,
-        0.6015625, 0.7402344, 0.6152344, 0.7890625,
-        0.5917969, 0.71484375, 0.625, 0.8144531,
-        0.5859375, 0.6855469, 0.6308594, 0.84375,
-        0.578125, 0.65234375, 0.6386719, 0.8769531,
-        0.6328125, 0.7402344, 0.6464844, 0.7890625,
-        0.6230469, 0.71484375, 0.65625, 0.8144531,
-        0.6171875, 0.6855469, 0.6621094, 0.84375,
-        0.609375, 0.65234375, 0.6699219, 0.8769531,
-        0.6640625, 0.7402344, 0.6777344, 0.7890625,
-        0.6542969, 0.71484375, 0.6875, 0.8144531,
-        0.6484375, 0.6855469, 0.6933594, 0.84375,
-        0.640625, 0.65234375, 0.7011719, 0.8769531,
-        0.6953125, 0.7402344, 0.7089844, 0.7890625,
-        0.6855469, 0.71484375, 0.71875, 0.8144531,
-        0.6796875, 0.6855469, 0.7246094, 0.84375,
-        0.671875, 0.65234375, 0.7324219, 0.8769531,
-        0.7265625, 0.7402344, 0.7402344, 0.7890625,
-        0.7167969, 0.71484375, 0.75, 0.8144531,
-        0.7109375, 0.6855469, 0.7558594, 0.84375,
-        0.703125, 0.65234375, 0.7636719, 0.8769531,
-        0.7578125, 0.7402344, 0.7714844, 0.7890625,
-        0.7480469, 0.71484375, 0.78125, 0.8144531,
-        0.7421875, 0.6855469, 0.7871094, 0.84375,
-        0.734375, 0.65234375, 0.7949219, 0.8769531,
-        0.7890625, 0.7402344, 0.8027344, 0.7890625,
-        0.7792969, 0.71484375, 0.8125, 0.8144531,
-        0.7734375, 0.6855469, 0.8183594, 0.84375,
-        0.765625, 0.65234375, 0.8261719, 0.8769531,
-        0.8203125, 0.7402344, 0.8339844, 0.7890625,
-        0.8105469, 0.71484375, 0.84375, 0.8144531,
-        0.8046875, 0.6855469, 0.8496094, 0.84375,
-        0.796875, 0.65234375, 0.8574219, 0.8769531,
-        0.8515625, 0.7402344, 0.8652344, 0.7890625,
-        0.8417969, 0.71484375, 0.875, 0.8144531,
-        0.8359375, 0.6855469, 0.8808594, 0.84375,
-        0.828125, 0.65234375, 0.8886719, 0.8769531,
-        0.8828125, 0.7402344, 0.8964844, 0.7890625,
-        0.8730469, 0.71484375, 0.90625, 0.8144531,
-        0.8671875, 0.6855469, 0.9121094, 0.84375,
-        0.859375, 0.65234375, 0.9199219, 0.8769531,
-        0.9140625, 0.7402344, 0.9277344, 0.7890625,
-        0.9042969, 0.71484375, 0.9375, 0.8144531,
-        0.8984375, 0.6855469, 0.9433594, 0.84375,
-        0.890625, 0.65234375, 0.9511719, 0.8769531,
-        0.9453125, 0.7402344, 0.9589844, 0.7890625,
-        0.9355469, 0.71484375, 0.96875, 0.8144531,
-        0.9296875, 0.6855469, 0.9746094, 0.84375,
-        0.921875, 0.65234375, 0.9824219, 0.8769531,
-        0.9765625, 0.7402344, 0.9902344, 0.7890625,
-        0.9667969, 0.71484375, 1.0, 0.8144531,
-        0.9609375, 0.6855469, 1.0058594, 0.84375,
-        0.953125, 0.65234375, 1.0136719, 0.8769531,
-        0.0078125, 0.7714844, 0.021484375, 0.8203125,
-        0.0, 0.74609375, 0.03125, 0.8457031,
-        -0.005859375, 0.7167969, 0.037109375, 0.875,
-        -0.013671875, 0.68359375, 0.044921875, 0.9082031,
-        0.0390625, 0.7714844, 0.052734375, 0.8203125,
-        0.029296875, 0.74609375, 0.0625, 0.8457031,
-        0.0234375, 0.7167969, 0.068359375, 0.875,
-        0.015625, 0.68359375, 0.076171875, 0.9082031,
-        0.0703125, 0.7714844, 0.083984375, 0.8203125,
-        0.060546875, 0.74609375, 0.09375, 0.8457031,
-        0.0546875, 0.7167969, 0.099609375, 0.875,
-        0.046875, 0.68359375, 0.107421875, 0.9082031,
-        0.1015625, 0.7714844, 0.115234375, 0.8203125,
-        0.091796875, 0.74609375, 0.125, 0.8457031,
-        0.0859375, 0.7167969, 0.13085938, 0.875,
-        0.078125, 0.68359375, 0.13867188, 0.9082031,
-        0.1328125, 0.7714844, 0.14648438, 0.8203125,
-        0.123046875, 0.74609375, 0.15625, 0.8457031,
-        0.1171875, 0.7167969, 0.16210938, 0.875,
-        0.109375, 0.68359375, 0.16992188, 0.9082031,
-        0.1640625, 0.7714844, 0.17773438, 0.8203125,
-        0.15429688, 0.74609375, 0.1875, 0.8457031,
-        0.1484375, 0.7167969, 0.19335938, 0.875,
-        0.140625, 0.68359375, 0.20117188, 0.9082031,
-        0.1953125, 0.7714844, 0.20898438, 0.8203125,
-        0.18554688, 0.74609375, 0.21875, 0.8457031,
-        0.1796875, 0.7167969, 0.22460938, 0.875,
-        0.171875, 0.68359375, 0.23242188, 0.9082031,
-        0.2265625, 0.7714844, 0.24023438, 0.8203125,
-        0.21679688, 0.74609375, 0.25, 0.8457031,
-        0.2109375, 0.7167969, 0.25585938, 0.875,
-        0.203125, 0.68359375, 0.26367188, 0.9082031,
-        0.2578125, 0.7714844, 0.27148438, 0.8203125,
-        0.24804688, 0.74609375, 0.28125, 0.8457031,
-        0.2421875, 0.7167969, 0.28710938, 0.875,
-        0.234375, 0.68359375, 0.29492188, 0.9082031,
-        0.2890625, 0.7714844, 0.30273438, 0.8203125,
-        0.27929688, 0.74609375, 0.3125, 0.8457031,
-        0.2734375, 0.7167969, 0.31835938, 0.875,
-        0.265625, 0.68359375, 0.32617188, 0.9082031,
-        0.3203125, 0.7714844, 0.33398438, 0.8203125,
-        0.31054688, 0.74609375, 0.34375, 0.8457031,
-        0.3046875, 0.7167969, 0.34960938, 0.875,
-        0.296875, 0.68359375, 0.35742188, 0.9082031,
-        0.3515625, 0.7714844, 0.36523438, 0.8203125,
-        0.34179688, 0.74609375, 0.375, 0.8457031,
-        0.3359375, 0.7167969, 0.38085938, 0.875,
-        0.328125, 0.68359375, 0.38867188, 0.9082031,
-        0.3828125, 0.7714844, 0.39648438, 0.8203125,
-        0.37304688, 0.74609375, 0.40625, 0.8457031,
-        0.3671875, 0.7167969, 0.41210938, 0.875,
-        0.359375, 0.68359375, 0.41992188, 0.9082031,
-        0.4140625, 0.7714844, 0.42773438, 0.8203125,
-        0.40429688, 0.74609375, 0.4375, 0.8457031,
-        0.3984375, 0.7167969, 0.44335938, 0.875,
-        0.390625, 0.68359375, 0.45117188, 0.9082031,
-        0.4453125, 0.7714844, 0.45898438, 0.8203125,
-        0.43554688, 0.74609375, 0.46875, 0.8457031,
-        0.4296875, 0.7167969, 0.47460938, 0.875,
-        0.421875, 0.68359375, 0.48242188, 0.9082031,
-        0.4765625, 0.7714844, 0.49023438, 0.8203125,
-        0.46679688, 0.74609375, 0.5, 0.8457031,
-        0.4609375, 0.7167969, 0.5058594, 0.875,
-        0.453125, 0.68359375, 0.5136719, 0.9082031,
-        0.5078125, 0.7714844, 0.5214844, 0.8203125,
-        0.49804688, 0.74609375, 0.53125, 0.8457031,
-        0.4921875, 0.7167969, 0.5371094, 0.875,
-        0.484375, 0.68359375, 0.5449219, 0.9082031,
-        0.5390625, 0.7714844, 0.5527344, 0.8203125,
-        0.5292969, 0.74609375, 0.5625, 0.8457031,
-        0.5234375, 0.7167969, 0.5683594, 0.875,
-        0.515625, 0.68359375, 0.5761719, 0.9082031,
-        0.5703125, 0.7714844, 0.5839844, 0.8203125,
-        0.5605469, 0.74609375, 0.59375, 0.8457031,
-        0.5546875, 0.7167969, 0.5996094, 0.875,
-        0.546875, 0.68359375, 0.6074219, 0.9082031,
-        0.6015625, 0.7714844, 0.6152344, 0.8203125,
-        0.5917969, 0.74609375, 0.625, 0.8457031,
-        0.5859375, 0.7167969, 0.6308594, 0.875,
-        0.578125, 0.68359375, 0.6386719, 0.9082031,
-        0.6328125, 0.7714844, 0.6464844, 0.8203125,
-        0.6230469, 0.74609375, 0.65625, 0.8457031,
-        0.6171875, 0.7167969, 0.6621094, 0.875,
-        0.609375, 0.68359375, 0.6699219, 0.9082031,
-        0.6640625, 0.7714844, 0.6777344, 0.8203125,
-        0.6542969, 0.74609375, 0.6875, 0.8457031,
-        0.6484375, 0.7167969, 0.6933594, 0.875,
-        0.640625, 0.68359375, 0.7011719, 0.9082031,
-        0.6953125, 0.7714844, 0.7089844, 0.8203125,
-        0.6855469, 0.74609375, 0.71875, 0.8457031,
-        0.6796875, 0.7167969, 0.7246094, 0.875,
-        0.671875, 0.68359375, 0.7324219, 0.9082031,
-        0.7265625, 0.7714844, 0.7402344, 0.8203125,
-        0.7167969, 0.74609375, 0.75, 0.8457031,
-        0.7109375, 0.7167969, 0.7558594, 0.875,
-        0.703125, 0.68359375, 0.7636719, 0.9082031,
-        0.7578125, 0.7714844, 0.7714844, 0.8203125,
-        0.7480469, 0.74609375, 0.78125, 0.8457031,
-        0.7421875, 0.7167969, 0.7871094, 0.875,
-        0.734375, 0.68359375, 0.7949219, 0.9082031,
-        0.7890625, 0.7714844, 0.8027344, 0.8203125,
-        0.7792969, 0.74609375, 0.8125, 0.8457031,
-        0.7734375, 0.7167969, 0.8183594, 0.875,
-        0.765625, 0.68359375, 0.8261719, 0.9082031,
-        0.8203125, 0.7714844, 0.8339844, 0.8203125,
-        0.8105469, 0.74609375, 0.84375, 0.8457031,
-        0.8046875, 0.7167969, 0.8496094, 0.875,
-        0.796875, 0.68359375, 0.8574219, 0.9082031,
-        0.8515625, 0.7714844, 0.8652344, 0.8203125,
-        0.8417969, 0.74609375, 0.875, 0.8457031,
-        0.8359375, 0.7167969, 0.8808594, 0.875,
-        0.828125, 0.68359375, 0.8886719, 0.9082031,
-        0.8828125, 0.7714844, 0.8964844, 0.8203125,
-        0.8730469, 0.74609375, 0.90625, 0.8457031,
-        0.8671875, 0.7167969, 0.9121094, 0.875,
-        0.859375, 0.68359375, 0.9199219, 0.9082031,
-        0.9140625, 0.7714844, 0.9277344, 0.8203125,
-        0.9042969, 0.74609375, 0.9375, 0.8457031,
-        0.8984375, 0.7167969, 0.9433594, 0.875,
-        0.890625, 0.68359375, 0.9511719, 0.9082031,
-        0.9453125, 0.7714844, 0.9589844, 0.8203125,
-        0.9355469, 0.74609375, 0.96875, 0.8457031,
-        0.9296875, 0.7167969, 0.9746094, 0.875,
-        0.921875, 0.68359375, 0.9824219, 0.9082031,
-        0.9765625, 0.7714844, 0.9902344, 0.8203125,
-        0.9667969, 0.74609375, 1.0, 0.8457031,
-        0.9609375, 0.7167969, 1.0058594, 0.875,
-        0.953125, 0.68359375, 1.0136719, 0.9082031,
-        0.0078125, 0.8027344, 0.021484375, 0.8515625,
-        0.0, 0.77734375, 0.03125, 0.8769531,
-        -0.005859375, 0.7480469, 0.037109375, 0.90625,
-        -0.013671875, 0.71484375, 0.044921875, 0.9394531,
-        0.0390625, 0.8027344, 0.052734375, 0.8515625,
-        0.029296875, 0.77734375, 0.0625, 0.8769531,
-        0.0234375, 0.7480469, 0.068359375, 0.90625,
-        0.015625, 0.71484375, 0.076171875, 0.9394531,
-        0.0703125, 0.8027344, 0.083984375, 0.8515625,
-        0.060546875, 0.77734375, 0.09375, 0.8769531,
-        0.0546875, 0.7480469, 0.099609375, 0.90625,
-        0.046875, 0.71484375, 0.107421875, 0.9394531,
-        0.1015625, 0.8027344, 0.115234375, 0.8515625,
-        0.091796875, 0.77734375, 0.125, 0.8769531,
-        0.0859375, 0.7480469, 0.13085938, 0.90625,
-        0.078125, 0.71484375, 0.13867188, 0.9394531,
-        0.1328125, 0.8027344, 0.14648438, 0.8515625,
-        0.123046875, 0.77734375, 0.15625, 0.8769531,
-        0.1171875, 0.7480469, 0.16210938, 0.90625,
-        0.109375, 0.71484375, 0.16992188, 0.9394531,
-        0.1640625, 0.8027344, 0.17773438, 0.8515625,
-        0.15429688, 0.77734375, 0.1875, 0.8769531,
-        0.1484375, 0.7480469, 0.19335938, 0.90625,
-        0.140625, 0.71484375, 0.20117188, 0.9394531,
-        0.1953125, 0.8027344, 0.20898438, 0.8515625,
-        0.18554688, 0.77734375, 0.21875, 0.8769531,
-        0.1796875, 0.7480469, 0.22460938, 0.90625,
-        0.171875, 0.71484375, 0.23242188, 0.9394531,
-        0.2265625, 0.8027344, 0.24023438, 0.8515625,
-        0.21679688, 0.77734375, 0.25, 0.8769531,
-        0.2109375, 0.7480469, 0.25585938, 0.90625,
-        0.203125, 0.71484375, 0.26367188, 0.9394531,
-        0.2578125, 0.8027344, 0.27148438, 0.8515625,
-        0.24804688, 0.77734375, 0.28125, 0.8769531,
-        0.2421875, 0.7480469, 0.28710938, 0.90625,
-        0.234375, 0.71484375, 0.29492188, 0.9394531,
-        0.2890625, 0.8027344, 0.30273438, 0.8515625,
-        0.27929688, 0.77734375, 0.3125, 0.8769531,
-        0.2734375, 0.7480469, 0.31835938, 0.90625,
-        0.265625, 0.71484375, 0.32617188, 0.9394531,
-        0.3203125, 0.8027344, 0.33398438, 0.8515625,
-        0.31054688, 0.77734375, 0.34375, 0.8769531,
-        0.3046875, 0.7480469, 0.34960938, 0.90625,
-        0.296875, 0.71484375, 0.35742188, 0.9394531,
-        0.3515625, 0.8027344, 0.36523438, 0.8515625,
-        0.34179688, 0.77734375, 0.375, 0.8769531,
-        0.3359375, 0.7480469, 0.38085938, 0.90625,
-        0.328125, 0.71484375, 0.38867188, 0.9394531,
-        0.3828125, 0.8027344, 0.39648438, 0.8515625,
-        0.37304688, 0.77734375, 0.40625, 0.8769531,
-        0.3671875, 0.7480469, 0.41210938, 0.90625,
-        0.359375, 0.71484375, 0.41992188, 0.9394531,
-        0.4140625, 0.8027344, 0.42773438, 0.8515625,
-        0.40429688, 0.77734375, 0.4375, 0.8769531,
-        0.3984375, 0.7480469, 0.44335938, 0.90625,
-        0.390625, 0.71484375, 0.45117188, 0.9394531,
-        0.4453125, 0.8027344, 0.45898438, 0.8515625,
-        0.43554688, 0.77734375, 0.46875, 0.8769531,
-        0.4296875, 0.7480469, 0.47460938, 0.90625,
-        0.421875, 0.71484375, 0.48242188, 0.9394531,
-        0.4765625, 0.8027344, 0.49023438, 0.8515625,
-        0.46679688, 0.77734375, 0.5, 0.8769531,
-        0.4609375, 0.7480469, 0.5058594, 0.90625,
-        0.453125, 0.71484375, 0.5136719, 0.9394531,
-        0.5078125, 0.8027344, 0.5214844, 0.8515625,
-        0.49804688, 0.77734375, 0.53125, 0.8769531,
-        0.4921875, 0.7480469, 0.5371094, 0.90625,
-        0.484375, 0.71484375, 0.5449219, 0.9394531,
-        0.5390625, 0.8027344, 0.5527344, 0.8515625,
-        0.5292969, 0.77734375, 0.5625, 0.8769531,
-        0.5234375, 0.7480469, 0.5683594, 0.90625,
-        0.515625, 0.71484375, 0.5761719, 0.9394531,
-        0.5703125, 0.8027344, 0.5839844, 0.8515625,
-        0.5605469, 0.77734375, 0.59375, 0.8769531,
-        0.5546875, 0.7480469, 0.5996094, 0.90625,
-        0.546875, 0.71484375, 0.6074219, 0.9394531,
-        0.6015625, 0.8027344, 0.6152344, 0.8515625,
-        0.5917969, 0.77734375, 0.625, 0.8769531,
-        0.5859375, 0.7480469, 0.6308594, 0.90625,
-        0.578125, 0.71484375, 0.6386719, 0.9394531,
-        0.6328125, 0.8027344, 0.6464844, 0.8515625,
-        0.6230469, 0.77734375, 0.65625, 0.8769531,
-        0.6171875, 0.7480469, 0.6621094, 0.90625,
-        0.609375, 0.71484375, 0.6699219, 0.9394531,
-        0.6640625, 0.8027344, 0.6777344, 0.8515625,
-        0.6542969, 0.77734375, 0.6875, 0.8769531,
-        0.6484375, 0.7480469, 0.6933594, 0.90625,
-        0.640625, 0.71484375, 0.7011719, 0.9394531,
-        0.6953125, 0.8027344, 0.7089844, 0.8515625,
-        0.6855469, 0.77734375, 0.71875, 0.8769531,
-        0.6796875, 0.7480469, 0.7246094, 0.90625,
-        0.671875, 0.71484375, 0.7324219, 0.9394531,
-        0.7265625, 0.8027344, 0.7402344, 0.8515625,
-        0.7167969, 0.77734375, 0.75, 0.8769531,
-        0.7109375, 0.7480469, 0.7558594, 0.90625,
-        0.703125, 0.71484375, 0.7636719, 0.9394531,
-        0.7578125, 0.8027344, 0.7714844, 0.8515625,
-        0.7480469, 0.77734375, 0.78125, 0.8769531,
-        0.7421875, 0.7480469, 0.7871094, 0.90625,
-        0.734375, 0.71484375, 0.7949219, 0.9394531,
-        0.7890625, 0.8027344, 0.8027344, 0.8515625,
-        0.7792969, 0.77734375, 0.8125, 0.8769531,
-        0.7734375, 0.7480469, 0.8183594, 0.90625,
-        0.765625, 0.71484375, 0.8261719, 0.9394531,
-        0.8203125, 0.8027344, 0.8339844, 0.8515625,
-        0.8105469, 0.77734375, 0.84375, 0.8769531,
-        0.8046875, 0.7480469, 0.8496094, 0.90625,
-        0.796875, 0.71484375, 0.8574219, 0.9394531,
-        0.8515625, 0.8027344, 0.8652344, 0.8515625,
-        0.8417969, 0.77734375, 0.875, 0.8769531,
-        0.8359375, 0.7480469, 0.8808594, 0.90625,
-        0.828125, 0.71484375, 0.8886719, 0.9394531,
-        0.8828125, 0.8027344, 0.8964844, 0.8515625,
-        0.8730469, 0.77734375, 0.90625, 0.8769531,
-        0.8671875, 0.7480469, 0.9121094, 0.90625,
-        0.859375, 0.71484375, 0.9199219, 0.9394531,
-        0.9140625, 0.8027344, 0.9277344, 0.8515625,
-        0.9042969, 0.77734375, 0.9375, 0.8769531,
-        0.8984375, 0.7480469, 0.9433594, 0.90625,
-        0.890625, 0.71484375, 0.9511719, 0.9394531,
-        0.9453125, 0.8027344, 0.9589844, 0.8515625,
-        0.9355469, 0.77734375, 0.96875, 0.8769531,
-        0.9296875, 0.7480469, 0.9746094, 0.90625,
-        0.921875, 0.71484375, 0.9824219, 0.9394531,
-        0.9765625, 0.8027344, 0.9902344, 0.8515625,
-        0.9667969, 0.77734375, 1.0, 0.8769531,
-        0.9609375, 0.7480469, 1.0058594, 0.90625,
-        0.953125, 0.71484375, 1.0136719, 0.9394531,
-        0.0078125, 0.8339844, 0.021484375, 0.8828125,
-        0.0, 0.80859375, 0.03125, 0.9082031,
-        -0.005859375, 0.7792969, 0.037109375, 0.9375,
-        -0.013671875, 0.74609375, 0.044921875, 0.9707031,
-        0.0390625, 0.8339844, 0.052734375, 0.8828125,
-        0.029296875, 0.80859375, 0.0625, 0.9082031,
-        0.0234375, 0.7792969, 0.068359375, 0.9375,
-        0.015625, 0.74609375, 0.076171875, 0.9707031,
-        0.0703125, 0.8339844, 0.083984375, 0.8828125,
-        0.060546875, 0.80859375, 0.09375, 0.9082031,
-        0.0546875, 0.7792969, 0.099609375, 0.9375,
-        0.046875, 0.74609375, 0.107421875, 0.9707031,
-        0.1015625, 0.8339844, 0.115234375, 0.8828125,
-        0.091796875, 0.80859375, 0.125, 0.9082031,
-        0.0859375, 0.7792969, 0.13085938, 0.9375,
-        0.078125, 0.74609375, 0.13867188, 0.9707031,
-        0.1328125, 0.8339844, 0.14648438, 0.8828125,
-        0.123046875, 0.80859375, 0.15625, 0.9082031,
-        0.1171875, 0.7792969, 0.16210938, 0.9375,
-        0.109375, 0.74609375, 0.16992188, 0.9707031,
-        0.1640625, 0.8339844, 0.17773438, 0.8828125,
-        0.15429688, 0.80859375, 0.1875, 0.9082031,
-        0.1484375, 0.7792969, 0.19335938, 0.9375,
-        0.140625, 0.74609375, 0.20117188, 0.9707031,
-        0.1953125, 0.8339844, 0.20898438, 0.8828125,
-        0.18554688, 0.80859375, 0.21875, 0.9082031,
-        0.1796875, 0.7792969, 0.22460938, 0.9375,
-        0.171875, 0.74609375, 0.23242188, 0.9707031,
-        0.2265625, 0.8339844, 0.24023438, 0.8828125,
-        0.21679688, 0.80859375, 0.25, 0.9082031,
-        0.2109375, 0.7792969, 0.25585938, 0.9375,
-        0.203125, 0.74609375, 0.26367188, 0.9707031,
-        0.2578125, 0.8339844, 0.27148438, 0.8828125,
-        0.24804688, 0.80859375, 0.28125, 0.9082031,
-        0.2421875, 0.7792969, 0.28710938, 0.9375,
-        0.234375, 0.74609375, 0.29492188, 0.9707031,
-        0.2890625, 0.8339844, 0.30273438, 0.8828125,
-        0.27929688, 0.80859375, 0.3125, 0.9082031,
-        0.2734375, 0.7792969, 0.31835938, 0.9375,
-        0.265625, 0.74609375, 0.32617188, 0.9707031,
-        0.3203125, 0.8339844, 0.33398438, 0.8828125,
-        0.31054688, 0.80859375, 0.34375, 0.9082031,
-        0.3046875, 0.7792969, 0.34960938, 0.9375,
-        0.296875, 0.74609375, 0.35742188, 0.9707031,
-        0.3515625, 0.8339844, 0.36523438, 0.8828125,
-        0.34179688, 0.80859375, 0.375, 0.9082031,
-        0.3359375, 0.7792969, 0.38085938, 0.9375,
-        0.328125, 0.74609375, 0.38867188, 0.9707031,
-        0.3828125, 0.8339844, 0.39648438, 0.8828125,
-        0.37304688, 0.80859375, 0.40625, 0.9082031,
-        0.3671875, 0.7792969, 0.41210938, 0.9375,
-        0.359375, 0.74609375, 0.41992188, 0.9707031,
-        0.4140625, 0.8339844, 0.42773438, 0.8828125,
-        0.40429688, 0.80859375, 0.4375, 0.9082031,
-        0.3984375, 0.7792969, 0.44335938, 0.9375,
-        0.390625, 0.74609375, 0.45117188, 0.9707031,
-        0.4453125, 0.8339844, 0.45898438, 0.8828125,
-        0.43554688, 0.80859375, 0.46875, 0.9082031,
-        0.4296875, 0.7792969, 0.47460938, 0.9375,
-        0.421875, 0.74609375, 0.48242188, 0.9707031,
-        0.4765625, 0.8339844, 0.49023438, 0.8828125,
-        0.46679688, 0.80859375, 0.5, 0.9082031,
-        0.4609375, 0.7792969, 0.5058594, 0.9375,
-        0.453125, 0.74609375, 0.5136719, 0.9707031,
-        0.5078125, 0.8339844, 0.5214844, 0.8828125,
-        0.49804688, 0.80859375, 0.53125, 0.9082031,
-        0.4921875, 0.7792969, 0.5371094, 0.9375,
-        0.484375, 0.74609375, 0.5449219, 0.9707031,
-        0.5390625, 0.8339844, 0.5527344, 0.8828125,
-        0.5292969, 0.80859375, 0.5625, 0.9082031,
-        0.5234375, 0.7792969, 0.5683594, 0.9375,
-        0.515625, 0.74609375, 0.5761719, 0.9707031,
-        0.5703125, 0.8339844, 0.5839844, 0.8828125,
-        0.5605469, 0.80859375, 0.59375, 0.9082031,
-        0.5546875, 0.7792969, 0.5996094, 0.9375,
-        0.546875, 0.74609375, 0.6074219, 0.9707031,
-        0.6015625, 0.8339844, 0.6152344, 0.8828125,
-        0.5917969, 0.80859375, 0.625, 0.9082031,
-        0.5859375, 0.7792969, 0.6308594, 0.9375,
-        0.578125, 0.74609375, 0.6386719, 0.9707031,
-        0.6328125, 0.8339844, 0.6464844, 0.8828125,
-        0.6230469, 0.80859375, 0.65625, 0.9082031,
-        0.6171875, 0.7792969, 0.6621094, 0.9375,
-        0.609375, 0.74609375, 0.6699219, 0.9707031,
-        0.6640625, 0.8339844, 0.6777344, 0.8828125,
-        0.6542969, 0.80859375, 0.6875, 0.9082031,
-        0.6484375, 0.7792969, 0.6933594, 0.9375,
-        0.640625, 0.74609375, 0.7011719, 0.9707031,
-        0.6953125, 0.8339844, 0.7089844, 0.8828125,
-        0.6855469, 0.80859375, 0.71875, 0.9082031,
-        0.6796875, 0.7792969, 0.7246094, 0.9375,
-        0.671875, 0.74609375, 0.7324219, 0.9707031,
-        0.7265625, 0.8339844, 0.7402344, 0.8828125,
-        0.7167969, 0.80859375, 0.75, 0.9082031,
-        0.7109375, 0.7792969, 0.7558594, 0.9375,
-        0.703125, 0.74609375, 0.7636719, 0.9707031,
-        0.7578125, 0.8339844, 0.7714844, 0.8828125,
-        0.7480469, 0.80859375, 0.78125, 0.9082031,
-        0.7421875, 0.7792969, 0.7871094, 0.9375,
-        0.734375, 0.74609375, 0.7949219, 0.9707031,
-        0.7890625, 0.8339844, 0.8027344, 0.8828125,
-        0.7792969, 0.80859375, 0.8125, 0.9082031,
-        0.7734375, 0.7792969, 0.8183594, 0.9375,
-        0.765625, 0.74609375, 0.8261719, 0.9707031,
-        0.8203125, 0.8339844, 0.8339844, 0.8828125,
-        0.8105469, 0.80859375, 0.84375, 0.9082031,
-        0.8046875, 0.7792969, 0.8496094, 0.9375,
-        0.796875, 0.74609375, 0.8574219, 0.9707031,
-        0.8515625, 0.8339844, 0.8652344, 0.8828125,
-        0.8417969, 0.80859375, 0.875, 0.9082031,
-        0.8359375, 0.7792969, 0.8808594, 0.9375,
-        0.828125, 0.74609375, 0.8886719, 0.9707031,
-        0.8828125, 0.8339844, 0.8964844, 0.8828125,
-        0.8730469, 0.80859375, 0.90625, 0.9082031,
-        0.8671875, 0.7792969, 0.9121094, 0.9375,
-        0.859375, 0.74609375, 0.9199219, 0.9707031,
-        0.9140625, 0.8339844, 0.9277344, 0.8828125,
-        0.9042969, 0.80859375, 0.9375, 0.9082031,
-        0.8984375, 0.7792969, 0.9433594, 0.9375,
-        0.890625, 0.74609375, 0.9511719, 0.9707031,
-        0.9453125, 0.8339844, 0.9589844, 0.8828125,
-        0.9355469, 0.80859375, 0.96875, 0.9082031,
-        0.9296875, 0.7792969, 0.9746094, 0.9375,
-        0.921875, 0.74609375, 0.9824219, 0.9707031,
-        0.9765625, 0.8339844, 0.9902344, 0.8828125,
-        0.9667969, 0.80859375, 1.0, 0.9082031,
-        0.9609375, 0.7792969, 1.0058594, 0.9375,
-        0.953125, 0.74609375, 1.0136719, 0.9707031,
-        0.0078125, 0.8652344, 0.021484375, 0.9140625,
-        0.0, 0.83984375, 0.03125, 0.9394531,
-        -0.005859375, 0.8105469, 0.037109375, 0.96875,
-        -0.013671875, 0.77734375, 0.044921875, 1.0019531,
-        0.0390625, 0.8652344, 0.052734375, 0.9140625,
-        0.029296875, 0.83984375, 0.0625, 0.9394531,
-        0.0234375, 0.8105469, 0.068359375, 0.96875,
-        0.015625, 0.77734375, 0.076171875, 1.0019531,
-        0.0703125, 0.8652344, 0.083984375, 0.9140625,
-        0.060546875, 0.83984375, 0.09375, 0.9394531,
-        0.0546875, 0.8105469, 0.099609375, 0.96875,
-        0.046875, 0.77734375, 0.107421875, 1.0019531,
-        0.1015625, 0.8652344, 0.115234375, 0.9140625,
-        0.091796875, 0.83984375, 0.125, 0.9394531,
-        0.0859375, 0.8105469, 0.13085938, 0.96875,
-        0.078125, 0.77734375, 0.13867188, 1.0019531,
-        0.1328125, 0.8652344, 0.14648438, 0.9140625,
-        0.123046875, 0.83984375, 0.15625, 0.9394531,
-        0.1171875, 0.8105469, 0.16210938, 0.96875,
-        0.109375, 0.77734375, 0.16992188, 1.0019531,
-        0.1640625, 0.8652344, 0.17773438, 0.9140625,
-        0.15429688, 0.83984375, 0.1875, 0.9394531,
-        0.1484375, 0.8105469, 0.19335938, 0.96875,
-        0.140625, 0.77734375, 0.20117188, 1.0019531,
-        0.1953125, 0.8652344, 0.20898438, 0.9140625,
-        0.18554688, 0.83984375, 0.21875, 0.9394531,
-        0.1796875, 0.8105469, 0.22460938, 0.96875,
-        0.171875, 0.77734375, 0.23242188, 1.0019531,
-        0.2265625, 0.8652344, 0.24023438, 0.9140625,
-        0.21679688, 0.83984375, 0.25, 0.9394531,
-        0.2109375, 0.8105469, 0.25585938, 0.96875,
-        0.203125, 0.77734375, 0.26367188, 1.0019531,
-        0.2578125, 0.8652344, 0.27148438, 0.9140625,
-        0.24804688, 0.83984375, 0.28125, 0.9394531,
-        0.2421875, 0.8105469, 0.28710938, 0.96875,
-        0.234375, 0.77734375, 0.29492188, 1.0019531,
-        0.2890625, 0.8652344, 0.30273438, 0.9140625,
-        0.27929688, 0.83984375, 0.3125, 0.9394531,
-        0.2734375, 0.8105469, 0.31835938, 0.96875,
-        0.265625, 0.77734375, 0.32617188, 1.0019531,
-        0.3203125, 0.8652344, 0.33398438, 0.9140625,
-        0.31054688, 0.83984375, 0.34375, 0.9394531,
-        0.3046875, 0.8105469, 0.34960938, 0.96875,
-        0.296875, 0.77734375, 0.35742188, 1.0019531,
-        0.3515625, 0.8652344, 0.36523438, 0.9140625,
-        0.34179688, 0.83984375, 0.375, 0.9394531,
-        0.3359375, 0.8105469, 0.38085938, 0.96875,
-        0.328125, 0.77734375, 0.38867188, 1.0019531,
-        0.3828125, 0.8652344, 0.39648438, 0.9140625,
-        0.37304688, 0.83984375, 0.40625, 0.9394531,
-        0.3671875, 0.8105469, 0.41210938, 0.96875,
-        0.359375, 0.77734375, 0.41992188, 1.0019531,
-        0.4140625, 0.8652344, 0.42773438, 0.9140625,
-        0.40429688, 0.83984375, 0.4375, 0.9394531,
-        0.3984375, 0.8105469, 0.44335938, 0.96875,
-        0.390625, 0.77734375, 0.45117188, 1.0019531,
-        0.4453125, 0.8652344, 0.45898438, 0.9140625,
-        0.43554688, 0.83984375, 0.46875, 0.9394531,
-        0.4296875, 0.8105469, 0.47460938, 0.96875,
-        0.421875, 0.77734375, 0.48242188, 1.0019531,
-        0.4765625, 0.8652344, 0.49023438, 0.9140625,
-        0.46679688, 0.83984375, 0.5, 0.9394531,
-        0.4609375, 0.8105469, 0.5058594, 0.96875,
-        0.453125, 0.77734375, 0.5136719, 1.0019531,
-        0.5078125, 0.8652344, 0.5214844, 0.9140625,
-        0.49804688, 0.83984375, 0.53125, 0.9394531,
-        0.4921875, 0.8105469, 0.5371094, 0.96875,
-        0.484375, 0.77734375, 0.5449219, 1.0019531,
-        0.5390625, 0.8652344, 0.5527344, 0.9140625,
-        0.5292969, 0.83984375, 0.5625, 0.9394531,
-        0.5234375, 0.8105469, 0.5683594, 0.96875,
-        0.515625, 0.77734375, 0.5761719, 1.0019531,
-        0.5703125, 0.8652344, 0.5839844, 0.9140625,
-        0.5605469, 0.83984375, 0.59375, 0.9394531,
-        0.5546875, 0.8105469, 0.5996094, 0.96875,
-        0.546875, 0.77734375, 0.6074219, 1.0019531,
-        0.6015625, 0.8652344, 0.6152344, 0.9140625,
-        0.5917969, 0.83984375, 0.625, 0.9394531,
-        0.5859375, 0.8105469, 0.6308594, 0.96875,
-        0.578125, 0.77734375, 0.6386719, 1.0019531,
-        0.6328125, 0.8652344, 0.6464844, 0.9140625,
-        0.6230469, 0.83984375, 0.65625, 0.9394531,
-        0.6171875, 0.8105469, 0.6621094, 0.96875,
-        0.609375, 0.77734375, 0.6699219, 1.0019531,
-        0.6640625, 0.8652344, 0.6777344, 0.9140625,
-        0.6542969, 0.83984375, 0.6875, 0.9394531,
-        0.6484375, 0.8105469, 0.6933594, 0.96875,
-        0.640625, 0.77734375, 0.7011719, 1.0019531,
-        0.6953125, 0.8652344, 0.7089844, 0.9140625,
-        0.6855469, 0.83984375, 0.71875, 0.9394531,
-        0.6796875, 0.8105469, 0.7246094, 0.96875,
-        0.671875, 0.77734375, 0.7324219, 1.0019531,
-        0.7265625, 0.8652344, 0.7402344, 0.9140625,
-        0.7167969, 0.83984375, 0.75, 0.9394531,
-        0.7109375, 0.8105469, 0.7558594, 0.96875,
-        0.703125, 0.77734375, 0.7636719, 1.0019531,
-        0.7578125, 0.8652344, 0.7714844, 0.9140625,
-        0.7480469, 0.83984375, 0.78125, 0.9394531,
-        0.7421875, 0.8105469, 0.7871094, 0.96875,
-        0.734375, 0.77734375, 0.7949219, 1.0019531,
-        0.7890625, 0.8652344, 0.8027344, 0.9140625,
-        0.7792969, 0.83984375, 0.8125, 0.9394531,
-        0.7734375, 0.8105469, 0.8183594, 0.96875,
-        0.765625, 0.77734375, 0.8261719, 1.0019531,
-        0.8203125, 0.8652344, 0.8339844, 0.9140625,
-        0.8105469, 0.83984375, 0.84375, 0.9394531,
-        0.8046875, 0.8105469, 0.8496094, 0.96875,
-        0.796875, 0.77734375, 0.8574219, 1.0019531,
-        0.8515625, 0.8652344, 0.8652344, 0.9140625,
-        0.8417969, 0.83984375, 0.875, 0.9394531,
-        0.8359375, 0.8105469, 0.8808594, 0.96875,
-        0.828125, 0.77734375, 0.8886719, 1.0019531,
-        0.8828125, 0.8652344, 0.8964844, 0.9140625,
-        0.8730469, 0.83984375, 0.90625, 0.9394531,
-        0.8671875, 0.8105469, 0.9121094, 0.96875,
-        0.859375, 0.77734375, 0.9199219, 1.0019531,
-        0.9140625, 0.8652344, 0.9277344, 0.9140625,
-        0.9042969, 0.83984375, 0.9375, 0.9394531,
-        0.8984375, 0.8105469, 0.9433594, 0.96875,
-        0.890625, 0.77734375, 0.9511719, 1.0019531,
-        0.9453125, 0.8652344, 0.9589844, 0.9140625,
-        0.9355469, 0.83984375, 0.96875, 0.9394531,
-        0.9296875, 0.8105469, 0.9746094, 0.96875,
-        0.921875, 0.77734375, 0.9824219, 1.0019531,
-        0.9765625, 0.8652344, 0.9902344, 0.9140625,
-        0.9667969, 0.83984375, 1.0, 0.9394531,
-        0.9609375, 0.8105469, 1.0058594, 0.96875,
-        0.953125, 0.77734375, 1.0136719, 1.0019531,
-        0.0078125, 0.8964844, 0.021484375, 0.9453125,
-        0.0, 0.87109375, 0.03125, 0.9707031,
-        -0.005859375, 0.8417969, 0.037109375, 1.0,
-        -0.013671875, 0.80859375, 0.044921875, 1.0332031,
-        0.0390625, 0.8964844, 0.052734375, 0.9453125,
-        0.029296875, 0.87109375, 0.0625, 0.9707031,
-        0.0234375, 0.8417969, 0.068359375, 1.0,
-        0.015625, 0.80859375, 0.076171875, 1.0332031,
-        0.0703125, 0.8964844, 0.083984375, 0.9453125,
-        0.060546875, 0.87109375, 0.09375, 0.9707031,
-        0.0546875, 0.8417969, 0.099609375, 1.0,
-        0.046875, 0.80859375, 0.107421875, 1.0332031,
-        0.1015625, 0.8964844, 0.115234375, 0.9453125,
-        0.091796875, 0.87109375, 0.125, 0.9707031,
-        0.0859375, 0.8417969, 0.13085938, 1.0,
-        0.078125, 0.80859375, 0.13867188, 1.0332031,
-        0.1328125, 0.8964844, 0.14648438, 0.9453125,
-        0.123046875, 0.87109375, 0.15625, 0.9707031,
-        0.1171875, 0.8417969, 0.16210938, 1.0,
-        0.109375, 0.80859375, 0.16992188, 1.0332031,
-        0.1640625, 0.8964844, 0.17773438, 0.9453125,
-        0.15429688, 0.87109375, 0.1875, 0.9707031,
-        0.1484375, 0.8417969, 0.19335938, 1.0,
-        0.140625, 0.80859375, 0.20117188, 1.0332031,
-        0.1953125, 0.8964844, 0.20898438, 0.9453125,
-        0.18554688, 0.87109375, 0.21875, 0.9707031,
-        0.1796875, 0.8417969, 0.22460938, 1.0,
-        0.171875, 0.80859375, 0.23242188, 1.0332031,
-        0.2265625, 0.8964844, 0.24023438, 0.9453125,
-        0.21679688, 0.87109375, 0.25, 0.9707031,
-        0.2109375, 0.8417969, 0.25585938, 1.0,
-        0.203125, 0.80859375, 0.26367188, 1.0332031,
-        0.2578125, 0.8964844, 0.27148438, 0.9453125,
-        0.24804688, 0.87109375, 0.28125, 0.9707031,
-        0.2421875, 0.8417969, 0.28710938, 1.0,
-        0.234375, 0.80859375, 0.29492188, 1.0332031,
-        0.2890625, 0.8964844, 0.30273438, 0.9453125,
-        0.27929688, 0.87109375, 0.3125, 0.9707031,
-        0.2734375, 0.8417969, 0.31835938, 1.0,
-        0.265625, 0.80859375, 0.32617188, 1.0332031,
-        0.3203125, 0.8964844, 0.33398438, 0.9453125,
-        0.31054688, 0.87109375, 0.34375, 0.9707031,
-        0.3046875, 0.8417969, 0.34960938, 1.0,
-        0.296875, 0.80859375, 0.35742188, 1.0332031,
-        0.3515625, 0.8964844, 0.36523438, 0.9453125,
-        0.34179688, 0.87109375, 0.375, 0.9707031,
-        0.3359375, 0.8417969, 0.38085938, 1.0,
-        0.328125, 0.80859375, 0.38867188, 1.0332031,
-        0.3828125, 0.8964844, 0.39648438, 0.9453125,
-        0.37304688, 0.87109375, 0.40625, 0.9707031,
-        0.3671875, 0.8417969, 0.41210938, 1.0,
-        0.359375, 0.80859375, 0.41992188, 1.0332031,
-        0.4140625, 0.8964844, 0.42773438, 0.9453125,
-        0.40429688, 0.87109375, 0.4375, 0.9707031,
-        0.3984375, 0.8417969, 0.44335938, 1.0,
-        0.390625, 0.80859375, 0.45117188, 1.0332031,
-        0.4453125, 0.8964844, 0.45898438, 0.9453125,
-        0.43554688, 0.87109375, 0.46875, 0.9707031,
-        0.4296875, 0.8417969, 0.47460938, 1.0,
-        0.421875, 0.80859375, 0.48242188, 1.0332031,
-        0.4765625, 0.8964844, 0.49023438, 0.9453125,
-        0.46679688, 0.87109375, 0.5, 0.9707031,
-        0.4609375, 0.8417969, 0.5058594, 1.0,
-        0.453125, 0.80859375, 0.5136719, 1.0332031,
-        0.5078125, 0.8964844, 0.5214844, 0.9453125,
-        0.49804688, 0.87109375, 0.53125, 0.9707031,
-        0.4921875, 0.8417969, 0.5371094, 1.0,
-        0.484375, 0.80859375, 0.5449219, 1.0332031,
-        0.5390625, 0.8964844, 0.5527344, 0.9453125,
-        0.5292969, 0.87109375, 0.5625, 0.9707031,
-        0.5234375, 0.8417969, 0.5683594, 1.0,
-        0.515625, 0.80859375, 0.5761719, 1.0332031,
-        0.5703125, 0.8964844, 0.5839844, 0.9453125,
-        0.5605469, 0.87109375, 0.59375, 0.9707031,
-        0.5546875, 0.8417969, 0.5996094, 1.0,
-        0.546875, 0.80859375, 0.6074219, 1.0332031,
-        0.6015625, 0.8964844, 0.6152344, 0.9453125,
-        0.5917969, 0.87109375, 0.625, 0.9707031,
-        0.5859375, 0.8417969, 0.6308594, 1.0,
-        0.578125, 0.80859375, 0.6386719, 1.0332031,
-        0.6328125, 0.8964844, 0.6464844, 0.9453125,
-        0.6230469, 0.87109375, 0.65625, 0.9707031,
-        0.6171875, 0.8417969, 0.6621094, 1.0,
-        0.609375, 0.80859375, 0.6699219, 1.0332031,
-        0.6640625, 0.8964844, 0.6777344, 0.9453125,
-        0.6542969, 0.87109375, 0.6875, 0.9707031,
-        0.6484375, 0.8417969, 0.6933594, 1.0,
-        0.640625, 0.80859375, 0.7011719, 1.0332031,
-        0.6953125, 0.8964844, 0.7089844, 0.9453125,
-        0.6855469, 0.87109375, 0.71875, 0.9707031,
-        0.6796875, 0.8417969, 0.7246094, 1.0,
-        0.671875, 0.80859375, 0.7324219, 1.0332031,
-        0.7265625, 0.8964844, 0.7402344, 0.9453125,
-        0.7167969, 0.87109375, 0.75, 0.9707031,
-        0.7109375, 0.8417969, 0.7558594, 1.0,
-        0.703125, 0.80859375, 0.7636719, 1.0332031,
-        0.7578125, 0.8964844, 0.7714844, 0.9453125,
-        0.7480469, 0.87109375, 0.78125, 0.9707031,
-        0.7421875, 0.8417969, 0.7871094, 1.0,
-        0.734375, 0.80859375, 0.7949219, 1.0332031,
-        0.7890625, 0.8964844, 0.8027344, 0.9453125,
-        0.7792969, 0.87109375, 0.8125, 0.9707031,
-        0.7734375, 0.8417969, 0.8183594, 1.0,
-        0.765625, 0.80859375, 0.8261719, 1.0332031,
-        0.8203125, 0.8964844, 0.8339844, 0.9453125,
-        0.8105469, 0.87109375, 0.84375, 0.9707031,
-        0.8046875, 0.8417969, 0.8496094, 1.0,
-        0.796875, 0.80859375, 0.8574219, 1.0332031,
-        0.8515625, 0.8964844, 0.8652344, 0.9453125,
-        0.8417969, 0.87109375, 0.875, 0.9707031,
-        0.8359375, 0.8417969, 0.8808594, 1.0,
-        0.828125, 0.80859375, 0.8886719, 1.0332031,
-        0.8828125, 0.8964844, 0.8964844, 0.9453125,
-        0.8730469, 0.87109375, 0.90625, 0.9707031,
-        0.8671875, 0.8417969, 0.9121094, 1.0,
-        0.859375, 0.80859375, 0.9199219, 1.0332031,
-        0.9140625, 0.8964844, 0.9277344, 0.9453125,
-        0.9042969, 0.87109375, 0.9375, 0.9707031,
-        0.8984375, 0.8417969, 0.9433594, 1.0,
-        0.890625, 0.80859375, 0.9511719, 1.0332031,
-        0.9453125, 0.8964844, 0.9589844, 0.9453125,
-        0.9355469, 0.87109375, 0.96875, 0.9707031,
-        0.9296875, 0.8417969, 0.9746094, 1.0,
-        0.921875, 0.80859375, 0.9824219, 1.0332031,
-        0.9765625, 0.8964844, 0.9902344, 0.9453125,
-        0.9667969, 0.87109375, 1.0, 0.9707031,
-        0.9609375, 0.8417969, 1.0058594, 1.0,
-        0.953125, 0.80859375, 1.0136719, 1.0332031,
-        0.0078125, 0.9277344, 0.021484375, 0.9765625,
-        0.0, 0.90234375, 0.03125, 1.0019531,
-        -0.005859375, 0.8730469, 0.037109375, 1.03125,
-        -0.013671875, 0.83984375, 0.044921875, 1.0644531,
-        0.0390625, 0.9277344, 0.052734375, 0.9765625,
-        0.029296875, 0.90234375, 0.0625, 1.0019531,
-        0.0234375, 0.8730469, 0.068359375, 1.03125,
-        0.015625, 0.83984375, 0.076171875, 1.0644531,
-        0.0703125, 0.9277344, 0.083984375, 0.9765625,
-        0.060546875, 0.90234375, 0.09375, 1.0019531,
-        0.0546875, 0.8730469, 0.099609375, 1.03125,
-        0.046875, 0.83984375, 0.107421875, 1.0644531,
-        0.1015625, 0.9277344, 0.115234375, 0.9765625,
-        0.091796875, 0.90234375, 0.125, 1.0019531,
-        0.0859375, 0.8730469, 0.13085938, 1.03125,
-        0.078125, 0.83984375, 0.13867188, 1.0644531,
-        0.1328125, 0.9277344, 0.14648438, 0.9765625,
-        0.123046875, 0.90234375, 0.15625, 1.0019531,
-        0.1171875, 0.8730469, 0.16210938, 1.03125,
-        0.109375, 0.83984375, 0.16992188, 1.0644531,
-        0.1640625, 0.9277344, 0.17773438, 0.9765625,
-        0.15429688, 0.90234375, 0.1875, 1.0019531,
-        0.1484375, 0.8730469, 0.19335938, 1.03125,
-        0.140625, 0.83984375, 0.20117188, 1.0644531,
-        0.1953125, 0.9277344, 0.20898438, 0.9765625,
-        0.18554688, 0.90234375, 0.21875, 1.0019531,
-        0.1796875, 0.8730469, 0.22460938, 1.03125,
-        0.171875, 0.83984375, 0.23242188, 1.0644531,
-        0.2265625, 0.9277344, 0.24023438, 0.9765625,
-        0.21679688, 0.90234375, 0.25, 1.0019531,
-        0.2109375, 0.8730469, 0.25585938, 1.03125,
-        0.203125, 0.83984375, 0.26367188, 1.0644531,
-        0.2578125, 0.9277344, 0.27148438, 0.9765625,
-        0.24804688, 0.90234375, 0.28125, 1.0019531,
-        0.2421875, 0.8730469, 0.28710938, 1.03125,
-        0.234375, 0.83984375, 0.29492188, 1.0644531,
-        0.2890625, 0.9277344, 0.30273438, 0.9765625,
-        0.27929688, 0.90234375, 0.3125, 1.0019531,
-        0.2734375, 0.8730469, 0.31835938, 1.03125,
-        0.265625, 0.83984375, 0.32617188, 1.0644531,
-        0.3203125, 0.9277344, 0.33398438, 0.9765625,
-        0.31054688, 0.90234375, 0.34375, 1.0019531,
-        0.3046875, 0.8730469, 0.34960938, 1.03125,
-        0.296875, 0.83984375, 0.35742188, 1.0644531,
-        0.3515625, 0.9277344, 0.36523438, 0.9765625,
-        0.34179688, 0.90234375, 0.375, 1.0019531,
-        0.3359375, 0.8730469, 0.38085938, 1.03125,
-        0.328125, 0.83984375, 0.38867188, 1.0644531,
-        0.3828125, 0.9277344, 0.39648438, 0.9765625,
-        0.37304688, 0.90234375, 0.40625, 1.0019531,
-        0.3671875, 0.8730469, 0.41210938, 1.03125,
-        0.359375, 0.83984375, 0.41992188, 1.0644531,
-        0.4140625, 0.9277344, 0.42773438, 0.9765625,
-        0.40429688, 0.90234375, 0.4375, 1.0019531,
-        0.3984375, 0.8730469, 0.44335938, 1.03125,
-        0.390625, 0.83984375, 0.45117188, 1.0644531,
-        0.4453125, 0.9277344, 0.45898438, 0.9765625,
-        0.43554688, 0.90234375, 0.46875, 1.0019531,
-        0.4296875, 0.8730469, 0.47460938, 1.03125,
-        0.421875, 0.83984375, 0.48242188, 1.0644531,
-        0.4765625, 0.9277344, 0.49023438, 0.9765625,
-        0.46679688, 0.90234375, 0.5, 1.0019531,
-        0.4609375, 0.8730469, 0.5058594, 1.03125,
-        0.453125, 0.83984375, 0.5136719, 1.0644531,
-        0.5078125, 0.9277344, 0.5214844, 0.9765625,
-        0.49804688, 0.90234375, 0.53125, 1.0019531,
-        0.4921875, 0.8730469, 0.5371094, 1.03125,
-        0.484375, 0.83984375, 0.5449219, 1.0644531,
-        0.5390625, 0.9277344, 0.5527344, 0.9765625,
-        0.5292969, 0.90234375, 0.5625, 1.0019531,
-        0.5234375, 0.8730469, 0.5683594, 1.03125,
-        0.515625, 0.83984375, 0.5761719, 1.0644531,
-        0.5703125, 0.9277344, 0.5839844, 0.9765625,
-        0.5605469, 0.90234375, 0.59375, 1.0019531,
-        0.5546875, 0.8730469, 0.5996094, 1.03125,
-        0.546875, 0.83984375, 0.6074219, 1.0644531,
-        0.6015625, 0.9277344, 0.6152344, 0.9765625,
-        0.5917969, 0.90234375, 0.625, 1.0019531,
-        0.5859375, 0.8730469, 0.6308594, 1.03125,
-        0.578125, 0.83984375, 0.6386719, 1.0644531,
-        0.6328125, 0.9277344, 0.6464844, 0.9765625,
-        0.6230469, 0.90234375, 0.65625, 1.0019531,
-        0.6171875, 0.8730469, 0.6621094, 1.03125,
-        0.609375, 0.83984375, 0.6699219, 1.0644531,
-        0.6640625, 0.9277344, 0.6777344, 0.9765625,
-        0.6542969, 0.90234375, 0.6875, 1.0019531,
-        0.6484375, 0.8730469, 0.6933594, 1.03125,
-        0.640625, 0.83984375, 0.7011719, 1.0644531,
-        0.6953125, 0.9277344, 0.7089844, 0.9765625,
-        0.6855469, 0.90234375, 0.71875, 1.0019531,
-        0.6796875, 0.8730469, 0.7246094, 1.03125,
-        0.671875, 0.83984375, 0.7324219, 1.0644531,
-        0.7265625, 0.9277344, 0.7402344, 0.9765625,
-        0.7167969, 0.90234375, 0.75, 1.0019531,
-        0.7109375, 0.8730469, 0.7558594, 1.03125,
-        0.703125, 0.83984375, 0.7636719, 1.0644531,
-        0.7578125, 0.9277344, 0.7714844, 0.9765625,
-        0.7480469, 0.90234375, 0.78125, 1.0019531,
-        0.7421875, 0.8730469, 0.7871094, 1.03125,
-        0.734375, 0.83984375, 0.7949219, 1.0644531,
-        0.7890625, 0.9277344, 0.8027344, 0.9765625,
-        0.7792969, 0.90234375, 0.8125, 1.0019531,
-        0.7734375, 0.8730469, 0.8183594, 1.03125,
-        0.765625, 0.83984375, 0.8261719, 1.0644531,
-        0.8203125, 0.9277344, 0.8339844, 0.9765625,
-        0.8105469, 0.90234375, 0.84375, 1.0019531,
-        0.8046875, 0.8730469, 0.8496094, 1.03125,
-        0.796875, 0.83984375, 0.8574219, 1.0644531,
-        0.8515625, 0.9277344, 0.8652344, 0.9765625,
-        0.8417969, 0.90234375, 0.875, 1.0019531,
-        0.8359375, 0.8730469, 0.8808594, 1.03125,
-        0.828125, 0.83984375, 0.8886719, 1.0644531,
-        0.8828125, 0.9277344, 0.8964844, 0.9765625,
-        0.8730469, 0.90234375, 0.90625, 1.0019531,
-        0.8671875, 0.8730469, 0.9121094, 1.03125,
-        0.859375, 0.83984375, 0.9199219, 1.0644531,
-        0.9140625, 0.9277344, 0.9277344, 0.9765625,
-        0.9042969, 0.90234375, 0.9375, 1.0019531,
-        0.8984375, 0.8730469, 0.9433594, 1.03125,
-        0.890625, 0.83984375, 0.9511719, 1.0644531,
-        0.9453125, 0.9277344, 0.9589844, 0.9765625,
-        0.9355469, 0.90234375, 0.96875, 1.0019531,
-        0.9296875, 0.8730469, 0.9746094, 1.03125,
-        0.921875, 0.83984375, 0.9824219, 1.0644531,
-        0.9765625, 0.9277344, 0.9902344, 0.9765625,
-        0.9667969, 0.90234375, 1.0, 1.0019531,
-        0.9609375, 0.8730469, 1.0058594, 1.03125,
-        0.953125, 0.83984375, 1.0136719, 1.0644531,
-        0.0078125, 0.9589844, 0.021484375, 1.0078125,
-        0.0, 0.93359375, 0.03125, 1.0332031,
-        -0.005859375, 0.9042969, 0.037109375, 1.0625,
-        -0.013671875, 0.87109375, 0.044921875, 1.0957031,
-        0.0390625, 0.9589844, 0.052734375, 1.0078125,
-        0.029296875, 0.93359375, 0.0625, 1.0332031,
-        0.0234375, 0.9042969, 0.068359375, 1.0625,
-        0.015625, 0.87109375, 0.076171875, 1.0957031,
-        0.0703125, 0.9589844, 0.083984375, 1.0078125,
-        0.060546875, 0.93359375, 0.09375, 1.0332031,
-        0.0546875, 0.9042969, 0.099609375, 1.0625,
-        0.046875, 0.87109375, 0.107421875, 1.0957031,
-        0.1015625, 0.9589844, 0.115234375, 1.0078125,
-        0.091796875, 0.93359375, 0.125, 1.0332031,
-        0.0859375, 0.9042969, 0.13085938, 1.0625,
-        0.078125, 0.87109375, 0.13867188, 1.0957031,
-        0.1328125, 0.9589844, 0.14648438, 1.0078125,
-        0.123046875, 0.93359375, 0.15625, 1.0332031,
-        0.1171875, 0.9042969, 0.16210938, 1.0625,
-        0.109375, 0.87109375, 0.16992188, 1.0957031,
-        0.1640625, 0.9589844, 0.17773438, 1.0078125,
-        0.15429688, 0.93359375, 0.1875, 1.0332031,
-        0.1484375, 0.9042969, 0.19335938, 1.0625,
-        0.140625, 0.87109375, 0.20117188, 1.0957031,
-        0.1953125, 0.9589844, 0.20898438, 1.0078125,
-        0.18554688, 0.93359375, 0.21875, 1.0332031,
-        0.1796875, 0.9042969, 0.22460938, 1.0625,
-        0.171875, 0.87109375, 0.23242188, 1.0957031,
-        0.2265625, 0.9589844, 0.24023438, 1.0078125,
-        0.21679688, 0.93359375, 0.25, 1.0332031,
-        0.2109375, 0.9042969, 0.25585938, 1.0625,
-        0.203125, 0.87109375, 0.26367188, 1.0957031,
-        0.2578125, 0.9589844, 0.27148438, 1.0078125,
-        0.24804688, 0.93359375, 0.28125, 1.0332031,
-        0.2421875, 0.9042969, 0.28710938, 1.0625,
-        0.234375, 0.87109375, 0.29492188, 1.0957031,
-        0.2890625, 0.9589844, 0.30273438, 1.0078125,
-        0.27929688, 0.93359375, 0.3125, 1.0332031,
-        0.2734375, 0.9042969, 0.31835938, 1.0625,
-        0.265625, 0.87109375, 0.32617188, 1.0957031,
-        0.3203125, 0.9589844, 0.33398438, 1.0078125,
-        0.31054688, 0.93359375, 0.34375, 1.0332031,
-        0.3046875, 0.9042969, 0.34960938, 1.0625,
-        0.296875, 0.87109375, 0.35742188, 1.0957031,
-        0.3515625, 0.9589844, 0.36523438, 1.0078125,
-        0.34179688, 0.93359375, 0.375, 1.0332031,
-        0.3359375, 0.9042969, 0.38085938, 1.0625,
-        0.328125, 0.87109375, 0.38867188, 1.0957031,
-        0.3828125, 0.9589844, 0.39648438, 1.0078125,
-        0.37304688, 0.93359375, 0.40625, 1.0332031,
-        0.3671875, 0.9042969, 0.41210938, 1.0625,
-        0.359375, 0.87109375, 0.41992188, 1.0957031,
-        0.4140625, 0.9589844, 0.42773438, 1.0078125,
-        0.40429688, 0.93359375, 0.4375, 1.0332031,
-        0.3984375, 0.9042969, 0.44335938, 1.0625,
-        0.390625, 0.87109375, 0.45117188, 1.0957031,
-        0.4453125, 0.9589844, 0.45898438, 1.0078125,
-        0.43554688, 0.93359375, 0.46875, 1.0332031,
-        0.4296875, 0.9042969, 0.47460938, 1.0625,
-        0.421875, 0.87109375, 0.48242188, 1.0957031,
-        0.4765625, 0.9589844, 0.49023438, 1.0078125,
-        0.46679688, 0.93359375, 0.5, 1.0332031,
-        0.4609375, 0.9042969, 0.5058594, 1.0625,
-        0.453125, 0.87109375, 0.5136719, 1.0957031,
-        0.5078125, 0.9589844, 0.5214844, 1.0078125,
-        0.49804688, 0.93359375, 0.53125, 1.0332031,
-        0.4921875, 0.9042969, 0.5371094, 1.0625,
-        0.484375, 0.87109375, 0.5449219, 1.0957031,
-        0.5390625, 0.9589844, 0.5527344, 1.0078125,
-        0.5292969, 0.93359375, 0.5625, 1.0332031,
-        0.5234375, 0.9042969, 0.5683594, 1.0625,
-        0.515625, 0.87109375, 0.5761719, 1.0957031,
-        0.5703125, 0.9589844, 0.5839844, 1.0078125,
-        0.5605469, 0.93359375, 0.59375, 1.0332031,
-        0.5546875, 0.9042969, 0.5996094, 1.0625,
-        0.546875, 0.87109375, 0.6074219, 1.0957031,
-        0.6015625, 0.9589844, 0.6152344, 1.0078125,
-        0.5917969, 0.93359375, 0.625, 1.0332031,
-        0.5859375, 0.9042969, 0.6308594, 1.0625,
-        0.578125, 0.87109375, 0.6386719, 1.0957031,
-        0.6328125, 0.9589844, 0.6464844, 1.0078125,
-        0.6230469, 0.93359375, 0.65625, 1.0332031,
-        0.6171875, 0.9042969, 0.6621094, 1.0625,
-        0.609375, 0.87109375, 0.6699219, 1.0957031,
-        0.6640625, 0.9589844, 0.6777344, 1.0078125,
-        0.6542969, 0.93359375, 0.6875, 1.0332031,
-        0.6484375, 0.9042969, 0.6933594, 1.0625,
-        0.640625, 0.87109375, 0.7011719, 1.0957031,
-        0.6953125, 0.9589844, 0.7089844, 1.0078125,
-        0.6855469, 0.93359375, 0.71875, 1.0332031,
-        0.6796875, 0.9042969, 0.7246094, 1.0625,
-        0.671875, 0.87109375, 0.7324219, 1.0957031,
-        0.7265625, 0.9589844, 0.7402344, 1.0078125,
-        0.7167969, 0.93359375, 0.75, 1.0332031,
-        0.7109375, 0.9042969, 0.7558594, 1.0625,
-        0.703125, 0.87109375, 0.7636719, 1.0957031,
-        0.7578125, 0.9589844, 0.7714844, 1.0078125,
-        0.7480469, 0.93359375, 0.78125, 1.0332031,
-        0.7421875, 0.9042969, 0.7871094, 1.0625,
-        0.734375, 0.87109375, 0.7949219, 1.0957031,
-        0.7890625, 0.9589844, 0.8027344, 1.0078125,
-        0.7792969, 0.93359375, 0.8125, 1.0332031,
-        0.7734375, 0.9042969, 0.8183594, 1.0625,
-        0.765625, 0.87109375, 0.8261719, 1.0957031,
-        0.8203125, 0.9589844, 0.8339844, 1.0078125,
-        0.8105469, 0.93359375, 0.84375, 1.0332031,
-        0.8046875, 0.9042969, 0.8496094, 1.0625,
-        0.796875, 0.87109375, 0.8574219, 1.0957031,
-        0.8515625, 0.9589844, 0.8652344, 1.0078125,
-        0.8417969, 0.93359375, 0.875, 1.0332031,
-        0.8359375, 0.9042969, 0.8808594, 1.0625,
-        0.828125, 0.87109375, 0.8886719, 1.0957031,
-        0.8828125, 0.9589844, 0.8964844, 1.0078125,
-        0.8730469, 0.93359375, 0.90625, 1.0332031,
-        0.8671875, 0.9042969, 0.9121094, 1.0625,
-        0.859375, 0.87109375, 0.9199219, 1.0957031,
-        0.9140625, 0.9589844, 0.9277344, 1.0078125,
-        0.9042969, 0.93359375, 0.9375, 1.0332031,
-        0.8984375, 0.9042969, 0.9433594, 1.0625,
-        0.890625, 0.87109375, 0.9511719, 1.0957031,
-        0.9453125, 0.9589844, 0.9589844, 1.0078125,
-        0.9355469, 0.93359375, 0.96875, 1.0332031,
-        0.9296875, 0.9042969, 0.9746094, 1.0625,
-        0.921875, 0.87109375, 0.9824219, 1.0957031,
-        0.9765625, 0.9589844, 0.9902344, 1.0078125,
-        0.9667969, 0.93359375, 1.0, 1.0332031,
-        0.9609375, 0.9042969, 1.0058594, 1.0625,
-        0.953125, 0.87109375, 1.0136719, 1.0957031,
-        -0.0078125, -0.119140625, 0.0703125, 0.18164062,
-        -0.017578125, -0.1640625, 0.080078125, 0.2265625,
-        -0.03125, -0.21679688, 0.09375, 0.27929688,
-        -0.048828125, -0.28710938, 0.111328125, 0.34960938,
-        -0.076171875, -0.38671875, 0.13867188, 0.44921875,
-        0.052734375, -0.119140625, 0.1328125, 0.18164062,
-        0.04296875, -0.1640625, 0.14257812, 0.2265625,
-        0.029296875, -0.21679688, 0.15625, 0.27929688,
-        0.01171875, -0.28710938, 0.17382812, 0.34960938,
-        -0.013671875, -0.38671875, 0.20117188, 0.44921875,
-        0.115234375, -0.119140625, 0.1953125, 0.18164062,
-        0.10546875, -0.1640625, 0.20507812, 0.2265625,
-        0.091796875, -0.21679688, 0.21875, 0.27929688,
-        0.07421875, -0.28710938, 0.23632812, 0.34960938,
-        0.046875, -0.38671875, 0.26367188, 0.44921875,
-        0.17773438, -0.119140625, 0.2578125, 0.18164062,
-        0.16796875, -0.1640625, 0.26757812, 0.2265625,
-        0.15429688, -0.21679688, 0.28125, 0.27929688,
-        0.13671875, -0.28710938, 0.29882812, 0.34960938,
-        0.109375, -0.38671875, 0.32617188, 0.44921875,
-        0.24023438, -0.119140625, 0.3203125, 0.18164062,
-        0.23046875, -0.1640625, 0.33007812, 0.2265625,
-        0.21679688, -0.21679688, 0.34375, 0.27929688,
-        0.19921875, -0.28710938, 0.36132812, 0.34960938,
-        0.171875, -0.38671875, 0.38867188, 0.44921875,
-        0.30273438, -0.119140625, 0.3828125, 0.18164062,
-        0.29296875, -0.1640625, 0.39257812, 0.2265625,
-        0.27929688, -0.21679688, 0.40625, 0.27929688,
-        0.26171875, -0.28710938, 0.42382812, 0.34960938,
-        0.234375, -0.38671875, 0.45117188, 0.44921875,
-        0.36523438, -0.119140625, 0.4453125, 0.18164062,
-        0.35546875, -0.1640625, 0.45507812, 0.2265625,
-        0.34179688, -0.21679688, 0.46875, 0.27929688,
-        0.32421875, -0.28710938, 0.48632812, 0.34960938,
-        0.296875, -0.38671875, 0.5136719, 0.44921875,
-        0.42773438, -0.119140625, 0.5078125, 0.18164062,
-        0.41796875, -0.1640625, 0.5175781, 0.2265625,
-        0.40429688, -0.21679688, 0.53125, 0.27929688,
-        0.38671875, -0.28710938, 0.5488281, 0.34960938,
-        0.359375, -0.38671875, 0.5761719, 0.44921875,
-        0.49023438, -0.119140625, 0.5703125, 0.18164062,
-        0.48046875, -0.1640625, 0.5800781, 0.2265625,
-        0.46679688, -0.21679688, 0.59375, 0.27929688,
-        0.44921875, -0.28710938, 0.6113281, 0.34960938,
-        0.421875, -0.38671875, 0.6386719, 0.44921875,
-        0.5527344, -0.119140625, 0.6328125, 0.18164062,
-        0.54296875, -0.1640625, 0.6425781, 0.2265625,
-        0.5292969, -0.21679688, 0.65625, 0.27929688,
-        0.51171875, -0.28710938, 0.6738281, 0.34960938,
-        0.484375, -0.38671875, 0.7011719, 0.44921875,
-        0.6152344, -0.119140625, 0.6953125, 0.18164062,
-        0.60546875, -0.1640625, 0.7050781, 0.2265625,
-        0.5917969, -0.21679688, 0.71875, 0.27929688,
-        0.57421875, -0.28710938, 0.7363281, 0.34960938,
-        0.546875, -0.38671875, 0.7636719, 0.44921875,
-        0.6777344, -0.119140625, 0.7578125, 0.18164062,
-        0.66796875, -0.1640625, 0.7675781, 0.2265625,
-        0.6542969, -0.21679688, 0.78125, 0.27929688,
-        0.63671875, -0.28710938, 0.7988281, 0.34960938,
-        0.609375, -0.38671875, 0.8261719, 0.44921875,
-        0.7402344, -0.119140625, 0.8203125, 0.18164062,
-        0.73046875, -0.1640625, 0.8300781, 0.2265625,
-        0.7167969, -0.21679688, 0.84375, 0.27929688,
-        0.69921875, -0.28710938, 0.8613281, 0.34960938,
-        0.671875, -0.38671875, 0.8886719, 0.44921875,
-        0.8027344, -0.119140625, 0.8828125, 0.18164062,
-        0.79296875, -0.1640625, 0.8925781, 0.2265625,
-        0.7792969, -0.21679688, 0.90625, 0.27929688,
-        0.76171875, -0.28710938, 0.9238281, 0.34960938,
-        0.734375, -0.38671875, 0.9511719, 0.44921875,
-        0.8652344, -0.119140625, 0.9453125, 0.18164062,
-        0.85546875, -0.1640625, 0.9550781, 0.2265625,
-        0.8417969, -0.21679688, 0.96875, 0.27929688,
-        0.82421875, -0.28710938, 0.9863281, 0.34960938,
-        0.796875, -0.38671875, 1.0136719, 0.44921875,
-        0.9277344, -0.119140625, 1.0078125, 0.18164062,
-        0.91796875, -0.1640625, 1.0175781, 0.2265625,
-        0.9042969, -0.21679688, 1.03125, 0.27929688,
-        0.88671875, -0.28710938, 1.0488281, 0.34960938,
-        0.859375, -0.38671875, 1.0761719, 0.44921875,
-        -0.0078125, -0.056640625, 0.0703125, 0.24414062,
-        -0.017578125, -0.1015625, 0.080078125, 0.2890625,
-        -0.03125, -0.15429688, 0.09375, 0.34179688,
-        -0.048828125, -0.22460938, 0.111328125, 0.41210938,
-        -0.076171875, -0.32421875, 0.13867188, 0.51171875,
-        0.052734375, -0.056640625, 0.1328125, 0.24414062,
-        0.04296875, -0.1015625, 0.14257812, 0.2890625,
-        0.029296875, -0.15429688, 0.15625, 0.34179688,
-        0.01171875, -0.22460938, 0.17382812, 0.41210938,
-        -0.013671875, -0.32421875, 0.20117188, 0.51171875,
-        0.115234375, -0.056640625, 0.1953125, 0.24414062,
-        0.10546875, -0.1015625, 0.20507812, 0.2890625,
-        0.091796875, -0.15429688, 0.21875, 0.34179688,
-        0.07421875, -0.22460938, 0.23632812, 0.41210938,
-        0.046875, -0.32421875, 0.26367188, 0.51171875,
-        0.17773438, -0.056640625, 0.2578125, 0.24414062,
-        0.16796875, -0.1015625, 0.26757812, 0.2890625,
-        0.15429688, -0.15429688, 0.28125, 0.34179688,
-        0.13671875, -0.22460938, 0.29882812, 0.41210938,
-        0.109375, -0.32421875, 0.32617188, 0.51171875,
-        0.24023438, -0.056640625, 0.3203125, 0.24414062,
-        0.23046875, -0.1015625, 0.33007812, 0.2890625,
-        0.21679688, -0.15429688, 0.34375, 0.34179688,
-        0.19921875, -0.22460938, 0.36132812, 0.41210938,
-        0.171875, -0.32421875, 0.38867188, 0.51171875,
-        0.30273438, -0.056640625, 0.3828125, 0.24414062,
-        0.29296875, -0.1015625, 0.39257812, 0.2890625,
-        0.27929688, -0.15429688, 0.40625, 0.34179688,
-        0.26171875, -0.22460938, 0.42382812, 0.41210938,
-        0.234375, -0.32421875, 0.45117188, 0.51171875,
-        0.36523438, -0.056640625, 0.4453125, 0.24414062,
-        0.35546875, -0.1015625, 0.45507812, 0.2890625,
-        0.34179688, -0.15429688, 0.46875, 0.34179688,
-        0.32421875, -0.22460938, 0.48632812, 0.41210938,
-        0.296875, -0.32421875, 0.5136719, 0.51171875,
-        0.42773438, -0.056640625, 0.5078125, 0.24414062,
-        0.41796875, -0.1015625, 0.5175781, 0.2890625,
-        0.40429688, -0.15429688, 0.53125, 0.34179688,
-        0.38671875, -0.22460938, 0.5488281, 0.41210938,
-        0.359375, -0.32421875, 0.5761719, 0.51171875,
-        0.49023438, -0.056640625, 0.5703125, 0.24414062,
-        0.48046875, -0.1015625, 0.5800781, 0.2890625,
-        0.46679688, -0.15429688, 0.59375, 0.34179688,
-        0.44921875, -0.22460938, 0.6113281, 0.41210938,
-        0.421875, -0.32421875, 0.6386719, 0.51171875,
-        0.5527344, -0.056640625, 0.6328125, 0.24414062,
-        0.54296875, -0.1015625, 0.6425781, 0.2890625,
-        0.5292969, -0.15429688, 0.65625, 0.34179688,
-        0.51171875, -0.22460938, 0.6738281, 0.41210938,
-        0.484375, -0.32421875, 0.7011719, 0.51171875,
-        0.6152344, -0.056640625, 0.6953125, 0.24414062,
-        0.60546875, -0.1015625, 0.7050781, 0.2890625,
-        0.5917969, -0.15429688, 0.71875, 0.34179688,
-        0.57421875, -0.22460938, 0.7363281, 0.41210938,
-        0.546875, -0.32421875, 0.7636719, 0.51171875,
-        0.6777344, -0.056640625, 0.7578125, 0.24414062,
-        0.66796875, -0.1015625, 0.7675781, 0.2890625,
-        0.6542969, -0.15429688, 0.78125, 0.34179688,
-        0.63671875, -0.22460938, 0.7988281, 0.41210938,
-        0.609375, -0.32421875, 0.8261719, 0.51171875,
-        0.7402344, -0.056640625, 0.8203125, 0.24414062,
-        0.73046875, -0.1015625, 0.8300781, 0.2890625,
-        0.7167969, -0.15429688, 0.84375, 0.34179688,
-        0.69921875, -0.22460938, 0.8613281, 0.41210938,
-        0.671875, -0.32421875, 0.8886719, 0.51171875,
-        0.8027344, -0.056640625, 0.8828125, 0.24414062,
-        0.79296875, -0.1015625, 0.8925781, 0.2890625,
-        0.7792969, -0.15429688, 0.90625, 0.34179688,
-        0.76171875, -0.22460938, 0.9238281, 0.41210938,
-        0.734375, -0.32421875, 0.9511719, 0.51171875,
-        0.8652344, -0.056640625, 0.9453125, 0.24414062,
-        0.85546875, -0.1015625, 0.9550781, 0.2890625,
-        0.8417969, -0.15429688, 0.96875, 0.34179688,
-        0.82421875, -0.22460938, 0.9863281, 0.41210938,
-        0.796875, -0.32421875, 1.0136719, 0.51171875,
-        0.9277344, -0.056640625, 1.0078125, 0.24414062,
-        0.91796875, -0.1015625, 1.0175781, 0.2890625,
-        0.9042969, -0.15429688, 1.03125, 0.34179688,
-        0.88671875, -0.22460938, 1.0488281, 0.41210938,
-        0.859375, -0.32421875, 1.0761719, 0.51171875,
-        -0.0078125, 0.00390625, 0.0703125, 0.30664062,
-        -0.017578125, -0.0390625, 0.080078125, 0.3515625,
-        -0.03125, -0.091796875, 0.09375, 0.40429688,
-        -0.048828125, -0.16210938, 0.111328125, 0.47460938,
-        -0.076171875, -0.26171875, 0.13867188, 0.57421875,
-        0.052734375, 0.00390625, 0.1328125, 0.30664062,
-        0.04296875, -0.0390625, 0.14257812, 0.3515625,
-        0.029296875, -0.091796875, 0.15625, 0.40429688,
-        0.01171875, -0.16210938, 0.17382812, 0.47460938,
-        -0.013671875, -0.26171875, 0.20117188, 0.57421875,
-        0.115234375, 0.00390625, 0.1953125, 0.30664062,
-        0.10546875, -0.0390625, 0.20507812, 0.3515625,
-        0.091796875, -0.091796875, 0.21875, 0.40429688,
-        0.07421875, -0.16210938, 0.23632812, 0.47460938,
-        0.046875, -0.26171875, 0.26367188, 0.57421875,
-        0.17773438, 0.00390625, 0.2578125, 0.30664062,
-        0.16796875, -0.0390625, 0.26757812, 0.3515625,
-        0.15429688, -0.091796875, 0.28125, 0.40429688,
-        0.13671875, -0.16210938, 0.29882812, 0.47460938,
-        0.109375, -0.26171875, 0.32617188, 0.57421875,
-        0.24023438, 0.00390625, 0.3203125, 0.30664062,
-        0.23046875, -0.0390625, 0.33007812, 0.3515625,
-        0.21679688, -0.091796875, 0.34375, 0.40429688,
-        0.19921875, -0.16210938, 0.36132812, 0.47460938,
-        0.171875, -0.26171875, 0.38867188, 0.57421875,
-        0.30273438, 0.00390625, 0.3828125, 0.30664062,
-        0.29296875, -0.0390625, 0.39257812, 0.3515625,
-        0.27929688, -0.091796875, 0.40625, 0.40429688,
-        0.26171875, -0.16210938, 0.42382812, 0.47460938,
-        0.234375, -0.26171875, 0.45117188, 0.57421875,
-        0.36523438, 0.00390625, 0.4453125, 0.30664062,
-        0.35546875, -0.0390625, 0.45507812, 0.3515625,
-        0.34179688, -0.091796875, 0.46875, 0.40429688,
-        0.32421875, -0.16210938, 0.48632812, 0.47460938,
-        0.296875, -0.26171875, 0.5136719, 0.57421875,
-        0.42773438, 0.00390625, 0.5078125, 0.30664062,
-        0.41796875, -0.0390625, 0.5175781, 0.3515625,
-        0.40429688, -0.091796875, 0.53125, 0.40429688,
-        0.38671875, -0.16210938, 0.5488281, 0.47460938,
-        0.359375, -0.26171875, 0.5761719, 0.57421875,
-        0.49023438, 0.00390625, 0.5703125, 0.30664062,
-        0.48046875, -0.0390625, 0.5800781, 0.3515625,
-        0.46679688, -0.091796875, 0.59375, 0.40429688,
-        0.44921875, -0.16210938, 0.6113281, 0.47460938,
-        0.421875, -0.26171875, 0.6386719, 0.57421875,
-        0.5527344, 0.00390625, 0.6328125, 0.30664062,
-        0.54296875, -0.0390625, 0.6425781, 0.3515625,
-        0.5292969, -0.091796875, 0.65625, 0.40429688,
-        0.51171875, -0.16210938, 0.6738281, 0.47460938,
-        0.484375, -0.26171875, 0.7011719, 0.57421875,
-        0.6152344, 0.00390625, 0.6953125, 0.30664062,
-        0.60546875, -0.0390625, 0.7050781, 0.3515625,
-        0.5917969, -0.091796875, 0.71875, 0.40429688,
-        0.57421875, -0.16210938, 0.7363281, 0.47460938,
-        0.546875, -0.26171875, 0.7636719, 0.57421875,
-        0.6777344, 0.00390625, 0.7578125, 0.30664062,
-        0.66796875, -0.0390625, 0.7675781, 0.3515625,
-        0.6542969, -0.091796875, 0.78125, 0.40429688,
-        0.63671875, -0.16210938, 0.7988281, 0.47460938,
-        0.609375, -0.26171875, 0.8261719, 0.57421875,
-        0.7402344, 0.00390625, 0.8203125, 0.30664062,
-        0.73046875, -0.0390625, 0.8300781, 0.3515625,
-        0.7167969, -0.091796875, 0.84375, 0.40429688,
-        0.69921875, -0.16210938, 0.8613281, 0.47460938,
-        0.671875, -0.26171875, 0.8886719, 0.57421875,
-        0.8027344, 0.00390625, 0.8828125, 0.30664062,
-        0.79296875, -0.0390625, 0.8925781, 0.3515625,
-        0.7792969, -0.091796875, 0.90625, 0.40429688,
-        0.76171875, -0.16210938, 0.9238281, 0.47460938,
-        0.734375, -0.26171875, 0.9511719, 0.57421875,
-        0.8652344, 0.00390625, 0.9453125, 0.30664062,
-        0.85546875, -0.0390625, 0.9550781, 0.3515625,
-        0.8417969, -0.091796875, 0.96875, 0.40429688,
-        0.82421875, -0.16210938, 0.9863281, 0.47460938,
-        0.796875, -0.26171875, 1.0136719, 0.57421875,
-        0.9277344, 0.00390625, 1.0078125, 0.30664062,
-        0.91796875, -0.0390625, 1.0175781, 0.3515625,
-        0.9042969, -0.091796875, 1.03125, 0.40429688,
-        0.88671875, -0.16210938, 1.0488281, 0.47460938,
-        0.859375, -0.26171875, 1.0761719, 0.57421875,
-        -0.0078125, 0.06640625, 0.0703125, 0.36914062,
-        -0.017578125, 0.021484375, 0.080078125, 0.4140625,
-        -0.03125, -0.029296875, 0.09375, 0.46679688,
-        -0.048828125, -0.099609375, 0.111328125, 0.5371094,
-        -0.076171875, -0.19921875, 0.13867188, 0.63671875,
-        0.052734375, 0.06640625, 0.1328125, 0.36914062,
-        0.04296875, 0.021484375, 0.14257812, 0.4140625,
-        0.029296875, -0.029296875, 0.15625, 0.46679688,
-        0.01171875, -0.099609375, 0.17382812, 0.5371094,
-        -0.013671875, -0.19921875, 0.20117188, 0.63671875,
-        0.115234375, 0.06640625, 0.1953125, 0.36914062,
-        0.10546875, 0.021484375, 0.20507812, 0.4140625,
-        0.091796875, -0.029296875, 0.21875, 0.46679688,
-        0.07421875, -0.099609375, 0.23632812, 0.5371094,
-        0.046875, -0.19921875, 0.26367188, 0.63671875,
-        0.17773438, 0.06640625, 0.2578125, 0.36914062,
-        0.16796875, 0.021484375, 0.26757812, 0.4140625,
-        0.15429688, -0.029296875, 0.28125, 0.46679688,
-        0.13671875, -0.099609375, 0.29882812, 0.5371094,
-        0.109375, -0.19921875, 0.32617188, 0.63671875,
-        0.24023438, 0.06640625, 0.3203125, 0.36914062,
-        0.23046875, 0.021484375, 0.33007812, 0.4140625,
-        0.21679688, -0.029296875, 0.34375, 0.46679688,
-        0.19921875, -0.099609375, 0.36132812, 0.5371094,
-        0.171875, -0.19921875, 0.38867188, 0.63671875,
-        0.30273438, 0.06640625, 0.3828125, 0.36914062,
-        0.29296875, 0.021484375, 0.39257812, 0.4140625,
-        0.27929688, -0.029296875, 0.40625, 0.46679688,
-        0.26171875, -0.099609375, 0.42382812, 0.5371094,
-        0.234375, -0.19921875, 0.45117188, 0.63671875,
-        0.36523438, 0.06640625, 0.4453125, 0.36914062,
-        0.35546875, 0.021484375, 0.45507812, 0.4140625,
-        0.34179688, -0.029296875, 0.46875, 0.46679688,
-        0.32421875, -0.099609375, 0.48632812, 0.5371094,
-        0.296875, -0.19921875, 0.5136719, 0.63671875,
-        0.42773438, 0.06640625, 0.5078125, 0.36914062,
-        0.41796875, 0.021484375, 0.5175781, 0.4140625,
-        0.40429688, -0.029296875, 0.53125, 0.46679688,
-        0.38671875, -0.099609375, 0.5488281, 0.5371094,
-        0.359375, -0.19921875, 0.5761719, 0.63671875,
-        0.49023438, 0.06640625, 0.5703125, 0.36914062,
-        0.48046875, 0.021484375, 0.5800781, 0.4140625,
-        0.46679688, -0.029296875, 0.59375, 0.46679688,
-        0.44921875, -0.099609375, 0.6113281, 0.5371094,
-        0.421875, -0.19921875, 0.6386719, 0.63671875,
-        0.5527344, 0.06640625, 0.6328125, 0.36914062,
-        0.54296875, 0.021484375, 0.6425781, 0.4140625,
-        0.5292969, -0.029296875, 0.65625, 0.46679688,
-        0.51171875, -0.099609375, 0.6738281, 0.5371094,
-        0.484375, -0.19921875, 0.7011719, 0.63671875,
-        0.6152344, 0.06640625, 0.6953125, 0.36914062,
-        0.60546875, 0.021484375, 0.7050781, 0.4140625,
-        0.5917969, -0.029296875, 0.71875, 0.46679688,
-        0.57421875, -0.099609375, 0.7363281, 0.5371094,
-        0.546875, -0.19921875, 0.7636719, 0.63671875,
-        0.6777344, 0.06640625, 0.7578125, 0.36914062,
-        0.66796875, 0.021484375, 0.7675781, 0.4140625,
-        0.6542969, -0.029296875, 0.78125, 0.46679688,
-        0.63671875, -0.099609375, 0.7988281, 0.5371094,
-        0.609375, -0.19921875, 0.8261719, 0.63671875,
-        0.7402344, 0.06640625, 0.8203125, 0.36914062,
-        0.73046875, 0.021484375, 0.8300781, 0.4140625,
-        0.7167969, -0.029296875, 0.84375, 0.46679688,
-        0.69921875, -0.099609375, 0.8613281, 0.5371094,
-        0.671875, -0.19921875, 0.8886719, 0.63671875,
-        0.8027344, 0.06640625, 0.8828125, 0.36914062,
-        0.79296875, 0.021484375, 0.8925781, 0.4140625,
-        0.7792969, -0.029296875, 0.90625, 0.46679688,
-        0.76171875, -0.099609375, 0.9238281, 0.5371094,
-        0.734375, -0.19921875, 0.9511719, 0.63671875,
-        0.8652344, 0.06640625, 0.9453125, 0.36914062,
-        0.85546875, 0.021484375, 0.9550781, 0.4140625,
-        0.8417969, -0.029296875, 0.96875, 0.46679688,
-        0.82421875, -0.099609375, 0.9863281, 0.5371094,
-        0.796875, -0.19921875, 1.0136719, 0.63671875,
-        0.9277344, 0.06640625, 1.0078125, 0.36914062,
-        0.91796875, 0.021484375, 1.0175781, 0.4140625,
-        0.9042969, -0.029296875, 1.03125, 0.46679688,
-        0.88671875, -0.099609375, 1.0488281, 0.5371094,
-        0.859375, -0.19921875, 1.0761719, 0.63671875,
-        -0.0078125, 0.12890625, 0.0703125, 0.43164062,
-        -0.017578125, 0.083984375, 0.080078125, 0.4765625,
-        -0.03125, 0.03125, 0.09375, 0.5292969,
-        -0.048828125, -0.037109375, 0.111328125, 0.5996094,
-        -0.076171875, -0.13671875, 0.13867188, 0.69921875,
-        0.052734375, 0.12890625, 0.1328125, 0.43164062,
-        0.04296875, 0.083984375, 0.14257812, 0.4765625,
-        0.029296875, 0.03125, 0.15625, 0.5292969,
-        0.01171875, -0.037109375, 0.17382812, 0.5996094,
-        -0.013671875, -0.13671875, 0.20117188, 0.69921875,
-        0.115234375, 0.12890625, 0.1953125, 0.43164062,
-        0.10546875, 0.083984375, 0.20507812, 0.4765625,
-        0.091796875, 0.03125, 0.21875, 0.5292969,
-        0.07421875, -0.037109375, 0.23632812, 0.5996094,
-        0.046875, -0.13671875, 0.26367188, 0.69921875,
-        0.17773438, 0.12890625, 0.2578125, 0.43164062,
-        0.16796875, 0.083984375, 0.26757812, 0.4765625,
-        0.15429688, 0.03125, 0.28125, 0.5292969,
-        0.13671875, -0.037109375, 0.29882812, 0.5996094,
-        0.109375, -0.13671875, 0.32617188, 0.69921875,
-        0.24023438, 0.12890625, 0.3203125, 0.43164062,
-        0.23046875, 0.083984375, 0.33007812, 0.4765625,
-        0.21679688, 0.03125, 0.34375, 0.5292969,
-        0.19921875, -0.037109375, 0.36132812, 0.5996094,
-        0.171875, -0.13671875, 0.38867188, 0.69921875,
-        0.30273438, 0.12890625, 0.3828125, 0.43164062,
-        0.29296875, 0.083984375, 0.39257812, 0.4765625,
-        0.27929688, 0.03125, 0.40625, 0.5292969,
-        0.26171875, -0.037109375, 0.42382812, 0.5996094,
-        0.234375, -0.13671875, 0.45117188, 0.69921875,
-        0.36523438, 0.12890625, 0.4453125, 0.43164062,
-        0.35546875, 0.083984375, 0.45507812, 0.4765625,
-        0.34179688, 0.03125, 0.46875, 0.5292969,
-        0.32421875, -0.037109375, 0.48632812, 0.5996094,
-        0.296875, -0.13671875, 0.5136719, 0.69921875,
-        0.42773438, 0.12890625, 0.5078125, 0.43164062,
-        0.41796875, 0.083984375, 0.5175781, 0.4765625,
-        0.40429688, 0.03125, 0.53125, 0.5292969,
-        0.38671875, -0.037109375, 0.5488281, 0.5996094,
-        0.359375, -0.13671875, 0.5761719, 0.69921875,
-        0.49023438, 0.12890625, 0.5703125, 0.43164062,
-        0.48046875, 0.083984375, 0.5800781, 0.4765625,
-        0.46679688, 0.03125, 0.59375, 0.5292969,
-        0.44921875, -0.037109375, 0.6113281, 0.5996094,
-        0.421875, -0.13671875, 0.6386719, 0.69921875,
-        0.5527344, 0.12890625, 0.6328125, 0.43164062,
-        0.54296875, 0.083984375, 0.6425781, 0.4765625,
-        0.5292969, 0.03125, 0.65625, 0.5292969,
-        0.51171875, -0.037109375, 0.6738281, 0.5996094,
-        0.484375, -0.13671875, 0.7011719, 0.69921875,
-        0.6152344, 0.12890625, 0.6953125, 0.43164062,
-        0.60546875, 0.083984375, 0.7050781, 0.4765625,
-        0.5917969, 0.03125, 0.71875, 0.5292969,
-        0.57421875, -0.037109375, 0.7363281, 0.5996094,
-        0.546875, -0.13671875, 0.7636719, 0.69921875,
-        0.6777344, 0.12890625, 0.7578125, 0.43164062,
-        0.66796875, 0.083984375, 0.7675781, 0.4765625,
-        0.6542969, 0.03125, 0.78125, 0.5292969,
-        0.63671875, -0.037109375, 0.7988281, 0.5996094,
-        0.609375, -0.13671875, 0.8261719, 0.69921875,
-        0.7402344, 0.12890625, 0.8203125, 0.43164062,
-        0.73046875, 0.083984375, 0.8300781, 0.4765625,
-        0.7167969, 0.03125, 0.84375, 0.5292969,
-        0.69921875, -0.037109375, 0.8613281, 0.5996094,
-        0.671875, -0.13671875, 0.8886719, 0.69921875,
-        0.8027344, 0.12890625, 0.8828125, 0.43164062,
-        0.79296875, 0.083984375, 0.8925781, 0.4765625,
-        0.7792969, 0.03125, 0.90625, 0.5292969,
-        0.76171875, -0.037109375, 0.9238281, 0.5996094,
-        0.734375, -0.13671875, 0.9511719, 0.69921875,
-        0.8652344, 0.12890625, 0.9453125, 0.43164062,
-        0.85546875, 0.083984375, 0.9550781, 0.4765625,
-        0.8417969, 0.03125, 0.96875, 0.5292969,
-        0.82421875, -0.037109375, 0.9863281, 0.5996094,
-        0.796875, -0.13671875, 1.0136719, 0.69921875,
-        0.9277344, 0.12890625, 1.0078125, 0.43164062,
-        0.91796875, 0.083984375, 1.0175781, 0.4765625,
-        0.9042969, 0.03125, 1.03125, 0.5292969,
-        0.88671875, -0.037109375, 1.0488281, 0.5996094,
-        0.859375, -0.13671875, 1.0761719, 0.69921875,
-        -0.0078125, 0.19140625, 0.0703125, 0.49414062,
-        -0.017578125, 0.14648438, 0.080078125, 0.5390625,
-        -0.03125, 0.09375, 0.09375, 0.5917969,
-        -0.048828125, 0.0234375, 0.111328125, 0.6621094,
-        -0.076171875, -0.07421875, 0.13867188, 0.76171875,
-        0.052734375, 0.19140625, 0.1328125, 0.49414062,
-        0.04296875, 0.14648438, 0.14257812, 0.5390625,
-        0.029296875, 0.09375, 0.15625, 0.5917969,
-        0.01171875, 0.0234375, 0.17382812, 0.6621094,
-        -0.013671875, -0.07421875, 0.20117188, 0.76171875,
-        0.115234375, 0.19140625, 0.1953125, 0.49414062,
-        0.10546875, 0.14648438, 0.20507812, 0.5390625,
-        0.091796875, 0.09375, 0.21875, 0.5917969,
-        0.07421875, 0.0234375, 0.23632812, 0.6621094,
-        0.046875, -0.07421875, 0.26367188, 0.76171875,
-        0.17773438, 0.19140625, 0.2578125, 0.49414062,
-        0.16796875, 0.14648438, 0.26757812, 0.5390625,
-        0.15429688, 0.09375, 0.28125, 0.5917969,
-        0.13671875, 0.0234375, 0.29882812, 0.6621094,
-        0.109375, -0.07421875, 0.32617188, 0.76171875,
-        0.24023438, 0.19140625, 0.3203125, 0.49414062,
-        0.23046875, 0.14648438, 0.33007812, 0.5390625,
-        0.21679688, 0.09375, 0.34375, 0.5917969,
-        0.19921875, 0.0234375, 0.36132812, 0.6621094,
-        0.171875, -0.07421875, 0.38867188, 0.76171875,
-        0.30273438, 0.19140625, 0.3828125, 0.49414062,
-        0.29296875, 0.14648438, 0.39257812, 0.5390625,
-        0.27929688, 0.09375, 0.40625, 0.5917969,
-        0.26171875, 0.0234375, 0.42382812, 0.6621094,
-        0.234375, -0.07421875, 0.45117188, 0.76171875,
-        0.36523438, 0.19140625, 0.4453125, 0.49414062,
-        0.35546875, 0.14648438, 0.45507812, 0.5390625,
-        0.34179688, 0.09375, 0.46875, 0.5917969,
-        0.32421875, 0.0234375, 0.48632812, 0.6621094,
-        0.296875, -0.07421875, 0.5136719, 0.76171875,
-        0.42773438, 0.19140625, 0.5078125, 0.49414062,
-        0.41796875, 0.14648438, 0.5175781, 0.5390625,
-        0.40429688, 0.09375, 0.53125, 0.5917969,
-        0.38671875, 0.0234375, 0.5488281, 0.6621094,
-        0.359375, -0.07421875, 0.5761719, 0.76171875,
-        0.49023438, 0.19140625, 0.5703125, 0.49414062,
-        0.48046875, 0.14648438, 0.5800781, 0.5390625,
-        0.46679688, 0.09375, 0.59375, 0.5917969,
-        0.44921875, 0.0234375, 0.6113281, 0.6621094,
-        0.421875, -0.07421875, 0.6386719, 0.76171875,
-        0.5527344, 0.19140625, 0.6328125, 0.49414062,
-        0.54296875, 0.14648438, 0.6425781, 0.5390625,
-        0.5292969, 0.09375, 0.65625, 0.5917969,
-        0.51171875, 0.0234375, 0.6738281, 0.6621094,
-        0.484375, -0.07421875, 0.7011719, 0.76171875,
-        0.6152344, 0.19140625, 0.6953125, 0.49414062,
-        0.60546875, 0.14648438, 0.7050781, 0.5390625,
-        0.5917969, 0.09375, 0.71875, 0.5917969,
-        0.57421875, 0.0234375, 0.7363281, 0.6621094,
-        0.546875, -0.07421875, 0.7636719, 0.76171875,
-        0.6777344, 0.19140625, 0.7578125, 0.49414062,
-        0.66796875, 0.14648438, 0.7675781, 0.5390625,
-        0.6542969, 0.09375, 0.78125, 0.5917969,
-        0.63671875, 0.0234375, 0.7988281, 0.6621094,
-        0.609375, -0.07421875, 0.8261719, 0.76171875,
-        0.7402344, 0.19140625, 0.8203125, 0.49414062,
-        0.73046875, 0.14648438, 0.8300781, 0.5390625,
-        0.7167969, 0.09375, 0.84375, 0.5917969,
-        0.69921875, 0.0234375, 0.8613281, 0.6621094,
-        0.671875, -0.07421875, 0.8886719, 0.76171875,
-        0.8027344, 0.19140625, 0.8828125, 0.49414062,
-        0.79296875, 0.14648438, 0.8925781, 0.5390625,
-        0.7792969, 0.09375, 0.90625, 0.5917969,
-        0.76171875, 0.0234375, 0.9238281, 0.6621094,
-        0.734375, -0.07421875, 0.9511719, 0.76171875,
-        0.8652344, 0.19140625, 0.9453125, 0.49414062,
-        0.85546875, 0.14648438, 0.9550781, 0.5390625,
-        0.8417969, 0.09375, 0.96875, 0.5917969,
-        0.82421875, 0.0234375, 0.9863281, 0.6621094,
-        0.796875, -0.07421875, 1.0136719, 0.76171875,
-        0.9277344, 0.19140625, 1.0078125, 0.49414062,
-        0.91796875, 0.14648438, 1.0175781, 0.5390625,
-        0.9042969, 0.09375, 1.03125, 0.5917969,
-        0.88671875, 0.0234375, 1.0488281, 0.6621094,
-        0.859375, -0.07421875, 1.0761719, 0.76171875,
-        -0.0078125, 0.25390625, 0.0703125, 0.5566406,
-        -0.017578125, 0.20898438, 0.080078125, 0.6015625,
-        -0.03125, 0.15625, 0.09375, 0.6542969,
-        -0.048828125, 0.0859375, 0.111328125, 0.7246094,
-        -0.076171875, -0.01171875, 0.13867188, 0.82421875,
-        0.052734375, 0.25390625, 0.1328125, 0.5566406,
-        0.04296875, 0.20898438, 0.14257812, 0.6015625,
-        0.029296875, 0.15625, 0.15625, 0.6542969,
-        0.01171875, 0.0859375, 0.17382812, 0.7246094,
-        -0.013671875, -0.01171875, 0.20117188, 0.82421875,
-        0.115234375, 0.25390625, 0.1953125, 0.5566406,
-        0.10546875, 0.20898438, 0.20507812, 0.6015625,
-        0.091796875, 0.15625, 0.21875, 0.6542969,
-        0.07421875, 0.0859375, 0.23632812, 0.7246094,
-        0.046875, -0.01171875, 0.26367188, 0.82421875,
-        0.17773438, 0.25390625, 0.2578125, 0.5566406,
-        0.16796875, 0.20898438, 0.26757812, 0.6015625,
-        0.15429688, 0.15625, 0.28125, 0.6542969,
-        0.13671875, 0.0859375, 0.29882812, 0.7246094,
-        0.109375, -0.01171875, 0.32617188, 0.82421875,
-        0.24023438, 0.25390625, 0.3203125, 0.5566406,
-        0.23046875, 0.20898438, 0.33007812, 0.6015625,
-        0.21679688, 0.15625, 0.34375, 0.6542969,
-        0.19921875, 0.0859375, 0.36132812, 0.7246094,
-        0.171875, -0.01171875, 0.38867188, 0.82421875,
-        0.30273438, 0.25390625, 0.3828125, 0.5566406,
-        0.29296875, 0.20898438, 0.39257812, 0.6015625,
-        0.27929688, 0.15625, 0.40625, 0.6542969,
-        0.26171875, 0.0859375, 0.42382812, 0.7246094,
-        0.234375, -0.01171875, 0.45117188, 0.82421875,
-        0.36523438, 0.25390625, 0.4453125, 0.5566406,
-        0.35546875, 0.20898438, 0.45507812, 0.6015625,
-        0.34179688, 0.15625, 0.46875, 0.6542969,
-        0.32421875, 0.0859375, 0.48632812, 0.7246094,
-        0.296875, -0.01171875, 0.5136719, 0.82421875,
-        0.42773438, 0.25390625, 0.5078125, 0.5566406,
-        0.41796875, 0.20898438, 0.5175781, 0.6015625,
-        0.40429688, 0.15625, 0.53125, 0.6542969,
-        0.38671875, 0.0859375, 0.5488281, 0.7246094,
-        0.359375, -0.01171875, 0.5761719, 0.82421875,
-        0.49023438, 0.25390625, 0.5703125, 0.5566406,
-        0.48046875, 0.20898438, 0.5800781, 0.6015625,
-        0.46679688, 0.15625, 0.59375, 0.6542969,
-        0.44921875, 0.0859375, 0.6113281, 0.7246094,
-        0.421875, -0.01171875, 0.6386719, 0.82421875,
-        0.5527344, 0.25390625, 0.6328125, 0.5566406,
-        0.54296875, 0.20898438, 0.6425781, 0.6015625,
-        0.5292969, 0.15625, 0.65625, 0.6542969,
-        0.51171875, 0.0859375, 0.6738281, 0.7246094,
-        0.484375, -0.01171875, 0.7011719, 0.82421875,
-        0.6152344, 0.25390625, 0.6953125, 0.5566406,
-        0.60546875, 0.20898438, 0.7050781, 0.6015625,
-        0.5917969, 0.15625, 0.71875, 0.6542969,
-        0.57421875, 0.0859375, 0.7363281, 0.7246094,
-        0.546875, -0.01171875, 0.7636719, 0.82421875,
-        0.6777344, 0.25390625, 0.7578125, 0.5566406,
-        0.66796875, 0.20898438, 0.7675781, 0.6015625,
-        0.6542969, 0.15625, 0.78125, 0.6542969,
-        0.63671875, 0.0859375, 0.7988281, 0.7246094,
-        0.609375, -0.01171875, 0.8261719, 0.82421875,
-        0.7402344, 0.25390625, 0.8203125, 0.5566406,
-        0.73046875, 0.20898438, 0.8300781, 0.6015625,
-        0.7167969, 0.15625, 0.84375, 0.6542969,
-        0.69921875, 0.0859375, 0.8613281, 0.7246094,
-        0.671875, -0.01171875, 0.8886719, 0.82421875,
-        0.8027344, 0.25390625, 0.8828125, 0.5566406,
-        0.79296875, 0.20898438, 0.8925781, 0.6015625,
-        0.7792969, 0.15625, 0.90625, 0.6542969,
-        0.76171875, 0.0859375, 0.9238281, 0.7246094,
-        0.734375, -0.01171875, 0.9511719, 0.82421875,
-        0.8652344, 0.25390625, 0.9453125, 0.5566406,
-        0.85546875, 0.20898438, 0.9550781, 0.6015625,
-        0.8417969, 0.15625, 0.96875, 0.6542969,
-        0.82421875, 0.0859375, 0.9863281, 0.7246094,
-        0.796875, -0.01171875, 1.0136719, 0.82421875,
-        0.9277344, 0.25390625, 1.0078125, 0.5566406,
-        0.91796875, 0.20898438, 1.0175781, 0.6015625,
-        0.9042969, 0.15625, 1.03125, 0.6542969,
-        0.88671875, 0.0859375, 1.0488281, 0.7246094,
-        0.859375, -0.01171875, 1.0761719, 0.82421875,
-        -0.0078125, 0.31640625, 0.0703125, 0.6191406,
-        -0.017578125, 0.27148438, 0.080078125, 0.6640625,
-        -0.03125, 0.21875, 0.09375, 0.7167969,
-        -0.048828125, 0.1484375, 0.111328125, 0.7871094,
-        -0.076171875, 0.048828125, 0.13867188, 0.88671875,
-        0.052734375, 0.31640625, 0.1328125, 0.6191406,
-        0.04296875, 0.27148438, 0.14257812, 0.6640625,
-        0.029296875, 0.21875, 0.15625, 0.7167969,
-        0.01171875, 0.1484375, 0.17382812, 0.7871094,
-        -0.013671875, 0.048828125, 0.20117188, 0.88671875,
-        0.115234375, 0.31640625, 0.1953125, 0.6191406,
-        0.10546875, 0.27148438, 0.20507812, 0.6640625,
-        0.091796875, 0.21875, 0.21875, 0.7167969,
-        0.07421875, 0.1484375, 0.23632812, 0.7871094,
-        0.046875, 0.048828125, 0.26367188, 0.88671875,
-        0.17773438, 0.31640625, 0.2578125, 0.6191406,
-        0.16796875, 0.27148438, 0.26757812, 0.6640625,
-        0.15429688, 0.21875, 0.28125, 0.7167969,
-        0.13671875, 0.1484375, 0.29882812, 0.7871094,
-        0.109375, 0.048828125, 0.32617188, 0.88671875,
-        0.24023438, 0.31640625, 0.3203125, 0.6191406,
-        0.23046875, 0.27148438, 0.33007812, 0.6640625,
-        0.21679688, 0.21875, 0.34375, 0.7167969,
-        0.19921875, 0.1484375, 0.36132812, 0.7871094,
-        0.171875, 0.048828125, 0.38867188, 0.88671875,
-        0.30273438, 0.31640625, 0.3828125, 0.6191406,
-        0.29296875, 0.27148438, 0.39257812, 0.6640625,
-        0.27929688, 0.21875, 0.40625, 0.7167969,
-        0.26171875, 0.1484375, 0.42382812, 0.7871094,
-        0.234375, 0.048828125, 0.45117188, 0.88671875,
-        0.36523438, 0.31640625, 0.4453125, 0.6191406,
-        0.35546875, 0.27148438, 0.45507812, 0.6640625,
-        0.34179688, 0.21875, 0.46875, 0.7167969,
-        0.32421875, 0.1484375, 0.48632812, 0.7871094,
-        0.296875, 0.048828125, 0.5136719, 0.88671875,
-        0.42773438, 0.31640625, 0.5078125, 0.6191406,
-        0.41796875, 0.27148438, 0.5175781, 0.6640625,
-        0.40429688, 0.21875, 0.53125, 0.7167969,
-        0.38671875, 0.1484375, 0.5488281, 0.7871094,
-        0.359375, 0.048828125, 0.5761719, 0.88671875,
-        0.49023438, 0.31640625, 0.5703125, 0.6191406,
-        0.48046875, 0.27148438, 0.5800781, 0.6640625,
-        0.46679688, 0.21875, 0.59375, 0.7167969,
-        0.44921875, 0.1484375, 0.6113281, 0.7871094,
-        0.421875, 0.048828125, 0.6386719, 0.88671875,
-        0.5527344, 0.31640625, 0.6328125, 0.6191406,
-        0.54296875, 0.27148438, 0.6425781, 0.6640625,
-        0.5292969, 0.21875, 0.65625, 0.7167969,
-        0.51171875, 0.1484375, 0.6738281, 0.7871094,
-        0.484375, 0.048828125, 0.7011719, 0.88671875,
-        0.6152344, 0.31640625, 0.6953125, 0.6191406,
-        0.60546875, 0.27148438, 0.7050781, 0.6640625,
-        0.5917969, 0.21875, 0.71875, 0.7167969,
-        0.57421875, 0.1484375, 0.7363281, 0.7871094,
-        0.546875, 0.048828125, 0.7636719, 0.88671875,
-        0.6777344, 0.31640625, 0.7578125, 0.6191406,
-        0.66796875, 0.27148438, 0.7675781, 0.6640625,
-        0.6542969, 0.21875, 0.78125, 0.7167969,
-        0.63671875, 0.1484375, 0.7988281, 0.7871094,
-        0.609375, 0.048828125, 0.8261719, 0.88671875,
-        0.7402344, 0.31640625, 0.8203125, 0.6191406,
-        0.73046875, 0.27148438, 0.8300781, 0.6640625,
-        0.7167969, 0.21875, 0.84375, 0.7167969,
-        0.69921875, 0.1484375, 0.8613281, 0.7871094,
-        0.671875, 0.048828125, 0.8886719, 0.88671875,
-        0.8027344, 0.31640625, 0.8828125, 0.6191406,
-        0.79296875, 0.27148438, 0.8925781, 0.6640625,
-        0.7792969, 0.21875, 0.90625, 0.7167969,
-        0.76171875, 0.1484375, 0.9238281, 0.7871094,
-        0.734375, 0.048828125, 0.9511719, 0.88671875,
-        0.8652344, 0.31640625, 0.9453125, 0.6191406,
-        0.85546875, 0.27148438, 0.9550781, 0.6640625,
-        0.8417969, 0.21875, 0.96875, 0.7167969,
-        0.82421875, 0.1484375, 0.9863281, 0.7871094,
-        0.796875, 0.048828125, 1.0136719, 0.88671875,
-        0.9277344, 0.31640625, 1.0078125, 0.6191406,
-        0.91796875, 0.27148438, 1.0175781, 0.6640625,
-        0.9042969, 0.21875, 1.03125, 0.7167969,
-        0.88671875, 0.1484375, 1.0488281, 0.7871094,
-        0.859375, 0.048828125, 1.0761719, 0.88671875,
-        -0.0078125, 0.37890625, 0.0703125, 0.6816406,
-        -0.017578125, 0.33398438, 0.080078125, 0.7265625,
-        -0.03125, 0.28125, 0.09375, 0.7792969,
-        -0.048828125, 0.2109375, 0.111328125, 0.8496094,
-        -0.076171875, 0.111328125, 0.13867188, 0.94921875,
-        0.052734375, 0.37890625, 0.1328125, 0.6816406,
-        0.04296875, 0.33398438, 0.14257812, 0.7265625,
-        0.029296875, 0.28125, 0.15625, 0.7792969,
-        0.01171875, 0.2109375, 0.17382812, 0.8496094,
-        -0.013671875, 0.111328125, 0.20117188, 0.94921875,
-        0.115234375, 0.37890625, 0.1953125, 0.6816406,
-        0.10546875, 0.33398438, 0.20507812, 0.7265625,
-        0.091796875, 0.28125, 0.21875, 0.7792969,
-        0.07421875, 0.2109375, 0.23632812, 0.8496094,
-        0.046875, 0.111328125, 0.26367188, 0.94921875,
-        0.17773438, 0.37890625, 0.2578125, 0.6816406,
-        0.16796875, 0.33398438, 0.26757812, 0.7265625,
-        0.15429688, 0.28125, 0.28125, 0.7792969,
-        0.13671875, 0.2109375, 0.29882812, 0.8496094,
-        0.109375, 0.111328125, 0.32617188, 0.94921875,
-        0.24023438, 0.37890625, 0.3203125, 0.6816406,
-        0.23046875, 0.33398438, 0.33007812, 0.7265625,
-        0.21679688, 0.28125, 0.34375, 0.7792969,
-        0.19921875, 0.2109375, 0.36132812, 0.8496094,
-        0.171875, 0.111328125, 0.38867188, 0.94921875,
-        0.30273438, 0.37890625, 0.3828125, 0.6816406,
-        0.29296875, 0.33398438, 0.39257812, 0.7265625,
-        0.27929688, 0.28125, 0.40625, 0.7792969,
-        0.26171875, 0.2109375, 0.42382812, 0.8496094,
-        0.234375, 0.111328125, 0.45117188, 0.94921875,
-        0.36523438, 0.37890625, 0.4453125, 0.6816406,
-        0.35546875, 0.33398438, 0.45507812, 0.7265625,
-        0.34179688, 0.28125, 0.46875, 0.7792969,
-        0.32421875, 0.2109375, 0.48632812, 0.8496094,
-        0.296875, 0.111328125, 0.5136719, 0.94921875,
-        0.42773438, 0.37890625, 0.5078125, 0.6816406,
-        0.41796875, 0.33398438, 0.5175781, 0.7265625,
-        0.40429688, 0.28125, 0.53125, 0.7792969,
-        0.38671875, 0.2109375, 0.5488281, 0.8496094,
-        0.359375, 0.111328125, 0.5761719, 0.94921875,
-        0.49023438, 0.37890625, 0.5703125, 0.6816406,
-        0.48046875, 0.33398438, 0.5800781, 0.7265625,
-        0.46679688, 0.28125, 0.59375, 0.7792969,
-        0.44921875, 0.2109375, 0.6113281, 0.8496094,
-        0.421875, 0.111328125, 0.6386719, 0.94921875,
-        0.5527344, 0.37890625, 0.6328125, 0.6816406,
-        0.54296875, 0.33398438, 0.6425781, 0.7265625,
-        0.5292969, 0.28125, 0.65625, 0.7792969,
-        0.51171875, 0.2109375, 0.6738281, 0.8496094,
-        0.484375, 0.111328125, 0.7011719, 0.94921875,
-        0.6152344, 0.37890625, 0.6953125, 0.6816406,
-        0.60546875, 0.33398438, 0.7050781, 0.7265625,
-        0.5917969, 0.28125, 0.71875, 0.7792969,
-        0.57421875, 0.2109375, 0.7363281, 0.8496094,
-        0.546875, 0.111328125, 0.7636719, 0.94921875,
-        0.6777344, 0.37890625, 0.7578125, 0.6816406,
-        0.66796875, 0.33398438, 0.7675781, 0.7265625,
-        0.6542969, 0.28125, 0.78125, 0.7792969,
-        0.63671875, 0.2109375, 0.7988281, 0.8496094,
-        0.609375, 0.111328125, 0.8261719, 0.94921875,
-        0.7402344, 0.37890625, 0.8203125, 0.6816406,
-        0.73046875, 0.33398438, 0.8300781, 0.7265625,
-        0.7167969, 0.28125, 0.84375, 0.7792969,
-        0.69921875, 0.2109375, 0.8613281, 0.8496094,
-        0.671875, 0.111328125, 0.8886719, 0.94921875,
-        0.8027344, 0.37890625, 0.8828125, 0.6816406,
-        0.79296875, 0.33398438, 0.8925781, 0.7265625,
-        0.7792969, 0.28125, 0.90625, 0.7792969,
-        0.76171875, 0.2109375, 0.9238281, 0.8496094,
-        0.734375, 0.111328125, 0.9511719, 0.94921875,
-        0.8652344, 0.37890625, 0.9453125, 0.6816406,
-        0.85546875, 0.33398438, 0.9550781, 0.7265625,
-        0.8417969, 0.28125, 0.96875, 0.7792969,
-        0.82421875, 0.2109375, 0.9863281, 0.8496094,
-        0.796875, 0.111328125, 1.0136719, 0.94921875,
-        0.9277344, 0.37890625, 1.0078125, 0.6816406,
-        0.91796875, 0.33398438, 1.0175781, 0.7265625,
-        0.9042969, 0.28125, 1.03125, 0.7792969,
-        0.88671875, 0.2109375, 1.0488281, 0.8496094,
-        0.859375, 0.111328125, 1.0761719, 0.94921875,
-        -0.0078125, 0.44140625, 0.0703125, 0.7441406,
-        -0.017578125, 0.39648438, 0.080078125, 0.7890625,
-        -0.03125, 0.34375, 0.09375, 0.8417969,
-        -0.048828125, 0.2734375, 0.111328125, 0.9121094,
-        -0.076171875, 0.17382812, 0.13867188, 1.0117188,
-        0.052734375, 0.44140625, 0.1328125, 0.7441406,
-        0.04296875, 0.39648438, 0.14257812, 0.7890625,
-        0.029296875, 0.34375, 0.15625, 0.8417969,
-        0.01171875, 0.2734375, 0.17382812, 0.9121094,
-        -0.013671875, 0.17382812, 0.20117188, 1.0117188,
-        0.115234375, 0.44140625, 0.1953125, 0.7441406,
-        0.10546875, 0.39648438, 0.20507812, 0.7890625,
-        0.091796875, 0.34375, 0.21875, 0.8417969,
-        0.07421875, 0.2734375, 0.23632812, 0.9121094,
-        0.046875, 0.17382812, 0.26367188, 1.0117188,
-        0.17773438, 0.44140625, 0.2578125, 0.7441406,
-        0.16796875, 0.39648438, 0.26757812, 0.7890625,
-        0.15429688, 0.34375, 0.28125, 0.8417969,
-        0.13671875, 0.2734375, 0.29882812, 0.9121094,
-        0.109375, 0.17382812, 0.32617188, 1.0117188,
-        0.24023438, 0.44140625, 0.3203125, 0.7441406,
-        0.23046875, 0.39648438, 0.33007812, 0.7890625,
-        0.21679688, 0.34375, 0.34375, 0.8417969,
-        0.19921875, 0.2734375, 0.36132812, 0.9121094,
-        0.171875, 0.17382812, 0.38867188, 1.0117188,
-        0.30273438, 0.44140625, 0.3828125, 0.7441406,
-        0.29296875, 0.39648438, 0.39257812, 0.7890625,
-        0.27929688, 0.34375, 0.40625, 0.8417969,
-        0.26171875, 0.2734375, 0.42382812, 0.9121094,
-        0.234375, 0.17382812, 0.45117188, 1.0117188,
-        0.36523438, 0.44140625, 0.4453125, 0.7441406,
-        0.35546875, 0.39648438, 0.45507812, 0.7890625,
-        0.34179688, 0.34375, 0.46875, 0.8417969,
-        0.32421875, 0.2734375, 0.48632812, 0.9121094,
-        0.296875, 0.17382812, 0.5136719, 1.0117188,
-        0.42773438, 0.44140625, 0.5078125, 0.7441406,
-        0.41796875, 0.39648438, 0.5175781, 0.7890625,
-        0.40429688, 0.34375, 0.53125, 0.8417969,
-        0.38671875, 0.2734375, 0.5488281, 0.9121094,
-        0.359375, 0.17382812, 0.5761719, 1.0117188,
-        0.49023438, 0.44140625, 0.5703125, 0.7441406,
-        0.48046875, 0.39648438, 0.5800781, 0.7890625,
-        0.46679688, 0.34375, 0.59375, 0.8417969,
-        0.44921875, 0.2734375, 0.6113281, 0.9121094,
-        0.421875, 0.17382812, 0.6386719, 1.0117188,
-        0.5527344, 0.44140625, 0.6328125, 0.7441406,
-        0.54296875, 0.39648438, 0.6425781, 0.7890625,
-        0.5292969, 0.34375, 0.65625, 0.8417969,
-        0.51171875, 0.2734375, 0.6738281, 0.9121094,
-        0.484375, 0.17382812, 0.7011719, 1.0117188,
-        0.6152344, 0.44140625, 0.6953125, 0.7441406,
-        0.60546875, 0.39648438, 0.7050781, 0.7890625,
-        0.5917969, 0.34375, 0.71875, 0.8417969,
-        0.57421875, 0.2734375, 0.7363281, 0.9121094,
-        0.546875, 0.17382812, 0.7636719, 1.0117188,
-        0.6777344, 0.44140625, 0.7578125, 0.7441406,
-        0.66796875, 0.39648438, 0.7675781, 0.7890625,
-        0.6542969, 0.34375, 0.78125, 0.8417969,
-        0.63671875, 0.2734375, 0.7988281, 0.9121094,
-        0.609375, 0.17382812, 0.8261719, 1.0117188,
-        0.7402344, 0.44140625, 0.8203125, 0.7441406,
-        0.73046875, 0.39648438, 0.8300781, 0.7890625,
-        0.7167969, 0.34375, 0.84375, 0.8417969,
-        0.69921875, 0.2734375, 0.8613281, 0.9121094,
-        0.671875, 0.17382812, 0.8886719, 1.0117188,
-        0.8027344, 0.44140625, 0.8828125, 0.7441406,
-        0.79296875, 0.39648438, 0.8925781, 0.7890625,
-        0.7792969, 0.34375, 0.90625, 0.8417969,
-        0.76171875, 0.2734375, 0.9238281, 0.9121094,
-        0.734375, 0.17382812, 0.9511719, 1.0117188,
-        0.8652344, 0.44140625, 0.9453125, 0.7441406,
-        0.85546875, 0.39648438, 0.9550781, 0.7890625,
-        0.8417969, 0.34375, 0.96875, 0.8417969,
-        0.82421875, 0.2734375, 0.9863281, 0.9121094,
-        0.796875, 0.17382812, 1.0136719, 1.0117188,
-        0.9277344, 0.44140625, 1.0078125, 0.7441406,
-        0.91796875, 0.39648438, 1.0175781, 0.7890625,
-        0.9042969, 0.34375, 1.03125, 0.8417969,
-        0.88671875, 0.2734375, 1.0488281, 0.9121094,
-        0.859375, 0.17382812, 1.0761719, 1.0117188,
-        -0.0078125, 0.50390625, 0.0703125, 0.8066406,
-        -0.017578125, 0.45898438, 0.080078125, 0.8515625,
-        -0.03125, 0.40625, 0.09375, 0.9042969,
-        -0.048828125, 0.3359375, 0.111328125, 0.9746094,
-        -0.076171875, 0.23632812, 0.13867188, 1.0742188,
-        0.052734375, 0.50390625, 0.1328125, 0.8066406,
-        0.04296875, 0.45898438, 0.14257812, 0.8515625,
-        0.029296875, 0.40625, 0.15625, 0.9042969,
-        0.01171875, 0.3359375, 0.17382812, 0.9746094,
-        -0.013671875, 0.23632812, 0.20117188, 1.0742188,
-        0.115234375, 0.50390625, 0.1953125, 0.8066406,
-        0.10546875, 0.45898438, 0.20507812, 0.8515625,
-        0.091796875, 0.40625, 0.21875, 0.9042969,
-        0.07421875, 0.3359375, 0.23632812, 0.9746094,
-        0.046875, 0.23632812, 0.26367188, 1.0742188,
-        0.17773438, 0.50390625, 0.2578125, 0.8066406,
-        0.16796875, 0.45898438, 0.26757812, 0.8515625,
-        0.15429688, 0.40625, 0.28125, 0.9042969,
-        0.13671875, 0.3359375, 0.29882812, 0.9746094,
-        0.109375, 0.23632812, 0.32617188, 1.0742188,
-        0.24023438, 0.50390625, 0.3203125, 0.8066406,
-        0.23046875, 0.45898438, 0.33007812, 0.8515625,
-        0.21679688, 0.40625, 0.34375, 0.9042969,
-        0.19921875, 0.3359375, 0.36132812, 0.9746094,
-        0.171875, 0.23632812, 0.38867188, 1.0742188,
-        0.30273438, 0.50390625, 0.3828125, 0.8066406,
-        0.29296875, 0.45898438, 0.39257812, 0.8515625,
-        0.27929688, 0.40625, 0.40625, 0.9042969,
-        0.26171875, 0.3359375, 0.42382812, 0.9746094,
-        0.234375, 0.23632812, 0.45117188, 1.0742188,
-        0.36523438, 0.50390625, 0.4453125, 0.8066406,
-        0.35546875, 0.45898438, 0.45507812, 0.8515625,
-        0.34179688, 0.40625, 0.46875, 0.9042969,
-        0.32421875, 0.3359375, 0.48632812, 0.9746094,
-        0.296875, 0.23632812, 0.5136719, 1.0742188,
-        0.42773438, 0.50390625, 0.5078125, 0.8066406,
-        0.41796875, 0.45898438, 0.5175781, 0.8515625,
-        0.40429688, 0.40625, 0.53125, 0.9042969,
-        0.38671875, 0.3359375, 0.5488281, 0.9746094,
-        0.359375, 0.23632812, 0.5761719, 1.0742188,
-        0.49023438, 0.50390625, 0.5703125, 0.8066406,
-        0.48046875, 0.45898438, 0.5800781, 0.8515625,
-        0.46679688, 0.40625, 0.59375, 0.9042969,
-        0.44921875, 0.3359375, 0.6113281, 0.9746094,
-        0.421875, 0.23632812, 0.6386719, 1.0742188,
-        0.5527344, 0.50390625, 0.6328125, 0.8066406,
-        0.54296875, 0.45898438, 0.6425781, 0.8515625,
-        0.5292969, 0.40625, 0.65625, 0.9042969,
-        0.51171875, 0.3359375, 0.6738281, 0.9746094,
-        0.484375, 0.23632812, 0.7011719, 1.0742188,
-        0.6152344, 0.50390625, 0.6953125, 0.8066406,
-        0.60546875, 0.45898438, 0.7050781, 0.8515625,
-        0.5917969, 0.40625, 0.71875, 0.9042969,
-        0.57421875, 0.3359375, 0.7363281, 0.9746094,
-        0.546875, 0.23632812, 0.7636719, 1.0742188,
-        0.6777344, 0.50390625, 0.7578125, 0.8066406,
-        0.66796875, 0.45898438, 0.7675781, 0.8515625,
-        0.6542969, 0.40625, 0.78125, 0.9042969,
-        0.63671875, 0.3359375, 0.7988281, 0.9746094,
-        0.609375, 0.23632812, 0.8261719, 1.0742188,
-        0.7402344, 0.50390625, 0.8203125, 0.8066406,
-        0.73046875, 0.45898438, 0.8300781, 0.8515625,
-        0.7167969, 0.40625, 0.84375, 0.9042969,
-        0.69921875, 0.3359375, 0.8613281, 0.9746094,
-        0.671875, 0.23632812, 0.8886719, 1.0742188,
-        0.8027344, 0.50390625, 0.8828125, 0.8066406,
-        0.79296875, 0.45898438, 0.8925781, 0.8515625,
-        0.7792969, 0.40625, 0.90625, 0.9042969,
-        0.76171875, 0.3359375, 0.9238281, 0.9746094,
-        0.734375, 0.23632812, 0.9511719, 1.0742188,
-        0.8652344, 0.50390625, 0.9453125, 0.8066406,
-        0.85546875, 0.45898438, 0.9550781, 0.8515625,
-        0.8417969, 0.40625, 0.96875, 0.9042969,
-        0.82421875, 0.3359375, 0.9863281, 0.9746094,
-        0.796875, 0.23632812, 1.0136719, 1.0742188,
-        0.9277344, 0.50390625, 1.0078125, 0.8066406,
-        0.91796875, 0.45898438, 1.0175781, 0.8515625,
-        0.9042969, 0.40625, 1.03125, 0.9042969,
-        0.88671875, 0.3359375, 1.0488281, 0.9746094,
-        0.859375, 0.23632812, 1.0761719, 1.0742188,
-        -0.0078125, 0.56640625, 0.0703125, 0.8691406,
-        -0.017578125, 0.5214844, 0.080078125, 0.9140625,
-        -0.03125, 0.46875, 0.09375, 0.9667969,
-        -0.048828125, 0.3984375, 0.111328125, 1.0371094,
-        -0.076171875, 0.29882812, 0.13867188, 1.1367188,
-        0.052734375, 0.56640625, 0.1328125, 0.8691406,
-        0.04296875, 0.5214844, 0.14257812, 0.9140625,
-        0.029296875, 0.46875, 0.15625, 0.9667969,
-        0.01171875, 0.3984375, 0.17382812, 1.0371094,
-        -0.013671875, 0.29882812, 0.20117188, 1.1367188,
-        0.115234375, 0.56640625, 0.1953125, 0.8691406,
-        0.10546875, 0.5214844, 0.20507812, 0.9140625,
-        0.091796875, 0.46875, 0.21875, 0.9667969,
-        0.07421875, 0.3984375, 0.23632812, 1.0371094,
-        0.046875, 0.29882812, 0.26367188, 1.1367188,
-        0.17773438, 0.56640625, 0.2578125, 0.8691406,
-        0.16796875, 0.5214844, 0.26757812, 0.9140625,
-        0.15429688, 0.46875, 0.28125, 0.9667969,
-        0.13671875, 0.3984375, 0.29882812, 1.0371094,
-        0.109375, 0.29882812, 0.32617188, 1.1367188,
-        0.24023438, 0.56640625, 0.3203125, 0.8691406,
-        0.23046875, 0.5214844, 0.33007812, 0.9140625,
-        0.21679688, 0.46875, 0.34375, 0.9667969,
-        0.19921875, 0.3984375, 0.36132812, 1.0371094,
-        0.171875, 0.29882812, 0.38867188, 1.1367188,
-        0.30273438, 0.56640625, 0.3828125, 0.8691406,
-        0.29296875, 0.5214844, 0.39257812, 0.9140625,
-        0.27929688, 0.46875, 0.40625, 0.9667969,
-        0.26171875, 0.3984375, 0.42382812, 1.0371094,
-        0.234375, 0.29882812, 0.45117188, 1.1367188,
-        0.36523438, 0.56640625, 0.4453125, 0.8691406,
-        0.35546875, 0.5214844, 0.45507812, 0.9140625,
-        0.34179688, 0.46875, 0.46875, 0.9667969,
-        0.32421875, 0.3984375, 0.48632812, 1.0371094,
-        0.296875, 0.29882812, 0.5136719, 1.1367188,
-        0.42773438, 0.56640625, 0.5078125, 0.8691406,
-        0.41796875, 0.5214844, 0.5175781, 0.9140625,
-        0.40429688, 0.46875, 0.53125, 0.9667969,
-        0.38671875, 0.3984375, 0.5488281, 1.0371094,
-        0.359375, 0.29882812, 0.5761719, 1.1367188,
-        0.49023438, 0.56640625, 0.5703125, 0.8691406,
-        0.48046875, 0.5214844, 0.5800781, 0.9140625,
-        0.46679688, 0.46875, 0.59375, 0.9667969,
-        0.44921875, 0.3984375, 0.6113281, 1.0371094,
-        0.421875, 0.29882812, 0.6386719, 1.1367188,
-        0.5527344, 0.56640625, 0.6328125, 0.8691406,
-        0.54296875, 0.5214844, 0.6425781, 0.9140625,
-        0.5292969, 0.46875, 0.65625, 0.9667969,
-        0.51171875, 0.3984375, 0.6738281, 1.0371094,
-        0.484375, 0.29882812, 0.7011719, 1.1367188,
-        0.6152344, 0.56640625, 0.6953125, 0.8691406,
-        0.60546875, 0.5214844, 0.7050781, 0.9140625,
-        0.5917969, 0.46875, 0.71875, 0.9667969,
-        0.57421875, 0.3984375, 0.7363281, 1.0371094,
-        0.546875, 0.29882812, 0.7636719, 1.1367188,
-        0.6777344, 0.56640625, 0.7578125, 0.8691406,
-        0.66796875, 0.5214844, 0.7675781, 0.9140625,
-        0.6542969, 0.46875, 0.78125, 0.9667969,
-        0.63671875, 0.3984375, 0.7988281, 1.0371094,
-        0.609375, 0.29882812, 0.8261719, 1.1367188,
-        0.7402344, 0.56640625, 0.8203125, 0.8691406,
-        0.73046875, 0.5214844, 0.8300781, 0.9140625,
-        0.7167969, 0.46875, 0.84375, 0.9667969,
-        0.69921875, 0.3984375, 0.8613281, 1.0371094,
-        0.671875, 0.29882812, 0.8886719, 1.1367188,
-        0.8027344, 0.56640625, 0.8828125, 0.8691406,
-        0.79296875, 0.5214844, 0.8925781, 0.9140625,
-        0.7792969, 0.46875, 0.90625, 0.9667969,
-        0.76171875, 0.3984375, 0.9238281, 1.0371094,
-        0.734375, 0.29882812, 0.9511719, 1.1367188,
-        0.8652344, 0.56640625, 0.9453125, 0.8691406,
-        0.85546875, 0.5214844, 0.9550781, 0.9140625,
-        0.8417969, 0.46875, 0.96875, 0.9667969,
-        0.82421875, 0.3984375, 0.9863281, 1.0371094,
-        0.796875, 0.29882812, 1.0136719, 1.1367188,
-        0.9277344, 0.56640625, 1.0078125, 0.8691406,
-        0.91796875, 0.5214844, 1.0175781, 0.9140625,
-        0.9042969, 0.46875, 1.03125, 0.9667969,
-        0.88671875, 0.3984375, 1.0488281, 1.0371094,
-        0.859375, 0.29882812, 1.0761719, 1.1367188,
-        -0.0078125, 0.62890625, 0.0703125, 0.9316406,
-        -0.017578125, 0.5839844, 0.080078125, 0.9765625,
-        -0.03125, 0.53125, 0.09375, 1.0292969,
-        -0.048828125, 0.4609375, 0.111328125, 1.0996094,
-        -0.076171875, 0.36132812, 0.13867188, 1.1992188,
-        0.052734375, 0.62890625, 0.1328125, 0.9316406,
-        0.04296875, 0.5839844, 0.14257812, 0.9765625,
-        0.029296875, 0.53125, 0.15625, 1.0292969,
-        0.01171875, 0.4609375, 0.17382812, 1.0996094,
-        -0.013671875, 0.36132812, 0.20117188, 1.1992188,
-        0.115234375, 0.62890625, 0.1953125, 0.9316406,
-        0.10546875, 0.5839844, 0.20507812, 0.9765625,
-        0.091796875, 0.53125, 0.21875, 1.0292969,
-        0.07421875, 0.4609375, 0.23632812, 1.0996094,
-        0.046875, 0.36132812, 0.26367188, 1.1992188,
-        0.17773438, 0.62890625, 0.2578125, 0.9316406,
-        0.16796875, 0.5839844, 0.26757812, 0.9765625,
-        0.15429688, 0.53125, 0.28125, 1.0292969,
-        0.13671875, 0.4609375, 0.29882812, 1.0996094,
-        0.109375, 0.36132812, 0.32617188, 1.1992188,
-        0.24023438, 0.62890625, 0.3203125, 0.9316406,
-        0.23046875, 0.5839844, 0.33007812, 0.9765625,
-        0.21679688, 0.53125, 0.34375, 1.0292969,
-        0.19921875, 0.4609375, 0.36132812, 1.0996094,
-        0.171875, 0.36132812, 0.38867188, 1.1992188,
-        0.30273438, 0.62890625, 0.3828125, 0.9316406,
-        0.29296875, 0.5839844, 0.39257812, 0.9765625,
-        0.27929688, 0.53125, 0.40625, 1.0292969,
-        0.26171875, 0.4609375, 0.42382812, 1.0996094,
-        0.234375, 0.36132812, 0.45117188, 1.1992188,
-        0.36523438, 0.62890625, 0.4453125, 0.9316406,
-        0.35546875, 0.5839844, 0.45507812, 0.9765625,
-        0.34179688, 0.53125, 0.46875, 1.0292969,
-        0.32421875, 0.4609375, 0.48632812, 1.0996094,
-        0.296875, 0.36132812, 0.5136719, 1.1992188,
-        0.42773438, 0.62890625, 0.5078125, 0.9316406,
-        0.41796875, 0.5839844, 0.5175781, 0.9765625,
-        0.40429688, 0.53125, 0.53125, 1.0292969,
-        0.38671875, 0.4609375, 0.5488281, 1.0996094,
-        0.359375, 0.36132812, 0.5761719, 1.1992188,
-        0.49023438, 0.62890625, 0.5703125, 0.9316406,
-        0.48046875, 0.5839844, 0.5800781, 0.9765625,
-        0.46679688, 0.53125, 0.59375, 1.0292969,
-        0.44921875, 0.4609375, 0.6113281, 1.0996094,
-        0.421875, 0.36132812, 0.6386719, 1.1992188,
-        0.5527344, 0.62890625, 0.6328125, 0.9316406,
-        0.54296875, 0.5839844, 0.6425781, 0.9765625,
-        0.5292969, 0.53125, 0.65625, 1.0292969,
-        0.51171875, 0.4609375, 0.6738281, 1.0996094,
-        0.484375, 0.36132812, 0.7011719, 1.1992188,
-        0.6152344, 0.62890625, 0.6953125, 0.9316406,
-        0.60546875, 0.5839844, 0.7050781, 0.9765625,
-        0.5917969, 0.53125, 0.71875, 1.0292969,
-        0.57421875, 0.4609375, 0.7363281, 1.0996094,
-        0.546875, 0.36132812, 0.7636719, 1.1992188,
-        0.6777344, 0.62890625, 0.7578125, 0.9316406,
-        0.66796875, 0.5839844, 0.7675781, 0.9765625,
-        0.6542969, 0.53125, 0.78125, 1.0292969,
-        0.63671875, 0.4609375, 0.7988281, 1.0996094,
-        0.609375, 0.36132812, 0.8261719, 1.1992188,
-        0.7402344, 0.62890625, 0.8203125, 0.9316406,
-        0.73046875, 0.5839844, 0.8300781, 0.9765625,
-        0.7167969, 0.53125, 0.84375, 1.0292969,
-        0.69921875, 0.4609375, 0.8613281, 1.0996094,
-        0.671875, 0.36132812, 0.8886719, 1.1992188,
-        0.8027344, 0.62890625, 0.8828125, 0.9316406,
-        0.79296875, 0.5839844, 0.8925781, 0.9765625,
-        0.7792969, 0.53125, 0.90625, 1.0292969,
-        0.76171875, 0.4609375, 0.9238281, 1.0996094,
-        0.734375, 0.36132812, 0.9511719, 1.1992188,
-        0.8652344, 0.62890625, 0.9453125, 0.9316406,
-        0.85546875, 0.5839844, 0.9550781, 0.9765625,
-        0.8417969, 0.53125, 0.96875, 1.0292969,
-        0.82421875, 0.4609375, 0.9863281, 1.0996094,
-        0.796875, 0.36132812, 1.0136719, 1.1992188,
-        0.9277344, 0.62890625, 1.0078125, 0.9316406,
-        0.91796875, 0.5839844, 1.0175781, 0.9765625,
-        0.9042969, 0.53125, 1.03125, 1.0292969,
-        0.88671875, 0.4609375, 1.0488281, 1.0996094,
-        0.859375, 0.36132812, 1.0761719, 1.1992188,
-        -0.0078125, 0.69140625, 0.0703125, 0.9941406,
-        -0.017578125, 0.6464844, 0.080078125, 1.0390625,
-        -0.03125, 0.59375, 0.09375, 1.0917969,
-        -0.048828125, 0.5234375, 0.111328125, 1.1621094,
-        -0.076171875, 0.42382812, 0.13867188, 1.2617188,
-        0.052734375, 0.69140625, 0.1328125, 0.9941406,
-        0.04296875, 0.6464844, 0.14257812, 1.0390625,
-        0.029296875, 0.59375, 0.15625, 1.0917969,
-        0.01171875, 0.5234375, 0.17382812, 1.1621094,
-        -0.013671875, 0.42382812, 0.20117188, 1.2617188,
-        0.115234375, 0.69140625, 0.1953125, 0.9941406,
-        0.10546875, 0.6464844, 0.20507812, 1.0390625,
-        0.091796875, 0.59375, 0.21875, 1.0917969,
-        0.07421875, 0.5234375, 0.23632812, 1.1621094,
-        0.046875, 0.42382812, 0.26367188, 1.2617188,
-        0.17773438, 0.69140625, 0.2578125, 0.9941406,
-        0.16796875, 0.6464844, 0.26757812, 1.0390625,
-        0.15429688, 0.59375, 0.28125, 1.0917969,
-        0.13671875, 0.5234375, 0.29882812, 1.1621094,
-        0.109375, 0.42382812, 0.32617188, 1.2617188,
-        0.24023438, 0.69140625, 0.3203125, 0.9941406,
-        0.23046875, 0.6464844, 0.33007812, 1.0390625,
-        0.21679688, 0.59375, 0.34375, 1.0917969,
-        0.19921875, 0.5234375, 0.36132812, 1.1621094,
-        0.171875, 0.42382812, 0.38867188, 1.2617188,
-        0.30273438, 0.69140625, 0.3828125, 0.9941406,
-        0.29296875, 0.6464844, 0.39257812, 1.0390625,
-        0.27929688, 0.59375, 0.40625, 1.0917969,
-        0.26171875, 0.5234375, 0.42382812, 1.1621094,
-        0.234375, 0.42382812, 0.45117188, 1.2617188,
-        0.36523438, 0.69140625, 0.4453125, 0.9941406,
-        0.35546875, 0.6464844, 0.45507812, 1.0390625,
-        0.34179688, 0.59375, 0.46875, 1.0917969,
-        0.32421875, 0.5234375, 0.48632812, 1.1621094,
-        0.296875, 0.42382812, 0.5136719, 1.2617188,
-        0.42773438, 0.69140625, 0.5078125, 0.9941406,
-        0.41796875, 0.6464844, 0.5175781, 1.0390625,
-        0.40429688, 0.59375, 0.53125, 1.0917969,
-        0.38671875, 0.5234375, 0.5488281, 1.1621094,
-        0.359375, 0.42382812, 0.5761719, 1.2617188,
-        0.49023438, 0.69140625, 0.5703125, 0.9941406,
-        0.48046875, 0.6464844, 0.5800781, 1.0390625,
-        0.46679688, 0.59375, 0.59375, 1.0917969,
-        0.44921875, 0.5234375, 0.6113281, 1.1621094,
-        0.421875, 0.42382812, 0.6386719, 1.2617188,
-        0.5527344, 0.69140625, 0.6328125, 0.9941406,
-        0.54296875, 0.6464844, 0.6425781, 1.0390625,
-        0.5292969, 0.59375, 0.65625, 1.0917969,
-        0.51171875, 0.5234375, 0.6738281, 1.1621094,
-        0.484375, 0.42382812, 0.7011719, 1.2617188,
-        0.6152344, 0.69140625, 0.6953125, 0.9941406,
-        0.60546875, 0.6464844, 0.7050781, 1.0390625,
-        0.5917969, 0.59375, 0.71875, 1.0917969,
-        0.57421875, 0.5234375, 0.7363281, 1.1621094,
-        0.546875, 0.42382812, 0.7636719, 1.2617188,
-        0.6777344, 0.69140625, 0.7578125, 0.9941406,
-        0.66796875, 0.6464844, 0.7675781, 1.0390625,
-        0.6542969, 0.59375, 0.78125, 1.0917969,
-        0.63671875, 0.5234375, 0.7988281, 1.1621094,
-        0.609375, 0.42382812, 0.8261719, 1.2617188,
-        0.7402344, 0.69140625, 0.8203125, 0.9941406,
-        0.73046875, 0.6464844, 0.8300781, 1.0390625,
-        0.7167969, 0.59375, 0.84375, 1.0917969,
-        0.69921875, 0.5234375, 0.8613281, 1.1621094,
-        0.671875, 0.42382812, 0.8886719, 1.2617188,
-        0.8027344, 0.69140625, 0.8828125, 0.9941406,
-        0.79296875, 0.6464844, 0.8925781, 1.0390625,
-        0.7792969, 0.59375, 0.90625, 1.0917969,
-        0.76171875, 0.5234375, 0.9238281, 1.1621094,
-        0.734375, 0.42382812, 0.9511719, 1.2617188,
-        0.8652344, 0.69140625, 0.9453125, 0.9941406,
-        0.85546875, 0.6464844, 0.9550781, 1.0390625,
-        0.8417969, 0.59375, 0.96875, 1.0917969,
-        0.82421875, 0.5234375, 0.9863281, 1.1621094,
-        0.796875, 0.42382812, 1.0136719, 1.2617188,
-        0.9277344, 0.69140625, 1.0078125, 0.9941406,
-        0.91796875, 0.6464844, 1.0175781, 1.0390625,
-        0.9042969, 0.59375, 1.03125, 1.0917969,
-        0.88671875, 0.5234375, 1.0488281, 1.1621094,
-        0.859375, 0.42382812, 1.0761719, 1.2617188,
-        -0.0078125, 0.75390625, 0.0703125, 1.0566406,
-        -0.017578125, 0.7089844, 0.080078125, 1.1015625,
-        -0.03125, 0.65625, 0.09375, 1.1542969,
-        -0.048828125, 0.5859375, 0.111328125, 1.2246094,
-        -0.076171875, 0.48632812, 0.13867188, 1.3242188,
-        0.052734375, 0.75390625, 0.1328125, 1.0566406,
-        0.04296875, 0.7089844, 0.14257812, 1.1015625,
-        0.029296875, 0.65625, 0.15625, 1.1542969,
-        0.01171875, 0.5859375, 0.17382812, 1.2246094,
-        -0.013671875, 0.48632812, 0.20117188, 1.3242188,
-        0.115234375, 0.75390625, 0.1953125, 1.0566406,
-        0.10546875, 0.7089844, 0.20507812, 1.1015625,
-        0.091796875, 0.65625, 0.21875, 1.1542969,
-        0.07421875, 0.5859375, 0.23632812, 1.2246094,
-        0.046875, 0.48632812, 0.26367188, 1.3242188,
-        0.17773438, 0.75390625, 0.2578125, 1.0566406,
-        0.16796875, 0.7089844, 0.26757812, 1.1015625,
-        0.15429688, 0.65625, 0.28125, 1.1542969,
-        0.13671875, 0.5859375, 0.29882812, 1.2246094,
-        0.109375, 0.48632812, 0.32617188, 1.3242188,
-        0.24023438, 0.75390625, 0.3203125, 1.0566406,
-        0.23046875, 0.7089844, 0.33007812, 1.1015625,
-        0.21679688, 0.65625, 0.34375, 1.1542969,
-        0.19921875, 0.5859375, 0.36132812, 1.2246094,
-        0.171875, 0.48632812, 0.38867188, 1.3242188,
-        0.30273438, 0.75390625, 0.3828125, 1.0566406,
-        0.29296875, 0.7089844, 0.39257812, 1.1015625,
-        0.27929688, 0.65625, 0.40625, 1.1542969,
-        0.26171875, 0.5859375, 0.42382812, 1.2246094,
-        0.234375, 0.48632812, 0.45117188, 1.3242188,
-        0.36523438, 0.75390625, 0.4453125, 1.0566406,
-        0.35546875, 0.7089844, 0.45507812, 1.1015625,
-        0.34179688, 0.65625, 0.46875, 1.1542969,
-        0.32421875, 0.5859375, 0.48632812, 1.2246094,
-        0.296875, 0.48632812, 0.5136719, 1.3242188,
-        0.42773438, 0.75390625, 0.5078125, 1.0566406,
-        0.41796875, 0.7089844, 0.5175781, 1.1015625,
-        0.40429688, 0.65625, 0.53125, 1.1542969,
-        0.38671875, 0.5859375, 0.5488281, 1.2246094,
-        0.359375, 0.48632812, 0.5761719, 1.3242188,
-        0.49023438, 0.75390625, 0.5703125, 1.0566406,
-        0.48046875, 0.7089844, 0.5800781, 1.1015625,
-        0.46679688, 0.65625, 0.59375, 1.1542969,
-        0.44921875, 0.5859375, 0.6113281, 1.2246094,
-        0.421875, 0.48632812, 0.6386719, 1.3242188,
-        0.5527344, 0.75390625, 0.6328125, 1.0566406,
-        0.54296875, 0.7089844, 0.6425781, 1.1015625,
-        0.5292969, 0.65625, 0.65625, 1.1542969,
-        0.51171875, 0.5859375, 0.6738281, 1.2246094,
-        0.484375, 0.48632812, 0.7011719, 1.3242188,
-        0.6152344, 0.75390625, 0.6953125, 1.0566406,
-        0.60546875, 0.7089844, 0.7050781, 1.1015625,
-        0.5917969, 0.65625, 0.71875, 1.1542969,
-        0.57421875, 0.5859375, 0.7363281, 1.2246094,
-        0.546875, 0.48632812, 0.7636719, 1.3242188,
-        0.6777344, 0.75390625, 0.7578125, 1.0566406,
-        0.66796875, 0.7089844, 0.7675781, 1.1015625,
-        0.6542969, 0.65625, 0.78125, 1.1542969,
-        0.63671875, 0.5859375, 0.7988281, 1.2246094,
-        0.609375, 0.48632812, 0.8261719, 1.3242188,
-        0.7402344, 0.75390625, 0.8203125, 1.0566406,
-        0.73046875, 0.7089844, 0.8300781, 1.1015625,
-        0.7167969, 0.65625, 0.84375, 1.1542969,
-        0.69921875, 0.5859375, 0.8613281, 1.2246094,
-        0.671875, 0.48632812, 0.8886719, 1.3242188,
-        0.8027344, 0.75390625, 0.8828125, 1.0566406,
-        0.79296875, 0.7089844, 0.8925781, 1.1015625,
-        0.7792969, 0.65625, 0.90625, 1.1542969,
-        0.76171875, 0.5859375, 0.9238281, 1.2246094,
-        0.734375, 0.48632812, 0.9511719, 1.3242188,
-        0.8652344, 0.75390625, 0.9453125, 1.0566406,
-        0.85546875, 0.7089844, 0.9550781, 1.1015625,
-        0.8417969, 0.65625, 0.96875, 1.1542969,
-        0.82421875, 0.5859375, 0.9863281, 1.2246094,
-        0.796875, 0.48632812, 1.0136719, 1.3242188,
-        0.9277344, 0.75390625, 1.0078125, 1.0566406,
-        0.91796875, 0.7089844, 1.0175781, 1.1015625,
-        0.9042969, 0.65625, 1.03125, 1.1542969,
-        0.88671875, 0.5859375, 1.0488281, 1.2246094,
-        0.859375, 0.48632812, 1.0761719, 1.3242188,
-        -0.0078125, 0.81640625, 0.0703125, 1.1191406,
-        -0.017578125, 0.7714844, 0.080078125, 1.1640625,
-        -0.03125, 0.71875, 0.09375, 1.2167969,
-        -0.048828125, 0.6484375, 0.111328125, 1.2871094,
-        -0.076171875, 0.5488281, 0.13867188, 1.3867188,
-        0.052734375, 0.81640625, 0.1328125, 1.1191406,
-        0.04296875, 0.7714844, 0.14257812, 1.1640625,
-        0.029296875, 0.71875, 0.15625, 1.2167969,
-        0.01171875, 0.6484375, 0.17382812, 1.2871094,
-        -0.013671875, 0.5488281, 0.20117188, 1.3867188,
-        0.115234375, 0.81640625, 0.1953125, 1.1191406,
-        0.10546875, 0.7714844, 0.20507812, 1.1640625,
-        0.091796875, 0.71875, 0.21875, 1.2167969,
-        0.07421875, 0.6484375, 0.23632812, 1.2871094,
-        0.046875, 0.5488281, 0.26367188, 1.3867188,
-        0.17773438, 0.81640625, 0.2578125, 1.1191406,
-        0.16796875, 0.7714844, 0.26757812, 1.1640625,
-        0.15429688, 0.71875, 0.28125, 1.2167969,
-        0.13671875, 0.6484375, 0.29882812, 1.2871094,
-        0.109375, 0.5488281, 0.32617188, 1.3867188,
-        0.24023438, 0.81640625, 0.3203125, 1.1191406,
-        0.23046875, 0.7714844, 0.33007812, 1.1640625,
-        0.21679688, 0.71875, 0.34375, 1.2167969,
-        0.19921875, 0.6484375, 0.36132812, 1.2871094,
-        0.171875, 0.5488281, 0.38867188, 1.3867188,
-        0.30273438, 0.81640625, 0.3828125, 1.1191406,
-        0.29296875, 0.7714844, 0.39257812, 1.1640625,
-        0.27929688, 0.71875, 0.40625, 1.2167969,
-        0.26171875, 0.6484375, 0.42382812, 1.2871094,
-        0.234375, 0.5488281, 0.45117188, 1.3867188,
-        0.36523438, 0.81640625, 0.4453125, 1.1191406,
-        0.35546875, 0.7714844, 0.45507812, 1.1640625,
-        0.34179688, 0.71875, 0.46875, 1.2167969,
-        0.32421875, 0.6484375, 0.48632812, 1.2871094,
-        0.296875, 0.5488281, 0.5136719, 1.3867188,
-        0.42773438, 0.81640625, 0.5078125, 1.1191406,
-        0.41796875, 0.7714844, 0.5175781, 1.1640625,
-        0.40429688, 0.71875, 0.53125, 1.2167969,
-        0.38671875, 0.6484375, 0.5488281, 1.2871094,
-        0.359375, 0.5488281, 0.5761719, 1.3867188,
-        0.49023438, 0.81640625, 0.5703125, 1.1191406,
-        0.48046875, 0.7714844, 0.5800781, 1.1640625,
-        0.46679688, 0.71875, 0.59375, 1.2167969,
-        0.44921875, 0.6484375, 0.6113281, 1.2871094,
-        0.421875, 0.5488281, 0.6386719, 1.3867188,
-        0.5527344, 0.81640625, 0.6328125, 1.1191406,
-        0.54296875, 0.7714844, 0.6425781, 1.1640625,
-        0.5292969, 0.71875, 0.65625, 1.2167969,
-        0.51171875, 0.6484375, 0.6738281, 1.2871094,
-        0.484375, 0.5488281, 0.7011719, 1.3867188,
-        0.6152344, 0.81640625, 0.6953125, 1.1191406,
-        0.60546875, 0.7714844, 0.7050781, 1.1640625,
-        0.5917969, 0.71875, 0.71875, 1.2167969,
-        0.57421875, 0.6484375, 0.7363281, 1.2871094,
-        0.546875, 0.5488281, 0.7636719, 1.3867188,
-        0.6777344, 0.81640625, 0.7578125, 1.1191406,
-        0.66796875, 0.7714844, 0.7675781, 1.1640625,
-        0.6542969, 0.71875, 0.78125, 1.2167969,
-        0.63671875, 0.6484375, 0.7988281, 1.2871094,
-        0.609375, 0.5488281, 0.8261719, 1.3867188,
-        0.7402344, 0.81640625, 0.8203125, 1.1191406,
-        0.73046875, 0.7714844, 0.8300781, 1.1640625,
-        0.7167969, 0.71875, 0.84375, 1.2167969,
-        0.69921875, 0.6484375, 0.8613281, 1.2871094,
-        0.671875, 0.5488281, 0.8886719, 1.3867188,
-        0.8027344, 0.81640625, 0.8828125, 1.1191406,
-        0.79296875, 0.7714844, 0.8925781, 1.1640625,
-        0.7792969, 0.71875, 0.90625, 1.2167969,
-        0.76171875, 0.6484375, 0.9238281, 1.2871094,
-        0.734375, 0.5488281, 0.9511719, 1.3867188,
-        0.8652344, 0.81640625, 0.9453125, 1.1191406,
-        0.85546875, 0.7714844, 0.9550781, 1.1640625,
-        0.8417969, 0.71875, 0.96875, 1.2167969,
-        0.82421875, 0.6484375, 0.9863281, 1.2871094,
-        0.796875, 0.5488281, 1.0136719, 1.3867188,
-        0.9277344, 0.81640625, 1.0078125, 1.1191406,
-        0.91796875, 0.7714844, 1.0175781, 1.1640625,
-        0.9042969, 0.71875, 1.03125, 1.2167969,
-        0.88671875, 0.6484375, 1.0488281, 1.2871094,
-        0.859375, 0.5488281, 1.0761719, 1.3867188,
-    ];
 
 
     /* Other Parameters */
     const SCORE_THRESHOLD: f32 = 0.2;
     const NMS_IOU_THRESHOLD: f32 = 0.6;
 
-    pub fn new() -> Self {
+    pub fn config(&self) -> &ModelConfig {
+        &self.config
+    }
+
+    /* PriorBoxClustered of the original OpenVINO model */
+    /* Reproduces the prior box list retrieved from 0.npy at 512x512, and follows other input sizes */
+    fn default_prior_box() -> PriorBoxConfig {
+        PriorBoxConfig {
+            variance: Self::PRIOR_VARIANCE,
+            offset: 0.5,
+            clip: false,
+            snap_to_pixel: true,
+            filename: None,
+            layers: vec![
+                PriorBoxLayer {
+                    stride: 16,
+                    feature_map_size: None,
+                    box_sizes: vec![(7.0, 25.0), (17.0, 51.0), (23.0, 81.0), (31.0, 115.0)],
+                    min_sizes: vec![],
+                    max_sizes: vec![],
+                    aspect_ratios: vec![],
+                    flip: false,
+                },
+                PriorBoxLayer {
+                    stride: 32,
+                    feature_map_size: None,
+                    box_sizes: vec![(41.0, 155.0), (51.0, 201.0), (65.0, 255.0), (83.0, 327.0), (111.0, 429.0)],
+                    min_sizes: vec![],
+                    max_sizes: vec![],
+                    aspect_ratios: vec![],
+                    flip: false,
+                },
+            ],
+        }
+    }
+}
+
+impl FromModelConfig for DetectionEngine {
+    fn default_config() -> ModelConfig {
+        ModelConfig {
+            model_filename: Self::MODEL_FILENAME.to_string(),
+            label_filename: None,
+            input_width: Self::MODEL_WIDTH,
+            input_height: Self::MODEL_HEIGHT,
+            input_name: Self::MODEL_NAME_INPUT_0.to_string(),
+            output_names: vec![Self::MODEL_NAME_OUTPUT_0.to_string(), Self::MODEL_NAME_OUTPUT_1.to_string()],
+            normalize_mean: Self::MODEL_NORMALIZE_MEAN,     /* not used. the model takes [0, 255] input */
+            normalize_norm: Self::MODEL_NORMALIZE_NORM,
+            resize_mode: ResizeMode::Stretch,
+            pad_value: (0.0, 0.0, 0.0),
+            score_threshold: Self::SCORE_THRESHOLD,
+            score_class_threshold: Self::SCORE_THRESHOLD,
+            nms_iou_threshold: Self::NMS_IOU_THRESHOLD,
+            nms_mode: NmsMode::ClassAgnostic,
+            nms_method: NmsMethod::Hard,
+            soft_nms_sigma: 0.5,
+            max_detections: None,
+            prior_box: Some(Self::default_prior_box()),
+        }
+    }
+
+    fn from_config(config: ModelConfig) -> Result<Self> {
+        if config.output_names.len() != 2 {
+            return Err(Error::Config(format!("{} requires 2 output names (box, confidence)", Self::MODEL_NAME)));
+        }
+
+        /* Generate prior boxes for the input size */
+        let prior_box = config.prior_box.as_ref().ok_or_else(|| Error::Config(format!("{} requires prior_box", Self::MODEL_NAME)))?;
+        let prior_decoder = PriorBoxDecoder::new(prior_box, config.input_width, config.input_height)?;
+
         /* Load model */
-         let mut net = dnn::read_net_from_onnx(Self::MODEL_FILENAME).unwrap();
-        net.set_preferable_backend(dnn::DNN_BACKEND_OPENCV).unwrap();
-        net.set_preferable_target(dnn::DNN_TARGET_CPU).unwrap();
-        // net.set_preferable_target(dnn::DNN_TARGET_OPENCL).unwrap();
+        let net = read_net(&config.model_filename)?;
 
         /* Set output names */
-        let mut out_blob_names = core::Vector::<String>::new();
-        out_blob_names.push(Self::MODEL_NAME_OUTPUT_0);
-        out_blob_names.push(Self::MODEL_NAME_OUTPUT_1);
+        let out_blob_names = output_blob_names(&config.output_names);
 
         /* Initialize normalize param */
-        let normalize_mean = core::Scalar::from(Self::MODEL_NORMALIZE_MEAN);
-        let normalize_norm = core::Scalar::from(Self::MODEL_NORMALIZE_NORM);
+        let normalize_mean = core::Scalar::from(config.normalize_mean);
+        let normalize_norm = core::Scalar::from(config.normalize_norm);
+
+        /* Single class model (index 0 of the confidence is used) */
+        let label_list = match &config.label_filename {
+            Some(label_filename) => read_label_file(label_filename, 1)?,
+            None => vec![Self::MODEL_LABEL.to_string()],
+        };
         
-        DetectionEngine {
+        Ok(DetectionEngine {
+            config: config,
             net: net,
             out_blob_names: out_blob_names,
             normalize_mean: normalize_mean,
             normalize_norm: normalize_norm,
-        }
+            label_list: label_list,
+            prior_decoder: prior_decoder,
+        })
     }
+}
 
-    // fn normalize(&self, mat: &mut core::Mat) -> core::Mat {
-    //     let mut mat_normalized = Mat::default();
-    //     let mut mat_normalized_sub = Mat::default();
-    //     let mut mat_normalized_div = Mat::default();
-    //     mat.convert_to(&mut mat_normalized, core::CV_32FC3, 1.0 / 255.0, 0.0).unwrap();
-    //     core::subtract(&mat_normalized, &self.normalize_mean, &mut mat_normalized_sub, &core::no_array(), -1).unwrap();
-    //     core::divide2(&mat_normalized_sub, &self.normalize_norm, &mut mat_normalized_div, 1.0, -1).unwrap();
-    //     mat_normalized_div
-    // }
-
-    pub fn process(&mut self, mat: &core::Mat) -> Vec::<BoundingBox> {
+impl Detector for DetectionEngine {
+    fn process(&mut self, mat: &core::Mat) -> Result<Vec::<BoundingBox>> {
         /* Pre Process */
-        let mut mat_resized = Mat::default();
-        imgproc::resize(mat, &mut mat_resized, core::Size { width: Self::MODEL_WIDTH, height: Self::MODEL_HEIGHT }, 0.0, 0.0, imgproc::INTER_LINEAR).unwrap();
-        // let mat_normalized = self.normalize(&mut mat_resized);
+        let (mat_resized, transform) = resize(mat, self.input_size(), self.config.resize_mode, self.config.pad_value)?;
+        // let mat_normalized = normalize(&mat_resized, &self.normalize_mean, &self.normalize_norm)?;
         // let mat_blob = dnn::blob_from_image(&mat_normalized, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).unwrap();
-        let mat_blob = dnn::blob_from_image(&mat_resized, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).unwrap();
+        let mat_blob = dnn::blob_from_image(&mat_resized, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).map_err(Error::Preprocess)?;
         
 
         /* Feed input data */
-        self.net.set_input(&mat_blob, Self::MODEL_NAME_INPUT_0, 1.0, core::Scalar::default()).unwrap();
+        self.net.set_input(&mat_blob, &self.config.input_name, 1.0, core::Scalar::default()).map_err(Error::Inference)?;
 
         /* Run inference */
         let mut output_blobs = core::Vector::<core::Mat>::new();
-        self.net.forward(&mut output_blobs, &self.out_blob_names).unwrap();
+        self.net.forward(&mut output_blobs, &self.out_blob_names).map_err(Error::Inference)?;
 
         /* Retrieve output */
-        let box_logits_list = &output_blobs.to_vec()[0];
-        let confidence_list = &output_blobs.to_vec()[1];
+        let box_logits_list = output_blobs.get(0).map_err(Error::Inference)?;
+        let confidence_list = output_blobs.get(1).map_err(Error::Inference)?;
 
         /* Convert the output to vector */
-        let box_logits_list = unsafe {std::slice::from_raw_parts(box_logits_list.ptr(0).unwrap() as *mut f32, box_logits_list.total()).to_vec()};
-        let confidence_list = unsafe {std::slice::from_raw_parts(confidence_list.ptr(0).unwrap() as *mut f32, confidence_list.total()).to_vec()};
+        let box_logits_list = output_to_vec(&box_logits_list, &self.config.output_names[0], self.prior_decoder.len() * 4)?;
+        let confidence_list = output_to_vec(&confidence_list, &self.config.output_names[1], self.prior_decoder.len() * Self::MODEL_NUM_CLASS as usize)?;
 
         /* Decode bbox */
         let mut bbox_list = Vec::<BoundingBox>::new();
-        for i in 0 .. Self::MODEL_NUM_PRIOR as usize {
+        for i in 0 .. self.prior_decoder.len() {
             let score = confidence_list[i * 2 + 0];
-            if score >= Self::SCORE_THRESHOLD {
+            if score >= self.config.score_threshold {
                 // println!("{}, {}, {}, {}, {}, {}, {}", i, confidence_list[i * 2 + 0], confidence_list[i * 2 + 1], box_logits_list[i * 4 + 0], box_logits_list[i * 4 + 1], box_logits_list[i * 4 + 2], box_logits_list[i * 4 + 3]);
 
-                /* Prior Box: [0.0, 1.0] */
-                let prior_x0 = Self::PRIOR_BBOX[i * 4 + 0];
-                let prior_y0 = Self::PRIOR_BBOX[i * 4 + 1];
-                let prior_x1 = Self::PRIOR_BBOX[i * 4 + 2];
-                let prior_y1 = Self::PRIOR_BBOX[i * 4 + 3];
-                let prior_cx = (prior_x0 + prior_x1) / 2.0;
-                let prior_cy = (prior_y0 + prior_y1) / 2.0;
-                let prior_w = prior_x1 - prior_x0;
-                let prior_h = prior_y1 - prior_y0;
-                // let bbox = BoundingBox{
-                //     class_id: 0,
-                //     label: "Car".to_string(),
-                //     score: 0.5,
-                //     x: (prior_x0 * mat.cols() as f32) as i32,
-                //     y: (prior_y0 * mat.rows() as f32) as i32,
-                //     w: (prior_w * mat.cols() as f32) as i32,
-                //     h: (prior_h * mat.rows() as f32) as i32,
-                // };
-
-                /* Detected Box: [0.0, MODEL_SIZE] -> [0.0, 1.0] */
-                let mut box_cx = box_logits_list[i * 4 + 0];
-                let mut box_cy = box_logits_list[i * 4 + 1];
-                let mut box_w = box_logits_list[i * 4 + 2];
-                let mut box_h = box_logits_list[i * 4 + 3];
-
-                /* Adjust box [0.0, 1.0] */
-                /* Reference: */
-                /*   https://github.com/openvinotoolkit/openvino/blob/17091476d86cbb98392216fa3d4f0db90914449a/inference-engine/thirdparty/clDNN/src/impls/cpu/detection_output.cpp#L135 */
-                /*   https://docs.openvino.ai/latest/openvino_docs_ops_detection_DetectionOutput_1.html */
-                /*   https://github.com/openvinotoolkit/open_model_zoo/tree/2021.4.2/models/intel/vehicle-detection-0200 */
-                let cx = Self::PRIOR_VARIANCE.0 * box_cx * prior_w + prior_cx;
-                let cy = Self::PRIOR_VARIANCE.1 * box_cy * prior_h + prior_cy;
-                let w = (box_w * Self::PRIOR_VARIANCE.2).exp() * prior_w;
-                let h = (box_h * Self::PRIOR_VARIANCE.3).exp() * prior_h;
+                /* Detected Box: regression from the prior box -> original image */
+                let (x, y, w, h) = self.prior_decoder.decode_box(i, &box_logits_list[i * 4 .. i * 4 + 4], &transform);
 
                 /* Store the detected box */
                 let bbox = BoundingBox{
                     class_id: 0,
-                    label: "Car".to_string(),
+                    label: self.label_list[0].clone(),
                     score: score,
-                    x: ((cx - w / 2.0) * mat.cols() as f32) as i32,
-                    y: ((cy - h / 2.0) * mat.rows() as f32) as i32,
-                    w: (w * mat.cols() as f32) as i32,
-                    h: (h * mat.rows() as f32) as i32,
+                    x: x,
+                    y: y,
+                    w: w,
+                    h: h,
                 };
                 bbox_list.push(bbox);
             }
@@ -5551,9 +205,47 @@ for i in range(0, int(prio_box.shape[0] / 4)):
         // return bbox_list;
 
         /* NMS */
-        let bbox_nms_list = nms(&mut bbox_list, Self::NMS_IOU_THRESHOLD);
+        let bbox_nms_list = apply_nms(&mut bbox_list, &self.config.nms_param(self.config.score_threshold));
+
+        Ok(bbox_nms_list)
+    }
+
+    fn input_size(&self) -> core::Size {
+        core::Size { width: self.config.input_width, height: self.config.input_height }
+    }
+
+    fn label_list(&self) -> &[String] {
+        &self.label_list
+    }
 
-        bbox_nms_list
+    fn model_name(&self) -> &str {
+        Self::MODEL_NAME
+    }
+
+    fn score_threshold(&self) -> f32 {
+        self.config.score_threshold
+    }
+
+    fn set_score_threshold(&mut self, threshold: f32) {
+        self.config.score_threshold = threshold;
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vision_core::npy::NpyArray;
+
+    /* test_data/prior_box_512x512.npy is the prior box table which was hardcoded before it was generated at runtime */
+    #[test]
+    fn default_prior_box_matches_table() {
+        let prior_list = DetectionEngine::default_prior_box().generate(DetectionEngine::MODEL_WIDTH, DetectionEngine::MODEL_HEIGHT).unwrap();
+        let table = NpyArray::<f32>::read(concat!(env!("CARGO_MANIFEST_DIR"), "/test_data/prior_box_512x512.npy")).unwrap();
+        assert_eq!(table.shape, vec![prior_list.len(), 4]);
+        for (index, (prior, expected)) in prior_list.iter().zip(table.data.chunks_exact(4)).enumerate() {
+            for k in 0..4 {
+                assert!((prior[k] - expected[k]).abs() < 1e-6, "prior[{}] = {:?}, expected {:?}", index, prior, expected);
+            }
+        }
+    }
+}
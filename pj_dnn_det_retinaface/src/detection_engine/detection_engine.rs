@@ -0,0 +1,282 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::{prelude::*, core, dnn};
+
+use vision_core::bounding_box::{BoundingBox, NmsMethod, NmsMode, apply_nms_indices};
+use vision_core::config::{ModelConfig, FromModelConfig};
+use vision_core::detector::Detector;
+use vision_core::dnn_helper::{read_net, read_label_file, output_to_vec, normalize, output_blob_names};
+use vision_core::error::{Error, Result};
+use vision_core::face::FaceDetection;
+use vision_core::pose::Keypoint;
+use vision_core::preprocess::{resize, ResizeMode, ResizeTransform};
+use vision_core::prior_box::{PriorBoxConfig, PriorBoxLayer, PriorBoxDecoder};
+
+
+/////////////////////////////////////////////////////////////////
+pub struct DetectionEngine {
+    config: ModelConfig,
+    net: dnn::Net,
+    out_blob_names: core::Vector::<String>,
+    normalize_mean: core::Scalar,
+    normalize_norm: core::Scalar,
+    label_list: Vec::<String>,
+    prior_decoder: PriorBoxDecoder,
+}
+
+impl DetectionEngine {
+    /* Model Parameters */
+    const MODEL_NAME: &'static str = "RetinaFace";
+    const MODEL_FILENAME: &'static str = "../resource/model/retinaface_mobilenet0.25_640x640.onnx";
+    const MODEL_NORMALIZE_MEAN: (f64, f64, f64) = (104.0 / 255.0, 117.0 / 255.0, 123.0 / 255.0);    /* BGR */
+    const MODEL_NORMALIZE_NORM: (f64, f64, f64) = (1.0 / 255.0, 1.0 / 255.0, 1.0 / 255.0);
+    const MODEL_WIDTH: i32 = 640;
+    const MODEL_HEIGHT: i32 = 640;
+    const MODEL_NAME_INPUT_0: &'static str = "input";
+    const MODEL_NAME_OUTPUT_0: &'static str = "loc";        // [1, num_prior, 4]
+    const MODEL_NAME_OUTPUT_1: &'static str = "conf";       // [1, num_prior, 2] (background, face) after softmax
+    const MODEL_NAME_OUTPUT_2: &'static str = "landms";     // [1, num_prior, 5 * 2]
+    const MODEL_NUM_CLASS: usize = 2;
+    const MODEL_NUMBER_OF_LANDMARK: usize = 5;
+    const LABEL: &'static str = "face";
+
+    /* Prior boxes (see default_prior_box) */
+    const PRIOR_VARIANCE: (f32, f32, f32, f32) = (0.1, 0.1, 0.2, 0.2);
+
+    /* Other Parameters */
+    const SCORE_THRESHOLD: f32 = 0.5;
+    const NMS_IOU_THRESHOLD: f32 = 0.4;
+
+    pub fn config(&self) -> &ModelConfig {
+        &self.config
+    }
+
+    /* PriorBox of the original model (cfg_mnet and cfg_re50 in data/config.py) */
+    fn default_prior_box() -> PriorBoxConfig {
+        let layer = |stride: i32, min_sizes: Vec::<f32>| PriorBoxLayer {
+            stride: stride,
+            feature_map_size: None,
+            box_sizes: vec![],
+            min_sizes: min_sizes,
+            max_sizes: vec![],
+            aspect_ratios: vec![],
+            flip: false,
+        };
+        PriorBoxConfig {
+            variance: Self::PRIOR_VARIANCE,
+            offset: 0.5,
+            clip: false,
+            snap_to_pixel: false,
+            filename: None,
+            layers: vec![
+                layer(8, vec![16.0, 32.0]),
+                layer(16, vec![64.0, 128.0]),
+                layer(32, vec![256.0, 512.0]),
+            ],
+        }
+    }
+
+    /* Run detection with landmarks. Coordinates of the result are in the original image (mat) */
+    pub fn process_with_landmarks(&mut self, mat: &core::Mat) -> Result<Vec::<FaceDetection>> {
+        /* Pre Process (the model takes BGR image) */
+        let (mat_resized, transform) = resize(mat, self.input_size(), self.config.resize_mode, self.config.pad_value)?;
+        let mat_normalized = normalize(&mat_resized, &self.normalize_mean, &self.normalize_norm)?;
+        let mat_blob = dnn::blob_from_image(&mat_normalized, 1.0, core::Size::default(), core::Scalar::default(), false, false, core::CV_32F).map_err(Error::Preprocess)?;
+
+        /* Feed input data */
+        self.net.set_input(&mat_blob, &self.config.input_name, 1.0, core::Scalar::default()).map_err(Error::Inference)?;
+
+        /* Run inference */
+        let mut output_blobs = core::Vector::<core::Mat>::new();
+        self.net.forward(&mut output_blobs, &self.out_blob_names).map_err(Error::Inference)?;
+
+        /* Retrieve output */
+        let num_prior = self.prior_decoder.len();
+        let box_list = output_to_vec(&output_blobs.get(0).map_err(Error::Inference)?, &self.config.output_names[0], num_prior * 4)?;
+        let confidence_list = output_to_vec(&output_blobs.get(1).map_err(Error::Inference)?, &self.config.output_names[1], num_prior * Self::MODEL_NUM_CLASS)?;
+        let landmark_list = output_to_vec(&output_blobs.get(2).map_err(Error::Inference)?, &self.config.output_names[2], num_prior * Self::MODEL_NUMBER_OF_LANDMARK * 2)?;
+
+        /* Decode bbox and landmarks */
+        let candidate_list = decode(&self.prior_decoder, &box_list, &confidence_list, &landmark_list, self.config.score_threshold, &transform, &self.label_list[0]);
+
+        /* NMS, then landmarks of the remaining boxes */
+        let candidate_bbox_list: Vec::<BoundingBox> = candidate_list.iter().map(|face| face.bbox.clone()).collect();
+        let bbox_nms_list = apply_nms_indices(&candidate_bbox_list, &self.config.nms_param(self.config.score_threshold));
+        let face_list = bbox_nms_list.into_iter()
+            .map(|(index, bbox)| FaceDetection {
+                bbox: bbox,
+                landmark_list: candidate_list[index].landmark_list.clone(),
+            })
+            .collect();
+        Ok(face_list)
+    }
+}
+
+/* Decode the output ([num_prior, 4], [num_prior, 2], [num_prior, 5 * 2]) with the prior boxes */
+/* Score of each landmark is the face confidence, as the model doesn't output landmark confidence */
+pub fn decode(prior_decoder: &PriorBoxDecoder, box_list: &[f32], confidence_list: &[f32], landmark_list: &[f32], score_threshold: f32, transform: &ResizeTransform, label: &str) -> Vec::<FaceDetection> {
+    let num_element_of_landmark = DetectionEngine::MODEL_NUMBER_OF_LANDMARK * 2;
+    let mut face_list = Vec::<FaceDetection>::new();
+    for i in 0 .. prior_decoder.len() {
+        let score = confidence_list[i * DetectionEngine::MODEL_NUM_CLASS + 1];
+        if score >= score_threshold {
+            let (x, y, w, h) = prior_decoder.decode_box(i, &box_list[i * 4 .. i * 4 + 4], transform);
+            let bbox = BoundingBox {
+                class_id: 0,
+                label: label.to_string(),
+                score: score,
+                x: x,
+                y: y,
+                w: w,
+                h: h,
+            };
+            let landmark_data = &landmark_list[i * num_element_of_landmark .. (i + 1) * num_element_of_landmark];
+            let landmark_list = landmark_data.chunks_exact(2).map(|delta| {
+                let (x, y) = prior_decoder.decode_point(i, delta, transform);
+                Keypoint { x: x, y: y, score: score }
+            }).collect();
+            face_list.push(FaceDetection { bbox: bbox, landmark_list: landmark_list });
+        }
+    }
+    face_list
+}
+
+impl FromModelConfig for DetectionEngine {
+    fn default_config() -> ModelConfig {
+        ModelConfig {
+            model_filename: Self::MODEL_FILENAME.to_string(),
+            label_filename: None,
+            input_width: Self::MODEL_WIDTH,
+            input_height: Self::MODEL_HEIGHT,
+            input_name: Self::MODEL_NAME_INPUT_0.to_string(),
+            output_names: vec![Self::MODEL_NAME_OUTPUT_0.to_string(), Self::MODEL_NAME_OUTPUT_1.to_string(), Self::MODEL_NAME_OUTPUT_2.to_string()],
+            normalize_mean: Self::MODEL_NORMALIZE_MEAN,
+            normalize_norm: Self::MODEL_NORMALIZE_NORM,
+            resize_mode: ResizeMode::Letterbox,
+            pad_value: (104.0, 117.0, 123.0),      /* becomes 0 after normalization */
+            score_threshold: Self::SCORE_THRESHOLD,
+            score_class_threshold: Self::SCORE_THRESHOLD,
+            nms_iou_threshold: Self::NMS_IOU_THRESHOLD,
+            nms_mode: NmsMode::ClassAgnostic,
+            nms_method: NmsMethod::Hard,
+            soft_nms_sigma: 0.5,
+            max_detections: None,
+            prior_box: Some(Self::default_prior_box()),
+        }
+    }
+
+    fn from_config(config: ModelConfig) -> Result<Self> {
+        if config.output_names.len() != 3 {
+            return Err(Error::Config(format!("{} requires 3 output names (box, confidence, landmark)", Self::MODEL_NAME)));
+        }
+
+        /* Generate prior boxes for the input size */
+        let prior_box = config.prior_box.as_ref().ok_or_else(|| Error::Config(format!("{} requires prior_box", Self::MODEL_NAME)))?;
+        let prior_decoder = PriorBoxDecoder::new(prior_box, config.input_width, config.input_height)?;
+
+        /* Load model */
+        let net = read_net(&config.model_filename)?;
+
+        /* Set output names */
+        let out_blob_names = output_blob_names(&config.output_names);
+
+        /* Initialize normalize param */
+        let normalize_mean = core::Scalar::from(config.normalize_mean);
+        let normalize_norm = core::Scalar::from(config.normalize_norm);
+
+        /* Read label (only the first one is used, as the model has face class only) */
+        let label_list = match &config.label_filename {
+            Some(label_filename) => read_label_file(label_filename, 1)?,
+            None => vec![Self::LABEL.to_string()],
+        };
+
+        Ok(DetectionEngine {
+            config: config,
+            net: net,
+            out_blob_names: out_blob_names,
+            normalize_mean: normalize_mean,
+            normalize_norm: normalize_norm,
+            label_list: label_list,
+            prior_decoder: prior_decoder,
+        })
+    }
+}
+
+impl Detector for DetectionEngine {
+    /* Use process_with_landmarks to get landmarks */
+    fn process(&mut self, mat: &core::Mat) -> Result<Vec::<BoundingBox>> {
+        Ok(self.process_with_landmarks(mat)?.into_iter().map(|face| face.bbox).collect())
+    }
+
+    fn input_size(&self) -> core::Size {
+        core::Size { width: self.config.input_width, height: self.config.input_height }
+    }
+
+    fn label_list(&self) -> &[String] {
+        &self.label_list
+    }
+
+    fn model_name(&self) -> &str {
+        Self::MODEL_NAME
+    }
+
+    fn score_threshold(&self) -> f32 {
+        self.config.score_threshold
+    }
+
+    fn set_score_threshold(&mut self, threshold: f32) {
+        self.config.score_threshold = threshold;
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prior_box_of_original_model() {
+        /* 2 * (80 * 80 + 40 * 40 + 20 * 20) priors at 640x640 */
+        let prior_list = DetectionEngine::default_prior_box().generate(640, 640).unwrap();
+        assert_eq!(prior_list.len(), 16800);
+        assert_eq!(prior_list[0], [-4.0 / 640.0, -4.0 / 640.0, 12.0 / 640.0, 12.0 / 640.0]);
+        assert_eq!(prior_list[16799], [(624.0 - 256.0) / 640.0, (624.0 - 256.0) / 640.0, (624.0 + 256.0) / 640.0, (624.0 + 256.0) / 640.0]);
+    }
+
+    #[test]
+    fn decode_output() {
+        /* 2 priors of 20x20 at (50, 50) and (150, 50) in 200x200 input. Original image is 400x400 */
+        let prior_decoder = PriorBoxDecoder::from_prior_list(vec![[0.2, 0.2, 0.3, 0.3], [0.7, 0.2, 0.8, 0.3]], (0.1, 0.1, 0.2, 0.2), 200, 200);
+        let transform = ResizeTransform { scale_x: 0.5, scale_y: 0.5, offset_x: 0.0, offset_y: 0.0 };
+        let box_list = vec![0.0; 8];
+        let confidence_list = vec![0.1, 0.9, 0.8, 0.2];    /* the second is background */
+        let mut landmark_list = vec![0.0; 20];
+        landmark_list[0] = -5.0;    /* left_eye: 0.5 prior width to the left */
+        landmark_list[9] = 10.0;    /* right_mouth: 1.0 prior height to the bottom */
+        let face_list = decode(&prior_decoder, &box_list, &confidence_list, &landmark_list, 0.5, &transform, "face");
+        assert_eq!(face_list.len(), 1);
+        let face = &face_list[0];
+        for (actual, expected) in [face.bbox.x, face.bbox.y, face.bbox.w, face.bbox.h].iter().zip([80.0, 80.0, 40.0, 40.0].iter()) {
+            assert!((actual - expected).abs() < 1e-4, "{:?}", face.bbox);
+        }
+        assert_eq!(face.bbox.score, 0.9);
+        assert_eq!(face.landmark_list.len(), 5);
+        let left_eye = face.landmark("left_eye").unwrap();
+        assert!((left_eye.x - 80.0).abs() < 1e-4 && (left_eye.y - 100.0).abs() < 1e-4);
+        let right_mouth = face.landmark("right_mouth").unwrap();
+        assert!((right_mouth.x - 100.0).abs() < 1e-4 && (right_mouth.y - 140.0).abs() < 1e-4);
+        assert_eq!(right_mouth.score, 0.9);
+    }
+}
@@ -0,0 +1,49 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::core;
+
+use pj_dnn_det_retinaface::detection_engine::detection_engine::DetectionEngine;
+use vision_core::bounding_box::BoundingBox;
+use vision_core::cli::{Args, Parser};
+use vision_core::error::Error;
+use vision_core::helper_cv::{draw_joints, ColorGenerator};
+use vision_core::runner::{run_with, draw_bounding_box_list, AppResult};
+
+/////////////////////////////////////////////////////////////////
+/* Usage (see --help for all the options) */
+/*   cargo run */
+/*   cargo run -- --image ../resource/people_0.jpg --output result.jpg */
+/*   cargo run -- --video ../resource/Megamind.avi --output result.mp4 */
+/*   cargo run -- --camera 0 */
+/*   cargo run -- --dir ../resource --output result --headless */
+const DEFAULT_IMAGE_FILENAME: &str = "../resource/people_0.jpg";
+
+/////////////////////////////////////////////////////////////////
+fn main() -> AppResult {
+    let args = Args::parse();
+
+    /* Create detection engine */
+    let mut engine = args.engine_builder::<DetectionEngine>()?.build()?;
+
+    let color_generator = ColorGenerator::new(20, 30);
+    run_with(&mut |mat: &mut core::Mat, text_thickness: i32| {
+        let face_list = engine.process_with_landmarks(mat)?;
+        let bbox_list: Vec::<BoundingBox> = face_list.iter().map(|face| face.bbox.clone()).collect();
+        draw_bounding_box_list(mat, &bbox_list, &color_generator, text_thickness).map_err(Error::Postprocess)?;
+        for face in face_list.iter() {
+            draw_joints(mat, &face.landmark_list, 0.0, core::Scalar::new(0., 255., 255., 255.), 2).map_err(Error::Postprocess)?;
+        }
+        Ok(face_list.len())
+    }, &args, DEFAULT_IMAGE_FILENAME)
+}
@@ -11,23 +11,32 @@ limitations under the License.
 ==============================================================================*/
 
 /////////////////////////////////////////////////////////////////
-use opencv::{prelude::*, core, imgproc, dnn};
+use opencv::{prelude::*, core, dnn};
 
-// use crate::detection_engine::boundnig_box::BoundingBox;
-use super::boundnig_box::{BoundingBox, nms};
+use vision_core::bounding_box::{BoundingBox, NmsMethod, NmsMode, apply_nms};
+use vision_core::config::{ModelConfig, FromModelConfig};
+use vision_core::detector::Detector;
+use vision_core::dnn_helper::{read_net, read_label_file, output_to_vec, output_blob_names};
+use vision_core::error::{Error, Result};
+use vision_core::preprocess::{resize, ResizeMode};
+use vision_core::prior_box::{PriorBoxConfig, PriorBoxLayer, PriorBoxDecoder};
 
 
 /////////////////////////////////////////////////////////////////
 pub struct DetectionEngine {
+    config: ModelConfig,
     net: dnn::Net,
     out_blob_names: core::Vector::<String>,
     normalize_mean: core::Scalar,
     normalize_norm: core::Scalar,
+    label_list: Vec::<String>,
+    prior_decoder: PriorBoxDecoder,
 }
 
 
 impl DetectionEngine {
     /* Model Parameters */
+    const MODEL_NAME: &'static str = "vehicle-detection-0200";
     const MODEL_FILENAME: &'static str = "../resource/model/vehicle-detection-0200_256x256.onnx";
     const MODEL_NORMALIZE_MEAN: (f64, f64, f64) = (0.0, 0.0, 0.0);
     const MODEL_NORMALIZE_NORM: (f64, f64, f64) = (1.0, 1.0, 1.0);
@@ -36,1472 +45,158 @@ impl DetectionEngine {
     const MODEL_NAME_INPUT_0: &'static str = "image";
     const MODEL_NAME_OUTPUT_0: &'static str = "tf.identity";
     const MODEL_NAME_OUTPUT_1: &'static str = "tf.identity_1";
-    const MODEL_NUM_PRIOR: i32 = 1344;
     const MODEL_NUM_CLASS: i32 = 2;
+    const MODEL_LABEL: &'static str = "Car";
 
-    /* Retrieved from 0.npy */
+    /* Prior boxes (see default_prior_box) */
     const PRIOR_VARIANCE: (f32, f32, f32, f32) = (0.1, 0.1, 0.2, 0.2);
-    const PRIOR_BBOX: [f32; 4 * Self::MODEL_NUM_PRIOR as usize] = [
-0.01171875, 0.01171875, 0.046875, 0.046875         ,
--0.00390625, -0.01171875, 0.06640625, 0.07421875   ,
--0.03515625, -0.04296875, 0.09765625, 0.10546875   ,
--0.046875, -0.1171875, 0.109375, 0.1796875         ,
-0.07421875, 0.01171875, 0.109375, 0.046875         ,
-0.0546875, -0.01171875, 0.12890625, 0.07421875     ,
-0.0234375, -0.04296875, 0.16015625, 0.10546875     ,
-0.01171875, -0.1171875, 0.171875, 0.1796875        ,
-0.13671875, 0.01171875, 0.171875, 0.046875         ,
-0.1171875, -0.01171875, 0.19140625, 0.07421875     ,
-0.0859375, -0.04296875, 0.22265625, 0.10546875     ,
-0.07421875, -0.1171875, 0.234375, 0.1796875        ,
-0.19921875, 0.01171875, 0.234375, 0.046875         ,
-0.1796875, -0.01171875, 0.25390625, 0.07421875     ,
-0.1484375, -0.04296875, 0.28515625, 0.10546875     ,
-0.13671875, -0.1171875, 0.296875, 0.1796875        ,
-0.26171875, 0.01171875, 0.296875, 0.046875         ,
-0.2421875, -0.01171875, 0.31640625, 0.07421875     ,
-0.2109375, -0.04296875, 0.34765625, 0.10546875     ,
-0.19921875, -0.1171875, 0.359375, 0.1796875        ,
-0.32421875, 0.01171875, 0.359375, 0.046875         ,
-0.3046875, -0.01171875, 0.37890625, 0.07421875     ,
-0.2734375, -0.04296875, 0.41015625, 0.10546875     ,
-0.26171875, -0.1171875, 0.421875, 0.1796875        ,
-0.38671875, 0.01171875, 0.421875, 0.046875         ,
-0.3671875, -0.01171875, 0.44140625, 0.07421875     ,
-0.3359375, -0.04296875, 0.47265625, 0.10546875     ,
-0.32421875, -0.1171875, 0.484375, 0.1796875        ,
-0.44921875, 0.01171875, 0.484375, 0.046875         ,
-0.4296875, -0.01171875, 0.50390625, 0.07421875     ,
-0.3984375, -0.04296875, 0.53515625, 0.10546875     ,
-0.38671875, -0.1171875, 0.546875, 0.1796875        ,
-0.51171875, 0.01171875, 0.546875, 0.046875         ,
-0.4921875, -0.01171875, 0.56640625, 0.07421875     ,
-0.4609375, -0.04296875, 0.59765625, 0.10546875     ,
-0.44921875, -0.1171875, 0.609375, 0.1796875        ,
-0.57421875, 0.01171875, 0.609375, 0.046875         ,
-0.5546875, -0.01171875, 0.62890625, 0.07421875     ,
-0.5234375, -0.04296875, 0.66015625, 0.10546875     ,
-0.51171875, -0.1171875, 0.671875, 0.1796875        ,
-0.63671875, 0.01171875, 0.671875, 0.046875         ,
-0.6171875, -0.01171875, 0.69140625, 0.07421875     ,
-0.5859375, -0.04296875, 0.72265625, 0.10546875     ,
-0.57421875, -0.1171875, 0.734375, 0.1796875        ,
-0.69921875, 0.01171875, 0.734375, 0.046875         ,
-0.6796875, -0.01171875, 0.75390625, 0.07421875     ,
-0.6484375, -0.04296875, 0.78515625, 0.10546875     ,
-0.63671875, -0.1171875, 0.796875, 0.1796875        ,
-0.76171875, 0.01171875, 0.796875, 0.046875         ,
-0.7421875, -0.01171875, 0.81640625, 0.07421875     ,
-0.7109375, -0.04296875, 0.84765625, 0.10546875     ,
-0.69921875, -0.1171875, 0.859375, 0.1796875        ,
-0.82421875, 0.01171875, 0.859375, 0.046875         ,
-0.8046875, -0.01171875, 0.87890625, 0.07421875     ,
-0.7734375, -0.04296875, 0.91015625, 0.10546875     ,
-0.76171875, -0.1171875, 0.921875, 0.1796875        ,
-0.88671875, 0.01171875, 0.921875, 0.046875         ,
-0.8671875, -0.01171875, 0.94140625, 0.07421875     ,
-0.8359375, -0.04296875, 0.97265625, 0.10546875     ,
-0.82421875, -0.1171875, 0.984375, 0.1796875        ,
-0.94921875, 0.01171875, 0.984375, 0.046875         ,
-0.9296875, -0.01171875, 1.0039062, 0.07421875      ,
-0.8984375, -0.04296875, 1.0351562, 0.10546875      ,
-0.88671875, -0.1171875, 1.046875, 0.1796875        ,
-0.01171875, 0.07421875, 0.046875, 0.109375         ,
--0.00390625, 0.046875, 0.06640625, 0.13671875      ,
--0.03515625, 0.015625, 0.09765625, 0.16796875      ,
--0.046875, -0.0546875, 0.109375, 0.2421875         ,
-0.07421875, 0.07421875, 0.109375, 0.109375         ,
-0.0546875, 0.046875, 0.12890625, 0.13671875        ,
-0.0234375, 0.015625, 0.16015625, 0.16796875        ,
-0.01171875, -0.0546875, 0.171875, 0.2421875        ,
-0.13671875, 0.07421875, 0.171875, 0.109375         ,
-0.1171875, 0.046875, 0.19140625, 0.13671875        ,
-0.0859375, 0.015625, 0.22265625, 0.16796875        ,
-0.07421875, -0.0546875, 0.234375, 0.2421875        ,
-0.19921875, 0.07421875, 0.234375, 0.109375         ,
-0.1796875, 0.046875, 0.25390625, 0.13671875        ,
-0.1484375, 0.015625, 0.28515625, 0.16796875        ,
-0.13671875, -0.0546875, 0.296875, 0.2421875        ,
-0.26171875, 0.07421875, 0.296875, 0.109375         ,
-0.2421875, 0.046875, 0.31640625, 0.13671875        ,
-0.2109375, 0.015625, 0.34765625, 0.16796875        ,
-0.19921875, -0.0546875, 0.359375, 0.2421875        ,
-0.32421875, 0.07421875, 0.359375, 0.109375         ,
-0.3046875, 0.046875, 0.37890625, 0.13671875        ,
-0.2734375, 0.015625, 0.41015625, 0.16796875        ,
-0.26171875, -0.0546875, 0.421875, 0.2421875        ,
-0.38671875, 0.07421875, 0.421875, 0.109375         ,
-0.3671875, 0.046875, 0.44140625, 0.13671875        ,
-0.3359375, 0.015625, 0.47265625, 0.16796875        ,
-0.32421875, -0.0546875, 0.484375, 0.2421875        ,
-0.44921875, 0.07421875, 0.484375, 0.109375         ,
-0.4296875, 0.046875, 0.50390625, 0.13671875        ,
-0.3984375, 0.015625, 0.53515625, 0.16796875        ,
-0.38671875, -0.0546875, 0.546875, 0.2421875        ,
-0.51171875, 0.07421875, 0.546875, 0.109375         ,
-0.4921875, 0.046875, 0.56640625, 0.13671875        ,
-0.4609375, 0.015625, 0.59765625, 0.16796875        ,
-0.44921875, -0.0546875, 0.609375, 0.2421875        ,
-0.57421875, 0.07421875, 0.609375, 0.109375         ,
-0.5546875, 0.046875, 0.62890625, 0.13671875        ,
-0.5234375, 0.015625, 0.66015625, 0.16796875        ,
-0.51171875, -0.0546875, 0.671875, 0.2421875        ,
-0.63671875, 0.07421875, 0.671875, 0.109375         ,
-0.6171875, 0.046875, 0.69140625, 0.13671875        ,
-0.5859375, 0.015625, 0.72265625, 0.16796875        ,
-0.57421875, -0.0546875, 0.734375, 0.2421875        ,
-0.69921875, 0.07421875, 0.734375, 0.109375         ,
-0.6796875, 0.046875, 0.75390625, 0.13671875        ,
-0.6484375, 0.015625, 0.78515625, 0.16796875        ,
-0.63671875, -0.0546875, 0.796875, 0.2421875        ,
-0.76171875, 0.07421875, 0.796875, 0.109375         ,
-0.7421875, 0.046875, 0.81640625, 0.13671875        ,
-0.7109375, 0.015625, 0.84765625, 0.16796875        ,
-0.69921875, -0.0546875, 0.859375, 0.2421875        ,
-0.82421875, 0.07421875, 0.859375, 0.109375         ,
-0.8046875, 0.046875, 0.87890625, 0.13671875        ,
-0.7734375, 0.015625, 0.91015625, 0.16796875        ,
-0.76171875, -0.0546875, 0.921875, 0.2421875        ,
-0.88671875, 0.07421875, 0.921875, 0.109375         ,
-0.8671875, 0.046875, 0.94140625, 0.13671875        ,
-0.8359375, 0.015625, 0.97265625, 0.16796875        ,
-0.82421875, -0.0546875, 0.984375, 0.2421875        ,
-0.94921875, 0.07421875, 0.984375, 0.109375         ,
-0.9296875, 0.046875, 1.0039062, 0.13671875         ,
-0.8984375, 0.015625, 1.0351562, 0.16796875         ,
-0.88671875, -0.0546875, 1.046875, 0.2421875        ,
-0.01171875, 0.13671875, 0.046875, 0.171875         ,
--0.00390625, 0.109375, 0.06640625, 0.19921875      ,
--0.03515625, 0.078125, 0.09765625, 0.23046875      ,
--0.046875, 0.00390625, 0.109375, 0.3046875         ,
-0.07421875, 0.13671875, 0.109375, 0.171875         ,
-0.0546875, 0.109375, 0.12890625, 0.19921875        ,
-0.0234375, 0.078125, 0.16015625, 0.23046875        ,
-0.01171875, 0.00390625, 0.171875, 0.3046875        ,
-0.13671875, 0.13671875, 0.171875, 0.171875         ,
-0.1171875, 0.109375, 0.19140625, 0.19921875        ,
-0.0859375, 0.078125, 0.22265625, 0.23046875        ,
-0.07421875, 0.00390625, 0.234375, 0.3046875        ,
-0.19921875, 0.13671875, 0.234375, 0.171875         ,
-0.1796875, 0.109375, 0.25390625, 0.19921875        ,
-0.1484375, 0.078125, 0.28515625, 0.23046875        ,
-0.13671875, 0.00390625, 0.296875, 0.3046875        ,
-0.26171875, 0.13671875, 0.296875, 0.171875         ,
-0.2421875, 0.109375, 0.31640625, 0.19921875        ,
-0.2109375, 0.078125, 0.34765625, 0.23046875        ,
-0.19921875, 0.00390625, 0.359375, 0.3046875        ,
-0.32421875, 0.13671875, 0.359375, 0.171875         ,
-0.3046875, 0.109375, 0.37890625, 0.19921875        ,
-0.2734375, 0.078125, 0.41015625, 0.23046875        ,
-0.26171875, 0.00390625, 0.421875, 0.3046875        ,
-0.38671875, 0.13671875, 0.421875, 0.171875         ,
-0.3671875, 0.109375, 0.44140625, 0.19921875        ,
-0.3359375, 0.078125, 0.47265625, 0.23046875        ,
-0.32421875, 0.00390625, 0.484375, 0.3046875        ,
-0.44921875, 0.13671875, 0.484375, 0.171875         ,
-0.4296875, 0.109375, 0.50390625, 0.19921875        ,
-0.3984375, 0.078125, 0.53515625, 0.23046875        ,
-0.38671875, 0.00390625, 0.546875, 0.3046875        ,
-0.51171875, 0.13671875, 0.546875, 0.171875         ,
-0.4921875, 0.109375, 0.56640625, 0.19921875        ,
-0.4609375, 0.078125, 0.59765625, 0.23046875        ,
-0.44921875, 0.00390625, 0.609375, 0.3046875        ,
-0.57421875, 0.13671875, 0.609375, 0.171875         ,
-0.5546875, 0.109375, 0.62890625, 0.19921875        ,
-0.5234375, 0.078125, 0.66015625, 0.23046875        ,
-0.51171875, 0.00390625, 0.671875, 0.3046875        ,
-0.63671875, 0.13671875, 0.671875, 0.171875         ,
-0.6171875, 0.109375, 0.69140625, 0.19921875        ,
-0.5859375, 0.078125, 0.72265625, 0.23046875        ,
-0.57421875, 0.00390625, 0.734375, 0.3046875        ,
-0.69921875, 0.13671875, 0.734375, 0.171875         ,
-0.6796875, 0.109375, 0.75390625, 0.19921875        ,
-0.6484375, 0.078125, 0.78515625, 0.23046875        ,
-0.63671875, 0.00390625, 0.796875, 0.3046875        ,
-0.76171875, 0.13671875, 0.796875, 0.171875         ,
-0.7421875, 0.109375, 0.81640625, 0.19921875        ,
-0.7109375, 0.078125, 0.84765625, 0.23046875        ,
-0.69921875, 0.00390625, 0.859375, 0.3046875        ,
-0.82421875, 0.13671875, 0.859375, 0.171875         ,
-0.8046875, 0.109375, 0.87890625, 0.19921875        ,
-0.7734375, 0.078125, 0.91015625, 0.23046875        ,
-0.76171875, 0.00390625, 0.921875, 0.3046875        ,
-0.88671875, 0.13671875, 0.921875, 0.171875         ,
-0.8671875, 0.109375, 0.94140625, 0.19921875        ,
-0.8359375, 0.078125, 0.97265625, 0.23046875        ,
-0.82421875, 0.00390625, 0.984375, 0.3046875        ,
-0.94921875, 0.13671875, 0.984375, 0.171875         ,
-0.9296875, 0.109375, 1.0039062, 0.19921875         ,
-0.8984375, 0.078125, 1.0351562, 0.23046875         ,
-0.88671875, 0.00390625, 1.046875, 0.3046875        ,
-0.01171875, 0.19921875, 0.046875, 0.234375         ,
--0.00390625, 0.171875, 0.06640625, 0.26171875      ,
--0.03515625, 0.140625, 0.09765625, 0.29296875      ,
--0.046875, 0.06640625, 0.109375, 0.3671875         ,
-0.07421875, 0.19921875, 0.109375, 0.234375         ,
-0.0546875, 0.171875, 0.12890625, 0.26171875        ,
-0.0234375, 0.140625, 0.16015625, 0.29296875        ,
-0.01171875, 0.06640625, 0.171875, 0.3671875        ,
-0.13671875, 0.19921875, 0.171875, 0.234375         ,
-0.1171875, 0.171875, 0.19140625, 0.26171875        ,
-0.0859375, 0.140625, 0.22265625, 0.29296875        ,
-0.07421875, 0.06640625, 0.234375, 0.3671875        ,
-0.19921875, 0.19921875, 0.234375, 0.234375         ,
-0.1796875, 0.171875, 0.25390625, 0.26171875        ,
-0.1484375, 0.140625, 0.28515625, 0.29296875        ,
-0.13671875, 0.06640625, 0.296875, 0.3671875        ,
-0.26171875, 0.19921875, 0.296875, 0.234375         ,
-0.2421875, 0.171875, 0.31640625, 0.26171875        ,
-0.2109375, 0.140625, 0.34765625, 0.29296875        ,
-0.19921875, 0.06640625, 0.359375, 0.3671875        ,
-0.32421875, 0.19921875, 0.359375, 0.234375         ,
-0.3046875, 0.171875, 0.37890625, 0.26171875        ,
-0.2734375, 0.140625, 0.41015625, 0.29296875        ,
-0.26171875, 0.06640625, 0.421875, 0.3671875        ,
-0.38671875, 0.19921875, 0.421875, 0.234375         ,
-0.3671875, 0.171875, 0.44140625, 0.26171875        ,
-0.3359375, 0.140625, 0.47265625, 0.29296875        ,
-0.32421875, 0.06640625, 0.484375, 0.3671875        ,
-0.44921875, 0.19921875, 0.484375, 0.234375         ,
-0.4296875, 0.171875, 0.50390625, 0.26171875        ,
-0.3984375, 0.140625, 0.53515625, 0.29296875        ,
-0.38671875, 0.06640625, 0.546875, 0.3671875        ,
-0.51171875, 0.19921875, 0.546875, 0.234375         ,
-0.4921875, 0.171875, 0.56640625, 0.26171875        ,
-0.4609375, 0.140625, 0.59765625, 0.29296875        ,
-0.44921875, 0.06640625, 0.609375, 0.3671875        ,
-0.57421875, 0.19921875, 0.609375, 0.234375         ,
-0.5546875, 0.171875, 0.62890625, 0.26171875        ,
-0.5234375, 0.140625, 0.66015625, 0.29296875        ,
-0.51171875, 0.06640625, 0.671875, 0.3671875        ,
-0.63671875, 0.19921875, 0.671875, 0.234375         ,
-0.6171875, 0.171875, 0.69140625, 0.26171875        ,
-0.5859375, 0.140625, 0.72265625, 0.29296875        ,
-0.57421875, 0.06640625, 0.734375, 0.3671875        ,
-0.69921875, 0.19921875, 0.734375, 0.234375         ,
-0.6796875, 0.171875, 0.75390625, 0.26171875        ,
-0.6484375, 0.140625, 0.78515625, 0.29296875        ,
-0.63671875, 0.06640625, 0.796875, 0.3671875        ,
-0.76171875, 0.19921875, 0.796875, 0.234375         ,
-0.7421875, 0.171875, 0.81640625, 0.26171875        ,
-0.7109375, 0.140625, 0.84765625, 0.29296875        ,
-0.69921875, 0.06640625, 0.859375, 0.3671875        ,
-0.82421875, 0.19921875, 0.859375, 0.234375         ,
-0.8046875, 0.171875, 0.87890625, 0.26171875        ,
-0.7734375, 0.140625, 0.91015625, 0.29296875        ,
-0.76171875, 0.06640625, 0.921875, 0.3671875        ,
-0.88671875, 0.19921875, 0.921875, 0.234375         ,
-0.8671875, 0.171875, 0.94140625, 0.26171875        ,
-0.8359375, 0.140625, 0.97265625, 0.29296875        ,
-0.82421875, 0.06640625, 0.984375, 0.3671875        ,
-0.94921875, 0.19921875, 0.984375, 0.234375         ,
-0.9296875, 0.171875, 1.0039062, 0.26171875         ,
-0.8984375, 0.140625, 1.0351562, 0.29296875         ,
-0.88671875, 0.06640625, 1.046875, 0.3671875        ,
-0.01171875, 0.26171875, 0.046875, 0.296875         ,
--0.00390625, 0.234375, 0.06640625, 0.32421875      ,
--0.03515625, 0.203125, 0.09765625, 0.35546875      ,
--0.046875, 0.12890625, 0.109375, 0.4296875         ,
-0.07421875, 0.26171875, 0.109375, 0.296875         ,
-0.0546875, 0.234375, 0.12890625, 0.32421875        ,
-0.0234375, 0.203125, 0.16015625, 0.35546875        ,
-0.01171875, 0.12890625, 0.171875, 0.4296875        ,
-0.13671875, 0.26171875, 0.171875, 0.296875         ,
-0.1171875, 0.234375, 0.19140625, 0.32421875        ,
-0.0859375, 0.203125, 0.22265625, 0.35546875        ,
-0.07421875, 0.12890625, 0.234375, 0.4296875        ,
-0.19921875, 0.26171875, 0.234375, 0.296875         ,
-0.1796875, 0.234375, 0.25390625, 0.32421875        ,
-0.1484375, 0.203125, 0.28515625, 0.35546875        ,
-0.13671875, 0.12890625, 0.296875, 0.4296875        ,
-0.26171875, 0.26171875, 0.296875, 0.296875         ,
-0.2421875, 0.234375, 0.31640625, 0.32421875        ,
-0.2109375, 0.203125, 0.34765625, 0.35546875        ,
-0.19921875, 0.12890625, 0.359375, 0.4296875        ,
-0.32421875, 0.26171875, 0.359375, 0.296875         ,
-0.3046875, 0.234375, 0.37890625, 0.32421875        ,
-0.2734375, 0.203125, 0.41015625, 0.35546875        ,
-0.26171875, 0.12890625, 0.421875, 0.4296875        ,
-0.38671875, 0.26171875, 0.421875, 0.296875         ,
-0.3671875, 0.234375, 0.44140625, 0.32421875        ,
-0.3359375, 0.203125, 0.47265625, 0.35546875        ,
-0.32421875, 0.12890625, 0.484375, 0.4296875        ,
-0.44921875, 0.26171875, 0.484375, 0.296875         ,
-0.4296875, 0.234375, 0.50390625, 0.32421875        ,
-0.3984375, 0.203125, 0.53515625, 0.35546875        ,
-0.38671875, 0.12890625, 0.546875, 0.4296875        ,
-0.51171875, 0.26171875, 0.546875, 0.296875         ,
-0.4921875, 0.234375, 0.56640625, 0.32421875        ,
-0.4609375, 0.203125, 0.59765625, 0.35546875        ,
-0.44921875, 0.12890625, 0.609375, 0.4296875        ,
-0.57421875, 0.26171875, 0.609375, 0.296875         ,
-0.5546875, 0.234375, 0.62890625, 0.32421875        ,
-0.5234375, 0.203125, 0.66015625, 0.35546875        ,
-0.51171875, 0.12890625, 0.671875, 0.4296875        ,
-0.63671875, 0.26171875, 0.671875, 0.296875         ,
-0.6171875, 0.234375, 0.69140625, 0.32421875        ,
-0.5859375, 0.203125, 0.72265625, 0.35546875        ,
-0.57421875, 0.12890625, 0.734375, 0.4296875        ,
-0.69921875, 0.26171875, 0.734375, 0.296875         ,
-0.6796875, 0.234375, 0.75390625, 0.32421875        ,
-0.6484375, 0.203125, 0.78515625, 0.35546875        ,
-0.63671875, 0.12890625, 0.796875, 0.4296875        ,
-0.76171875, 0.26171875, 0.796875, 0.296875         ,
-0.7421875, 0.234375, 0.81640625, 0.32421875        ,
-0.7109375, 0.203125, 0.84765625, 0.35546875        ,
-0.69921875, 0.12890625, 0.859375, 0.4296875        ,
-0.82421875, 0.26171875, 0.859375, 0.296875         ,
-0.8046875, 0.234375, 0.87890625, 0.32421875        ,
-0.7734375, 0.203125, 0.91015625, 0.35546875        ,
-0.76171875, 0.12890625, 0.921875, 0.4296875        ,
-0.88671875, 0.26171875, 0.921875, 0.296875         ,
-0.8671875, 0.234375, 0.94140625, 0.32421875        ,
-0.8359375, 0.203125, 0.97265625, 0.35546875        ,
-0.82421875, 0.12890625, 0.984375, 0.4296875        ,
-0.94921875, 0.26171875, 0.984375, 0.296875         ,
-0.9296875, 0.234375, 1.0039062, 0.32421875         ,
-0.8984375, 0.203125, 1.0351562, 0.35546875         ,
-0.88671875, 0.12890625, 1.046875, 0.4296875        ,
-0.01171875, 0.32421875, 0.046875, 0.359375         ,
--0.00390625, 0.296875, 0.06640625, 0.38671875      ,
--0.03515625, 0.265625, 0.09765625, 0.41796875      ,
--0.046875, 0.19140625, 0.109375, 0.4921875         ,
-0.07421875, 0.32421875, 0.109375, 0.359375         ,
-0.0546875, 0.296875, 0.12890625, 0.38671875        ,
-0.0234375, 0.265625, 0.16015625, 0.41796875        ,
-0.01171875, 0.19140625, 0.171875, 0.4921875        ,
-0.13671875, 0.32421875, 0.171875, 0.359375         ,
-0.1171875, 0.296875, 0.19140625, 0.38671875        ,
-0.0859375, 0.265625, 0.22265625, 0.41796875        ,
-0.07421875, 0.19140625, 0.234375, 0.4921875        ,
-0.19921875, 0.32421875, 0.234375, 0.359375         ,
-0.1796875, 0.296875, 0.25390625, 0.38671875        ,
-0.1484375, 0.265625, 0.28515625, 0.41796875        ,
-0.13671875, 0.19140625, 0.296875, 0.4921875        ,
-0.26171875, 0.32421875, 0.296875, 0.359375         ,
-0.2421875, 0.296875, 0.31640625, 0.38671875        ,
-0.2109375, 0.265625, 0.34765625, 0.41796875        ,
-0.19921875, 0.19140625, 0.359375, 0.4921875        ,
-0.32421875, 0.32421875, 0.359375, 0.359375         ,
-0.3046875, 0.296875, 0.37890625, 0.38671875        ,
-0.2734375, 0.265625, 0.41015625, 0.41796875        ,
-0.26171875, 0.19140625, 0.421875, 0.4921875        ,
-0.38671875, 0.32421875, 0.421875, 0.359375         ,
-0.3671875, 0.296875, 0.44140625, 0.38671875        ,
-0.3359375, 0.265625, 0.47265625, 0.41796875        ,
-0.32421875, 0.19140625, 0.484375, 0.4921875        ,
-0.44921875, 0.32421875, 0.484375, 0.359375         ,
-0.4296875, 0.296875, 0.50390625, 0.38671875        ,
-0.3984375, 0.265625, 0.53515625, 0.41796875        ,
-0.38671875, 0.19140625, 0.546875, 0.4921875        ,
-0.51171875, 0.32421875, 0.546875, 0.359375         ,
-0.4921875, 0.296875, 0.56640625, 0.38671875        ,
-0.4609375, 0.265625, 0.59765625, 0.41796875        ,
-0.44921875, 0.19140625, 0.609375, 0.4921875        ,
-0.57421875, 0.32421875, 0.609375, 0.359375         ,
-0.5546875, 0.296875, 0.62890625, 0.38671875        ,
-0.5234375, 0.265625, 0.66015625, 0.41796875        ,
-0.51171875, 0.19140625, 0.671875, 0.4921875        ,
-0.63671875, 0.32421875, 0.671875, 0.359375         ,
-0.6171875, 0.296875, 0.69140625, 0.38671875        ,
-0.5859375, 0.265625, 0.72265625, 0.41796875        ,
-0.57421875, 0.19140625, 0.734375, 0.4921875        ,
-0.69921875, 0.32421875, 0.734375, 0.359375         ,
-0.6796875, 0.296875, 0.75390625, 0.38671875        ,
-0.6484375, 0.265625, 0.78515625, 0.41796875        ,
-0.63671875, 0.19140625, 0.796875, 0.4921875        ,
-0.76171875, 0.32421875, 0.796875, 0.359375         ,
-0.7421875, 0.296875, 0.81640625, 0.38671875        ,
-0.7109375, 0.265625, 0.84765625, 0.41796875        ,
-0.69921875, 0.19140625, 0.859375, 0.4921875        ,
-0.82421875, 0.32421875, 0.859375, 0.359375         ,
-0.8046875, 0.296875, 0.87890625, 0.38671875        ,
-0.7734375, 0.265625, 0.91015625, 0.41796875        ,
-0.76171875, 0.19140625, 0.921875, 0.4921875        ,
-0.88671875, 0.32421875, 0.921875, 0.359375         ,
-0.8671875, 0.296875, 0.94140625, 0.38671875        ,
-0.8359375, 0.265625, 0.97265625, 0.41796875        ,
-0.82421875, 0.19140625, 0.984375, 0.4921875        ,
-0.94921875, 0.32421875, 0.984375, 0.359375         ,
-0.9296875, 0.296875, 1.0039062, 0.38671875         ,
-0.8984375, 0.265625, 1.0351562, 0.41796875         ,
-0.88671875, 0.19140625, 1.046875, 0.4921875        ,
-0.01171875, 0.38671875, 0.046875, 0.421875         ,
--0.00390625, 0.359375, 0.06640625, 0.44921875      ,
--0.03515625, 0.328125, 0.09765625, 0.48046875      ,
--0.046875, 0.25390625, 0.109375, 0.5546875         ,
-0.07421875, 0.38671875, 0.109375, 0.421875         ,
-0.0546875, 0.359375, 0.12890625, 0.44921875        ,
-0.0234375, 0.328125, 0.16015625, 0.48046875        ,
-0.01171875, 0.25390625, 0.171875, 0.5546875        ,
-0.13671875, 0.38671875, 0.171875, 0.421875         ,
-0.1171875, 0.359375, 0.19140625, 0.44921875        ,
-0.0859375, 0.328125, 0.22265625, 0.48046875        ,
-0.07421875, 0.25390625, 0.234375, 0.5546875        ,
-0.19921875, 0.38671875, 0.234375, 0.421875         ,
-0.1796875, 0.359375, 0.25390625, 0.44921875        ,
-0.1484375, 0.328125, 0.28515625, 0.48046875        ,
-0.13671875, 0.25390625, 0.296875, 0.5546875        ,
-0.26171875, 0.38671875, 0.296875, 0.421875         ,
-0.2421875, 0.359375, 0.31640625, 0.44921875        ,
-0.2109375, 0.328125, 0.34765625, 0.48046875        ,
-0.19921875, 0.25390625, 0.359375, 0.5546875        ,
-0.32421875, 0.38671875, 0.359375, 0.421875         ,
-0.3046875, 0.359375, 0.37890625, 0.44921875        ,
-0.2734375, 0.328125, 0.41015625, 0.48046875        ,
-0.26171875, 0.25390625, 0.421875, 0.5546875        ,
-0.38671875, 0.38671875, 0.421875, 0.421875         ,
-0.3671875, 0.359375, 0.44140625, 0.44921875        ,
-0.3359375, 0.328125, 0.47265625, 0.48046875        ,
-0.32421875, 0.25390625, 0.484375, 0.5546875        ,
-0.44921875, 0.38671875, 0.484375, 0.421875         ,
-0.4296875, 0.359375, 0.50390625, 0.44921875        ,
-0.3984375, 0.328125, 0.53515625, 0.48046875        ,
-0.38671875, 0.25390625, 0.546875, 0.5546875        ,
-0.51171875, 0.38671875, 0.546875, 0.421875         ,
-0.4921875, 0.359375, 0.56640625, 0.44921875        ,
-0.4609375, 0.328125, 0.59765625, 0.48046875        ,
-0.44921875, 0.25390625, 0.609375, 0.5546875        ,
-0.57421875, 0.38671875, 0.609375, 0.421875         ,
-0.5546875, 0.359375, 0.62890625, 0.44921875        ,
-0.5234375, 0.328125, 0.66015625, 0.48046875        ,
-0.51171875, 0.25390625, 0.671875, 0.5546875        ,
-0.63671875, 0.38671875, 0.671875, 0.421875         ,
-0.6171875, 0.359375, 0.69140625, 0.44921875        ,
-0.5859375, 0.328125, 0.72265625, 0.48046875        ,
-0.57421875, 0.25390625, 0.734375, 0.5546875        ,
-0.69921875, 0.38671875, 0.734375, 0.421875         ,
-0.6796875, 0.359375, 0.75390625, 0.44921875        ,
-0.6484375, 0.328125, 0.78515625, 0.48046875        ,
-0.63671875, 0.25390625, 0.796875, 0.5546875        ,
-0.76171875, 0.38671875, 0.796875, 0.421875         ,
-0.7421875, 0.359375, 0.81640625, 0.44921875        ,
-0.7109375, 0.328125, 0.84765625, 0.48046875        ,
-0.69921875, 0.25390625, 0.859375, 0.5546875        ,
-0.82421875, 0.38671875, 0.859375, 0.421875         ,
-0.8046875, 0.359375, 0.87890625, 0.44921875        ,
-0.7734375, 0.328125, 0.91015625, 0.48046875        ,
-0.76171875, 0.25390625, 0.921875, 0.5546875        ,
-0.88671875, 0.38671875, 0.921875, 0.421875         ,
-0.8671875, 0.359375, 0.94140625, 0.44921875        ,
-0.8359375, 0.328125, 0.97265625, 0.48046875        ,
-0.82421875, 0.25390625, 0.984375, 0.5546875        ,
-0.94921875, 0.38671875, 0.984375, 0.421875         ,
-0.9296875, 0.359375, 1.0039062, 0.44921875         ,
-0.8984375, 0.328125, 1.0351562, 0.48046875         ,
-0.88671875, 0.25390625, 1.046875, 0.5546875        ,
-0.01171875, 0.44921875, 0.046875, 0.484375         ,
--0.00390625, 0.421875, 0.06640625, 0.51171875      ,
--0.03515625, 0.390625, 0.09765625, 0.54296875      ,
--0.046875, 0.31640625, 0.109375, 0.6171875         ,
-0.07421875, 0.44921875, 0.109375, 0.484375         ,
-0.0546875, 0.421875, 0.12890625, 0.51171875        ,
-0.0234375, 0.390625, 0.16015625, 0.54296875        ,
-0.01171875, 0.31640625, 0.171875, 0.6171875        ,
-0.13671875, 0.44921875, 0.171875, 0.484375         ,
-0.1171875, 0.421875, 0.19140625, 0.51171875        ,
-0.0859375, 0.390625, 0.22265625, 0.54296875        ,
-0.07421875, 0.31640625, 0.234375, 0.6171875        ,
-0.19921875, 0.44921875, 0.234375, 0.484375         ,
-0.1796875, 0.421875, 0.25390625, 0.51171875        ,
-0.1484375, 0.390625, 0.28515625, 0.54296875        ,
-0.13671875, 0.31640625, 0.296875, 0.6171875        ,
-0.26171875, 0.44921875, 0.296875, 0.484375         ,
-0.2421875, 0.421875, 0.31640625, 0.51171875        ,
-0.2109375, 0.390625, 0.34765625, 0.54296875        ,
-0.19921875, 0.31640625, 0.359375, 0.6171875        ,
-0.32421875, 0.44921875, 0.359375, 0.484375         ,
-0.3046875, 0.421875, 0.37890625, 0.51171875        ,
-0.2734375, 0.390625, 0.41015625, 0.54296875        ,
-0.26171875, 0.31640625, 0.421875, 0.6171875        ,
-0.38671875, 0.44921875, 0.421875, 0.484375         ,
-0.3671875, 0.421875, 0.44140625, 0.51171875        ,
-0.3359375, 0.390625, 0.47265625, 0.54296875        ,
-0.32421875, 0.31640625, 0.484375, 0.6171875        ,
-0.44921875, 0.44921875, 0.484375, 0.484375         ,
-0.4296875, 0.421875, 0.50390625, 0.51171875        ,
-0.3984375, 0.390625, 0.53515625, 0.54296875        ,
-0.38671875, 0.31640625, 0.546875, 0.6171875        ,
-0.51171875, 0.44921875, 0.546875, 0.484375         ,
-0.4921875, 0.421875, 0.56640625, 0.51171875        ,
-0.4609375, 0.390625, 0.59765625, 0.54296875        ,
-0.44921875, 0.31640625, 0.609375, 0.6171875        ,
-0.57421875, 0.44921875, 0.609375, 0.484375         ,
-0.5546875, 0.421875, 0.62890625, 0.51171875        ,
-0.5234375, 0.390625, 0.66015625, 0.54296875        ,
-0.51171875, 0.31640625, 0.671875, 0.6171875        ,
-0.63671875, 0.44921875, 0.671875, 0.484375         ,
-0.6171875, 0.421875, 0.69140625, 0.51171875        ,
-0.5859375, 0.390625, 0.72265625, 0.54296875        ,
-0.57421875, 0.31640625, 0.734375, 0.6171875        ,
-0.69921875, 0.44921875, 0.734375, 0.484375         ,
-0.6796875, 0.421875, 0.75390625, 0.51171875        ,
-0.6484375, 0.390625, 0.78515625, 0.54296875        ,
-0.63671875, 0.31640625, 0.796875, 0.6171875        ,
-0.76171875, 0.44921875, 0.796875, 0.484375         ,
-0.7421875, 0.421875, 0.81640625, 0.51171875        ,
-0.7109375, 0.390625, 0.84765625, 0.54296875        ,
-0.69921875, 0.31640625, 0.859375, 0.6171875        ,
-0.82421875, 0.44921875, 0.859375, 0.484375         ,
-0.8046875, 0.421875, 0.87890625, 0.51171875        ,
-0.7734375, 0.390625, 0.91015625, 0.54296875        ,
-0.76171875, 0.31640625, 0.921875, 0.6171875        ,
-0.88671875, 0.44921875, 0.921875, 0.484375         ,
-0.8671875, 0.421875, 0.94140625, 0.51171875        ,
-0.8359375, 0.390625, 0.97265625, 0.54296875        ,
-0.82421875, 0.31640625, 0.984375, 0.6171875        ,
-0.94921875, 0.44921875, 0.984375, 0.484375         ,
-0.9296875, 0.421875, 1.0039062, 0.51171875         ,
-0.8984375, 0.390625, 1.0351562, 0.54296875         ,
-0.88671875, 0.31640625, 1.046875, 0.6171875        ,
-0.01171875, 0.51171875, 0.046875, 0.546875         ,
--0.00390625, 0.484375, 0.06640625, 0.57421875      ,
--0.03515625, 0.453125, 0.09765625, 0.60546875      ,
--0.046875, 0.37890625, 0.109375, 0.6796875         ,
-0.07421875, 0.51171875, 0.109375, 0.546875         ,
-0.0546875, 0.484375, 0.12890625, 0.57421875        ,
-0.0234375, 0.453125, 0.16015625, 0.60546875        ,
-0.01171875, 0.37890625, 0.171875, 0.6796875        ,
-0.13671875, 0.51171875, 0.171875, 0.546875         ,
-0.1171875, 0.484375, 0.19140625, 0.57421875        ,
-0.0859375, 0.453125, 0.22265625, 0.60546875        ,
-0.07421875, 0.37890625, 0.234375, 0.6796875        ,
-0.19921875, 0.51171875, 0.234375, 0.546875         ,
-0.1796875, 0.484375, 0.25390625, 0.57421875        ,
-0.1484375, 0.453125, 0.28515625, 0.60546875        ,
-0.13671875, 0.37890625, 0.296875, 0.6796875        ,
-0.26171875, 0.51171875, 0.296875, 0.546875         ,
-0.2421875, 0.484375, 0.31640625, 0.57421875        ,
-0.2109375, 0.453125, 0.34765625, 0.60546875        ,
-0.19921875, 0.37890625, 0.359375, 0.6796875        ,
-0.32421875, 0.51171875, 0.359375, 0.546875         ,
-0.3046875, 0.484375, 0.37890625, 0.57421875        ,
-0.2734375, 0.453125, 0.41015625, 0.60546875        ,
-0.26171875, 0.37890625, 0.421875, 0.6796875        ,
-0.38671875, 0.51171875, 0.421875, 0.546875         ,
-0.3671875, 0.484375, 0.44140625, 0.57421875        ,
-0.3359375, 0.453125, 0.47265625, 0.60546875        ,
-0.32421875, 0.37890625, 0.484375, 0.6796875        ,
-0.44921875, 0.51171875, 0.484375, 0.546875         ,
-0.4296875, 0.484375, 0.50390625, 0.57421875        ,
-0.3984375, 0.453125, 0.53515625, 0.60546875        ,
-0.38671875, 0.37890625, 0.546875, 0.6796875        ,
-0.51171875, 0.51171875, 0.546875, 0.546875         ,
-0.4921875, 0.484375, 0.56640625, 0.57421875        ,
-0.4609375, 0.453125, 0.59765625, 0.60546875        ,
-0.44921875, 0.37890625, 0.609375, 0.6796875        ,
-0.57421875, 0.51171875, 0.609375, 0.546875         ,
-0.5546875, 0.484375, 0.62890625, 0.57421875        ,
-0.5234375, 0.453125, 0.66015625, 0.60546875        ,
-0.51171875, 0.37890625, 0.671875, 0.6796875        ,
-0.63671875, 0.51171875, 0.671875, 0.546875         ,
-0.6171875, 0.484375, 0.69140625, 0.57421875        ,
-0.5859375, 0.453125, 0.72265625, 0.60546875        ,
-0.57421875, 0.37890625, 0.734375, 0.6796875        ,
-0.69921875, 0.51171875, 0.734375, 0.546875         ,
-0.6796875, 0.484375, 0.75390625, 0.57421875        ,
-0.6484375, 0.453125, 0.78515625, 0.60546875        ,
-0.63671875, 0.37890625, 0.796875, 0.6796875        ,
-0.76171875, 0.51171875, 0.796875, 0.546875         ,
-0.7421875, 0.484375, 0.81640625, 0.57421875        ,
-0.7109375, 0.453125, 0.84765625, 0.60546875        ,
-0.69921875, 0.37890625, 0.859375, 0.6796875        ,
-0.82421875, 0.51171875, 0.859375, 0.546875         ,
-0.8046875, 0.484375, 0.87890625, 0.57421875        ,
-0.7734375, 0.453125, 0.91015625, 0.60546875        ,
-0.76171875, 0.37890625, 0.921875, 0.6796875        ,
-0.88671875, 0.51171875, 0.921875, 0.546875         ,
-0.8671875, 0.484375, 0.94140625, 0.57421875        ,
-0.8359375, 0.453125, 0.97265625, 0.60546875        ,
-0.82421875, 0.37890625, 0.984375, 0.6796875        ,
-0.94921875, 0.51171875, 0.984375, 0.546875         ,
-0.9296875, 0.484375, 1.0039062, 0.57421875         ,
-0.8984375, 0.453125, 1.0351562, 0.60546875         ,
-0.88671875, 0.37890625, 1.046875, 0.6796875        ,
-0.01171875, 0.57421875, 0.046875, 0.609375         ,
--0.00390625, 0.546875, 0.06640625, 0.63671875      ,
--0.03515625, 0.515625, 0.09765625, 0.66796875      ,
--0.046875, 0.44140625, 0.109375, 0.7421875         ,
-0.07421875, 0.57421875, 0.109375, 0.609375         ,
-0.0546875, 0.546875, 0.12890625, 0.63671875        ,
-0.0234375, 0.515625, 0.16015625, 0.66796875        ,
-0.01171875, 0.44140625, 0.171875, 0.7421875        ,
-0.13671875, 0.57421875, 0.171875, 0.609375         ,
-0.1171875, 0.546875, 0.19140625, 0.63671875        ,
-0.0859375, 0.515625, 0.22265625, 0.66796875        ,
-0.07421875, 0.44140625, 0.234375, 0.7421875        ,
-0.19921875, 0.57421875, 0.234375, 0.609375         ,
-0.1796875, 0.546875, 0.25390625, 0.63671875        ,
-0.1484375, 0.515625, 0.28515625, 0.66796875        ,
-0.13671875, 0.44140625, 0.296875, 0.7421875        ,
-0.26171875, 0.57421875, 0.296875, 0.609375         ,
-0.2421875, 0.546875, 0.31640625, 0.63671875        ,
-0.2109375, 0.515625, 0.34765625, 0.66796875        ,
-0.19921875, 0.44140625, 0.359375, 0.7421875        ,
-0.32421875, 0.57421875, 0.359375, 0.609375         ,
-0.3046875, 0.546875, 0.37890625, 0.63671875        ,
-0.2734375, 0.515625, 0.41015625, 0.66796875        ,
-0.26171875, 0.44140625, 0.421875, 0.7421875        ,
-0.38671875, 0.57421875, 0.421875, 0.609375         ,
-0.3671875, 0.546875, 0.44140625, 0.63671875        ,
-0.3359375, 0.515625, 0.47265625, 0.66796875        ,
-0.32421875, 0.44140625, 0.484375, 0.7421875        ,
-0.44921875, 0.57421875, 0.484375, 0.609375         ,
-0.4296875, 0.546875, 0.50390625, 0.63671875        ,
-0.3984375, 0.515625, 0.53515625, 0.66796875        ,
-0.38671875, 0.44140625, 0.546875, 0.7421875        ,
-0.51171875, 0.57421875, 0.546875, 0.609375         ,
-0.4921875, 0.546875, 0.56640625, 0.63671875        ,
-0.4609375, 0.515625, 0.59765625, 0.66796875        ,
-0.44921875, 0.44140625, 0.609375, 0.7421875        ,
-0.57421875, 0.57421875, 0.609375, 0.609375         ,
-0.5546875, 0.546875, 0.62890625, 0.63671875        ,
-0.5234375, 0.515625, 0.66015625, 0.66796875        ,
-0.51171875, 0.44140625, 0.671875, 0.7421875        ,
-0.63671875, 0.57421875, 0.671875, 0.609375         ,
-0.6171875, 0.546875, 0.69140625, 0.63671875        ,
-0.5859375, 0.515625, 0.72265625, 0.66796875        ,
-0.57421875, 0.44140625, 0.734375, 0.7421875        ,
-0.69921875, 0.57421875, 0.734375, 0.609375         ,
-0.6796875, 0.546875, 0.75390625, 0.63671875        ,
-0.6484375, 0.515625, 0.78515625, 0.66796875        ,
-0.63671875, 0.44140625, 0.796875, 0.7421875        ,
-0.76171875, 0.57421875, 0.796875, 0.609375         ,
-0.7421875, 0.546875, 0.81640625, 0.63671875        ,
-0.7109375, 0.515625, 0.84765625, 0.66796875        ,
-0.69921875, 0.44140625, 0.859375, 0.7421875        ,
-0.82421875, 0.57421875, 0.859375, 0.609375         ,
-0.8046875, 0.546875, 0.87890625, 0.63671875        ,
-0.7734375, 0.515625, 0.91015625, 0.66796875        ,
-0.76171875, 0.44140625, 0.921875, 0.7421875        ,
-0.88671875, 0.57421875, 0.921875, 0.609375         ,
-0.8671875, 0.546875, 0.94140625, 0.63671875        ,
-0.8359375, 0.515625, 0.97265625, 0.66796875        ,
-0.82421875, 0.44140625, 0.984375, 0.7421875        ,
-0.94921875, 0.57421875, 0.984375, 0.609375         ,
-0.9296875, 0.546875, 1.0039062, 0.63671875         ,
-0.8984375, 0.515625, 1.0351562, 0.66796875         ,
-0.88671875, 0.44140625, 1.046875, 0.7421875        ,
-0.01171875, 0.63671875, 0.046875, 0.671875         ,
--0.00390625, 0.609375, 0.06640625, 0.69921875      ,
--0.03515625, 0.578125, 0.09765625, 0.73046875      ,
--0.046875, 0.50390625, 0.109375, 0.8046875         ,
-0.07421875, 0.63671875, 0.109375, 0.671875         ,
-0.0546875, 0.609375, 0.12890625, 0.69921875        ,
-0.0234375, 0.578125, 0.16015625, 0.73046875        ,
-0.01171875, 0.50390625, 0.171875, 0.8046875        ,
-0.13671875, 0.63671875, 0.171875, 0.671875         ,
-0.1171875, 0.609375, 0.19140625, 0.69921875        ,
-0.0859375, 0.578125, 0.22265625, 0.73046875        ,
-0.07421875, 0.50390625, 0.234375, 0.8046875        ,
-0.19921875, 0.63671875, 0.234375, 0.671875         ,
-0.1796875, 0.609375, 0.25390625, 0.69921875        ,
-0.1484375, 0.578125, 0.28515625, 0.73046875        ,
-0.13671875, 0.50390625, 0.296875, 0.8046875        ,
-0.26171875, 0.63671875, 0.296875, 0.671875         ,
-0.2421875, 0.609375, 0.31640625, 0.69921875        ,
-0.2109375, 0.578125, 0.34765625, 0.73046875        ,
-0.19921875, 0.50390625, 0.359375, 0.8046875        ,
-0.32421875, 0.63671875, 0.359375, 0.671875         ,
-0.3046875, 0.609375, 0.37890625, 0.69921875        ,
-0.2734375, 0.578125, 0.41015625, 0.73046875        ,
-0.26171875, 0.50390625, 0.421875, 0.8046875        ,
-0.38671875, 0.63671875, 0.421875, 0.671875         ,
-0.3671875, 0.609375, 0.44140625, 0.69921875        ,
-0.3359375, 0.578125, 0.47265625, 0.73046875        ,
-0.32421875, 0.50390625, 0.484375, 0.8046875        ,
-0.44921875, 0.63671875, 0.484375, 0.671875         ,
-0.4296875, 0.609375, 0.50390625, 0.69921875        ,
-0.3984375, 0.578125, 0.53515625, 0.73046875        ,
-0.38671875, 0.50390625, 0.546875, 0.8046875        ,
-0.51171875, 0.63671875, 0.546875, 0.671875         ,
-0.4921875, 0.609375, 0.56640625, 0.69921875        ,
-0.4609375, 0.578125, 0.59765625, 0.73046875        ,
-0.44921875, 0.50390625, 0.609375, 0.8046875        ,
-0.57421875, 0.63671875, 0.609375, 0.671875         ,
-0.5546875, 0.609375, 0.62890625, 0.69921875        ,
-0.5234375, 0.578125, 0.66015625, 0.73046875        ,
-0.51171875, 0.50390625, 0.671875, 0.8046875        ,
-0.63671875, 0.63671875, 0.671875, 0.671875         ,
-0.6171875, 0.609375, 0.69140625, 0.69921875        ,
-0.5859375, 0.578125, 0.72265625, 0.73046875        ,
-0.57421875, 0.50390625, 0.734375, 0.8046875        ,
-0.69921875, 0.63671875, 0.734375, 0.671875         ,
-0.6796875, 0.609375, 0.75390625, 0.69921875        ,
-0.6484375, 0.578125, 0.78515625, 0.73046875        ,
-0.63671875, 0.50390625, 0.796875, 0.8046875        ,
-0.76171875, 0.63671875, 0.796875, 0.671875         ,
-0.7421875, 0.609375, 0.81640625, 0.69921875        ,
-0.7109375, 0.578125, 0.84765625, 0.73046875        ,
-0.69921875, 0.50390625, 0.859375, 0.8046875        ,
-0.82421875, 0.63671875, 0.859375, 0.671875         ,
-0.8046875, 0.609375, 0.87890625, 0.69921875        ,
-0.7734375, 0.578125, 0.91015625, 0.73046875        ,
-0.76171875, 0.50390625, 0.921875, 0.8046875        ,
-0.88671875, 0.63671875, 0.921875, 0.671875         ,
-0.8671875, 0.609375, 0.94140625, 0.69921875        ,
-0.8359375, 0.578125, 0.97265625, 0.73046875        ,
-0.82421875, 0.50390625, 0.984375, 0.8046875        ,
-0.94921875, 0.63671875, 0.984375, 0.671875         ,
-0.9296875, 0.609375, 1.0039062, 0.69921875         ,
-0.8984375, 0.578125, 1.0351562, 0.73046875         ,
-0.88671875, 0.50390625, 1.046875, 0.8046875        ,
-0.01171875, 0.69921875, 0.046875, 0.734375         ,
--0.00390625, 0.671875, 0.06640625, 0.76171875      ,
--0.03515625, 0.640625, 0.09765625, 0.79296875      ,
--0.046875, 0.56640625, 0.109375, 0.8671875         ,
-0.07421875, 0.69921875, 0.109375, 0.734375         ,
-0.0546875, 0.671875, 0.12890625, 0.76171875        ,
-0.0234375, 0.640625, 0.16015625, 0.79296875        ,
-0.01171875, 0.56640625, 0.171875, 0.8671875        ,
-0.13671875, 0.69921875, 0.171875, 0.734375         ,
-0.1171875, 0.671875, 0.19140625, 0.76171875        ,
-0.0859375, 0.640625, 0.22265625, 0.79296875        ,
-0.07421875, 0.56640625, 0.234375, 0.8671875        ,
-0.19921875, 0.69921875, 0.234375, 0.734375         ,
-0.1796875, 0.671875, 0.25390625, 0.76171875        ,
-0.1484375, 0.640625, 0.28515625, 0.79296875        ,
-0.13671875, 0.56640625, 0.296875, 0.8671875        ,
-0.26171875, 0.69921875, 0.296875, 0.734375         ,
-0.2421875, 0.671875, 0.31640625, 0.76171875        ,
-0.2109375, 0.640625, 0.34765625, 0.79296875        ,
-0.19921875, 0.56640625, 0.359375, 0.8671875        ,
-0.32421875, 0.69921875, 0.359375, 0.734375         ,
-0.3046875, 0.671875, 0.37890625, 0.76171875        ,
-0.2734375, 0.640625, 0.41015625, 0.79296875        ,
-0.26171875, 0.56640625, 0.421875, 0.8671875        ,
-0.38671875, 0.69921875, 0.421875, 0.734375         ,
-0.3671875, 0.671875, 0.44140625, 0.76171875        ,
-0.3359375, 0.640625, 0.47265625, 0.79296875        ,
-0.32421875, 0.56640625, 0.484375, 0.8671875        ,
-0.44921875, 0.69921875, 0.484375, 0.734375         ,
-0.4296875, 0.671875, 0.50390625, 0.76171875        ,
-0.3984375, 0.640625, 0.53515625, 0.79296875        ,
-0.38671875, 0.56640625, 0.546875, 0.8671875        ,
-0.51171875, 0.69921875, 0.546875, 0.734375         ,
-0.4921875, 0.671875, 0.56640625, 0.76171875        ,
-0.4609375, 0.640625, 0.59765625, 0.79296875        ,
-0.44921875, 0.56640625, 0.609375, 0.8671875        ,
-0.57421875, 0.69921875, 0.609375, 0.734375         ,
-0.5546875, 0.671875, 0.62890625, 0.76171875        ,
-0.5234375, 0.640625, 0.66015625, 0.79296875        ,
-0.51171875, 0.56640625, 0.671875, 0.8671875        ,
-0.63671875, 0.69921875, 0.671875, 0.734375         ,
-0.6171875, 0.671875, 0.69140625, 0.76171875        ,
-0.5859375, 0.640625, 0.72265625, 0.79296875        ,
-0.57421875, 0.56640625, 0.734375, 0.8671875        ,
-0.69921875, 0.69921875, 0.734375, 0.734375         ,
-0.6796875, 0.671875, 0.75390625, 0.76171875        ,
-0.6484375, 0.640625, 0.78515625, 0.79296875        ,
-0.63671875, 0.56640625, 0.796875, 0.8671875        ,
-0.76171875, 0.69921875, 0.796875, 0.734375         ,
-0.7421875, 0.671875, 0.81640625, 0.76171875        ,
-0.7109375, 0.640625, 0.84765625, 0.79296875        ,
-0.69921875, 0.56640625, 0.859375, 0.8671875        ,
-0.82421875, 0.69921875, 0.859375, 0.734375         ,
-0.8046875, 0.671875, 0.87890625, 0.76171875        ,
-0.7734375, 0.640625, 0.91015625, 0.79296875        ,
-0.76171875, 0.56640625, 0.921875, 0.8671875        ,
-0.88671875, 0.69921875, 0.921875, 0.734375         ,
-0.8671875, 0.671875, 0.94140625, 0.76171875        ,
-0.8359375, 0.640625, 0.97265625, 0.79296875        ,
-0.82421875, 0.56640625, 0.984375, 0.8671875        ,
-0.94921875, 0.69921875, 0.984375, 0.734375         ,
-0.9296875, 0.671875, 1.0039062, 0.76171875         ,
-0.8984375, 0.640625, 1.0351562, 0.79296875         ,
-0.88671875, 0.56640625, 1.046875, 0.8671875        ,
-0.01171875, 0.76171875, 0.046875, 0.796875         ,
--0.00390625, 0.734375, 0.06640625, 0.82421875      ,
--0.03515625, 0.703125, 0.09765625, 0.85546875      ,
--0.046875, 0.62890625, 0.109375, 0.9296875         ,
-0.07421875, 0.76171875, 0.109375, 0.796875         ,
-0.0546875, 0.734375, 0.12890625, 0.82421875        ,
-0.0234375, 0.703125, 0.16015625, 0.85546875        ,
-0.01171875, 0.62890625, 0.171875, 0.9296875        ,
-0.13671875, 0.76171875, 0.171875, 0.796875         ,
-0.1171875, 0.734375, 0.19140625, 0.82421875        ,
-0.0859375, 0.703125, 0.22265625, 0.85546875        ,
-0.07421875, 0.62890625, 0.234375, 0.9296875        ,
-0.19921875, 0.76171875, 0.234375, 0.796875         ,
-0.1796875, 0.734375, 0.25390625, 0.82421875        ,
-0.1484375, 0.703125, 0.28515625, 0.85546875        ,
-0.13671875, 0.62890625, 0.296875, 0.9296875        ,
-0.26171875, 0.76171875, 0.296875, 0.796875         ,
-0.2421875, 0.734375, 0.31640625, 0.82421875        ,
-0.2109375, 0.703125, 0.34765625, 0.85546875        ,
-0.19921875, 0.62890625, 0.359375, 0.9296875        ,
-0.32421875, 0.76171875, 0.359375, 0.796875         ,
-0.3046875, 0.734375, 0.37890625, 0.82421875        ,
-0.2734375, 0.703125, 0.41015625, 0.85546875        ,
-0.26171875, 0.62890625, 0.421875, 0.9296875        ,
-0.38671875, 0.76171875, 0.421875, 0.796875         ,
-0.3671875, 0.734375, 0.44140625, 0.82421875        ,
-0.3359375, 0.703125, 0.47265625, 0.85546875        ,
-0.32421875, 0.62890625, 0.484375, 0.9296875        ,
-0.44921875, 0.76171875, 0.484375, 0.796875         ,
-0.4296875, 0.734375, 0.50390625, 0.82421875        ,
-0.3984375, 0.703125, 0.53515625, 0.85546875        ,
-0.38671875, 0.62890625, 0.546875, 0.9296875        ,
-0.51171875, 0.76171875, 0.546875, 0.796875         ,
-0.4921875, 0.734375, 0.56640625, 0.82421875        ,
-0.4609375, 0.703125, 0.59765625, 0.85546875        ,
-0.44921875, 0.62890625, 0.609375, 0.9296875        ,
-0.57421875, 0.76171875, 0.609375, 0.796875         ,
-0.5546875, 0.734375, 0.62890625, 0.82421875        ,
-0.5234375, 0.703125, 0.66015625, 0.85546875        ,
-0.51171875, 0.62890625, 0.671875, 0.9296875        ,
-0.63671875, 0.76171875, 0.671875, 0.796875         ,
-0.6171875, 0.734375, 0.69140625, 0.82421875        ,
-0.5859375, 0.703125, 0.72265625, 0.85546875        ,
-0.57421875, 0.62890625, 0.734375, 0.9296875        ,
-0.69921875, 0.76171875, 0.734375, 0.796875         ,
-0.6796875, 0.734375, 0.75390625, 0.82421875        ,
-0.6484375, 0.703125, 0.78515625, 0.85546875        ,
-0.63671875, 0.62890625, 0.796875, 0.9296875        ,
-0.76171875, 0.76171875, 0.796875, 0.796875         ,
-0.7421875, 0.734375, 0.81640625, 0.82421875        ,
-0.7109375, 0.703125, 0.84765625, 0.85546875        ,
-0.69921875, 0.62890625, 0.859375, 0.9296875        ,
-0.82421875, 0.76171875, 0.859375, 0.796875         ,
-0.8046875, 0.734375, 0.87890625, 0.82421875        ,
-0.7734375, 0.703125, 0.91015625, 0.85546875        ,
-0.76171875, 0.62890625, 0.921875, 0.9296875        ,
-0.88671875, 0.76171875, 0.921875, 0.796875         ,
-0.8671875, 0.734375, 0.94140625, 0.82421875        ,
-0.8359375, 0.703125, 0.97265625, 0.85546875        ,
-0.82421875, 0.62890625, 0.984375, 0.9296875        ,
-0.94921875, 0.76171875, 0.984375, 0.796875         ,
-0.9296875, 0.734375, 1.0039062, 0.82421875         ,
-0.8984375, 0.703125, 1.0351562, 0.85546875         ,
-0.88671875, 0.62890625, 1.046875, 0.9296875        ,
-0.01171875, 0.82421875, 0.046875, 0.859375         ,
--0.00390625, 0.796875, 0.06640625, 0.88671875      ,
--0.03515625, 0.765625, 0.09765625, 0.91796875      ,
--0.046875, 0.69140625, 0.109375, 0.9921875         ,
-0.07421875, 0.82421875, 0.109375, 0.859375         ,
-0.0546875, 0.796875, 0.12890625, 0.88671875        ,
-0.0234375, 0.765625, 0.16015625, 0.91796875        ,
-0.01171875, 0.69140625, 0.171875, 0.9921875        ,
-0.13671875, 0.82421875, 0.171875, 0.859375         ,
-0.1171875, 0.796875, 0.19140625, 0.88671875        ,
-0.0859375, 0.765625, 0.22265625, 0.91796875        ,
-0.07421875, 0.69140625, 0.234375, 0.9921875        ,
-0.19921875, 0.82421875, 0.234375, 0.859375         ,
-0.1796875, 0.796875, 0.25390625, 0.88671875        ,
-0.1484375, 0.765625, 0.28515625, 0.91796875        ,
-0.13671875, 0.69140625, 0.296875, 0.9921875        ,
-0.26171875, 0.82421875, 0.296875, 0.859375         ,
-0.2421875, 0.796875, 0.31640625, 0.88671875        ,
-0.2109375, 0.765625, 0.34765625, 0.91796875        ,
-0.19921875, 0.69140625, 0.359375, 0.9921875        ,
-0.32421875, 0.82421875, 0.359375, 0.859375         ,
-0.3046875, 0.796875, 0.37890625, 0.88671875        ,
-0.2734375, 0.765625, 0.41015625, 0.91796875        ,
-0.26171875, 0.69140625, 0.421875, 0.9921875        ,
-0.38671875, 0.82421875, 0.421875, 0.859375         ,
-0.3671875, 0.796875, 0.44140625, 0.88671875        ,
-0.3359375, 0.765625, 0.47265625, 0.91796875        ,
-0.32421875, 0.69140625, 0.484375, 0.9921875        ,
-0.44921875, 0.82421875, 0.484375, 0.859375         ,
-0.4296875, 0.796875, 0.50390625, 0.88671875        ,
-0.3984375, 0.765625, 0.53515625, 0.91796875        ,
-0.38671875, 0.69140625, 0.546875, 0.9921875        ,
-0.51171875, 0.82421875, 0.546875, 0.859375         ,
-0.4921875, 0.796875, 0.56640625, 0.88671875        ,
-0.4609375, 0.765625, 0.59765625, 0.91796875        ,
-0.44921875, 0.69140625, 0.609375, 0.9921875        ,
-0.57421875, 0.82421875, 0.609375, 0.859375         ,
-0.5546875, 0.796875, 0.62890625, 0.88671875        ,
-0.5234375, 0.765625, 0.66015625, 0.91796875        ,
-0.51171875, 0.69140625, 0.671875, 0.9921875        ,
-0.63671875, 0.82421875, 0.671875, 0.859375         ,
-0.6171875, 0.796875, 0.69140625, 0.88671875        ,
-0.5859375, 0.765625, 0.72265625, 0.91796875        ,
-0.57421875, 0.69140625, 0.734375, 0.9921875        ,
-0.69921875, 0.82421875, 0.734375, 0.859375         ,
-0.6796875, 0.796875, 0.75390625, 0.88671875        ,
-0.6484375, 0.765625, 0.78515625, 0.91796875        ,
-0.63671875, 0.69140625, 0.796875, 0.9921875        ,
-0.76171875, 0.82421875, 0.796875, 0.859375         ,
-0.7421875, 0.796875, 0.81640625, 0.88671875        ,
-0.7109375, 0.765625, 0.84765625, 0.91796875        ,
-0.69921875, 0.69140625, 0.859375, 0.9921875        ,
-0.82421875, 0.82421875, 0.859375, 0.859375         ,
-0.8046875, 0.796875, 0.87890625, 0.88671875        ,
-0.7734375, 0.765625, 0.91015625, 0.91796875        ,
-0.76171875, 0.69140625, 0.921875, 0.9921875        ,
-0.88671875, 0.82421875, 0.921875, 0.859375         ,
-0.8671875, 0.796875, 0.94140625, 0.88671875        ,
-0.8359375, 0.765625, 0.97265625, 0.91796875        ,
-0.82421875, 0.69140625, 0.984375, 0.9921875        ,
-0.94921875, 0.82421875, 0.984375, 0.859375         ,
-0.9296875, 0.796875, 1.0039062, 0.88671875         ,
-0.8984375, 0.765625, 1.0351562, 0.91796875         ,
-0.88671875, 0.69140625, 1.046875, 0.9921875        ,
-0.01171875, 0.88671875, 0.046875, 0.921875         ,
--0.00390625, 0.859375, 0.06640625, 0.94921875      ,
--0.03515625, 0.828125, 0.09765625, 0.98046875      ,
--0.046875, 0.75390625, 0.109375, 1.0546875         ,
-0.07421875, 0.88671875, 0.109375, 0.921875         ,
-0.0546875, 0.859375, 0.12890625, 0.94921875        ,
-0.0234375, 0.828125, 0.16015625, 0.98046875        ,
-0.01171875, 0.75390625, 0.171875, 1.0546875        ,
-0.13671875, 0.88671875, 0.171875, 0.921875         ,
-0.1171875, 0.859375, 0.19140625, 0.94921875        ,
-0.0859375, 0.828125, 0.22265625, 0.98046875        ,
-0.07421875, 0.75390625, 0.234375, 1.0546875        ,
-0.19921875, 0.88671875, 0.234375, 0.921875         ,
-0.1796875, 0.859375, 0.25390625, 0.94921875        ,
-0.1484375, 0.828125, 0.28515625, 0.98046875        ,
-0.13671875, 0.75390625, 0.296875, 1.0546875        ,
-0.26171875, 0.88671875, 0.296875, 0.921875         ,
-0.2421875, 0.859375, 0.31640625, 0.94921875        ,
-0.2109375, 0.828125, 0.34765625, 0.98046875        ,
-0.19921875, 0.75390625, 0.359375, 1.0546875        ,
-0.32421875, 0.88671875, 0.359375, 0.921875         ,
-0.3046875, 0.859375, 0.37890625, 0.94921875        ,
-0.2734375, 0.828125, 0.41015625, 0.98046875        ,
-0.26171875, 0.75390625, 0.421875, 1.0546875        ,
-0.38671875, 0.88671875, 0.421875, 0.921875         ,
-0.3671875, 0.859375, 0.44140625, 0.94921875        ,
-0.3359375, 0.828125, 0.47265625, 0.98046875        ,
-0.32421875, 0.75390625, 0.484375, 1.0546875        ,
-0.44921875, 0.88671875, 0.484375, 0.921875         ,
-0.4296875, 0.859375, 0.50390625, 0.94921875        ,
-0.3984375, 0.828125, 0.53515625, 0.98046875        ,
-0.38671875, 0.75390625, 0.546875, 1.0546875        ,
-0.51171875, 0.88671875, 0.546875, 0.921875         ,
-0.4921875, 0.859375, 0.56640625, 0.94921875        ,
-0.4609375, 0.828125, 0.59765625, 0.98046875        ,
-0.44921875, 0.75390625, 0.609375, 1.0546875        ,
-0.57421875, 0.88671875, 0.609375, 0.921875         ,
-0.5546875, 0.859375, 0.62890625, 0.94921875        ,
-0.5234375, 0.828125, 0.66015625, 0.98046875        ,
-0.51171875, 0.75390625, 0.671875, 1.0546875        ,
-0.63671875, 0.88671875, 0.671875, 0.921875         ,
-0.6171875, 0.859375, 0.69140625, 0.94921875        ,
-0.5859375, 0.828125, 0.72265625, 0.98046875        ,
-0.57421875, 0.75390625, 0.734375, 1.0546875        ,
-0.69921875, 0.88671875, 0.734375, 0.921875         ,
-0.6796875, 0.859375, 0.75390625, 0.94921875        ,
-0.6484375, 0.828125, 0.78515625, 0.98046875        ,
-0.63671875, 0.75390625, 0.796875, 1.0546875        ,
-0.76171875, 0.88671875, 0.796875, 0.921875         ,
-0.7421875, 0.859375, 0.81640625, 0.94921875        ,
-0.7109375, 0.828125, 0.84765625, 0.98046875        ,
-0.69921875, 0.75390625, 0.859375, 1.0546875        ,
-0.82421875, 0.88671875, 0.859375, 0.921875         ,
-0.8046875, 0.859375, 0.87890625, 0.94921875        ,
-0.7734375, 0.828125, 0.91015625, 0.98046875        ,
-0.76171875, 0.75390625, 0.921875, 1.0546875        ,
-0.88671875, 0.88671875, 0.921875, 0.921875         ,
-0.8671875, 0.859375, 0.94140625, 0.94921875        ,
-0.8359375, 0.828125, 0.97265625, 0.98046875        ,
-0.82421875, 0.75390625, 0.984375, 1.0546875        ,
-0.94921875, 0.88671875, 0.984375, 0.921875         ,
-0.9296875, 0.859375, 1.0039062, 0.94921875         ,
-0.8984375, 0.828125, 1.0351562, 0.98046875         ,
-0.88671875, 0.75390625, 1.046875, 1.0546875        ,
-0.01171875, 0.94921875, 0.046875, 0.984375         ,
--0.00390625, 0.921875, 0.06640625, 1.0117188       ,
--0.03515625, 0.890625, 0.09765625, 1.0429688       ,
--0.046875, 0.81640625, 0.109375, 1.1171875         ,
-0.07421875, 0.94921875, 0.109375, 0.984375         ,
-0.0546875, 0.921875, 0.12890625, 1.0117188         ,
-0.0234375, 0.890625, 0.16015625, 1.0429688         ,
-0.01171875, 0.81640625, 0.171875, 1.1171875        ,
-0.13671875, 0.94921875, 0.171875, 0.984375         ,
-0.1171875, 0.921875, 0.19140625, 1.0117188         ,
-0.0859375, 0.890625, 0.22265625, 1.0429688         ,
-0.07421875, 0.81640625, 0.234375, 1.1171875        ,
-0.19921875, 0.94921875, 0.234375, 0.984375         ,
-0.1796875, 0.921875, 0.25390625, 1.0117188         ,
-0.1484375, 0.890625, 0.28515625, 1.0429688         ,
-0.13671875, 0.81640625, 0.296875, 1.1171875        ,
-0.26171875, 0.94921875, 0.296875, 0.984375         ,
-0.2421875, 0.921875, 0.31640625, 1.0117188         ,
-0.2109375, 0.890625, 0.34765625, 1.0429688         ,
-0.19921875, 0.81640625, 0.359375, 1.1171875        ,
-0.32421875, 0.94921875, 0.359375, 0.984375         ,
-0.3046875, 0.921875, 0.37890625, 1.0117188         ,
-0.2734375, 0.890625, 0.41015625, 1.0429688         ,
-0.26171875, 0.81640625, 0.421875, 1.1171875        ,
-0.38671875, 0.94921875, 0.421875, 0.984375         ,
-0.3671875, 0.921875, 0.44140625, 1.0117188         ,
-0.3359375, 0.890625, 0.47265625, 1.0429688         ,
-0.32421875, 0.81640625, 0.484375, 1.1171875        ,
-0.44921875, 0.94921875, 0.484375, 0.984375         ,
-0.4296875, 0.921875, 0.50390625, 1.0117188         ,
-0.3984375, 0.890625, 0.53515625, 1.0429688         ,
-0.38671875, 0.81640625, 0.546875, 1.1171875        ,
-0.51171875, 0.94921875, 0.546875, 0.984375         ,
-0.4921875, 0.921875, 0.56640625, 1.0117188         ,
-0.4609375, 0.890625, 0.59765625, 1.0429688         ,
-0.44921875, 0.81640625, 0.609375, 1.1171875        ,
-0.57421875, 0.94921875, 0.609375, 0.984375         ,
-0.5546875, 0.921875, 0.62890625, 1.0117188         ,
-0.5234375, 0.890625, 0.66015625, 1.0429688         ,
-0.51171875, 0.81640625, 0.671875, 1.1171875        ,
-0.63671875, 0.94921875, 0.671875, 0.984375         ,
-0.6171875, 0.921875, 0.69140625, 1.0117188         ,
-0.5859375, 0.890625, 0.72265625, 1.0429688         ,
-0.57421875, 0.81640625, 0.734375, 1.1171875        ,
-0.69921875, 0.94921875, 0.734375, 0.984375         ,
-0.6796875, 0.921875, 0.75390625, 1.0117188         ,
-0.6484375, 0.890625, 0.78515625, 1.0429688         ,
-0.63671875, 0.81640625, 0.796875, 1.1171875        ,
-0.76171875, 0.94921875, 0.796875, 0.984375         ,
-0.7421875, 0.921875, 0.81640625, 1.0117188         ,
-0.7109375, 0.890625, 0.84765625, 1.0429688         ,
-0.69921875, 0.81640625, 0.859375, 1.1171875        ,
-0.82421875, 0.94921875, 0.859375, 0.984375         ,
-0.8046875, 0.921875, 0.87890625, 1.0117188         ,
-0.7734375, 0.890625, 0.91015625, 1.0429688         ,
-0.76171875, 0.81640625, 0.921875, 1.1171875        ,
-0.88671875, 0.94921875, 0.921875, 0.984375         ,
-0.8671875, 0.921875, 0.94140625, 1.0117188         ,
-0.8359375, 0.890625, 0.97265625, 1.0429688         ,
-0.82421875, 0.81640625, 0.984375, 1.1171875        ,
-0.94921875, 0.94921875, 0.984375, 0.984375         ,
-0.9296875, 0.921875, 1.0039062, 1.0117188          ,
-0.8984375, 0.890625, 1.0351562, 1.0429688          ,
-0.88671875, 0.81640625, 1.046875, 1.1171875        ,
--0.08203125, -0.03515625, 0.20703125, 0.16015625   ,
--0.1015625, -0.140625, 0.2265625, 0.265625         ,
--0.04296875, -0.26953125, 0.16796875, 0.39453125   ,
--0.1640625, -0.17578125, 0.2890625, 0.30078125     ,
--0.2734375, -0.265625, 0.3984375, 0.390625         ,
-0.0390625, -0.03515625, 0.33203125, 0.16015625     ,
-0.01953125, -0.140625, 0.3515625, 0.265625         ,
-0.078125, -0.26953125, 0.29296875, 0.39453125      ,
--0.0390625, -0.17578125, 0.4140625, 0.30078125     ,
--0.1484375, -0.265625, 0.5234375, 0.390625         ,
-0.1640625, -0.03515625, 0.45703125, 0.16015625     ,
-0.14453125, -0.140625, 0.4765625, 0.265625         ,
-0.203125, -0.26953125, 0.41796875, 0.39453125      ,
-0.08203125, -0.17578125, 0.5390625, 0.30078125     ,
--0.0234375, -0.265625, 0.6484375, 0.390625         ,
-0.2890625, -0.03515625, 0.58203125, 0.16015625     ,
-0.26953125, -0.140625, 0.6015625, 0.265625         ,
-0.328125, -0.26953125, 0.54296875, 0.39453125      ,
-0.20703125, -0.17578125, 0.6640625, 0.30078125     ,
-0.09765625, -0.265625, 0.7734375, 0.390625         ,
-0.4140625, -0.03515625, 0.70703125, 0.16015625     ,
-0.39453125, -0.140625, 0.7265625, 0.265625         ,
-0.453125, -0.26953125, 0.66796875, 0.39453125      ,
-0.33203125, -0.17578125, 0.7890625, 0.30078125     ,
-0.22265625, -0.265625, 0.8984375, 0.390625         ,
-0.5390625, -0.03515625, 0.83203125, 0.16015625     ,
-0.51953125, -0.140625, 0.8515625, 0.265625         ,
-0.578125, -0.26953125, 0.79296875, 0.39453125      ,
-0.45703125, -0.17578125, 0.9140625, 0.30078125     ,
-0.34765625, -0.265625, 1.0234375, 0.390625         ,
-0.6640625, -0.03515625, 0.95703125, 0.16015625     ,
-0.64453125, -0.140625, 0.9765625, 0.265625         ,
-0.703125, -0.26953125, 0.91796875, 0.39453125      ,
-0.58203125, -0.17578125, 1.0390625, 0.30078125     ,
-0.47265625, -0.265625, 1.1484375, 0.390625         ,
-0.7890625, -0.03515625, 1.0820312, 0.16015625      ,
-0.76953125, -0.140625, 1.1015625, 0.265625         ,
-0.828125, -0.26953125, 1.0429688, 0.39453125       ,
-0.70703125, -0.17578125, 1.1640625, 0.30078125     ,
-0.59765625, -0.265625, 1.2734375, 0.390625         ,
--0.08203125, 0.0859375, 0.20703125, 0.28515625     ,
--0.1015625, -0.015625, 0.2265625, 0.390625         ,
--0.04296875, -0.14453125, 0.16796875, 0.51953125   ,
--0.1640625, -0.05078125, 0.2890625, 0.42578125     ,
--0.2734375, -0.140625, 0.3984375, 0.515625         ,
-0.0390625, 0.0859375, 0.33203125, 0.28515625       ,
-0.01953125, -0.015625, 0.3515625, 0.390625         ,
-0.078125, -0.14453125, 0.29296875, 0.51953125      ,
--0.0390625, -0.05078125, 0.4140625, 0.42578125     ,
--0.1484375, -0.140625, 0.5234375, 0.515625         ,
-0.1640625, 0.0859375, 0.45703125, 0.28515625       ,
-0.14453125, -0.015625, 0.4765625, 0.390625         ,
-0.203125, -0.14453125, 0.41796875, 0.51953125      ,
-0.08203125, -0.05078125, 0.5390625, 0.42578125     ,
--0.0234375, -0.140625, 0.6484375, 0.515625         ,
-0.2890625, 0.0859375, 0.58203125, 0.28515625       ,
-0.26953125, -0.015625, 0.6015625, 0.390625         ,
-0.328125, -0.14453125, 0.54296875, 0.51953125      ,
-0.20703125, -0.05078125, 0.6640625, 0.42578125     ,
-0.09765625, -0.140625, 0.7734375, 0.515625         ,
-0.4140625, 0.0859375, 0.70703125, 0.28515625       ,
-0.39453125, -0.015625, 0.7265625, 0.390625         ,
-0.453125, -0.14453125, 0.66796875, 0.51953125      ,
-0.33203125, -0.05078125, 0.7890625, 0.42578125     ,
-0.22265625, -0.140625, 0.8984375, 0.515625         ,
-0.5390625, 0.0859375, 0.83203125, 0.28515625       ,
-0.51953125, -0.015625, 0.8515625, 0.390625         ,
-0.578125, -0.14453125, 0.79296875, 0.51953125      ,
-0.45703125, -0.05078125, 0.9140625, 0.42578125     ,
-0.34765625, -0.140625, 1.0234375, 0.515625         ,
-0.6640625, 0.0859375, 0.95703125, 0.28515625       ,
-0.64453125, -0.015625, 0.9765625, 0.390625         ,
-0.703125, -0.14453125, 0.91796875, 0.51953125      ,
-0.58203125, -0.05078125, 1.0390625, 0.42578125     ,
-0.47265625, -0.140625, 1.1484375, 0.515625         ,
-0.7890625, 0.0859375, 1.0820312, 0.28515625        ,
-0.76953125, -0.015625, 1.1015625, 0.390625         ,
-0.828125, -0.14453125, 1.0429688, 0.51953125       ,
-0.70703125, -0.05078125, 1.1640625, 0.42578125     ,
-0.59765625, -0.140625, 1.2734375, 0.515625         ,
--0.08203125, 0.2109375, 0.20703125, 0.41015625     ,
--0.1015625, 0.10546875, 0.2265625, 0.515625        ,
--0.04296875, -0.01953125, 0.16796875, 0.64453125   ,
--0.1640625, 0.0703125, 0.2890625, 0.55078125       ,
--0.2734375, -0.015625, 0.3984375, 0.640625         ,
-0.0390625, 0.2109375, 0.33203125, 0.41015625       ,
-0.01953125, 0.10546875, 0.3515625, 0.515625        ,
-0.078125, -0.01953125, 0.29296875, 0.64453125      ,
--0.0390625, 0.0703125, 0.4140625, 0.55078125       ,
--0.1484375, -0.015625, 0.5234375, 0.640625         ,
-0.1640625, 0.2109375, 0.45703125, 0.41015625       ,
-0.14453125, 0.10546875, 0.4765625, 0.515625        ,
-0.203125, -0.01953125, 0.41796875, 0.64453125      ,
-0.08203125, 0.0703125, 0.5390625, 0.55078125       ,
--0.0234375, -0.015625, 0.6484375, 0.640625         ,
-0.2890625, 0.2109375, 0.58203125, 0.41015625       ,
-0.26953125, 0.10546875, 0.6015625, 0.515625        ,
-0.328125, -0.01953125, 0.54296875, 0.64453125      ,
-0.20703125, 0.0703125, 0.6640625, 0.55078125       ,
-0.09765625, -0.015625, 0.7734375, 0.640625         ,
-0.4140625, 0.2109375, 0.70703125, 0.41015625       ,
-0.39453125, 0.10546875, 0.7265625, 0.515625        ,
-0.453125, -0.01953125, 0.66796875, 0.64453125      ,
-0.33203125, 0.0703125, 0.7890625, 0.55078125       ,
-0.22265625, -0.015625, 0.8984375, 0.640625         ,
-0.5390625, 0.2109375, 0.83203125, 0.41015625       ,
-0.51953125, 0.10546875, 0.8515625, 0.515625        ,
-0.578125, -0.01953125, 0.79296875, 0.64453125      ,
-0.45703125, 0.0703125, 0.9140625, 0.55078125       ,
-0.34765625, -0.015625, 1.0234375, 0.640625         ,
-0.6640625, 0.2109375, 0.95703125, 0.41015625       ,
-0.64453125, 0.10546875, 0.9765625, 0.515625        ,
-0.703125, -0.01953125, 0.91796875, 0.64453125      ,
-0.58203125, 0.0703125, 1.0390625, 0.55078125       ,
-0.47265625, -0.015625, 1.1484375, 0.640625         ,
-0.7890625, 0.2109375, 1.0820312, 0.41015625        ,
-0.76953125, 0.10546875, 1.1015625, 0.515625        ,
-0.828125, -0.01953125, 1.0429688, 0.64453125       ,
-0.70703125, 0.0703125, 1.1640625, 0.55078125       ,
-0.59765625, -0.015625, 1.2734375, 0.640625         ,
--0.08203125, 0.3359375, 0.20703125, 0.53515625     ,
--0.1015625, 0.23046875, 0.2265625, 0.640625        ,
--0.04296875, 0.1015625, 0.16796875, 0.76953125     ,
--0.1640625, 0.1953125, 0.2890625, 0.67578125       ,
--0.2734375, 0.10546875, 0.3984375, 0.765625        ,
-0.0390625, 0.3359375, 0.33203125, 0.53515625       ,
-0.01953125, 0.23046875, 0.3515625, 0.640625        ,
-0.078125, 0.1015625, 0.29296875, 0.76953125        ,
--0.0390625, 0.1953125, 0.4140625, 0.67578125       ,
--0.1484375, 0.10546875, 0.5234375, 0.765625        ,
-0.1640625, 0.3359375, 0.45703125, 0.53515625       ,
-0.14453125, 0.23046875, 0.4765625, 0.640625        ,
-0.203125, 0.1015625, 0.41796875, 0.76953125        ,
-0.08203125, 0.1953125, 0.5390625, 0.67578125       ,
--0.0234375, 0.10546875, 0.6484375, 0.765625        ,
-0.2890625, 0.3359375, 0.58203125, 0.53515625       ,
-0.26953125, 0.23046875, 0.6015625, 0.640625        ,
-0.328125, 0.1015625, 0.54296875, 0.76953125        ,
-0.20703125, 0.1953125, 0.6640625, 0.67578125       ,
-0.09765625, 0.10546875, 0.7734375, 0.765625        ,
-0.4140625, 0.3359375, 0.70703125, 0.53515625       ,
-0.39453125, 0.23046875, 0.7265625, 0.640625        ,
-0.453125, 0.1015625, 0.66796875, 0.76953125        ,
-0.33203125, 0.1953125, 0.7890625, 0.67578125       ,
-0.22265625, 0.10546875, 0.8984375, 0.765625        ,
-0.5390625, 0.3359375, 0.83203125, 0.53515625       ,
-0.51953125, 0.23046875, 0.8515625, 0.640625        ,
-0.578125, 0.1015625, 0.79296875, 0.76953125        ,
-0.45703125, 0.1953125, 0.9140625, 0.67578125       ,
-0.34765625, 0.10546875, 1.0234375, 0.765625        ,
-0.6640625, 0.3359375, 0.95703125, 0.53515625       ,
-0.64453125, 0.23046875, 0.9765625, 0.640625        ,
-0.703125, 0.1015625, 0.91796875, 0.76953125        ,
-0.58203125, 0.1953125, 1.0390625, 0.67578125       ,
-0.47265625, 0.10546875, 1.1484375, 0.765625        ,
-0.7890625, 0.3359375, 1.0820312, 0.53515625        ,
-0.76953125, 0.23046875, 1.1015625, 0.640625        ,
-0.828125, 0.1015625, 1.0429688, 0.76953125         ,
-0.70703125, 0.1953125, 1.1640625, 0.67578125       ,
-0.59765625, 0.10546875, 1.2734375, 0.765625        ,
--0.08203125, 0.4609375, 0.20703125, 0.66015625     ,
--0.1015625, 0.35546875, 0.2265625, 0.765625        ,
--0.04296875, 0.2265625, 0.16796875, 0.89453125     ,
--0.1640625, 0.3203125, 0.2890625, 0.80078125       ,
--0.2734375, 0.23046875, 0.3984375, 0.890625        ,
-0.0390625, 0.4609375, 0.33203125, 0.66015625       ,
-0.01953125, 0.35546875, 0.3515625, 0.765625        ,
-0.078125, 0.2265625, 0.29296875, 0.89453125        ,
--0.0390625, 0.3203125, 0.4140625, 0.80078125       ,
--0.1484375, 0.23046875, 0.5234375, 0.890625        ,
-0.1640625, 0.4609375, 0.45703125, 0.66015625       ,
-0.14453125, 0.35546875, 0.4765625, 0.765625        ,
-0.203125, 0.2265625, 0.41796875, 0.89453125        ,
-0.08203125, 0.3203125, 0.5390625, 0.80078125       ,
--0.0234375, 0.23046875, 0.6484375, 0.890625        ,
-0.2890625, 0.4609375, 0.58203125, 0.66015625       ,
-0.26953125, 0.35546875, 0.6015625, 0.765625        ,
-0.328125, 0.2265625, 0.54296875, 0.89453125        ,
-0.20703125, 0.3203125, 0.6640625, 0.80078125       ,
-0.09765625, 0.23046875, 0.7734375, 0.890625        ,
-0.4140625, 0.4609375, 0.70703125, 0.66015625       ,
-0.39453125, 0.35546875, 0.7265625, 0.765625        ,
-0.453125, 0.2265625, 0.66796875, 0.89453125        ,
-0.33203125, 0.3203125, 0.7890625, 0.80078125       ,
-0.22265625, 0.23046875, 0.8984375, 0.890625        ,
-0.5390625, 0.4609375, 0.83203125, 0.66015625       ,
-0.51953125, 0.35546875, 0.8515625, 0.765625        ,
-0.578125, 0.2265625, 0.79296875, 0.89453125        ,
-0.45703125, 0.3203125, 0.9140625, 0.80078125       ,
-0.34765625, 0.23046875, 1.0234375, 0.890625        ,
-0.6640625, 0.4609375, 0.95703125, 0.66015625       ,
-0.64453125, 0.35546875, 0.9765625, 0.765625        ,
-0.703125, 0.2265625, 0.91796875, 0.89453125        ,
-0.58203125, 0.3203125, 1.0390625, 0.80078125       ,
-0.47265625, 0.23046875, 1.1484375, 0.890625        ,
-0.7890625, 0.4609375, 1.0820312, 0.66015625        ,
-0.76953125, 0.35546875, 1.1015625, 0.765625        ,
-0.828125, 0.2265625, 1.0429688, 0.89453125         ,
-0.70703125, 0.3203125, 1.1640625, 0.80078125       ,
-0.59765625, 0.23046875, 1.2734375, 0.890625        ,
--0.08203125, 0.5859375, 0.20703125, 0.78515625     ,
--0.1015625, 0.48046875, 0.2265625, 0.890625        ,
--0.04296875, 0.3515625, 0.16796875, 1.0195312      ,
--0.1640625, 0.4453125, 0.2890625, 0.92578125       ,
--0.2734375, 0.35546875, 0.3984375, 1.015625        ,
-0.0390625, 0.5859375, 0.33203125, 0.78515625       ,
-0.01953125, 0.48046875, 0.3515625, 0.890625        ,
-0.078125, 0.3515625, 0.29296875, 1.0195312         ,
--0.0390625, 0.4453125, 0.4140625, 0.92578125       ,
--0.1484375, 0.35546875, 0.5234375, 1.015625        ,
-0.1640625, 0.5859375, 0.45703125, 0.78515625       ,
-0.14453125, 0.48046875, 0.4765625, 0.890625        ,
-0.203125, 0.3515625, 0.41796875, 1.0195312         ,
-0.08203125, 0.4453125, 0.5390625, 0.92578125       ,
--0.0234375, 0.35546875, 0.6484375, 1.015625        ,
-0.2890625, 0.5859375, 0.58203125, 0.78515625       ,
-0.26953125, 0.48046875, 0.6015625, 0.890625        ,
-0.328125, 0.3515625, 0.54296875, 1.0195312         ,
-0.20703125, 0.4453125, 0.6640625, 0.92578125       ,
-0.09765625, 0.35546875, 0.7734375, 1.015625        ,
-0.4140625, 0.5859375, 0.70703125, 0.78515625       ,
-0.39453125, 0.48046875, 0.7265625, 0.890625        ,
-0.453125, 0.3515625, 0.66796875, 1.0195312         ,
-0.33203125, 0.4453125, 0.7890625, 0.92578125       ,
-0.22265625, 0.35546875, 0.8984375, 1.015625        ,
-0.5390625, 0.5859375, 0.83203125, 0.78515625       ,
-0.51953125, 0.48046875, 0.8515625, 0.890625        ,
-0.578125, 0.3515625, 0.79296875, 1.0195312         ,
-0.45703125, 0.4453125, 0.9140625, 0.92578125       ,
-0.34765625, 0.35546875, 1.0234375, 1.015625        ,
-0.6640625, 0.5859375, 0.95703125, 0.78515625       ,
-0.64453125, 0.48046875, 0.9765625, 0.890625        ,
-0.703125, 0.3515625, 0.91796875, 1.0195312         ,
-0.58203125, 0.4453125, 1.0390625, 0.92578125       ,
-0.47265625, 0.35546875, 1.1484375, 1.015625        ,
-0.7890625, 0.5859375, 1.0820312, 0.78515625        ,
-0.76953125, 0.48046875, 1.1015625, 0.890625        ,
-0.828125, 0.3515625, 1.0429688, 1.0195312          ,
-0.70703125, 0.4453125, 1.1640625, 0.92578125       ,
-0.59765625, 0.35546875, 1.2734375, 1.015625        ,
--0.08203125, 0.7109375, 0.20703125, 0.91015625     ,
--0.1015625, 0.60546875, 0.2265625, 1.015625        ,
--0.04296875, 0.4765625, 0.16796875, 1.1445312      ,
--0.1640625, 0.5703125, 0.2890625, 1.0507812        ,
--0.2734375, 0.48046875, 0.3984375, 1.140625        ,
-0.0390625, 0.7109375, 0.33203125, 0.91015625       ,
-0.01953125, 0.60546875, 0.3515625, 1.015625        ,
-0.078125, 0.4765625, 0.29296875, 1.1445312         ,
--0.0390625, 0.5703125, 0.4140625, 1.0507812        ,
--0.1484375, 0.48046875, 0.5234375, 1.140625        ,
-0.1640625, 0.7109375, 0.45703125, 0.91015625       ,
-0.14453125, 0.60546875, 0.4765625, 1.015625        ,
-0.203125, 0.4765625, 0.41796875, 1.1445312         ,
-0.08203125, 0.5703125, 0.5390625, 1.0507812        ,
--0.0234375, 0.48046875, 0.6484375, 1.140625        ,
-0.2890625, 0.7109375, 0.58203125, 0.91015625       ,
-0.26953125, 0.60546875, 0.6015625, 1.015625        ,
-0.328125, 0.4765625, 0.54296875, 1.1445312         ,
-0.20703125, 0.5703125, 0.6640625, 1.0507812        ,
-0.09765625, 0.48046875, 0.7734375, 1.140625        ,
-0.4140625, 0.7109375, 0.70703125, 0.91015625       ,
-0.39453125, 0.60546875, 0.7265625, 1.015625        ,
-0.453125, 0.4765625, 0.66796875, 1.1445312         ,
-0.33203125, 0.5703125, 0.7890625, 1.0507812        ,
-0.22265625, 0.48046875, 0.8984375, 1.140625        ,
-0.5390625, 0.7109375, 0.83203125, 0.91015625       ,
-0.51953125, 0.60546875, 0.8515625, 1.015625        ,
-0.578125, 0.4765625, 0.79296875, 1.1445312         ,
-0.45703125, 0.5703125, 0.9140625, 1.0507812        ,
-0.34765625, 0.48046875, 1.0234375, 1.140625        ,
-0.6640625, 0.7109375, 0.95703125, 0.91015625       ,
-0.64453125, 0.60546875, 0.9765625, 1.015625        ,
-0.703125, 0.4765625, 0.91796875, 1.1445312         ,
-0.58203125, 0.5703125, 1.0390625, 1.0507812        ,
-0.47265625, 0.48046875, 1.1484375, 1.140625        ,
-0.7890625, 0.7109375, 1.0820312, 0.91015625        ,
-0.76953125, 0.60546875, 1.1015625, 1.015625        ,
-0.828125, 0.4765625, 1.0429688, 1.1445312          ,
-0.70703125, 0.5703125, 1.1640625, 1.0507812        ,
-0.59765625, 0.48046875, 1.2734375, 1.140625        ,
--0.08203125, 0.8359375, 0.20703125, 1.0351562      ,
--0.1015625, 0.73046875, 0.2265625, 1.140625        ,
--0.04296875, 0.6015625, 0.16796875, 1.2695312      ,
--0.1640625, 0.6953125, 0.2890625, 1.1757812        ,
--0.2734375, 0.60546875, 0.3984375, 1.265625        ,
-0.0390625, 0.8359375, 0.33203125, 1.0351562        ,
-0.01953125, 0.73046875, 0.3515625, 1.140625        ,
-0.078125, 0.6015625, 0.29296875, 1.2695312         ,
--0.0390625, 0.6953125, 0.4140625, 1.1757812        ,
--0.1484375, 0.60546875, 0.5234375, 1.265625        ,
-0.1640625, 0.8359375, 0.45703125, 1.0351562        ,
-0.14453125, 0.73046875, 0.4765625, 1.140625        ,
-0.203125, 0.6015625, 0.41796875, 1.2695312         ,
-0.08203125, 0.6953125, 0.5390625, 1.1757812        ,
--0.0234375, 0.60546875, 0.6484375, 1.265625        ,
-0.2890625, 0.8359375, 0.58203125, 1.0351562        ,
-0.26953125, 0.73046875, 0.6015625, 1.140625        ,
-0.328125, 0.6015625, 0.54296875, 1.2695312         ,
-0.20703125, 0.6953125, 0.6640625, 1.1757812        ,
-0.09765625, 0.60546875, 0.7734375, 1.265625        ,
-0.4140625, 0.8359375, 0.70703125, 1.0351562        ,
-0.39453125, 0.73046875, 0.7265625, 1.140625        ,
-0.453125, 0.6015625, 0.66796875, 1.2695312         ,
-0.33203125, 0.6953125, 0.7890625, 1.1757812        ,
-0.22265625, 0.60546875, 0.8984375, 1.265625        ,
-0.5390625, 0.8359375, 0.83203125, 1.0351562        ,
-0.51953125, 0.73046875, 0.8515625, 1.140625        ,
-0.578125, 0.6015625, 0.79296875, 1.2695312         ,
-0.45703125, 0.6953125, 0.9140625, 1.1757812        ,
-0.34765625, 0.60546875, 1.0234375, 1.265625        ,
-0.6640625, 0.8359375, 0.95703125, 1.0351562        ,
-0.64453125, 0.73046875, 0.9765625, 1.140625        ,
-0.703125, 0.6015625, 0.91796875, 1.2695312         ,
-0.58203125, 0.6953125, 1.0390625, 1.1757812        ,
-0.47265625, 0.60546875, 1.1484375, 1.265625        ,
-0.7890625, 0.8359375, 1.0820312, 1.0351562         ,
-0.76953125, 0.73046875, 1.1015625, 1.140625        ,
-0.828125, 0.6015625, 1.0429688, 1.2695312          ,
-0.70703125, 0.6953125, 1.1640625, 1.1757812        ,
-0.59765625, 0.60546875, 1.2734375, 1.265625        ,
-    ];
 
 
     /* Other Parameters */
     const SCORE_THRESHOLD: f32 = 0.2;
     const NMS_IOU_THRESHOLD: f32 = 0.6;
 
-    pub fn new() -> Self {
+    pub fn config(&self) -> &ModelConfig {
+        &self.config
+    }
+
+    /* PriorBoxClustered of the original OpenVINO model */
+    /* Reproduces the prior box list retrieved from 0.npy at 256x256, and follows other input sizes */
+    fn default_prior_box() -> PriorBoxConfig {
+        PriorBoxConfig {
+            variance: Self::PRIOR_VARIANCE,
+            offset: 0.5,
+            clip: false,
+            snap_to_pixel: true,
+            filename: None,
+            layers: vec![
+                PriorBoxLayer {
+                    stride: 16,
+                    feature_map_size: None,
+                    box_sizes: vec![(9.0, 9.0), (19.0, 23.0), (35.0, 39.0), (41.0, 77.0)],
+                    min_sizes: vec![],
+                    max_sizes: vec![],
+                    aspect_ratios: vec![],
+                    flip: false,
+                },
+                PriorBoxLayer {
+                    stride: 32,
+                    feature_map_size: None,
+                    box_sizes: vec![(75.0, 51.0), (85.0, 105.0), (55.0, 171.0), (117.0, 123.0), (173.0, 169.0)],
+                    min_sizes: vec![],
+                    max_sizes: vec![],
+                    aspect_ratios: vec![],
+                    flip: false,
+                },
+            ],
+        }
+    }
+}
+
+impl FromModelConfig for DetectionEngine {
+    fn default_config() -> ModelConfig {
+        ModelConfig {
+            model_filename: Self::MODEL_FILENAME.to_string(),
+            label_filename: None,
+            input_width: Self::MODEL_WIDTH,
+            input_height: Self::MODEL_HEIGHT,
+            input_name: Self::MODEL_NAME_INPUT_0.to_string(),
+            output_names: vec![Self::MODEL_NAME_OUTPUT_0.to_string(), Self::MODEL_NAME_OUTPUT_1.to_string()],
+            normalize_mean: Self::MODEL_NORMALIZE_MEAN,     /* not used. the model takes [0, 255] input */
+            normalize_norm: Self::MODEL_NORMALIZE_NORM,
+            resize_mode: ResizeMode::Stretch,
+            pad_value: (0.0, 0.0, 0.0),
+            score_threshold: Self::SCORE_THRESHOLD,
+            score_class_threshold: Self::SCORE_THRESHOLD,
+            nms_iou_threshold: Self::NMS_IOU_THRESHOLD,
+            nms_mode: NmsMode::ClassAgnostic,
+            nms_method: NmsMethod::Hard,
+            soft_nms_sigma: 0.5,
+            max_detections: None,
+            prior_box: Some(Self::default_prior_box()),
+        }
+    }
+
+    fn from_config(config: ModelConfig) -> Result<Self> {
+        if config.output_names.len() != 2 {
+            return Err(Error::Config(format!("{} requires 2 output names (box, confidence)", Self::MODEL_NAME)));
+        }
+
+        /* Generate prior boxes for the input size */
+        let prior_box = config.prior_box.as_ref().ok_or_else(|| Error::Config(format!("{} requires prior_box", Self::MODEL_NAME)))?;
+        let prior_decoder = PriorBoxDecoder::new(prior_box, config.input_width, config.input_height)?;
+
         /* Load model */
-         let mut net = dnn::read_net_from_onnx(Self::MODEL_FILENAME).unwrap();
-        net.set_preferable_backend(dnn::DNN_BACKEND_OPENCV).unwrap();
-        net.set_preferable_target(dnn::DNN_TARGET_CPU).unwrap();
-        // net.set_preferable_target(dnn::DNN_TARGET_OPENCL).unwrap();
+        let net = read_net(&config.model_filename)?;
 
         /* Set output names */
-        let mut out_blob_names = core::Vector::<String>::new();
-        out_blob_names.push(Self::MODEL_NAME_OUTPUT_0);
-        out_blob_names.push(Self::MODEL_NAME_OUTPUT_1);
+        let out_blob_names = output_blob_names(&config.output_names);
 
         /* Initialize normalize param */
-        let normalize_mean = core::Scalar::from(Self::MODEL_NORMALIZE_MEAN);
-        let normalize_norm = core::Scalar::from(Self::MODEL_NORMALIZE_NORM);
+        let normalize_mean = core::Scalar::from(config.normalize_mean);
+        let normalize_norm = core::Scalar::from(config.normalize_norm);
+
+        /* Single class model (index 0 of the confidence is used) */
+        let label_list = match &config.label_filename {
+            Some(label_filename) => read_label_file(label_filename, 1)?,
+            None => vec![Self::MODEL_LABEL.to_string()],
+        };
         
-        DetectionEngine {
+        Ok(DetectionEngine {
+            config: config,
             net: net,
             out_blob_names: out_blob_names,
             normalize_mean: normalize_mean,
             normalize_norm: normalize_norm,
-        }
+            label_list: label_list,
+            prior_decoder: prior_decoder,
+        })
     }
+}
 
-    // fn normalize(&self, mat: &mut core::Mat) -> core::Mat {
-    //     let mut mat_normalized = Mat::default();
-    //     let mut mat_normalized_sub = Mat::default();
-    //     let mut mat_normalized_div = Mat::default();
-    //     mat.convert_to(&mut mat_normalized, core::CV_32FC3, 1.0 / 255.0, 0.0).unwrap();
-    //     core::subtract(&mat_normalized, &self.normalize_mean, &mut mat_normalized_sub, &core::no_array(), -1).unwrap();
-    //     core::divide2(&mat_normalized_sub, &self.normalize_norm, &mut mat_normalized_div, 1.0, -1).unwrap();
-    //     mat_normalized_div
-    // }
-
-    pub fn process(&mut self, mat: &core::Mat) -> Vec::<BoundingBox> {
+impl Detector for DetectionEngine {
+    fn process(&mut self, mat: &core::Mat) -> Result<Vec::<BoundingBox>> {
         /* Pre Process */
-        let mut mat_resized = Mat::default();
-        imgproc::resize(mat, &mut mat_resized, core::Size { width: Self::MODEL_WIDTH, height: Self::MODEL_HEIGHT }, 0.0, 0.0, imgproc::INTER_LINEAR).unwrap();
-        // let mat_normalized = self.normalize(&mut mat_resized);
+        let (mat_resized, transform) = resize(mat, self.input_size(), self.config.resize_mode, self.config.pad_value)?;
+        // let mat_normalized = normalize(&mat_resized, &self.normalize_mean, &self.normalize_norm)?;
         // let mat_blob = dnn::blob_from_image(&mat_normalized, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).unwrap();
-        let mat_blob = dnn::blob_from_image(&mat_resized, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).unwrap();
+        let mat_blob = dnn::blob_from_image(&mat_resized, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).map_err(Error::Preprocess)?;
         
 
         /* Feed input data */
-        self.net.set_input(&mat_blob, Self::MODEL_NAME_INPUT_0, 1.0, core::Scalar::default()).unwrap();
+        self.net.set_input(&mat_blob, &self.config.input_name, 1.0, core::Scalar::default()).map_err(Error::Inference)?;
 
         /* Run inference */
         let mut output_blobs = core::Vector::<core::Mat>::new();
-        self.net.forward(&mut output_blobs, &self.out_blob_names).unwrap();
+        self.net.forward(&mut output_blobs, &self.out_blob_names).map_err(Error::Inference)?;
 
         /* Retrieve output */
-        let box_logits_list = &output_blobs.to_vec()[0];
-        let confidence_list = &output_blobs.to_vec()[1];
+        let box_logits_list = output_blobs.get(0).map_err(Error::Inference)?;
+        let confidence_list = output_blobs.get(1).map_err(Error::Inference)?;
 
         /* Convert the output to vector */
-        let box_logits_list = unsafe {std::slice::from_raw_parts(box_logits_list.ptr(0).unwrap() as *mut f32, box_logits_list.total()).to_vec()};
-        let confidence_list = unsafe {std::slice::from_raw_parts(confidence_list.ptr(0).unwrap() as *mut f32, confidence_list.total()).to_vec()};
+        let box_logits_list = output_to_vec(&box_logits_list, &self.config.output_names[0], self.prior_decoder.len() * 4)?;
+        let confidence_list = output_to_vec(&confidence_list, &self.config.output_names[1], self.prior_decoder.len() * Self::MODEL_NUM_CLASS as usize)?;
 
         /* Decode bbox */
         let mut bbox_list = Vec::<BoundingBox>::new();
-        for i in 0 .. Self::MODEL_NUM_PRIOR as usize {
+        for i in 0 .. self.prior_decoder.len() {
             let score = confidence_list[i * 2 + 0];
-            if score >= Self::SCORE_THRESHOLD {
+            if score >= self.config.score_threshold {
                 // println!("{}, {}, {}, {}, {}, {}, {}", i, confidence_list[i * 2 + 0], confidence_list[i * 2 + 1], box_logits_list[i * 4 + 0], box_logits_list[i * 4 + 1], box_logits_list[i * 4 + 2], box_logits_list[i * 4 + 3]);
 
-                /* Prior Box: [0.0, 1.0] */
-                let prior_x0 = Self::PRIOR_BBOX[i * 4 + 0];
-                let prior_y0 = Self::PRIOR_BBOX[i * 4 + 1];
-                let prior_x1 = Self::PRIOR_BBOX[i * 4 + 2];
-                let prior_y1 = Self::PRIOR_BBOX[i * 4 + 3];
-                let prior_cx = (prior_x0 + prior_x1) / 2.0;
-                let prior_cy = (prior_y0 + prior_y1) / 2.0;
-                let prior_w = prior_x1 - prior_x0;
-                let prior_h = prior_y1 - prior_y0;
-                // let bbox = BoundingBox{
-                //     class_id: 0,
-                //     label: "Car".to_string(),
-                //     score: 0.5,
-                //     x: (prior_x0 * mat.cols() as f32) as i32,
-                //     y: (prior_y0 * mat.rows() as f32) as i32,
-                //     w: (prior_w * mat.cols() as f32) as i32,
-                //     h: (prior_h * mat.rows() as f32) as i32,
-                // };
-
-                /* Detected Box: [0.0, MODEL_SIZE] -> [0.0, 1.0] */
-                let mut box_cx = box_logits_list[i * 4 + 0];
-                let mut box_cy = box_logits_list[i * 4 + 1];
-                let mut box_w = box_logits_list[i * 4 + 2];
-                let mut box_h = box_logits_list[i * 4 + 3];
-
-                /* Adjust box [0.0, 1.0] */
-                /* Reference: */
-                /*   https://github.com/openvinotoolkit/openvino/blob/17091476d86cbb98392216fa3d4f0db90914449a/inference-engine/thirdparty/clDNN/src/impls/cpu/detection_output.cpp#L135 */
-                /*   https://docs.openvino.ai/latest/openvino_docs_ops_detection_DetectionOutput_1.html */
-                /*   https://github.com/openvinotoolkit/open_model_zoo/tree/2021.4.2/models/intel/vehicle-detection-0200 */
-                let cx = Self::PRIOR_VARIANCE.0 * box_cx * prior_w + prior_cx;
-                let cy = Self::PRIOR_VARIANCE.1 * box_cy * prior_h + prior_cy;
-                let w = (box_w * Self::PRIOR_VARIANCE.2).exp() * prior_w;
-                let h = (box_h * Self::PRIOR_VARIANCE.3).exp() * prior_h;
+                /* Detected Box: regression from the prior box -> original image */
+                let (x, y, w, h) = self.prior_decoder.decode_box(i, &box_logits_list[i * 4 .. i * 4 + 4], &transform);
 
                 /* Store the detected box */
                 let bbox = BoundingBox{
                     class_id: 0,
-                    label: "Car".to_string(),
+                    label: self.label_list[0].clone(),
                     score: score,
-                    x: ((cx - w / 2.0) * mat.cols() as f32) as i32,
-                    y: ((cy - h / 2.0) * mat.rows() as f32) as i32,
-                    w: (w * mat.cols() as f32) as i32,
-                    h: (h * mat.rows() as f32) as i32,
+                    x: x,
+                    y: y,
+                    w: w,
+                    h: h,
                 };
                 bbox_list.push(bbox);
             }
@@ -1510,9 +205,47 @@ impl DetectionEngine {
         // return bbox_list;
 
         /* NMS */
-        let bbox_nms_list = nms(&mut bbox_list, Self::NMS_IOU_THRESHOLD);
+        let bbox_nms_list = apply_nms(&mut bbox_list, &self.config.nms_param(self.config.score_threshold));
+
+        Ok(bbox_nms_list)
+    }
+
+    fn input_size(&self) -> core::Size {
+        core::Size { width: self.config.input_width, height: self.config.input_height }
+    }
+
+    fn label_list(&self) -> &[String] {
+        &self.label_list
+    }
 
-        bbox_nms_list
+    fn model_name(&self) -> &str {
+        Self::MODEL_NAME
+    }
+
+    fn score_threshold(&self) -> f32 {
+        self.config.score_threshold
+    }
+
+    fn set_score_threshold(&mut self, threshold: f32) {
+        self.config.score_threshold = threshold;
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vision_core::npy::NpyArray;
+
+    /* test_data/prior_box_256x256.npy is the prior box table which was hardcoded before it was generated at runtime */
+    #[test]
+    fn default_prior_box_matches_table() {
+        let prior_list = DetectionEngine::default_prior_box().generate(DetectionEngine::MODEL_WIDTH, DetectionEngine::MODEL_HEIGHT).unwrap();
+        let table = NpyArray::<f32>::read(concat!(env!("CARGO_MANIFEST_DIR"), "/test_data/prior_box_256x256.npy")).unwrap();
+        assert_eq!(table.shape, vec![prior_list.len(), 4]);
+        for (index, (prior, expected)) in prior_list.iter().zip(table.data.chunks_exact(4)).enumerate() {
+            for k in 0..4 {
+                assert!((prior[k] - expected[k]).abs() < 1e-6, "prior[{}] = {:?}, expected {:?}", index, prior, expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,256 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::{prelude::*, core, dnn};
+
+use vision_core::bounding_box::{BoundingBox, NmsMethod, NmsMode, apply_nms};
+use vision_core::config::{ModelConfig, FromModelConfig};
+use vision_core::detector::{Detector, process_with_score_class_threshold};
+use vision_core::dnn_helper::{read_net, read_label_file, output_to_vec, output_shape, normalize, output_blob_names};
+use vision_core::error::{Error, Result};
+use vision_core::preprocess::{resize, ResizeMode, ResizeTransform};
+
+
+/////////////////////////////////////////////////////////////////
+pub struct DetectionEngine {
+    config: ModelConfig,
+    net: dnn::Net,
+    out_blob_names: core::Vector::<String>,
+    normalize_mean: core::Scalar,
+    normalize_norm: core::Scalar,
+    label_list: Vec::<String>,
+}
+
+impl DetectionEngine {
+    /* Model Parameters */
+    const MODEL_NAME: &'static str = "YOLOv5";
+    const MODEL_FILENAME: &'static str = "../resource/model/yolov5s.onnx";
+    const LABEL_FILENAME: &'static str = "../resource/model/label_coco_80.txt";
+    const MODEL_NORMALIZE_MEAN: (f64, f64, f64) = (0.0, 0.0, 0.0);
+    const MODEL_NORMALIZE_NORM: (f64, f64, f64) = (1.0, 1.0, 1.0);
+    const MODEL_WIDTH: i32 = 640;
+    const MODEL_HEIGHT: i32 = 640;
+    const MODEL_NAME_INPUT_0: &'static str = "images";
+    const MODEL_NAME_OUTPUT_0: &'static str = "output0";
+    const MODEL_ELEMENT_NUM_OF_BOX: usize = 5;     // x, y, w, h, objectness, [class confidence]
+
+    /* Other Parameters */
+    const SCORE_BOX_THRESHOLD: f32 = 0.25;
+    const SCORE_CLASS_THRESHOLD: f32 = 0.25;
+    const NMS_IOU_THRESHOLD: f32 = 0.45;
+
+    pub fn config(&self) -> &ModelConfig {
+        &self.config
+    }
+}
+
+/* Decode the output of the exported model ([1, num_box, 5 + num_class]). Anchors and sigmoid are already applied in the model */
+/* score = objectness * class confidence */
+pub fn decode(data: &[f32], num_class: usize, score_box_threshold: f32, score_class_threshold: f32, transform: &ResizeTransform, label_list: &[String]) -> Vec::<BoundingBox> {
+    let mut bbox_list = Vec::<BoundingBox>::new();
+    for box_data in data.chunks_exact(DetectionEngine::MODEL_ELEMENT_NUM_OF_BOX + num_class) {
+        let box_confidence = box_data[4];
+        if box_confidence < score_box_threshold {
+            continue;
+        }
+
+        let mut class_id = 0;
+        let mut confidence: f32 = 0.0;
+        for (class_index, confidence_of_class) in box_data[DetectionEngine::MODEL_ELEMENT_NUM_OF_BOX..].iter().enumerate() {
+            if *confidence_of_class > confidence {
+                confidence = *confidence_of_class;
+                class_id = class_index;
+            }
+        }
+        let score = box_confidence * confidence;
+
+        if score >= score_class_threshold {
+            /* Box in the model input, then in the original image */
+            let (cx, cy, w, h) = (box_data[0], box_data[1], box_data[2], box_data[3]);
+            let (x, y, w, h) = transform.to_original_rect(cx - w / 2.0, cy - h / 2.0, w, h);
+
+            let bbox = BoundingBox {
+                class_id: class_id as i32,
+                label: label_list[class_id].clone(),
+                score: score,
+                x: x,
+                y: y,
+                w: w,
+                h: h,
+            };
+            bbox_list.push(bbox);
+        }
+    }
+    bbox_list
+}
+
+impl FromModelConfig for DetectionEngine {
+    fn default_config() -> ModelConfig {
+        ModelConfig {
+            model_filename: Self::MODEL_FILENAME.to_string(),
+            label_filename: Some(Self::LABEL_FILENAME.to_string()),
+            input_width: Self::MODEL_WIDTH,
+            input_height: Self::MODEL_HEIGHT,
+            input_name: Self::MODEL_NAME_INPUT_0.to_string(),
+            output_names: vec![Self::MODEL_NAME_OUTPUT_0.to_string()],
+            normalize_mean: Self::MODEL_NORMALIZE_MEAN,
+            normalize_norm: Self::MODEL_NORMALIZE_NORM,
+            resize_mode: ResizeMode::Letterbox,
+            pad_value: (114.0, 114.0, 114.0),
+            score_threshold: Self::SCORE_BOX_THRESHOLD,
+            score_class_threshold: Self::SCORE_CLASS_THRESHOLD,
+            nms_iou_threshold: Self::NMS_IOU_THRESHOLD,
+            nms_mode: NmsMode::ClassAware,
+            nms_method: NmsMethod::Hard,
+            soft_nms_sigma: 0.5,
+            max_detections: None,
+            prior_box: None,
+        }
+    }
+
+    /* BoundingBox::score is compared with score_class_threshold (score_threshold is for box confidence) */
+    fn uses_score_class_threshold() -> bool {
+        true
+    }
+
+    fn from_config(config: ModelConfig) -> Result<Self> {
+        if config.output_names.len() != 1 {
+            return Err(Error::Config(format!("{} requires 1 output name", Self::MODEL_NAME)));
+        }
+        let label_filename = config.label_filename.clone()
+            .ok_or_else(|| Error::Config(format!("{} requires label_filename", Self::MODEL_NAME)))?;
+
+        /* Load model */
+        let net = read_net(&config.model_filename)?;
+
+        /* Set output names */
+        let out_blob_names = output_blob_names(&config.output_names);
+
+        /* Initialize normalize param */
+        let normalize_mean = core::Scalar::from(config.normalize_mean);
+        let normalize_norm = core::Scalar::from(config.normalize_norm);
+
+        /* Read label (the number of classes is checked with the output shape) */
+        let label_list = read_label_file(&label_filename, 1)?;
+
+        Ok(DetectionEngine {
+            config: config,
+            net: net,
+            out_blob_names: out_blob_names,
+            normalize_mean: normalize_mean,
+            normalize_norm: normalize_norm,
+            label_list: label_list
+        })
+    }
+}
+
+impl Detector for DetectionEngine {
+    fn process(&mut self, mat: &core::Mat) -> Result<Vec::<BoundingBox>> {
+        /* Pre Process */
+        let (mat_resized, transform) = resize(mat, self.input_size(), self.config.resize_mode, self.config.pad_value)?;
+        let mat_normalized = normalize(&mat_resized, &self.normalize_mean, &self.normalize_norm)?;
+        let mat_blob = dnn::blob_from_image(&mat_normalized, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).map_err(Error::Preprocess)?;
+
+        /* Feed input data */
+        self.net.set_input(&mat_blob, &self.config.input_name, 1.0, core::Scalar::default()).map_err(Error::Inference)?;
+
+        /* Run inference */
+        let mut output_blobs = core::Vector::<core::Mat>::new();
+        self.net.forward(&mut output_blobs, &self.out_blob_names).map_err(Error::Inference)?;
+
+        /* Retrieve output ([1, num_box, 5 + num_class]) */
+        let output_data = output_blobs.get(0).map_err(Error::Inference)?;
+        let shape = output_shape(&output_data);
+        let num_element_of_box = shape.last().cloned().unwrap_or(0);
+        if num_element_of_box <= Self::MODEL_ELEMENT_NUM_OF_BOX {
+            return Err(Error::OutputShape { name: self.config.output_names[0].clone(), expected: Self::MODEL_ELEMENT_NUM_OF_BOX + self.label_list.len(), actual: num_element_of_box });
+        }
+        let num_class = num_element_of_box - Self::MODEL_ELEMENT_NUM_OF_BOX;
+        if num_class > self.label_list.len() {
+            return Err(Error::Config(format!("the model has {} classes, but the label file has only {} labels", num_class, self.label_list.len())));
+        }
+        let output_data = output_to_vec(&output_data, &self.config.output_names[0], shape.iter().product())?;
+
+        /* Decode bbox */
+        let mut bbox_list = decode(&output_data, num_class, self.config.score_threshold, self.config.score_class_threshold, &transform, &self.label_list);
+
+        /* NMS */
+        let bbox_nms_list = apply_nms(&mut bbox_list, &self.config.nms_param(self.config.score_class_threshold));
+
+        Ok(bbox_nms_list)
+    }
+
+    fn input_size(&self) -> core::Size {
+        core::Size { width: self.config.input_width, height: self.config.input_height }
+    }
+
+    fn label_list(&self) -> &[String] {
+        &self.label_list
+    }
+
+    fn model_name(&self) -> &str {
+        Self::MODEL_NAME
+    }
+
+    /* The score of BoundingBox is compared with score_class_threshold */
+    fn score_threshold(&self) -> f32 {
+        self.config.score_class_threshold
+    }
+
+    fn set_score_threshold(&mut self, threshold: f32) {
+        self.config.score_class_threshold = threshold;
+    }
+
+    fn process_with_score_threshold(&mut self, mat: &core::Mat, score_threshold: f32) -> Result<Vec::<BoundingBox>> {
+        process_with_score_class_threshold(self, |engine| &mut engine.config, mat, score_threshold)
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label_list() -> Vec::<String> {
+        vec!["person".to_string(), "bicycle".to_string(), "car".to_string()]
+    }
+
+    #[test]
+    fn decode_output() {
+        /* 3 boxes, 3 classes */
+        let data = vec![
+            100.0, 200.0, 40.0, 80.0, 0.9, 0.1, 0.8, 0.1,       /* bicycle, 0.72 */
+            300.0, 300.0, 10.0, 10.0, 0.1, 0.9, 0.0, 0.0,       /* low objectness */
+            50.0, 60.0, 20.0, 20.0, 0.5, 0.2, 0.1, 0.4,         /* car, 0.2 (low score) */
+        ];
+        let transform = ResizeTransform { scale_x: 1.0, scale_y: 1.0, offset_x: 0.0, offset_y: 0.0 };
+        let bbox_list = decode(&data, 3, 0.25, 0.25, &transform, &label_list());
+        assert_eq!(bbox_list.len(), 1);
+        assert_eq!(bbox_list[0].class_id, 1);
+        assert_eq!(bbox_list[0].label, "bicycle");
+        assert!((bbox_list[0].score - 0.72).abs() < 1e-6);
+        assert_eq!((bbox_list[0].x, bbox_list[0].y, bbox_list[0].w, bbox_list[0].h), (80.0, 160.0, 40.0, 80.0));
+    }
+
+    #[test]
+    fn decode_letterbox() {
+        /* 1280x720 image letterboxed into 640x640: scale = 0.5, offset_y = 140 */
+        let data = vec![320.0, 320.0, 100.0, 50.0, 1.0, 0.0, 0.0, 1.0];
+        let transform = ResizeTransform { scale_x: 0.5, scale_y: 0.5, offset_x: 0.0, offset_y: 140.0 };
+        let bbox_list = decode(&data, 3, 0.25, 0.25, &transform, &label_list());
+        assert_eq!(bbox_list.len(), 1);
+        assert_eq!(bbox_list[0].label, "car");
+        assert_eq!((bbox_list[0].x, bbox_list[0].y, bbox_list[0].w, bbox_list[0].h), (540.0, 310.0, 200.0, 100.0));
+    }
+}
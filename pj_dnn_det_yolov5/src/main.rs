@@ -0,0 +1,36 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use pj_dnn_det_yolov5::detection_engine::detection_engine::DetectionEngine;
+use vision_core::cli::{Args, Parser};
+use vision_core::detector::Detector;
+use vision_core::runner::{run, AppResult};
+
+/////////////////////////////////////////////////////////////////
+/* Usage (see --help for all the options) */
+/*   cargo run */
+/*   cargo run -- --image ../resource/dog.jpg --output result.jpg */
+/*   cargo run -- --video ../resource/Megamind.avi --output result.mp4 */
+/*   cargo run -- --camera 0 */
+/*   cargo run -- --dir ../resource --output result --headless */
+const DEFAULT_IMAGE_FILENAME: &str = "../resource/dog.jpg";
+
+/////////////////////////////////////////////////////////////////
+fn main() -> AppResult {
+    let args = Args::parse();
+
+    /* Create detection engine */
+    let mut engine: Box<dyn Detector> = Box::new(args.engine_builder::<DetectionEngine>()?.build()?);
+
+    run(engine.as_mut(), &args, DEFAULT_IMAGE_FILENAME)
+}
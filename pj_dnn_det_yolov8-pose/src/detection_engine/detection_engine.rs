@@ -0,0 +1,245 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::{prelude::*, core, dnn};
+
+use vision_core::bounding_box::{BoundingBox, NmsMethod, NmsMode, apply_nms_indices};
+use vision_core::config::{ModelConfig, FromModelConfig};
+use vision_core::detector::Detector;
+use vision_core::dnn_helper::{read_net, read_label_file, output_to_vec, output_shape, normalize, output_blob_names};
+use vision_core::error::{Error, Result};
+use vision_core::pose::{Keypoint, PoseDetection};
+use vision_core::preprocess::{resize, ResizeMode, ResizeTransform};
+
+
+/////////////////////////////////////////////////////////////////
+pub struct DetectionEngine {
+    config: ModelConfig,
+    net: dnn::Net,
+    out_blob_names: core::Vector::<String>,
+    normalize_mean: core::Scalar,
+    normalize_norm: core::Scalar,
+    label_list: Vec::<String>,
+}
+
+impl DetectionEngine {
+    /* Model Parameters */
+    const MODEL_NAME: &'static str = "YOLOv8-pose";
+    const MODEL_FILENAME: &'static str = "../resource/model/yolov8n-pose.onnx";
+    const MODEL_NORMALIZE_MEAN: (f64, f64, f64) = (0.0, 0.0, 0.0);
+    const MODEL_NORMALIZE_NORM: (f64, f64, f64) = (1.0, 1.0, 1.0);
+    const MODEL_WIDTH: i32 = 640;
+    const MODEL_HEIGHT: i32 = 640;
+    const MODEL_NAME_INPUT_0: &'static str = "images";
+    const MODEL_NAME_OUTPUT_0: &'static str = "output0";     // [1, 4 + 1 + num_keypoint * 3, num_box]
+    const MODEL_ELEMENT_NUM_OF_BOX: usize = 5;               // x, y, w, h, person confidence, [keypoint x, y, score]
+    const MODEL_NUMBER_OF_KEYPOINT: usize = 17;
+    const LABEL: &'static str = "person";
+
+    /* Other Parameters */
+    const SCORE_THRESHOLD: f32 = 0.25;
+    const NMS_IOU_THRESHOLD: f32 = 0.45;
+
+    pub fn config(&self) -> &ModelConfig {
+        &self.config
+    }
+
+    /* Run detection with keypoints. Coordinates of the result are in the original image (mat) */
+    pub fn process_with_keypoints(&mut self, mat: &core::Mat) -> Result<Vec::<PoseDetection>> {
+        /* Pre Process */
+        let (mat_resized, transform) = resize(mat, self.input_size(), self.config.resize_mode, self.config.pad_value)?;
+        let mat_normalized = normalize(&mat_resized, &self.normalize_mean, &self.normalize_norm)?;
+        let mat_blob = dnn::blob_from_image(&mat_normalized, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).map_err(Error::Preprocess)?;
+
+        /* Feed input data */
+        self.net.set_input(&mat_blob, &self.config.input_name, 1.0, core::Scalar::default()).map_err(Error::Inference)?;
+
+        /* Run inference */
+        let mut output_blobs = core::Vector::<core::Mat>::new();
+        self.net.forward(&mut output_blobs, &self.out_blob_names).map_err(Error::Inference)?;
+
+        /* Retrieve output ([1, 5 + num_keypoint * 3, num_box]) */
+        let output_data = output_blobs.get(0).map_err(Error::Inference)?;
+        let shape = output_shape(&output_data);
+        let expected = Self::MODEL_ELEMENT_NUM_OF_BOX + Self::MODEL_NUMBER_OF_KEYPOINT * 3;
+        let num_element_of_box = if shape.len() == 3 { shape[1] } else { 0 };
+        if num_element_of_box != expected {
+            return Err(Error::OutputShape { name: self.config.output_names[0].clone(), expected: expected, actual: num_element_of_box });
+        }
+        let output_data = output_to_vec(&output_data, &self.config.output_names[0], shape.iter().product())?;
+
+        /* Decode bbox and keypoints */
+        let candidate_list = decode(&output_data, shape[2], self.config.score_threshold, &transform, &self.label_list[0]);
+
+        /* NMS, then keypoints of the remaining boxes */
+        let candidate_bbox_list: Vec::<BoundingBox> = candidate_list.iter().map(|pose| pose.bbox.clone()).collect();
+        let bbox_nms_list = apply_nms_indices(&candidate_bbox_list, &self.config.nms_param(self.config.score_threshold));
+        let pose_list = bbox_nms_list.into_iter()
+            .map(|(index, bbox)| PoseDetection {
+                bbox: bbox,
+                keypoint_list: candidate_list[index].keypoint_list.clone(),
+            })
+            .collect();
+        Ok(pose_list)
+    }
+}
+
+/* Decode the output of the exported model ([5 + num_keypoint * 3, num_box]) */
+pub fn decode(data: &[f32], num_box: usize, score_threshold: f32, transform: &ResizeTransform, label: &str) -> Vec::<PoseDetection> {
+    let num_element_of_box = DetectionEngine::MODEL_ELEMENT_NUM_OF_BOX + DetectionEngine::MODEL_NUMBER_OF_KEYPOINT * 3;
+    let mut pose_list = Vec::<PoseDetection>::new();
+    for box_index in 0 .. num_box.min(data.len() / num_element_of_box) {
+        let value = |element_index: usize| data[element_index * num_box + box_index];
+
+        let confidence = value(4);
+        if confidence >= score_threshold {
+            /* Box in the model input, then in the original image */
+            let (cx, cy, w, h) = (value(0), value(1), value(2), value(3));
+            let (x, y, w, h) = transform.to_original_rect(cx - w / 2.0, cy - h / 2.0, w, h);
+
+            let bbox = BoundingBox {
+                class_id: 0,
+                label: label.to_string(),
+                score: confidence,
+                x: x,
+                y: y,
+                w: w,
+                h: h,
+            };
+            let keypoint_list = (0 .. DetectionEngine::MODEL_NUMBER_OF_KEYPOINT).map(|i| {
+                let index = DetectionEngine::MODEL_ELEMENT_NUM_OF_BOX + i * 3;
+                let (x, y) = transform.to_original_point(value(index), value(index + 1));
+                Keypoint { x: x, y: y, score: value(index + 2) }
+            }).collect();
+            pose_list.push(PoseDetection { bbox: bbox, keypoint_list: keypoint_list });
+        }
+    }
+    pose_list
+}
+
+impl FromModelConfig for DetectionEngine {
+    fn default_config() -> ModelConfig {
+        ModelConfig {
+            model_filename: Self::MODEL_FILENAME.to_string(),
+            label_filename: None,
+            input_width: Self::MODEL_WIDTH,
+            input_height: Self::MODEL_HEIGHT,
+            input_name: Self::MODEL_NAME_INPUT_0.to_string(),
+            output_names: vec![Self::MODEL_NAME_OUTPUT_0.to_string()],
+            normalize_mean: Self::MODEL_NORMALIZE_MEAN,
+            normalize_norm: Self::MODEL_NORMALIZE_NORM,
+            resize_mode: ResizeMode::Letterbox,
+            pad_value: (114.0, 114.0, 114.0),
+            score_threshold: Self::SCORE_THRESHOLD,
+            score_class_threshold: Self::SCORE_THRESHOLD,
+            nms_iou_threshold: Self::NMS_IOU_THRESHOLD,
+            nms_mode: NmsMode::ClassAgnostic,
+            nms_method: NmsMethod::Hard,
+            soft_nms_sigma: 0.5,
+            max_detections: None,
+            prior_box: None,
+        }
+    }
+
+    fn from_config(config: ModelConfig) -> Result<Self> {
+        if config.output_names.len() != 1 {
+            return Err(Error::Config(format!("{} requires 1 output name", Self::MODEL_NAME)));
+        }
+
+        /* Load model */
+        let net = read_net(&config.model_filename)?;
+
+        /* Set output names */
+        let out_blob_names = output_blob_names(&config.output_names);
+
+        /* Initialize normalize param */
+        let normalize_mean = core::Scalar::from(config.normalize_mean);
+        let normalize_norm = core::Scalar::from(config.normalize_norm);
+
+        /* Read label (only the first one is used, as the model has person class only) */
+        let label_list = match &config.label_filename {
+            Some(label_filename) => read_label_file(label_filename, 1)?,
+            None => vec![Self::LABEL.to_string()],
+        };
+
+        Ok(DetectionEngine {
+            config: config,
+            net: net,
+            out_blob_names: out_blob_names,
+            normalize_mean: normalize_mean,
+            normalize_norm: normalize_norm,
+            label_list: label_list
+        })
+    }
+}
+
+impl Detector for DetectionEngine {
+    /* Use process_with_keypoints to get keypoints */
+    fn process(&mut self, mat: &core::Mat) -> Result<Vec::<BoundingBox>> {
+        Ok(self.process_with_keypoints(mat)?.into_iter().map(|pose| pose.bbox).collect())
+    }
+
+    fn input_size(&self) -> core::Size {
+        core::Size { width: self.config.input_width, height: self.config.input_height }
+    }
+
+    fn label_list(&self) -> &[String] {
+        &self.label_list
+    }
+
+    fn model_name(&self) -> &str {
+        Self::MODEL_NAME
+    }
+
+    fn score_threshold(&self) -> f32 {
+        self.config.score_threshold
+    }
+
+    fn set_score_threshold(&mut self, threshold: f32) {
+        self.config.score_threshold = threshold;
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_output() {
+        /* 2 boxes in [5 + 17 * 3, num_box] */
+        let num_element = 5 + 17 * 3;
+        let mut data = vec![0.0; num_element * 2];
+        let mut set = |element_index: usize, box_index: usize, value: f32| data[element_index * 2 + box_index] = value;
+        for (i, value) in [100.0, 200.0, 40.0, 80.0, 0.9].iter().enumerate() {
+            set(i, 0, *value);
+        }
+        set(4, 1, 0.1);     /* low score */
+        for k in 0 .. 17 {
+            set(5 + k * 3, 0, 90.0 + k as f32);
+            set(5 + k * 3 + 1, 0, 170.0 + k as f32 * 2.0);
+            set(5 + k * 3 + 2, 0, if k == 0 { 0.2 } else { 0.8 });
+        }
+
+        /* Letterbox: scale = 0.5, offset_y = 10 */
+        let transform = ResizeTransform { scale_x: 0.5, scale_y: 0.5, offset_x: 0.0, offset_y: 10.0 };
+        let pose_list = decode(&data, 2, 0.25, &transform, "person");
+        assert_eq!(pose_list.len(), 1);
+        let pose = &pose_list[0];
+        assert_eq!((pose.bbox.x, pose.bbox.y, pose.bbox.w, pose.bbox.h), (160.0, 300.0, 80.0, 160.0));
+        assert_eq!(pose.keypoint_list.len(), 17);
+        assert_eq!(pose.keypoint_list[0], Keypoint { x: 180.0, y: 320.0, score: 0.2 });
+        assert_eq!(pose.keypoint("right_ankle"), Some(&Keypoint { x: 212.0, y: 384.0, score: 0.8 }));
+    }
+}
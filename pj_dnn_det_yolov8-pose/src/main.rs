@@ -0,0 +1,53 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::core;
+
+use pj_dnn_det_yolov8_pose::detection_engine::detection_engine::DetectionEngine;
+use vision_core::bounding_box::BoundingBox;
+use vision_core::cli::{Args, Parser};
+use vision_core::error::Error;
+use vision_core::helper_cv::{draw_skeleton, draw_joints, ColorGenerator};
+use vision_core::pose::COCO_SKELETON;
+use vision_core::runner::{run_with, draw_bounding_box_list, AppResult};
+
+/////////////////////////////////////////////////////////////////
+/* Usage (see --help for all the options) */
+/*   cargo run */
+/*   cargo run -- --image ../resource/people_0.jpg --output result.jpg */
+/*   cargo run -- --video ../resource/Megamind.avi --output result.mp4 */
+/*   cargo run -- --camera 0 */
+/*   cargo run -- --dir ../resource --output result --headless */
+const DEFAULT_IMAGE_FILENAME: &str = "../resource/people_0.jpg";
+const KEYPOINT_SCORE_THRESHOLD: f32 = 0.5;
+
+/////////////////////////////////////////////////////////////////
+fn main() -> AppResult {
+    let args = Args::parse();
+
+    /* Create detection engine */
+    let mut engine = args.engine_builder::<DetectionEngine>()?.build()?;
+
+    let color_generator = ColorGenerator::new(20, 30);
+    let color_generator_limb = ColorGenerator::new(COCO_SKELETON.len() as u32, 50);
+    run_with(&mut |mat: &mut core::Mat, text_thickness: i32| {
+        let pose_list = engine.process_with_keypoints(mat)?;
+        let bbox_list: Vec::<BoundingBox> = pose_list.iter().map(|pose| pose.bbox.clone()).collect();
+        draw_bounding_box_list(mat, &bbox_list, &color_generator, text_thickness).map_err(Error::Postprocess)?;
+        for pose in pose_list.iter() {
+            draw_skeleton(mat, &pose.keypoint_list, &COCO_SKELETON, KEYPOINT_SCORE_THRESHOLD, &color_generator_limb, 2).map_err(Error::Postprocess)?;
+            draw_joints(mat, &pose.keypoint_list, KEYPOINT_SCORE_THRESHOLD, core::Scalar::new(255., 255., 255., 255.), 3).map_err(Error::Postprocess)?;
+        }
+        Ok(pose_list.len())
+    }, &args, DEFAULT_IMAGE_FILENAME)
+}
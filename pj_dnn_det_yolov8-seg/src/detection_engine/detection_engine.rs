@@ -0,0 +1,285 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::{prelude::*, core, dnn};
+
+use vision_core::bounding_box::{BoundingBox, NmsMethod, NmsMode, apply_nms_indices};
+use vision_core::config::{ModelConfig, FromModelConfig};
+use vision_core::detector::Detector;
+use vision_core::dnn_helper::{read_net, read_label_file, output_to_vec, output_shape, normalize, output_blob_names};
+use vision_core::error::{Error, Result};
+use vision_core::mask::{InstanceDetection, InstanceMask, PrototypeMask};
+use vision_core::preprocess::{resize, ResizeMode, ResizeTransform};
+
+
+/////////////////////////////////////////////////////////////////
+pub struct DetectionEngine {
+    config: ModelConfig,
+    net: dnn::Net,
+    out_blob_names: core::Vector::<String>,
+    normalize_mean: core::Scalar,
+    normalize_norm: core::Scalar,
+    label_list: Vec::<String>,
+}
+
+impl DetectionEngine {
+    /* Model Parameters */
+    const MODEL_NAME: &'static str = "YOLOv8-seg";
+    const MODEL_FILENAME: &'static str = "../resource/model/yolov8n-seg.onnx";
+    const LABEL_FILENAME: &'static str = "../resource/model/label_coco_80.txt";
+    const MODEL_NORMALIZE_MEAN: (f64, f64, f64) = (0.0, 0.0, 0.0);
+    const MODEL_NORMALIZE_NORM: (f64, f64, f64) = (1.0, 1.0, 1.0);
+    const MODEL_WIDTH: i32 = 640;
+    const MODEL_HEIGHT: i32 = 640;
+    const MODEL_NAME_INPUT_0: &'static str = "images";
+    const MODEL_NAME_OUTPUT_0: &'static str = "output0";     // box: [1, 4 + num_class + num_mask, num_box]
+    const MODEL_NAME_OUTPUT_1: &'static str = "output1";     // prototype: [1, num_mask, height, width]
+    const MODEL_ELEMENT_NUM_OF_BOX: usize = 4;               // x, y, w, h, [class confidence], [mask coefficient]
+
+    /* Other Parameters */
+    const SCORE_THRESHOLD: f32 = 0.25;
+    const NMS_IOU_THRESHOLD: f32 = 0.45;
+    const MASK_THRESHOLD: f32 = 0.5;
+
+    pub fn config(&self) -> &ModelConfig {
+        &self.config
+    }
+
+    /* Run detection with masks. Coordinates of the result are in the original image (mat) */
+    pub fn process_with_mask(&mut self, mat: &core::Mat) -> Result<Vec::<InstanceDetection>> {
+        self.detect(mat, true)
+    }
+
+    fn detect(&mut self, mat: &core::Mat, with_mask: bool) -> Result<Vec::<InstanceDetection>> {
+        /* Pre Process */
+        let (mat_resized, transform) = resize(mat, self.input_size(), self.config.resize_mode, self.config.pad_value)?;
+        let mat_normalized = normalize(&mat_resized, &self.normalize_mean, &self.normalize_norm)?;
+        let mat_blob = dnn::blob_from_image(&mat_normalized, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).map_err(Error::Preprocess)?;
+
+        /* Feed input data */
+        self.net.set_input(&mat_blob, &self.config.input_name, 1.0, core::Scalar::default()).map_err(Error::Inference)?;
+
+        /* Run inference */
+        let mut output_blobs = core::Vector::<core::Mat>::new();
+        self.net.forward(&mut output_blobs, &self.out_blob_names).map_err(Error::Inference)?;
+
+        /* Retrieve prototype ([1, num_mask, height, width]) */
+        let output_prototype = output_blobs.get(1).map_err(Error::Inference)?;
+        let prototype_shape = output_shape(&output_prototype);
+        if prototype_shape.len() != 4 {
+            return Err(Error::OutputShape { name: self.config.output_names[1].clone(), expected: 4, actual: prototype_shape.len() });
+        }
+        let (num_mask, prototype_height, prototype_width) = (prototype_shape[1], prototype_shape[2], prototype_shape[3]);
+        if prototype_width == 0 || prototype_height == 0 {
+            /* The prototype is 1/4 of the model input */
+            let expected = num_mask * (self.config.input_width / 4 * self.config.input_height / 4) as usize;
+            return Err(Error::OutputShape { name: self.config.output_names[1].clone(), expected: expected, actual: 0 });
+        }
+
+        /* Retrieve box ([1, 4 + num_class + num_mask, num_box]) */
+        let output_box = output_blobs.get(0).map_err(Error::Inference)?;
+        let shape = output_shape(&output_box);
+        let num_element_of_box = if shape.len() == 3 { shape[1] } else { 0 };
+        if num_element_of_box <= Self::MODEL_ELEMENT_NUM_OF_BOX + num_mask {
+            return Err(Error::OutputShape { name: self.config.output_names[0].clone(), expected: Self::MODEL_ELEMENT_NUM_OF_BOX + self.label_list.len() + num_mask, actual: num_element_of_box });
+        }
+        let num_class = num_element_of_box - Self::MODEL_ELEMENT_NUM_OF_BOX - num_mask;
+        if num_class > self.label_list.len() {
+            return Err(Error::Config(format!("the model has {} classes, but the label file has only {} labels", num_class, self.label_list.len())));
+        }
+        let output_box = output_to_vec(&output_box, &self.config.output_names[0], shape.iter().product())?;
+
+        /* Decode bbox */
+        let candidate_list = decode(&output_box, num_class, num_mask, shape[2], self.config.score_threshold, &transform, &self.label_list);
+
+        /* NMS */
+        let (candidate_bbox_list, coefficient_list): (Vec::<BoundingBox>, Vec::<Vec::<f32>>) = candidate_list.into_iter().unzip();
+        let bbox_nms_list = apply_nms_indices(&candidate_bbox_list, &self.config.nms_param(self.config.score_threshold));
+        if !with_mask {
+            return Ok(bbox_nms_list.into_iter().map(|(_, bbox)| InstanceDetection { bbox: bbox, mask: None }).collect());
+        }
+
+        /* Decode mask */
+        let output_prototype = output_to_vec(&output_prototype, &self.config.output_names[1], prototype_shape.iter().product())?;
+        let prototype = PrototypeMask::new(&output_prototype, num_mask, prototype_width, prototype_height)?;
+        let scale_x = prototype_width as f32 / self.config.input_width as f32;
+        let scale_y = prototype_height as f32 / self.config.input_height as f32;
+        let to_prototype = |x: f32, y: f32| {
+            let (x, y) = transform.to_model_point(x, y);
+            (x * scale_x, y * scale_y)
+        };
+        let mut detection_list = Vec::<InstanceDetection>::new();
+        for (index, bbox) in bbox_nms_list {
+            let mut bbox_in_image = bbox.clone();
+            bbox_in_image.clamp(mat.cols() as f32, mat.rows() as f32);
+            let rect = bbox_in_image.to_rect();
+            let mask = prototype.decode(&coefficient_list[index], &bbox, rect, &to_prototype, Self::MASK_THRESHOLD)?;
+            detection_list.push(InstanceDetection {
+                bbox: bbox,
+                mask: Some(InstanceMask::from_vec(rect, &mask)?),
+            });
+        }
+        Ok(detection_list)
+    }
+}
+
+/* Decode the output of the exported model ([4 + num_class + num_mask, num_box]). Returns bbox and its mask coefficients */
+pub fn decode(data: &[f32], num_class: usize, num_mask: usize, num_box: usize, score_threshold: f32, transform: &ResizeTransform, label_list: &[String]) -> Vec::<(BoundingBox, Vec::<f32>)> {
+    let num_element_of_box = DetectionEngine::MODEL_ELEMENT_NUM_OF_BOX + num_class + num_mask;
+    let mut candidate_list = Vec::<(BoundingBox, Vec::<f32>)>::new();
+    for box_index in 0 .. num_box.min(data.len() / num_element_of_box) {
+        let value = |element_index: usize| data[element_index * num_box + box_index];
+
+        let mut class_id = 0;
+        let mut confidence: f32 = 0.0;
+        for class_index in 0 .. num_class {
+            let confidence_of_class = value(DetectionEngine::MODEL_ELEMENT_NUM_OF_BOX + class_index);
+            if confidence_of_class > confidence {
+                confidence = confidence_of_class;
+                class_id = class_index;
+            }
+        }
+
+        if confidence >= score_threshold {
+            /* Box in the model input, then in the original image */
+            let (cx, cy, w, h) = (value(0), value(1), value(2), value(3));
+            let (x, y, w, h) = transform.to_original_rect(cx - w / 2.0, cy - h / 2.0, w, h);
+
+            let bbox = BoundingBox {
+                class_id: class_id as i32,
+                label: label_list[class_id].clone(),
+                score: confidence,
+                x: x,
+                y: y,
+                w: w,
+                h: h,
+            };
+            let coefficient = (0 .. num_mask).map(|i| value(DetectionEngine::MODEL_ELEMENT_NUM_OF_BOX + num_class + i)).collect();
+            candidate_list.push((bbox, coefficient));
+        }
+    }
+    candidate_list
+}
+
+impl FromModelConfig for DetectionEngine {
+    fn default_config() -> ModelConfig {
+        ModelConfig {
+            model_filename: Self::MODEL_FILENAME.to_string(),
+            label_filename: Some(Self::LABEL_FILENAME.to_string()),
+            input_width: Self::MODEL_WIDTH,
+            input_height: Self::MODEL_HEIGHT,
+            input_name: Self::MODEL_NAME_INPUT_0.to_string(),
+            output_names: vec![Self::MODEL_NAME_OUTPUT_0.to_string(), Self::MODEL_NAME_OUTPUT_1.to_string()],
+            normalize_mean: Self::MODEL_NORMALIZE_MEAN,
+            normalize_norm: Self::MODEL_NORMALIZE_NORM,
+            resize_mode: ResizeMode::Letterbox,
+            pad_value: (114.0, 114.0, 114.0),
+            score_threshold: Self::SCORE_THRESHOLD,
+            score_class_threshold: Self::SCORE_THRESHOLD,
+            nms_iou_threshold: Self::NMS_IOU_THRESHOLD,
+            nms_mode: NmsMode::ClassAware,
+            nms_method: NmsMethod::Hard,
+            soft_nms_sigma: 0.5,
+            max_detections: None,
+            prior_box: None,
+        }
+    }
+
+    fn from_config(config: ModelConfig) -> Result<Self> {
+        if config.output_names.len() != 2 {
+            return Err(Error::Config(format!("{} requires 2 output names (box, prototype)", Self::MODEL_NAME)));
+        }
+        let label_filename = config.label_filename.clone()
+            .ok_or_else(|| Error::Config(format!("{} requires label_filename", Self::MODEL_NAME)))?;
+
+        /* Load model */
+        let net = read_net(&config.model_filename)?;
+
+        /* Set output names */
+        let out_blob_names = output_blob_names(&config.output_names);
+
+        /* Initialize normalize param */
+        let normalize_mean = core::Scalar::from(config.normalize_mean);
+        let normalize_norm = core::Scalar::from(config.normalize_norm);
+
+        /* Read label (the number of classes is checked with the output shape) */
+        let label_list = read_label_file(&label_filename, 1)?;
+
+        Ok(DetectionEngine {
+            config: config,
+            net: net,
+            out_blob_names: out_blob_names,
+            normalize_mean: normalize_mean,
+            normalize_norm: normalize_norm,
+            label_list: label_list
+        })
+    }
+}
+
+impl Detector for DetectionEngine {
+    /* Masks are not decoded. Use process_with_mask to get them */
+    fn process(&mut self, mat: &core::Mat) -> Result<Vec::<BoundingBox>> {
+        Ok(self.detect(mat, false)?.into_iter().map(|detection| detection.bbox).collect())
+    }
+
+    fn input_size(&self) -> core::Size {
+        core::Size { width: self.config.input_width, height: self.config.input_height }
+    }
+
+    fn label_list(&self) -> &[String] {
+        &self.label_list
+    }
+
+    fn model_name(&self) -> &str {
+        Self::MODEL_NAME
+    }
+
+    fn score_threshold(&self) -> f32 {
+        self.config.score_threshold
+    }
+
+    fn set_score_threshold(&mut self, threshold: f32) {
+        self.config.score_threshold = threshold;
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_output() {
+        /* 2 boxes, 2 classes, 3 mask coefficients in [4 + num_class + num_mask, num_box] */
+        let label_list = vec!["person".to_string(), "car".to_string()];
+        let data = vec![
+            100.0, 50.0,        /* cx */
+            200.0, 60.0,        /* cy */
+            40.0, 20.0,         /* w */
+            80.0, 20.0,         /* h */
+            0.1, 0.1,           /* person */
+            0.9, 0.2,           /* car */
+            1.0, 4.0,           /* coefficient 0 */
+            2.0, 5.0,           /* coefficient 1 */
+            3.0, 6.0,           /* coefficient 2 */
+        ];
+        let transform = ResizeTransform { scale_x: 1.0, scale_y: 1.0, offset_x: 0.0, offset_y: 0.0 };
+        let candidate_list = decode(&data, 2, 3, 2, 0.25, &transform, &label_list);
+        assert_eq!(candidate_list.len(), 1);
+        let (bbox, coefficient) = &candidate_list[0];
+        assert_eq!(bbox.label, "car");
+        assert_eq!((bbox.x, bbox.y, bbox.w, bbox.h), (80.0, 160.0, 40.0, 80.0));
+        assert_eq!(coefficient, &vec![1.0, 2.0, 3.0]);
+    }
+}
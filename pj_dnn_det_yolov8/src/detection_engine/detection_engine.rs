@@ -0,0 +1,288 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::{prelude::*, core, dnn};
+
+use vision_core::bounding_box::{BoundingBox, NmsMethod, NmsMode, apply_nms};
+use vision_core::config::{ModelConfig, FromModelConfig};
+use vision_core::detector::Detector;
+use vision_core::dnn_helper::{read_net, read_label_file, output_to_vec, output_shape, normalize, output_blob_names};
+use vision_core::error::{Error, Result};
+use vision_core::preprocess::{resize, ResizeMode, ResizeTransform};
+
+
+/////////////////////////////////////////////////////////////////
+pub struct DetectionEngine {
+    config: ModelConfig,
+    net: dnn::Net,
+    out_blob_names: core::Vector::<String>,
+    normalize_mean: core::Scalar,
+    normalize_norm: core::Scalar,
+    label_list: Vec::<String>,
+}
+
+impl DetectionEngine {
+    /* Model Parameters */
+    const MODEL_NAME: &'static str = "YOLOv8";
+    const MODEL_FILENAME: &'static str = "../resource/model/yolov8n.onnx";
+    const LABEL_FILENAME: &'static str = "../resource/model/label_coco_80.txt";
+    const MODEL_NORMALIZE_MEAN: (f64, f64, f64) = (0.0, 0.0, 0.0);
+    const MODEL_NORMALIZE_NORM: (f64, f64, f64) = (1.0, 1.0, 1.0);
+    const MODEL_WIDTH: i32 = 640;
+    const MODEL_HEIGHT: i32 = 640;
+    const MODEL_NAME_INPUT_0: &'static str = "images";
+    const MODEL_NAME_OUTPUT_0: &'static str = "output0";
+    const MODEL_ELEMENT_NUM_OF_BOX: usize = 4;     // x, y, w, h, [class confidence]
+
+    /* Other Parameters */
+    const SCORE_THRESHOLD: f32 = 0.25;
+    const NMS_IOU_THRESHOLD: f32 = 0.45;
+
+    pub fn config(&self) -> &ModelConfig {
+        &self.config
+    }
+}
+
+/* Layout of the output [dim_0, dim_1] decided by the number of elements of a box (4 + num_class) */
+/* Returns Some(true) for [4 + num_class, num_box] (default of the exported model), Some(false) for [num_box, 4 + num_class] */
+pub fn is_transposed_layout(dim_0: usize, dim_1: usize, num_class: usize) -> Option<bool> {
+    let num_element_of_box = DetectionEngine::MODEL_ELEMENT_NUM_OF_BOX + num_class;
+    if dim_0 == num_element_of_box {
+        Some(true)
+    } else if dim_1 == num_element_of_box {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/* Decode the output of the exported model. There is no objectness, and sigmoid is already applied to class confidence */
+/* is_transposed: data is [4 + num_class, num_box] (default of the exported model), otherwise [num_box, 4 + num_class] */
+pub fn decode(data: &[f32], num_class: usize, num_box: usize, is_transposed: bool, score_threshold: f32, transform: &ResizeTransform, label_list: &[String]) -> Vec::<BoundingBox> {
+    let num_element_of_box = DetectionEngine::MODEL_ELEMENT_NUM_OF_BOX + num_class;
+    let (box_stride, element_stride) = if is_transposed { (1, num_box) } else { (num_element_of_box, 1) };
+    let mut bbox_list = Vec::<BoundingBox>::new();
+    for box_index in 0 .. num_box.min(data.len() / num_element_of_box) {
+        let value = |element_index: usize| data[box_index * box_stride + element_index * element_stride];
+
+        let mut class_id = 0;
+        let mut confidence: f32 = 0.0;
+        for class_index in 0 .. num_class {
+            let confidence_of_class = value(DetectionEngine::MODEL_ELEMENT_NUM_OF_BOX + class_index);
+            if confidence_of_class > confidence {
+                confidence = confidence_of_class;
+                class_id = class_index;
+            }
+        }
+
+        if confidence >= score_threshold {
+            /* Box in the model input, then in the original image */
+            let (cx, cy, w, h) = (value(0), value(1), value(2), value(3));
+            let (x, y, w, h) = transform.to_original_rect(cx - w / 2.0, cy - h / 2.0, w, h);
+
+            let bbox = BoundingBox {
+                class_id: class_id as i32,
+                label: label_list[class_id].clone(),
+                score: confidence,
+                x: x,
+                y: y,
+                w: w,
+                h: h,
+            };
+            bbox_list.push(bbox);
+        }
+    }
+    bbox_list
+}
+
+impl FromModelConfig for DetectionEngine {
+    fn default_config() -> ModelConfig {
+        ModelConfig {
+            model_filename: Self::MODEL_FILENAME.to_string(),
+            label_filename: Some(Self::LABEL_FILENAME.to_string()),
+            input_width: Self::MODEL_WIDTH,
+            input_height: Self::MODEL_HEIGHT,
+            input_name: Self::MODEL_NAME_INPUT_0.to_string(),
+            output_names: vec![Self::MODEL_NAME_OUTPUT_0.to_string()],
+            normalize_mean: Self::MODEL_NORMALIZE_MEAN,
+            normalize_norm: Self::MODEL_NORMALIZE_NORM,
+            resize_mode: ResizeMode::Letterbox,
+            pad_value: (114.0, 114.0, 114.0),
+            score_threshold: Self::SCORE_THRESHOLD,
+            score_class_threshold: Self::SCORE_THRESHOLD,
+            nms_iou_threshold: Self::NMS_IOU_THRESHOLD,
+            nms_mode: NmsMode::ClassAware,
+            nms_method: NmsMethod::Hard,
+            soft_nms_sigma: 0.5,
+            max_detections: None,
+            prior_box: None,
+        }
+    }
+
+    fn from_config(config: ModelConfig) -> Result<Self> {
+        if config.output_names.len() != 1 {
+            return Err(Error::Config(format!("{} requires 1 output name", Self::MODEL_NAME)));
+        }
+        let label_filename = config.label_filename.clone()
+            .ok_or_else(|| Error::Config(format!("{} requires label_filename", Self::MODEL_NAME)))?;
+
+        /* Load model */
+        let net = read_net(&config.model_filename)?;
+
+        /* Set output names */
+        let out_blob_names = output_blob_names(&config.output_names);
+
+        /* Initialize normalize param */
+        let normalize_mean = core::Scalar::from(config.normalize_mean);
+        let normalize_norm = core::Scalar::from(config.normalize_norm);
+
+        /* Read label (the number of classes is checked with the output shape) */
+        let label_list = read_label_file(&label_filename, 1)?;
+
+        Ok(DetectionEngine {
+            config: config,
+            net: net,
+            out_blob_names: out_blob_names,
+            normalize_mean: normalize_mean,
+            normalize_norm: normalize_norm,
+            label_list: label_list
+        })
+    }
+}
+
+impl Detector for DetectionEngine {
+    fn process(&mut self, mat: &core::Mat) -> Result<Vec::<BoundingBox>> {
+        /* Pre Process */
+        let (mat_resized, transform) = resize(mat, self.input_size(), self.config.resize_mode, self.config.pad_value)?;
+        let mat_normalized = normalize(&mat_resized, &self.normalize_mean, &self.normalize_norm)?;
+        let mat_blob = dnn::blob_from_image(&mat_normalized, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).map_err(Error::Preprocess)?;
+
+        /* Feed input data */
+        self.net.set_input(&mat_blob, &self.config.input_name, 1.0, core::Scalar::default()).map_err(Error::Inference)?;
+
+        /* Run inference */
+        let mut output_blobs = core::Vector::<core::Mat>::new();
+        self.net.forward(&mut output_blobs, &self.out_blob_names).map_err(Error::Inference)?;
+
+        /* Retrieve output ([1, 4 + num_class, num_box]. [1, num_box, 4 + num_class] is also accepted) */
+        let output_data = output_blobs.get(0).map_err(Error::Inference)?;
+        let shape = output_shape(&output_data);
+        if shape.len() < 2 {
+            return Err(Error::OutputShape { name: self.config.output_names[0].clone(), expected: 2, actual: shape.len() });
+        }
+        /* The layout is decided by the number of labels, because the number of boxes may be smaller than 4 + num_class */
+        let (dim_0, dim_1) = (shape[shape.len() - 2], shape[shape.len() - 1]);
+        let num_class = self.label_list.len();
+        let is_transposed = match is_transposed_layout(dim_0, dim_1, num_class) {
+            Some(is_transposed) => is_transposed,
+            None => return Err(Error::OutputShape { name: self.config.output_names[0].clone(), expected: Self::MODEL_ELEMENT_NUM_OF_BOX + num_class, actual: dim_1 }),
+        };
+        let num_box = if is_transposed { dim_1 } else { dim_0 };
+        let output_data = output_to_vec(&output_data, &self.config.output_names[0], shape.iter().product())?;
+
+        /* Decode bbox */
+        let mut bbox_list = decode(&output_data, num_class, num_box, is_transposed, self.config.score_threshold, &transform, &self.label_list);
+
+        /* NMS */
+        let bbox_nms_list = apply_nms(&mut bbox_list, &self.config.nms_param(self.config.score_threshold));
+
+        Ok(bbox_nms_list)
+    }
+
+    fn input_size(&self) -> core::Size {
+        core::Size { width: self.config.input_width, height: self.config.input_height }
+    }
+
+    fn label_list(&self) -> &[String] {
+        &self.label_list
+    }
+
+    fn model_name(&self) -> &str {
+        Self::MODEL_NAME
+    }
+
+    fn score_threshold(&self) -> f32 {
+        self.config.score_threshold
+    }
+
+    fn set_score_threshold(&mut self, threshold: f32) {
+        self.config.score_threshold = threshold;
+    }
+}
+
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label_list() -> Vec::<String> {
+        vec!["person".to_string(), "bicycle".to_string(), "car".to_string()]
+    }
+
+    /* 3 boxes, 3 classes in [num_box, 4 + num_class] */
+    fn box_major_data() -> Vec::<f32> {
+        vec![
+            100.0, 200.0, 40.0, 80.0, 0.1, 0.8, 0.1,        /* bicycle */
+            300.0, 300.0, 10.0, 10.0, 0.1, 0.2, 0.1,        /* low score */
+            50.0, 60.0, 20.0, 20.0, 0.3, 0.1, 0.4,          /* car */
+        ]
+    }
+
+    fn transpose(data: &[f32], rows: usize, cols: usize) -> Vec::<f32> {
+        (0 .. cols).flat_map(|col| (0 .. rows).map(move |row| data[row * cols + col])).collect()
+    }
+
+    #[test]
+    fn decode_output() {
+        let transform = ResizeTransform { scale_x: 1.0, scale_y: 1.0, offset_x: 0.0, offset_y: 0.0 };
+        let data = transpose(&box_major_data(), 3, 7);
+        let bbox_list = decode(&data, 3, 3, true, 0.25, &transform, &label_list());
+        assert_eq!(bbox_list.len(), 2);
+        assert_eq!(bbox_list[0].label, "bicycle");
+        assert_eq!(bbox_list[0].score, 0.8);
+        assert_eq!((bbox_list[0].x, bbox_list[0].y, bbox_list[0].w, bbox_list[0].h), (80.0, 160.0, 40.0, 80.0));
+        assert_eq!(bbox_list[1].class_id, 2);
+        assert_eq!((bbox_list[1].x, bbox_list[1].y, bbox_list[1].w, bbox_list[1].h), (40.0, 50.0, 20.0, 20.0));
+
+        /* The same result from [num_box, 4 + num_class] */
+        assert_eq!(decode(&box_major_data(), 3, 3, false, 0.25, &transform, &label_list()), bbox_list);
+    }
+
+    #[test]
+    fn decode_few_boxes() {
+        /* [num_box, 4 + num_class] with 3 boxes: dim_0 < dim_1, but it is not transposed */
+        assert_eq!(is_transposed_layout(3, 7, 3), Some(false));
+        assert_eq!(is_transposed_layout(7, 3, 3), Some(true));
+        assert_eq!(is_transposed_layout(7, 8400, 3), Some(true));
+        assert_eq!(is_transposed_layout(8400, 84, 3), None);
+
+        let transform = ResizeTransform { scale_x: 1.0, scale_y: 1.0, offset_x: 0.0, offset_y: 0.0 };
+        let bbox_list = decode(&box_major_data(), 3, 3, false, 0.25, &transform, &label_list());
+        assert_eq!(bbox_list.len(), 2);
+        assert_eq!(bbox_list[0].label, "bicycle");
+        assert_eq!((bbox_list[1].x, bbox_list[1].y, bbox_list[1].w, bbox_list[1].h), (40.0, 50.0, 20.0, 20.0));
+    }
+
+    #[test]
+    fn decode_letterbox() {
+        /* 1280x720 image letterboxed into 640x640: scale = 0.5, offset_y = 140 */
+        let data = vec![320.0, 320.0, 100.0, 50.0, 0.0, 0.0, 0.9];
+        let transform = ResizeTransform { scale_x: 0.5, scale_y: 0.5, offset_x: 0.0, offset_y: 140.0 };
+        let bbox_list = decode(&data, 3, 1, true, 0.25, &transform, &label_list());
+        assert_eq!(bbox_list.len(), 1);
+        assert_eq!(bbox_list[0].label, "car");
+        assert_eq!((bbox_list[0].x, bbox_list[0].y, bbox_list[0].w, bbox_list[0].h), (540.0, 310.0, 200.0, 100.0));
+    }
+}
@@ -11,16 +11,19 @@ limitations under the License.
 ==============================================================================*/
 
 /////////////////////////////////////////////////////////////////
-use opencv::{prelude::*, core, imgproc, dnn};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use opencv::{prelude::*, core, dnn};
 
-// use crate::detection_engine::boundnig_box::BoundingBox;
-use super::boundnig_box::{BoundingBox, nms};
+use vision_core::bounding_box::{BoundingBox, NmsMethod, NmsMode, apply_nms};
+use vision_core::config::{ModelConfig, FromModelConfig};
+use vision_core::detector::{Detector, process_with_score_class_threshold};
+use vision_core::dnn_helper::{read_net, read_label_file, output_to_vec, output_shape, normalize, output_blob_names};
+use vision_core::error::{Error, Result};
+use vision_core::preprocess::{resize, ResizeMode, ResizeTransform};
 
 
 /////////////////////////////////////////////////////////////////
 pub struct DetectionEngine {
+    config: ModelConfig,
     net: dnn::Net,
     out_blob_names: core::Vector::<String>,
     normalize_mean: core::Scalar,
@@ -30,6 +33,7 @@ pub struct DetectionEngine {
 
 impl DetectionEngine {
     /* Model Parameters */
+    const MODEL_NAME: &'static str = "YOLOX";
     const MODEL_FILENAME: &'static str = "../resource/model/yolox_nano_320x320.onnx";
     const LABEL_FILENAME: &'static str = "../resource/model/label_coco_80.txt";
     const MODEL_NORMALIZE_MEAN: (f64, f64, f64) = (0.485, 0.456, 0.406);
@@ -38,140 +42,244 @@ impl DetectionEngine {
     const MODEL_HEIGHT: i32 = 320;
     const MODEL_NAME_INPUT_0: &'static str = "images";
     const MODEL_NAME_OUTPUT_0: &'static str = "output";
-    const MODEL_GRID_SCALE_LIST: [i32; 3] = [ 8, 16, 32 ];
-    const MODEL_GRID_CHANNEL: i32 = 1;
-    const MODEL_NUMBER_OF_CLASS: i32 = 80;
-    const MODEL_ELEMENT_NUM_OF_ANCHOR: i32 = Self::MODEL_NUMBER_OF_CLASS + 5;    // x, y, w, h, bbox confidence, [class confidence]
+    const MODEL_GRID_SCALE_LIST: [i32; 3] = [ 8, 16, 32 ];     // grid size is derived from the input size
+    const MODEL_ELEMENT_NUM_OF_ANCHOR: usize = 5;              // x, y, w, h, bbox confidence, [class confidence]. the number of classes is derived from the output shape
 
     /* Other Parameters */
     const SCORE_BOX_THRESHOLD: f32 = 0.4;
     const SCORE_CLASS_THRESHOLD: f32 = 0.2;
     const NMS_IOU_THRESHOLD: f32 = 0.6;
     
-    pub fn new() -> Self {
+    pub fn config(&self) -> &ModelConfig {
+        &self.config
+    }
+}
+
+/* Decode the output ([1, num_anchor, 5 + num_class]) of the model whose input size is input_width x input_height */
+/* Anchors are in the order of grid scale -> grid y -> grid x */
+pub fn decode(data: &[f32], input_width: i32, input_height: i32, num_class: usize, score_box_threshold: f32, score_class_threshold: f32, transform: &ResizeTransform, label_list: &[String]) -> Vec::<BoundingBox> {
+    let num_element_of_anchor = DetectionEngine::MODEL_ELEMENT_NUM_OF_ANCHOR + num_class;
+    let mut bbox_list = Vec::<BoundingBox>::new();
+    let mut index: usize = 0;
+    for grid_scale in DetectionEngine::MODEL_GRID_SCALE_LIST.iter() {
+        let (grid_w, grid_h) = (input_width / grid_scale, input_height / grid_scale);
+        for grid_y in 0 .. grid_h {
+            for grid_x in 0 .. grid_w {
+                let anchor_data = &data[index .. index + num_element_of_anchor];
+                index += num_element_of_anchor;
+
+                let box_confidence = anchor_data[4];
+                if box_confidence < score_box_threshold {
+                    continue;
+                }
+
+                let mut class_id = 0;
+                let mut confidence: f32 = 0.0;
+                for (class_index, confidence_of_class) in anchor_data[DetectionEngine::MODEL_ELEMENT_NUM_OF_ANCHOR..].iter().enumerate() {
+                    if *confidence_of_class > confidence {
+                        confidence = *confidence_of_class;
+                        class_id = class_index;
+                    }
+                }
+
+                if confidence >= score_class_threshold {
+                    /* Box in the model input, then in the original image */
+                    let cx = (anchor_data[0] + grid_x as f32) * *grid_scale as f32;
+                    let cy = (anchor_data[1] + grid_y as f32) * *grid_scale as f32;
+                    let w  = anchor_data[2].exp() * *grid_scale as f32;
+                    let h  = anchor_data[3].exp() * *grid_scale as f32;
+                    let (x, y, w, h) = transform.to_original_rect(cx - w / 2.0, cy - h / 2.0, w, h);
+
+                    let bbox = BoundingBox {
+                        class_id: class_id as i32,
+                        label: label_list[class_id].clone(),
+                        score: confidence,
+                        x: x,
+                        y: y,
+                        w: w,
+                        h: h,
+                    };
+                    bbox_list.push(bbox);
+                }
+            }
+        }
+    }
+    bbox_list
+}
+
+/* The number of anchors of the model whose input size is input_width x input_height */
+pub fn num_anchor(input_width: i32, input_height: i32) -> usize {
+    DetectionEngine::MODEL_GRID_SCALE_LIST.iter()
+        .map(|grid_scale| ((input_width / grid_scale) * (input_height / grid_scale)) as usize)
+        .sum()
+}
+
+impl FromModelConfig for DetectionEngine {
+    fn default_config() -> ModelConfig {
+        ModelConfig {
+            model_filename: Self::MODEL_FILENAME.to_string(),
+            label_filename: Some(Self::LABEL_FILENAME.to_string()),
+            input_width: Self::MODEL_WIDTH,
+            input_height: Self::MODEL_HEIGHT,
+            input_name: Self::MODEL_NAME_INPUT_0.to_string(),
+            output_names: vec![Self::MODEL_NAME_OUTPUT_0.to_string()],
+            normalize_mean: Self::MODEL_NORMALIZE_MEAN,
+            normalize_norm: Self::MODEL_NORMALIZE_NORM,
+            resize_mode: ResizeMode::Stretch,
+            pad_value: (114.0, 114.0, 114.0),
+            score_threshold: Self::SCORE_BOX_THRESHOLD,
+            score_class_threshold: Self::SCORE_CLASS_THRESHOLD,
+            nms_iou_threshold: Self::NMS_IOU_THRESHOLD,
+            nms_mode: NmsMode::ClassAware,
+            nms_method: NmsMethod::Hard,
+            soft_nms_sigma: 0.5,
+            max_detections: None,
+            prior_box: None,
+        }
+    }
+
+    /* BoundingBox::score is compared with score_class_threshold (score_threshold is for box confidence) */
+    fn uses_score_class_threshold() -> bool {
+        true
+    }
+
+    fn from_config(config: ModelConfig) -> Result<Self> {
+        if config.output_names.len() != 1 {
+            return Err(Error::Config(format!("{} requires 1 output name", Self::MODEL_NAME)));
+        }
+        let label_filename = config.label_filename.clone()
+            .ok_or_else(|| Error::Config(format!("{} requires label_filename", Self::MODEL_NAME)))?;
+
         /* Load model */
-        let mut net = dnn::read_net_from_onnx(Self::MODEL_FILENAME).unwrap();
-        net.set_preferable_backend(dnn::DNN_BACKEND_OPENCV).unwrap();
-        net.set_preferable_target(dnn::DNN_TARGET_CPU).unwrap();
-        // net.set_preferable_target(dnn::DNN_TARGET_OPENCL).unwrap();
+        let net = read_net(&config.model_filename)?;
 
         /* Set output names */
-        let mut out_blob_names = core::Vector::<String>::new();
-        out_blob_names.push(Self::MODEL_NAME_OUTPUT_0);
+        let out_blob_names = output_blob_names(&config.output_names);
 
         /* Initialize normalize param */
-        let normalize_mean = core::Scalar::from(Self::MODEL_NORMALIZE_MEAN);
-        let normalize_norm = core::Scalar::from(Self::MODEL_NORMALIZE_NORM);
-
-        /* Read label */
-        let mut label_list = Vec::<String>::new();
-        let label_file = File::open(Self::LABEL_FILENAME).unwrap();
-        let reader = BufReader::new(label_file);
-        for (_, line) in reader.lines().enumerate() {
-            label_list.push(line.unwrap());
-        }
-        // println!("{:?}", label_list);
-        
-        DetectionEngine {
+        let normalize_mean = core::Scalar::from(config.normalize_mean);
+        let normalize_norm = core::Scalar::from(config.normalize_norm);
+
+        /* Read label (the number of classes is checked with the output shape) */
+        let label_list = read_label_file(&label_filename, 1)?;
+
+        Ok(DetectionEngine {
+            config: config,
             net: net,
             out_blob_names: out_blob_names,
             normalize_mean: normalize_mean,
             normalize_norm: normalize_norm,
             label_list: label_list
-        }
-    }
-
-    fn normalize(&self, mat: &mut core::Mat) -> core::Mat {
-        let mut mat_normalized = Mat::default();
-        let mut mat_normalized_sub = Mat::default();
-        let mut mat_normalized_div = Mat::default();
-        mat.convert_to(&mut mat_normalized, core::CV_32FC3, 1.0 / 255.0, 0.0).unwrap();
-        core::subtract(&mat_normalized, &self.normalize_mean, &mut mat_normalized_sub, &core::no_array(), -1).unwrap();
-        core::divide2(&mat_normalized_sub, &self.normalize_norm, &mut mat_normalized_div, 1.0, -1).unwrap();
-        mat_normalized_div
+        })
     }
+}
 
-    pub fn process(&mut self, mat: &core::Mat) -> Vec::<BoundingBox> {
+impl Detector for DetectionEngine {
+    fn process(&mut self, mat: &core::Mat) -> Result<Vec::<BoundingBox>> {
         /* Pre Process */
-        let mut mat_resized = Mat::default();
-        imgproc::resize(mat, &mut mat_resized, core::Size { width: Self::MODEL_WIDTH, height: Self::MODEL_HEIGHT }, 0.0, 0.0, imgproc::INTER_LINEAR).unwrap();
-        let mat_normalized = self.normalize(&mut mat_resized);
-        let mat_blob = dnn::blob_from_image(&mat_normalized, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).unwrap();
+        let (mat_resized, transform) = resize(mat, self.input_size(), self.config.resize_mode, self.config.pad_value)?;
+        let mat_normalized = normalize(&mat_resized, &self.normalize_mean, &self.normalize_norm)?;
+        let mat_blob = dnn::blob_from_image(&mat_normalized, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).map_err(Error::Preprocess)?;
 
         /* Feed input data */
-        self.net.set_input(&mat_blob, Self::MODEL_NAME_INPUT_0, 1.0, core::Scalar::default()).unwrap();
+        self.net.set_input(&mat_blob, &self.config.input_name, 1.0, core::Scalar::default()).map_err(Error::Inference)?;
 
         /* Run inference */
         let mut output_blobs = core::Vector::<core::Mat>::new();
-        self.net.forward(&mut output_blobs, &self.out_blob_names).unwrap();
+        self.net.forward(&mut output_blobs, &self.out_blob_names).map_err(Error::Inference)?;
 
-        /* Retrieve output */
-        let output_data = &output_blobs.to_vec()[0];
+        /* Retrieve output ([1, num_anchor, 5 + num_class]) */
+        let output_data = output_blobs.get(0).map_err(Error::Inference)?;
+        let shape = output_shape(&output_data);
+        let model_width = self.config.input_width;
+        let model_height = self.config.input_height;
+        let num_anchor = num_anchor(model_width, model_height);
+        let num_element: usize = shape.iter().product();
+        if num_anchor == 0 || num_element % num_anchor != 0 || num_element / num_anchor <= Self::MODEL_ELEMENT_NUM_OF_ANCHOR {
+            return Err(Error::OutputShape { name: self.config.output_names[0].clone(), expected: num_anchor * (Self::MODEL_ELEMENT_NUM_OF_ANCHOR + self.label_list.len()), actual: num_element });
+        }
+        let num_class = num_element / num_anchor - Self::MODEL_ELEMENT_NUM_OF_ANCHOR;
+        if num_class > self.label_list.len() {
+            return Err(Error::Config(format!("the model has {} classes, but the label file has only {} labels", num_class, self.label_list.len())));
+        }
+        let output_data = output_to_vec(&output_data, &self.config.output_names[0], num_element)?;
 
-        // /* Convert the output to vector */
-        let output_data = unsafe {std::slice::from_raw_parts(output_data.ptr(0).unwrap() as *mut f32, output_data.total()).to_vec()};
-        //  println!("{}, {}", output_data[10], output_data.len());
-        
         /* Decode bbox */
-        let mut bbox_list = Vec::<BoundingBox>::new();
+        let mut bbox_list = decode(&output_data, model_width, model_height, num_class, self.config.score_threshold, self.config.score_class_threshold, &transform, &self.label_list);
 
-        let mut index: usize = 0;
-        for grid_scale in Self::MODEL_GRID_SCALE_LIST.iter() {
-            let grid_w = Self::MODEL_WIDTH / grid_scale;
-            let grid_h = Self::MODEL_HEIGHT / grid_scale;
+        /* NMS */
+        let bbox_nms_list = apply_nms(&mut bbox_list, &self.config.nms_param(self.config.score_class_threshold));
 
-            let scale_x = *grid_scale as f32 * mat.cols() as f32 / Self::MODEL_WIDTH as f32;      /* scale to original image */
-            let scale_y = *grid_scale as f32 * mat.rows() as f32 / Self::MODEL_HEIGHT as f32;
+        Ok(bbox_nms_list)
+    }
 
-            self.get_bounding_box(&output_data, index, scale_x, scale_y, grid_w, grid_h, &mut bbox_list);
-            index += (grid_w * grid_h * Self::MODEL_GRID_CHANNEL * Self::MODEL_ELEMENT_NUM_OF_ANCHOR) as usize;
-        }
+    fn input_size(&self) -> core::Size {
+        core::Size { width: self.config.input_width, height: self.config.input_height }
+    }
 
-        /* NMS */
-        let bbox_nms_list = nms(&mut bbox_list, Self::NMS_IOU_THRESHOLD);
+    fn label_list(&self) -> &[String] {
+        &self.label_list
+    }
 
-        bbox_nms_list
+    fn model_name(&self) -> &str {
+        Self::MODEL_NAME
     }
 
-    fn get_bounding_box(&mut self, data: &Vec::<f32>, mut index: usize, scale_x: f32, scale_y: f32, grid_w: i32, grid_h: i32, bbox_list: &mut Vec::<BoundingBox>) {
-        for grid_y in 0 .. grid_h {
-            for grid_x in 0 .. grid_w {
-                for _grid_c in 0 .. Self::MODEL_GRID_CHANNEL {
-                    let box_confidence = data[index + 4];
-
-                    if box_confidence >= Self::SCORE_BOX_THRESHOLD {
-                        let mut class_id = 0;
-                        let mut confidence: f32 = 0.0;
-                        for class_index in 0 .. Self::MODEL_NUMBER_OF_CLASS {
-                            let confidence_of_class = data[index + 5 + class_index as usize];
-                            if confidence_of_class > confidence {
-                                confidence = confidence_of_class;
-                                class_id = class_index;
-                            }
-                        }
-
-                        if confidence >= Self::SCORE_CLASS_THRESHOLD {
-                            let cx = ((data[index + 0] + grid_x as f32) * scale_x) as i32;
-                            let cy = ((data[index + 1] + grid_y as f32) * scale_y) as i32;
-                            let w  = (data[index + 2].exp() * scale_x) as i32;
-                            let h  = (data[index + 3].exp() * scale_y) as i32;
-
-                            let bbox = BoundingBox {
-                                class_id: class_id,
-                                label: self.label_list[class_id as usize].clone(),
-                                score: confidence,
-                                x: cx - w / 2,
-                                y: cy - h / 2,
-                                w: w,
-                                h: h,
-                            };
-                            bbox_list.push(bbox);
-                        }
-                    }
-                    index += Self::MODEL_ELEMENT_NUM_OF_ANCHOR as usize;
-                }
-            }
-        }
+    /* The score of BoundingBox is compared with score_class_threshold */
+    fn score_threshold(&self) -> f32 {
+        self.config.score_class_threshold
+    }
+
+    fn set_score_threshold(&mut self, threshold: f32) {
+        self.config.score_class_threshold = threshold;
+    }
+
+    fn process_with_score_threshold(&mut self, mat: &core::Mat, score_threshold: f32) -> Result<Vec::<BoundingBox>> {
+        process_with_score_class_threshold(self, |engine| &mut engine.config, mat, score_threshold)
     }
 }
 
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_custom_classes() {
+        /* 64x96 input (grid: 8x12, 4x6, 2x3), 3 classes */
+        let label_list = vec!["apple".to_string(), "banana".to_string(), "orange".to_string()];
+        let num_anchor = num_anchor(64, 96);
+        assert_eq!(num_anchor, 96 + 24 + 6);
+        let mut data = vec![0.0; num_anchor * 8];
+
+        /* stride 8, grid (x, y) = (2, 3) */
+        let index = 3 * 8 + 2;
+        data[index * 8 .. index * 8 + 8].copy_from_slice(&[0.5, 0.5, 0.0, 1.0f32.ln(), 0.9, 0.1, 0.1, 0.7]);
+        /* stride 32, grid (x, y) = (1, 2). class confidence is low */
+        let index = 96 + 24 + 2 * 2 + 1;
+        data[index * 8 .. index * 8 + 8].copy_from_slice(&[0.5, 0.5, 0.0, 0.0, 0.9, 0.1, 0.1, 0.1]);
+        /* stride 32, grid (x, y) = (0, 0) */
+        let index = 96 + 24;
+        data[index * 8 .. index * 8 + 8].copy_from_slice(&[0.5, 0.5, 2.0f32.ln(), 0.0, 0.8, 0.0, 0.6, 0.0]);
+
+        let transform = ResizeTransform { scale_x: 1.0, scale_y: 1.0, offset_x: 0.0, offset_y: 0.0 };
+        let bbox_list = decode(&data, 64, 96, 3, 0.4, 0.2, &transform, &label_list);
+        assert_eq!(bbox_list.len(), 2);
+        assert_eq!(bbox_list[0].label, "orange");
+        assert_eq!(bbox_list[0].score, 0.7);
+        assert_eq!((bbox_list[0].x, bbox_list[0].y, bbox_list[0].w, bbox_list[0].h), (16.0, 24.0, 8.0, 8.0));
+        assert_eq!(bbox_list[1].label, "banana");
+        let expected = [-16.0, 0.0, 64.0, 32.0];
+        for (actual, expected) in [bbox_list[1].x, bbox_list[1].y, bbox_list[1].w, bbox_list[1].h].iter().zip(expected.iter()) {
+            assert!((actual - expected).abs() < 1e-4, "{} != {}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn anchor_num_of_variants() {
+        assert_eq!(num_anchor(320, 320), 2100);
+        assert_eq!(num_anchor(416, 416), 3549);
+        assert_eq!(num_anchor(640, 640), 8400);
+    }
+}
@@ -0,0 +1,40 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use pj_dnn_det_person_detection_0202::detection_engine::detection_engine::DetectionEngine;
+use pj_dnn_reid_osnet::reid_engine::reid_engine::ReidEngine;
+use vision_core::cli::{Args, Parser};
+use vision_core::config::FromModelConfig;
+use vision_core::detector::{Detector, EmbeddingExtractor};
+use vision_core::runner::{run_with_extractor, AppResult};
+use vision_core::tracker::TrackerMode;
+
+/////////////////////////////////////////////////////////////////
+/* Track people with person-detection-0202 and DeepSORT (OSNet embeddings) */
+/* Usage (see --help for all the options. The model options are for the detector) */
+/*   cargo run -- --video D:/devel/video/PETS09-S2L1-raw.mp4 --output result.mp4 */
+/*   cargo run -- --video D:/devel/video/PETS09-S2L1-raw.mp4 --tracker sort */
+/*   cargo run -- --camera 0 */
+const DEFAULT_IMAGE_FILENAME: &str = "../resource/people_0.jpg";
+
+/////////////////////////////////////////////////////////////////
+fn main() -> AppResult {
+    let mut args = Args::parse();
+    args.tracker.get_or_insert(TrackerMode::DeepSort);
+
+    /* Create detection engine and re-identification engine */
+    let mut detector: Box<dyn Detector> = Box::new(args.engine_builder::<DetectionEngine>()?.build()?);
+    let mut extractor: Box<dyn EmbeddingExtractor> = Box::new(ReidEngine::new()?);
+
+    run_with_extractor(detector.as_mut(), Some(extractor.as_mut()), &args, DEFAULT_IMAGE_FILENAME)
+}
@@ -0,0 +1,202 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::{prelude::*, core, dnn};
+
+use vision_core::bounding_box::{BoundingBox, NmsMethod, NmsMode};
+use vision_core::config::{ModelConfig, FromModelConfig};
+use vision_core::detector::EmbeddingExtractor;
+use vision_core::dnn_helper::{read_net, output_to_vec, output_shape, normalize, output_blob_names};
+use vision_core::error::{Error, Result};
+use vision_core::preprocess::{resize, process_crop_batch, ResizeMode};
+
+
+/////////////////////////////////////////////////////////////////
+/* Re-identification engine. Outputs an L2 normalized embedding of each person crop */
+pub struct ReidEngine {
+    config: ModelConfig,
+    net: dnn::Net,
+    out_blob_names: core::Vector::<String>,
+    normalize_mean: core::Scalar,
+    normalize_norm: core::Scalar,
+}
+
+impl ReidEngine {
+    /* Model Parameters */
+    const MODEL_NAME: &'static str = "OSNet";
+    const MODEL_FILENAME: &'static str = "../resource/model/osnet_x0_25_msmt17.onnx";
+    const MODEL_NORMALIZE_MEAN: (f64, f64, f64) = (0.485, 0.456, 0.406);
+    const MODEL_NORMALIZE_NORM: (f64, f64, f64) = (0.229, 0.224, 0.225);
+    const MODEL_WIDTH: i32 = 128;
+    const MODEL_HEIGHT: i32 = 256;
+    const MODEL_NAME_INPUT_0: &'static str = "input";
+    const MODEL_NAME_OUTPUT_0: &'static str = "output";     // embedding: [batch, dim]
+
+    /* Other Parameters */
+    const MAX_BATCH_SIZE: usize = 16;
+
+    pub fn config(&self) -> &ModelConfig {
+        &self.config
+    }
+
+    pub fn input_size(&self) -> core::Size {
+        core::Size { width: self.config.input_width, height: self.config.input_height }
+    }
+
+    pub fn model_name(&self) -> &str {
+        Self::MODEL_NAME
+    }
+
+    pub fn process(&mut self, mat: &core::Mat) -> Result<Vec::<f32>> {
+        Ok(self.process_batch(std::slice::from_ref(mat))?.pop().unwrap_or_default())
+    }
+
+    /* Embeddings of the images (e.g. person crops) in the order of mat_list */
+    pub fn process_batch(&mut self, mat_list: &[core::Mat]) -> Result<Vec::<Vec::<f32>>> {
+        if mat_list.is_empty() {
+            return Ok(vec![]);
+        }
+
+        /* Pre Process */
+        let mut mat_normalized_list = core::Vector::<core::Mat>::new();
+        for mat in mat_list {
+            let (mat_resized, _) = resize(mat, self.input_size(), self.config.resize_mode, self.config.pad_value)?;
+            mat_normalized_list.push(normalize(&mat_resized, &self.normalize_mean, &self.normalize_norm)?);
+        }
+        let mat_blob = dnn::blob_from_images(&mat_normalized_list, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).map_err(Error::Preprocess)?;
+
+        /* Feed input data */
+        self.net.set_input(&mat_blob, &self.config.input_name, 1.0, core::Scalar::default()).map_err(Error::Inference)?;
+
+        /* Run inference */
+        let mut output_blobs = core::Vector::<core::Mat>::new();
+        self.net.forward(&mut output_blobs, &self.out_blob_names).map_err(Error::Inference)?;
+
+        /* Retrieve output ([batch, dim]) */
+        let output_data = output_blobs.get(0).map_err(Error::Inference)?;
+        let num_element: usize = output_shape(&output_data).iter().product();
+        if num_element == 0 || num_element % mat_list.len() != 0 {
+            return Err(Error::OutputShape { name: self.config.output_names[0].clone(), expected: mat_list.len(), actual: num_element });
+        }
+        let output_data = output_to_vec(&output_data, &self.config.output_names[0], num_element)?;
+
+        Ok(decode(&output_data, mat_list.len()))
+    }
+}
+
+/* Split the output ([batch, dim]) into L2 normalized embeddings */
+pub fn decode(data: &[f32], batch_size: usize) -> Vec::<Vec::<f32>> {
+    if batch_size == 0 || data.len() < batch_size {
+        return vec![];
+    }
+    data.chunks_exact(data.len() / batch_size).map(|feature| {
+        let mut embedding = feature.to_vec();
+        l2_normalize(&mut embedding);
+        embedding
+    }).collect()
+}
+
+/* A zero vector is left as it is */
+pub fn l2_normalize(feature: &mut [f32]) {
+    let norm = feature.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for v in feature.iter_mut() {
+            *v /= norm;
+        }
+    }
+}
+
+impl EmbeddingExtractor for ReidEngine {
+    fn extract(&mut self, mat: &core::Mat, bbox_list: &[BoundingBox]) -> Result<Vec::<Vec::<f32>>> {
+        let embedding_list = process_crop_batch(mat, bbox_list, 0.0, Self::MAX_BATCH_SIZE, |mat_list| self.process_batch(mat_list))?;
+        Ok(embedding_list.into_iter().map(|embedding| embedding.unwrap_or_default()).collect())
+    }
+}
+
+impl FromModelConfig for ReidEngine {
+    fn default_config() -> ModelConfig {
+        ModelConfig {
+            model_filename: Self::MODEL_FILENAME.to_string(),
+            label_filename: None,
+            input_width: Self::MODEL_WIDTH,
+            input_height: Self::MODEL_HEIGHT,
+            input_name: Self::MODEL_NAME_INPUT_0.to_string(),
+            output_names: vec![Self::MODEL_NAME_OUTPUT_0.to_string()],
+            normalize_mean: Self::MODEL_NORMALIZE_MEAN,
+            normalize_norm: Self::MODEL_NORMALIZE_NORM,
+            resize_mode: ResizeMode::Stretch,
+            pad_value: (0.0, 0.0, 0.0),
+            /* Not used (see unused_keys) */
+            score_threshold: 0.0,
+            score_class_threshold: 0.0,
+            nms_iou_threshold: 0.0,
+            nms_mode: NmsMode::ClassAgnostic,
+            nms_method: NmsMethod::Hard,
+            soft_nms_sigma: 0.5,
+            max_detections: None,
+            prior_box: None,
+        }
+    }
+
+    fn unused_keys() -> &'static [&'static str] {
+        &["label_filename", "score_threshold", "score_class_threshold", "nms_iou_threshold", "nms_mode", "nms_method", "soft_nms_sigma", "max_detections", "prior_box"]
+    }
+
+    fn from_config(config: ModelConfig) -> Result<Self> {
+        if config.output_names.len() != 1 {
+            return Err(Error::Config(format!("{} requires 1 output name", Self::MODEL_NAME)));
+        }
+
+        /* Load model */
+        let net = read_net(&config.model_filename)?;
+
+        /* Set output names */
+        let out_blob_names = output_blob_names(&config.output_names);
+
+        /* Initialize normalize param */
+        let normalize_mean = core::Scalar::from(config.normalize_mean);
+        let normalize_norm = core::Scalar::from(config.normalize_norm);
+
+        Ok(ReidEngine {
+            config: config,
+            net: net,
+            out_blob_names: out_blob_names,
+            normalize_mean: normalize_mean,
+            normalize_norm: normalize_norm,
+        })
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_embedding() {
+        let data = vec![
+            3.0, 4.0, 0.0,
+            0.0, 0.0, 0.0,
+        ];
+        let embedding_list = decode(&data, 2);
+        assert_eq!(embedding_list.len(), 2);
+        assert!((embedding_list[0][0] - 0.6).abs() < 1e-6 && (embedding_list[0][1] - 0.8).abs() < 1e-6);
+        assert_eq!(embedding_list[1], vec![0.0, 0.0, 0.0]);
+        assert!(decode(&data, 0).is_empty());
+
+        /* Less elements than the batch size (dim = 0) */
+        assert!(decode(&[], 2).is_empty());
+        assert!(decode(&[1.0], 2).is_empty());
+    }
+}
@@ -0,0 +1,53 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::core;
+
+use pj_dnn_seg_deeplabv3::segmentation_engine::segmentation_engine::SegmentationEngine;
+use vision_core::cli::{Args, Parser};
+use vision_core::error::Error;
+use vision_core::helper_cv::{draw_class_map, draw_text, ColorGenerator};
+use vision_core::runner::{run_with, AppResult};
+
+/////////////////////////////////////////////////////////////////
+/* Usage (see --help for all the options) */
+/*   cargo run */
+/*   cargo run -- --image ../resource/dashcam_01.jpg --output result.jpg */
+/*   cargo run -- --video ../resource/Megamind.avi --output result.mp4 */
+/*   cargo run -- --dir ../resource --output result --headless */
+const DEFAULT_IMAGE_FILENAME: &str = "../resource/dashcam_00.jpg";
+const CLASS_MAP_ALPHA: f64 = 0.5;
+const NUM_CLASS_TO_SHOW: usize = 5;
+
+/////////////////////////////////////////////////////////////////
+fn main() -> AppResult {
+    let args = Args::parse();
+
+    /* Create segmentation engine */
+    let mut engine = args.engine_builder::<SegmentationEngine>()?.build()?;
+    let label_list = engine.label_list().to_vec();
+
+    let color_generator = ColorGenerator::new(label_list.len() as u32, 50);
+    run_with(&mut |mat: &mut core::Mat, text_thickness: i32| {
+        let result = engine.process(mat)?;
+        draw_class_map(mat, &result.class_map, &color_generator, CLASS_MAP_ALPHA).map_err(Error::Postprocess)?;
+
+        /* Area ratio of the major classes */
+        let ratio_list = result.class_ratio_list();
+        for (i, (class_id, ratio)) in ratio_list.iter().take(NUM_CLASS_TO_SHOW).enumerate() {
+            let text = format!("{}: {:.1}%", label_list[*class_id], ratio * 100.0);
+            draw_text(mat, &text, core::Point::new(0, 40 + i as i32 * 25), 0.6, text_thickness, core::Scalar::new(255., 255., 255., 255.), color_generator.get(*class_id as i32), true).map_err(Error::Postprocess)?;
+        }
+        Ok(ratio_list.len())
+    }, &args, DEFAULT_IMAGE_FILENAME)
+}
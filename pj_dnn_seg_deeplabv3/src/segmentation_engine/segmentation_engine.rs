@@ -0,0 +1,272 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::{prelude::*, core, dnn};
+
+use vision_core::bounding_box::{NmsMethod, NmsMode};
+use vision_core::config::{ModelConfig, FromModelConfig};
+use vision_core::dnn_helper::{read_net, read_label_file, output_to_vec, output_shape, normalize, output_blob_names};
+use vision_core::error::{Error, Result};
+use vision_core::preprocess::{resize, ResizeMode, ResizeTransform};
+
+
+/////////////////////////////////////////////////////////////////
+/* Class id of each pixel in the original image */
+pub struct SegmentationResult {
+    pub class_map: core::Mat,               /* CV_8UC1 */
+    pub class_area_list: Vec::<usize>,      /* the number of pixels of each class */
+}
+
+impl SegmentationResult {
+    /* (class_id, ratio of the area in the image), in descending order of the area. Classes which don't appear are not included */
+    pub fn class_ratio_list(&self) -> Vec::<(usize, f32)> {
+        let total: usize = self.class_area_list.iter().sum();
+        let mut ratio_list: Vec::<(usize, f32)> = self.class_area_list.iter().enumerate()
+            .filter(|(_, area)| **area > 0)
+            .map(|(class_id, area)| (class_id, *area as f32 / total as f32))
+            .collect();
+        ratio_list.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        ratio_list
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+pub struct SegmentationEngine {
+    config: ModelConfig,
+    net: dnn::Net,
+    out_blob_names: core::Vector::<String>,
+    normalize_mean: core::Scalar,
+    normalize_norm: core::Scalar,
+    label_list: Vec::<String>,
+}
+
+impl SegmentationEngine {
+    /* Model Parameters */
+    const MODEL_NAME: &'static str = "DeepLabV3";
+    const MODEL_FILENAME: &'static str = "../resource/model/deeplabv3_cityscapes_512x256.onnx";
+    const LABEL_FILENAME: &'static str = "../resource/model/label_cityscapes_19.txt";
+    const MODEL_NORMALIZE_MEAN: (f64, f64, f64) = (0.485, 0.456, 0.406);
+    const MODEL_NORMALIZE_NORM: (f64, f64, f64) = (0.229, 0.224, 0.225);
+    const MODEL_WIDTH: i32 = 512;
+    const MODEL_HEIGHT: i32 = 256;
+    const MODEL_NAME_INPUT_0: &'static str = "input";
+    const MODEL_NAME_OUTPUT_0: &'static str = "output";     // logits: [1, num_class, height, width]
+    const MODEL_MAX_NUMBER_OF_CLASS: usize = 256;           // class map is CV_8UC1
+
+    pub fn config(&self) -> &ModelConfig {
+        &self.config
+    }
+
+    pub fn input_size(&self) -> core::Size {
+        core::Size { width: self.config.input_width, height: self.config.input_height }
+    }
+
+    /* Labels indexed by class id */
+    pub fn label_list(&self) -> &[String] {
+        &self.label_list
+    }
+
+    pub fn model_name(&self) -> &str {
+        Self::MODEL_NAME
+    }
+
+    pub fn process(&mut self, mat: &core::Mat) -> Result<SegmentationResult> {
+        /* Pre Process */
+        let (mat_resized, transform) = resize(mat, self.input_size(), self.config.resize_mode, self.config.pad_value)?;
+        let mat_normalized = normalize(&mat_resized, &self.normalize_mean, &self.normalize_norm)?;
+        let mat_blob = dnn::blob_from_image(&mat_normalized, 1.0, core::Size::default(), core::Scalar::default(), true, false, core::CV_32F).map_err(Error::Preprocess)?;
+
+        /* Feed input data */
+        self.net.set_input(&mat_blob, &self.config.input_name, 1.0, core::Scalar::default()).map_err(Error::Inference)?;
+
+        /* Run inference */
+        let mut output_blobs = core::Vector::<core::Mat>::new();
+        self.net.forward(&mut output_blobs, &self.out_blob_names).map_err(Error::Inference)?;
+
+        /* Retrieve output ([1, num_class, height, width]) */
+        let output_data = output_blobs.get(0).map_err(Error::Inference)?;
+        let shape = output_shape(&output_data);
+        if shape.len() != 4 {
+            return Err(Error::OutputShape { name: self.config.output_names[0].clone(), expected: 4, actual: shape.len() });
+        }
+        let (num_class, output_height, output_width) = (shape[1], shape[2], shape[3]);
+        if num_class > self.label_list.len() || num_class > Self::MODEL_MAX_NUMBER_OF_CLASS {
+            return Err(Error::Config(format!("the model has {} classes, but the label file has {} labels (max {})", num_class, self.label_list.len(), Self::MODEL_MAX_NUMBER_OF_CLASS)));
+        }
+        let output_data = output_to_vec(&output_data, &self.config.output_names[0], shape.iter().product())?;
+
+        /* Decode class map in the output size, then map it into the original image */
+        let class_map = decode_argmax(&output_data, num_class, output_width, output_height);
+        let scale_x = output_width as f32 / self.config.input_width as f32;
+        let scale_y = output_height as f32 / self.config.input_height as f32;
+        let class_map = map_to_original(&class_map, output_width, output_height, mat.cols() as usize, mat.rows() as usize, &transform, scale_x, scale_y);
+        let class_area_list = calculate_class_area(&class_map, num_class);
+
+        let mut mat_class_map = core::Mat::new_rows_cols_with_default(mat.rows(), mat.cols(), core::CV_8UC1, core::Scalar::default()).map_err(Error::Postprocess)?;
+        if !class_map.is_empty() {
+            let ptr = mat_class_map.ptr_mut(0).map_err(Error::Postprocess)?;
+            unsafe {std::ptr::copy_nonoverlapping(class_map.as_ptr(), ptr, class_map.len())};
+        }
+
+        Ok(SegmentationResult {
+            class_map: mat_class_map,
+            class_area_list: class_area_list,
+        })
+    }
+}
+
+/* Class id of the max logit at each pixel. logits is [num_class, height, width] */
+/* The first class wins on a tie */
+pub fn decode_argmax(logits: &[f32], num_class: usize, width: usize, height: usize) -> Vec::<u8> {
+    let plane_size = width * height;
+    assert_eq!(logits.len(), num_class * plane_size);
+    let mut class_map = vec![0; plane_size];
+    let mut max_list = logits[.. plane_size].to_vec();
+    for class_id in 1 .. num_class {
+        let plane = &logits[class_id * plane_size .. (class_id + 1) * plane_size];
+        for (i, logit) in plane.iter().enumerate() {
+            if *logit > max_list[i] {
+                max_list[i] = *logit;
+                class_map[i] = class_id as u8;
+            }
+        }
+    }
+    class_map
+}
+
+/* Nearest neighbor. (x, y) in the original image -> transform -> * scale -> (x, y) in the class map */
+pub fn map_to_original(class_map: &[u8], width: usize, height: usize, original_width: usize, original_height: usize, transform: &ResizeTransform, scale_x: f32, scale_y: f32) -> Vec::<u8> {
+    let mut class_map_original = Vec::<u8>::with_capacity(original_width * original_height);
+    for y in 0 .. original_height {
+        for x in 0 .. original_width {
+            let (model_x, model_y) = transform.to_model_point(x as f32 + 0.5, y as f32 + 0.5);
+            let map_x = ((model_x * scale_x) as usize).min(width - 1);
+            let map_y = ((model_y * scale_y) as usize).min(height - 1);
+            class_map_original.push(class_map[map_y * width + map_x]);
+        }
+    }
+    class_map_original
+}
+
+/* The number of pixels of each class */
+pub fn calculate_class_area(class_map: &[u8], num_class: usize) -> Vec::<usize> {
+    let mut class_area_list = vec![0; num_class];
+    for class_id in class_map {
+        if let Some(area) = class_area_list.get_mut(*class_id as usize) {
+            *area += 1;
+        }
+    }
+    class_area_list
+}
+
+impl FromModelConfig for SegmentationEngine {
+    fn default_config() -> ModelConfig {
+        ModelConfig {
+            model_filename: Self::MODEL_FILENAME.to_string(),
+            label_filename: Some(Self::LABEL_FILENAME.to_string()),
+            input_width: Self::MODEL_WIDTH,
+            input_height: Self::MODEL_HEIGHT,
+            input_name: Self::MODEL_NAME_INPUT_0.to_string(),
+            output_names: vec![Self::MODEL_NAME_OUTPUT_0.to_string()],
+            normalize_mean: Self::MODEL_NORMALIZE_MEAN,
+            normalize_norm: Self::MODEL_NORMALIZE_NORM,
+            resize_mode: ResizeMode::Stretch,
+            pad_value: (0.0, 0.0, 0.0),
+            /* Not used (see unused_keys) */
+            score_threshold: 0.0,
+            score_class_threshold: 0.0,
+            nms_iou_threshold: 0.0,
+            nms_mode: NmsMode::ClassAgnostic,
+            nms_method: NmsMethod::Hard,
+            soft_nms_sigma: 0.5,
+            max_detections: None,
+            prior_box: None,
+        }
+    }
+
+    fn unused_keys() -> &'static [&'static str] {
+        &["score_threshold", "score_class_threshold", "nms_iou_threshold", "nms_mode", "nms_method", "soft_nms_sigma", "max_detections", "prior_box"]
+    }
+
+    fn from_config(config: ModelConfig) -> Result<Self> {
+        if config.output_names.len() != 1 {
+            return Err(Error::Config(format!("{} requires 1 output name", Self::MODEL_NAME)));
+        }
+        let label_filename = config.label_filename.clone()
+            .ok_or_else(|| Error::Config(format!("{} requires label_filename", Self::MODEL_NAME)))?;
+
+        /* Load model */
+        let net = read_net(&config.model_filename)?;
+
+        /* Set output names */
+        let out_blob_names = output_blob_names(&config.output_names);
+
+        /* Initialize normalize param */
+        let normalize_mean = core::Scalar::from(config.normalize_mean);
+        let normalize_norm = core::Scalar::from(config.normalize_norm);
+
+        /* Read label (the number of classes is checked with the output shape) */
+        let label_list = read_label_file(&label_filename, 1)?;
+
+        Ok(SegmentationEngine {
+            config: config,
+            net: net,
+            out_blob_names: out_blob_names,
+            normalize_mean: normalize_mean,
+            normalize_norm: normalize_norm,
+            label_list: label_list
+        })
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn argmax() {
+        /* 3 classes, 3x2 */
+        let logits = vec![
+            1.0, 0.0, 0.0,  0.0, 5.0, -1.0,     /* class 0 */
+            0.0, 2.0, 0.0,  0.0, 1.0, -2.0,     /* class 1 */
+            0.0, 0.0, 3.0,  0.0, 4.0, -3.0,     /* class 2 */
+        ];
+        let class_map = decode_argmax(&logits, 3, 3, 2);
+        assert_eq!(class_map, vec![0, 1, 2, 0, 0, 0]);
+        assert_eq!(calculate_class_area(&class_map, 3), vec![4, 1, 1]);
+    }
+
+    #[test]
+    fn map_and_ratio() {
+        /* 2x2 class map of 4x4 input (output stride = 2), for 8x4 image stretched */
+        let class_map = vec![0, 1, 2, 2];
+        let transform = ResizeTransform { scale_x: 0.5, scale_y: 1.0, offset_x: 0.0, offset_y: 0.0 };
+        let class_map_original = map_to_original(&class_map, 2, 2, 8, 4, &transform, 0.5, 0.5);
+        assert_eq!(class_map_original, vec![
+            0, 0, 0, 0, 1, 1, 1, 1,
+            0, 0, 0, 0, 1, 1, 1, 1,
+            2, 2, 2, 2, 2, 2, 2, 2,
+            2, 2, 2, 2, 2, 2, 2, 2,
+        ]);
+
+        let result = SegmentationResult {
+            class_map: core::Mat::default(),
+            class_area_list: calculate_class_area(&class_map_original, 4),
+        };
+        assert_eq!(result.class_area_list, vec![8, 8, 16, 0]);
+        assert_eq!(result.class_ratio_list(), vec![(2, 0.5), (0, 0.25), (1, 0.25)]);
+    }
+}
@@ -0,0 +1,436 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::bounding_box::BoundingBox;
+use crate::error::{Error, Result};
+use crate::geometry::Polygon;
+use crate::tracker::TrackedObject;
+
+
+/////////////////////////////////////////////////////////////////
+/* User-defined counting lines and zones (.toml or .json) */
+/* e.g. */
+/*   interval = 60.0 */
+/*   [[line]] */
+/*   name = "gate" */
+/*   start = [0.0, 300.0] */
+/*   end = [640.0, 300.0] */
+/*   [[zone]] */
+/*   name = "parking" */
+/*   polygon = { point_list = [[100.0, 100.0], [300.0, 100.0], [300.0, 250.0], [100.0, 250.0]] } */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AnalyticsConfig {
+    #[serde(default = "AnalyticsConfig::default_interval")]
+    pub interval: f64,              /* length of each aggregate interval in seconds. no aggregation if <= 0 */
+    #[serde(default = "AnalyticsConfig::default_lost_time")]
+    pub lost_time: f64,             /* an object which is not seen for this seconds is treated as gone (and leaves the zones) */
+    #[serde(default, rename = "line")]
+    pub line_list: Vec::<CountingLine>,
+    #[serde(default, rename = "zone")]
+    pub zone_list: Vec::<CountingZone>,
+}
+
+impl AnalyticsConfig {
+    fn default_interval() -> f64 {
+        60.0
+    }
+
+    fn default_lost_time() -> f64 {
+        1.0
+    }
+
+    pub fn from_file(filename: &str) -> Result<AnalyticsConfig> {
+        let text = std::fs::read_to_string(filename).map_err(|e| Error::Config(format!("{}: {}", filename, e)))?;
+        let config = if filename.ends_with(".json") {
+            serde_json::from_str(&text).map_err(|e| e.to_string())
+        } else {
+            toml::from_str(&text).map_err(|e| e.to_string())
+        };
+        config.map_err(|message| Error::Config(format!("{}: {}", filename, message)))
+    }
+}
+
+/* Forward is crossing from the left side to the right side when looking from start to end on the image */
+/* e.g. start = (0, 300), end = (640, 300): forward is downward */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CountingLine {
+    pub name: String,
+    pub start: (f32, f32),
+    pub end: (f32, f32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossingDirection {
+    Forward,
+    Backward,
+}
+
+impl CountingLine {
+    /* Positive on the right side, negative on the left side, 0 on the line */
+    pub fn side(&self, point: (f32, f32)) -> f32 {
+        cross(self.start, self.end, point)
+    }
+
+    /* Direction if the move from point0 to point1 crosses the line segment (not the infinite line) */
+    pub fn crossing(&self, point0: (f32, f32), point1: (f32, f32)) -> Option<CrossingDirection> {
+        let (side0, side1) = (self.side(point0), self.side(point1));
+        let direction = if side0 < 0.0 && side1 > 0.0 {
+            CrossingDirection::Forward
+        } else if side0 > 0.0 && side1 < 0.0 {
+            CrossingDirection::Backward
+        } else {
+            return None;
+        };
+        if cross(point0, point1, self.start) * cross(point0, point1, self.end) > 0.0 {
+            return None;
+        }
+        Some(direction)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CountingZone {
+    pub name: String,
+    pub polygon: Polygon,
+}
+
+/* Point of an object used for counting: bottom center of the box (where the object is on the ground) */
+pub fn anchor_point(bbox: &BoundingBox) -> (f32, f32) {
+    (bbox.x + bbox.w / 2.0, bbox.y + bbox.h)
+}
+
+fn cross(origin: (f32, f32), a: (f32, f32), b: (f32, f32)) -> f32 {
+    (a.0 - origin.0) * (b.1 - origin.1) - (a.1 - origin.1) * (b.0 - origin.0)
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CrossingCount {
+    pub forward: u32,
+    pub backward: u32,
+}
+
+impl CrossingCount {
+    fn add(&mut self, direction: CrossingDirection) {
+        match direction {
+            CrossingDirection::Forward => self.forward += 1,
+            CrossingDirection::Backward => self.backward += 1,
+        }
+    }
+}
+
+/* Counts of one line. Keys are the labels of the objects */
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineCounter {
+    pub line: CountingLine,
+    pub count_by_class: BTreeMap::<String, CrossingCount>,
+}
+
+impl LineCounter {
+    pub fn total(&self) -> CrossingCount {
+        self.count_by_class.values().fold(CrossingCount::default(), |total, count| CrossingCount { forward: total.forward + count.forward, backward: total.backward + count.backward })
+    }
+}
+
+/* Objects in one zone, and the time they stayed */
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoneCounter {
+    pub zone: CountingZone,
+    pub entry_by_class: BTreeMap::<String, u32>,
+    pub dwell_time_list: Vec::<f64>,        /* seconds of each finished stay */
+    inside_list: HashMap::<i32, f64>,       /* track_id -> time when the object entered */
+}
+
+impl ZoneCounter {
+    /* The number of objects in the zone now */
+    pub fn inside_count(&self) -> usize {
+        self.inside_list.len()
+    }
+
+    pub fn is_inside(&self, track_id: i32) -> bool {
+        self.inside_list.contains_key(&track_id)
+    }
+
+    /* Seconds the object has been in the zone. None if it is not in the zone */
+    pub fn dwell_time(&self, track_id: i32, timestamp: f64) -> Option<f64> {
+        self.inside_list.get(&track_id).map(|entered| timestamp - entered)
+    }
+
+    pub fn average_dwell_time(&self) -> Option<f64> {
+        if self.dwell_time_list.is_empty() {
+            None
+        } else {
+            Some(self.dwell_time_list.iter().sum::<f64>() / self.dwell_time_list.len() as f64)
+        }
+    }
+}
+
+/* Counts in [start_time, end_time). Lists are in the order of the lines and zones in the config */
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntervalCount {
+    pub start_time: f64,
+    pub end_time: f64,
+    pub line_count_list: Vec::<BTreeMap::<String, CrossingCount>>,
+    pub zone_entry_count_list: Vec::<BTreeMap::<String, u32>>,
+}
+
+struct TrackState {
+    last_seen: f64,
+    last_point_list: Vec::<Option<(f32, f32)>>,     /* the last position which was not on each line */
+}
+
+
+/////////////////////////////////////////////////////////////////
+/* Line crossing and zone counting with tracked objects (see Tracker) */
+pub struct Analytics {
+    config: AnalyticsConfig,
+    line_counter_list: Vec::<LineCounter>,
+    zone_counter_list: Vec::<ZoneCounter>,
+    track_state_list: HashMap::<i32, TrackState>,
+    current_interval: Option<IntervalCount>,
+    interval_history: Vec::<IntervalCount>,
+}
+
+impl Analytics {
+    pub fn new(config: AnalyticsConfig) -> Self {
+        let line_counter_list = config.line_list.iter().map(|line| LineCounter { line: line.clone(), count_by_class: BTreeMap::new() }).collect();
+        let zone_counter_list = config.zone_list.iter().map(|zone| ZoneCounter { zone: zone.clone(), entry_by_class: BTreeMap::new(), dwell_time_list: vec![], inside_list: HashMap::new() }).collect();
+        Analytics {
+            config: config,
+            line_counter_list: line_counter_list,
+            zone_counter_list: zone_counter_list,
+            track_state_list: HashMap::new(),
+            current_interval: None,
+            interval_history: vec![],
+        }
+    }
+
+    pub fn from_config_file(filename: &str) -> Result<Self> {
+        Ok(Self::new(AnalyticsConfig::from_file(filename)?))
+    }
+
+    pub fn config(&self) -> &AnalyticsConfig {
+        &self.config
+    }
+
+    /* Total counts since the start */
+    pub fn line_counter_list(&self) -> &[LineCounter] {
+        &self.line_counter_list
+    }
+
+    pub fn zone_counter_list(&self) -> &[ZoneCounter] {
+        &self.zone_counter_list
+    }
+
+    /* Finished intervals (oldest first) */
+    pub fn interval_history(&self) -> &[IntervalCount] {
+        &self.interval_history
+    }
+
+    /* Interval which is being counted */
+    pub fn current_interval(&self) -> Option<&IntervalCount> {
+        self.current_interval.as_ref()
+    }
+
+    /* Call once per frame with the tracked objects of the frame. timestamp is in seconds and must not decrease */
+    pub fn update(&mut self, object_list: &[TrackedObject], timestamp: f64) {
+        self.advance_interval(timestamp);
+        let num_line = self.line_counter_list.len();
+        let current_interval = match self.current_interval.as_mut() {
+            Some(current_interval) => current_interval,
+            None => return,
+        };
+
+        for object in object_list {
+            let point = anchor_point(&object.bbox);
+            let label = &object.bbox.label;
+            let track_state = self.track_state_list.entry(object.track_id).or_insert_with(|| TrackState { last_seen: timestamp, last_point_list: vec![None; num_line] });
+            track_state.last_seen = timestamp;
+
+            /* Lines */
+            for ((counter, last_point), interval_count) in self.line_counter_list.iter_mut().zip(track_state.last_point_list.iter_mut()).zip(current_interval.line_count_list.iter_mut()) {
+                if counter.line.side(point) == 0.0 {
+                    continue;
+                }
+                if let Some(direction) = last_point.and_then(|last_point| counter.line.crossing(last_point, point)) {
+                    counter.count_by_class.entry(label.clone()).or_default().add(direction);
+                    interval_count.entry(label.clone()).or_default().add(direction);
+                }
+                *last_point = Some(point);
+            }
+
+            /* Zones */
+            for (counter, interval_count) in self.zone_counter_list.iter_mut().zip(current_interval.zone_entry_count_list.iter_mut()) {
+                let is_inside = counter.zone.polygon.contains_point(point.0, point.1);
+                match (counter.inside_list.get(&object.track_id).cloned(), is_inside) {
+                    (None, true) => {
+                        counter.inside_list.insert(object.track_id, timestamp);
+                        *counter.entry_by_class.entry(label.clone()).or_default() += 1;
+                        *interval_count.entry(label.clone()).or_default() += 1;
+                    },
+                    (Some(entered), false) => {
+                        counter.inside_list.remove(&object.track_id);
+                        counter.dwell_time_list.push(timestamp - entered);
+                    },
+                    _ => {},
+                }
+            }
+        }
+
+        /* Objects which are gone leave the zones at the time they were seen last */
+        let lost_time = self.config.lost_time;
+        let lost_list: Vec::<(i32, f64)> = self.track_state_list.iter()
+            .filter(|(_, track_state)| timestamp - track_state.last_seen > lost_time)
+            .map(|(track_id, track_state)| (*track_id, track_state.last_seen))
+            .collect();
+        for (track_id, last_seen) in lost_list {
+            for counter in self.zone_counter_list.iter_mut() {
+                if let Some(entered) = counter.inside_list.remove(&track_id) {
+                    counter.dwell_time_list.push(last_seen - entered);
+                }
+            }
+            self.track_state_list.remove(&track_id);
+        }
+    }
+
+    /* Close the current interval (and empty ones) if timestamp is over it */
+    fn advance_interval(&mut self, timestamp: f64) {
+        let (num_line, num_zone) = (self.line_counter_list.len(), self.zone_counter_list.len());
+        let new_interval = |start_time: f64, end_time: f64| IntervalCount {
+            start_time: start_time,
+            end_time: end_time,
+            line_count_list: vec![BTreeMap::new(); num_line],
+            zone_entry_count_list: vec![BTreeMap::new(); num_zone],
+        };
+        let interval = if self.config.interval > 0.0 { self.config.interval } else { f64::INFINITY };
+        let current_interval = self.current_interval.get_or_insert_with(|| new_interval(timestamp, timestamp + interval));
+        while timestamp >= current_interval.end_time {
+            let next_interval = new_interval(current_interval.end_time, current_interval.end_time + interval);
+            self.interval_history.push(std::mem::replace(current_interval, next_interval));
+        }
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* Object whose anchor point (bottom center) is (x, y) */
+    fn make_object(track_id: i32, label: &str, x: f32, y: f32) -> TrackedObject {
+        TrackedObject { track_id: track_id, bbox: BoundingBox { class_id: 0, label: label.to_string(), score: 0.9, x: x - 10.0, y: y - 40.0, w: 20.0, h: 40.0 } }
+    }
+
+    fn make_config() -> AnalyticsConfig {
+        AnalyticsConfig {
+            interval: 10.0,
+            lost_time: 1.0,
+            line_list: vec![CountingLine { name: "gate".to_string(), start: (0.0, 100.0), end: (200.0, 100.0) }],
+            zone_list: vec![CountingZone { name: "area".to_string(), polygon: Polygon::new(vec![(300.0, 0.0), (400.0, 0.0), (400.0, 100.0), (300.0, 100.0)]) }],
+        }
+    }
+
+    #[test]
+    fn line_crossing() {
+        let line = CountingLine { name: "gate".to_string(), start: (0.0, 100.0), end: (200.0, 100.0) };
+        assert_eq!(line.crossing((50.0, 90.0), (50.0, 110.0)), Some(CrossingDirection::Forward));
+        assert_eq!(line.crossing((50.0, 110.0), (50.0, 90.0)), Some(CrossingDirection::Backward));
+        assert_eq!(line.crossing((250.0, 90.0), (250.0, 110.0)), None);     /* beside the segment */
+        assert_eq!(line.crossing((50.0, 90.0), (60.0, 95.0)), None);
+
+        /* Car goes down, person goes down then up, and a car passes beside the line */
+        let mut analytics = Analytics::new(make_config());
+        let script = [
+            (0.0, vec![make_object(1, "car", 50.0, 80.0), make_object(2, "person", 100.0, 90.0), make_object(3, "car", 250.0, 80.0)]),
+            (0.1, vec![make_object(1, "car", 50.0, 100.0), make_object(2, "person", 100.0, 110.0), make_object(3, "car", 250.0, 120.0)]),     /* 1 is on the line */
+            (0.2, vec![make_object(1, "car", 50.0, 120.0), make_object(2, "person", 100.0, 90.0)]),
+        ];
+        for (timestamp, object_list) in script.iter() {
+            analytics.update(object_list, *timestamp);
+        }
+        let counter = &analytics.line_counter_list()[0];
+        assert_eq!(counter.count_by_class["car"], CrossingCount { forward: 1, backward: 0 });
+        assert_eq!(counter.count_by_class["person"], CrossingCount { forward: 1, backward: 1 });
+        assert_eq!(counter.total(), CrossingCount { forward: 2, backward: 1 });
+    }
+
+    #[test]
+    fn zone_dwell_time() {
+        let mut analytics = Analytics::new(make_config());
+        /* Object 1 stays 2 seconds. Object 2 enters and disappears (lost) */
+        for frame in 0 .. 50 {
+            let timestamp = frame as f64 * 0.1;
+            let mut object_list = vec![];
+            if frame < 40 {
+                let x = if (10 .. 30).contains(&frame) { 350.0 } else { 250.0 };
+                object_list.push(make_object(1, "person", x, 50.0));
+            }
+            if (20 .. 25).contains(&frame) {
+                object_list.push(make_object(2, "person", 320.0, 50.0));
+            }
+            analytics.update(&object_list, timestamp);
+            if frame == 22 {
+                let counter = &analytics.zone_counter_list()[0];
+                assert_eq!(counter.inside_count(), 2);
+                assert!((counter.dwell_time(1, timestamp).unwrap() - 1.2).abs() < 1e-6);
+            }
+        }
+        let counter = &analytics.zone_counter_list()[0];
+        assert_eq!(counter.inside_count(), 0);
+        assert_eq!(counter.entry_by_class["person"], 2);
+        assert_eq!(counter.dwell_time_list.len(), 2);
+        assert!((counter.dwell_time_list[0] - 2.0).abs() < 1e-6);
+        assert!((counter.dwell_time_list[1] - 0.4).abs() < 1e-6);
+        assert!((counter.average_dwell_time().unwrap() - 1.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn interval_and_config() {
+        let mut analytics = Analytics::new(make_config());
+        analytics.update(&[make_object(1, "car", 50.0, 80.0)], 0.0);
+        analytics.update(&[make_object(1, "car", 50.0, 120.0)], 5.0);
+        analytics.update(&[make_object(2, "car", 50.0, 80.0)], 25.0);
+        analytics.update(&[make_object(2, "car", 50.0, 120.0)], 26.0);
+
+        /* [0, 10) has 1 car, [10, 20) is empty, and [20, 30) is being counted */
+        let history = analytics.interval_history();
+        assert_eq!(history.len(), 2);
+        assert_eq!((history[0].start_time, history[0].end_time), (0.0, 10.0));
+        assert_eq!(history[0].line_count_list[0]["car"].forward, 1);
+        assert!(history[1].line_count_list[0].is_empty());
+        assert_eq!(analytics.current_interval().unwrap().line_count_list[0]["car"].forward, 1);
+        assert_eq!(analytics.line_counter_list()[0].total().forward, 2);
+
+        let text = r#"
+            [[line]]
+            name = "gate"
+            start = [0.0, 100.0]
+            end = [200.0, 100.0]
+            [[zone]]
+            name = "area"
+            polygon = { point_list = [[300.0, 0.0], [400.0, 0.0], [400.0, 100.0], [300.0, 100.0]] }
+        "#;
+        let config: AnalyticsConfig = toml::from_str(text).unwrap();
+        assert_eq!(config.line_list, make_config().line_list);
+        assert_eq!(config.zone_list, make_config().zone_list);
+        assert_eq!(config.interval, 60.0);
+    }
+}
@@ -0,0 +1,568 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::core;
+use serde::{Deserialize, Serialize};
+
+
+/////////////////////////////////////////////////////////////////
+/* Box in pixel of the original image. (x, y) is the top left corner */
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundingBox {
+    pub class_id: i32,
+    pub label: String,
+    pub score: f32,
+    pub x: f32,
+    pub y: f32,
+    pub w: f32,
+    pub h: f32,
+}
+
+impl BoundingBox {
+    /* (x0, y0, x1, y1) */
+    pub fn corners(&self) -> (f32, f32, f32, f32) {
+        (self.x, self.y, self.x + self.w, self.y + self.h)
+    }
+
+    pub fn set_corners(&mut self, x0: f32, y0: f32, x1: f32, y1: f32) {
+        self.x = x0;
+        self.y = y0;
+        self.w = x1 - x0;
+        self.h = y1 - y0;
+    }
+
+    pub fn center(&self) -> (f32, f32) {
+        (self.x + self.w / 2.0, self.y + self.h / 2.0)
+    }
+
+    /* 0 for a box which has negative width or height */
+    pub fn area(&self) -> f32 {
+        self.w.max(0.0) * self.h.max(0.0)
+    }
+
+    /* Scale coordinates (e.g. from the model input size to the image size) */
+    pub fn scale(&mut self, scale_x: f32, scale_y: f32) {
+        self.x *= scale_x;
+        self.y *= scale_y;
+        self.w *= scale_x;
+        self.h *= scale_y;
+    }
+
+    /* Clip the box into [0, width] x [0, height]. A box which is completely outside becomes 0 size */
+    pub fn clamp(&mut self, width: f32, height: f32) {
+        let (width, height) = (width.max(0.0), height.max(0.0));
+        let (x0, y0, x1, y1) = self.corners();
+        let x0 = x0.clamp(0.0, width);
+        let y0 = y0.clamp(0.0, height);
+        let x1 = x1.clamp(x0, width);
+        let y1 = y1.clamp(y0, height);
+        self.set_corners(x0, y0, x1, y1);
+    }
+
+    /* Integer rect for drawing (corners are rounded) */
+    pub fn to_rect(&self) -> core::Rect {
+        let (x0, y0, x1, y1) = self.corners();
+        let (x0, y0, x1, y1) = (x0.round() as i32, y0.round() as i32, x1.round() as i32, y1.round() as i32);
+        core::Rect::new(x0, y0, x1 - x0, y1 - y0)
+    }
+}
+
+pub fn calculate_iou(bbox0: &BoundingBox, bbox1: &BoundingBox) -> f32 {
+    let (bbox0_x0, bbox0_y0, bbox0_x1, bbox0_y1) = bbox0.corners();
+    let (bbox1_x0, bbox1_y0, bbox1_x1, bbox1_y1) = bbox1.corners();
+    let interx0 = bbox0_x0.max(bbox1_x0);
+    let intery0 = bbox0_y0.max(bbox1_y0);
+    let interx1 = bbox0_x1.min(bbox1_x1);
+    let intery1 = bbox0_y1.min(bbox1_y1);
+    if interx1 < interx0 || intery1 < intery0 {
+        return 0.0;
+    }
+
+    let area0 = bbox0.area();
+    let area1 = bbox1.area();
+    let area_inter = (interx1 - interx0) * (intery1 - intery0);
+    let area_sum = area0 + area1 - area_inter;
+    if area_sum <= 0.0 {
+        return 0.0;
+    }
+    return area_inter / area_sum;
+}
+
+/* IoU - (distance between centers)^2 / (diagonal of the enclosing box)^2 */
+/* Reference: https://arxiv.org/abs/1911.08287 */
+pub fn calculate_diou(bbox0: &BoundingBox, bbox1: &BoundingBox) -> f32 {
+    calculate_iou(bbox0, bbox1) - center_distance_penalty(bbox0, bbox1)
+}
+
+/* DIoU - alpha * v (consistency of aspect ratio) */
+pub fn calculate_ciou(bbox0: &BoundingBox, bbox1: &BoundingBox) -> f32 {
+    let iou = calculate_iou(bbox0, bbox1);
+    let v = 4.0 / (std::f32::consts::PI * std::f32::consts::PI) * ((bbox0.w / bbox0.h).atan() - (bbox1.w / bbox1.h).atan()).powi(2);
+    let v = if v.is_finite() { v } else { 0.0 };
+    let alpha = if v > 0.0 { v / ((1.0 - iou) + v) } else { 0.0 };
+    iou - center_distance_penalty(bbox0, bbox1) - alpha * v
+}
+
+fn center_distance_penalty(bbox0: &BoundingBox, bbox1: &BoundingBox) -> f32 {
+    let (bbox0_x0, bbox0_y0, bbox0_x1, bbox0_y1) = bbox0.corners();
+    let (bbox1_x0, bbox1_y0, bbox1_x1, bbox1_y1) = bbox1.corners();
+    let (bbox0_cx, bbox0_cy) = bbox0.center();
+    let (bbox1_cx, bbox1_cy) = bbox1.center();
+    let enclose_w = bbox0_x1.max(bbox1_x1) - bbox0_x0.min(bbox1_x0);
+    let enclose_h = bbox0_y1.max(bbox1_y1) - bbox0_y0.min(bbox1_y0);
+    let diagonal = enclose_w * enclose_w + enclose_h * enclose_h;
+    if diagonal <= 0.0 {
+        return 0.0;
+    }
+    ((bbox0_cx - bbox1_cx).powi(2) + (bbox0_cy - bbox1_cy).powi(2)) / diagonal
+}
+
+
+/* Which boxes suppress each other in NMS */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NmsMode {
+    ClassAgnostic,  /* any boxes */
+    ClassAware,     /* only boxes which have the same class_id */
+}
+
+impl Default for NmsMode {
+    fn default() -> Self {
+        NmsMode::ClassAgnostic
+    }
+}
+
+impl std::str::FromStr for NmsMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "class_agnostic" | "class-agnostic" => Ok(NmsMode::ClassAgnostic),
+            "class_aware" | "class-aware" => Ok(NmsMode::ClassAware),
+            _ => Err(format!("unknown nms mode: {} (class_agnostic or class_aware)", s)),
+        }
+    }
+}
+
+/* Class agnostic NMS without limit of the number of boxes */
+pub fn nms(bbox_list: &mut Vec::<BoundingBox>, iou_threshold: f32) -> Vec::<BoundingBox> {
+    batched_nms(bbox_list, iou_threshold, NmsMode::ClassAgnostic, None)
+}
+
+/* Sort in descending order of score. Boxes with NaN score are removed. Boxes with the same score keep their order */
+pub fn sort_by_score(bbox_list: &mut Vec::<BoundingBox>) {
+    bbox_list.retain(|bbox| !bbox.score.is_nan());
+    bbox_list.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+}
+
+/* Greedy NMS. At most max_detections boxes are returned (in descending order of score) */
+pub fn batched_nms(bbox_list: &mut Vec::<BoundingBox>, iou_threshold: f32, mode: NmsMode, max_detections: Option<usize>) -> Vec::<BoundingBox> {
+    without_index(greedy_nms(bbox_list, iou_threshold, mode, max_detections, calculate_iou))
+}
+
+fn without_index(result_list: Vec::<(usize, BoundingBox)>) -> Vec::<BoundingBox> {
+    result_list.into_iter().map(|(_, bbox)| bbox).collect()
+}
+
+/* Greedy NMS which suppresses a box when overlap(high score box, the box) > threshold */
+/* Each result has the index of the box in bbox_list (after sorted) */
+fn greedy_nms(bbox_list: &mut Vec::<BoundingBox>, threshold: f32, mode: NmsMode, max_detections: Option<usize>, overlap: fn(&BoundingBox, &BoundingBox) -> f32) -> Vec::<(usize, BoundingBox)> {
+    let mut bbox_list_new = Vec::<(usize, BoundingBox)>::new();
+    let max_detections = max_detections.unwrap_or(usize::MAX);
+    
+    /* Descending order of score: High score -> Low score */
+    sort_by_score(bbox_list);
+
+    let mut is_merged_list = Vec::<bool>::new();
+    is_merged_list.resize(bbox_list.len(), false);
+
+    for index_high_score in 0 .. bbox_list.len() {
+        if bbox_list_new.len() >= max_detections {
+            break;
+        }
+        if is_merged_list[index_high_score] {
+            continue;
+        }
+        bbox_list_new.push((index_high_score, bbox_list[index_high_score].clone()));
+        for index_low_score in index_high_score + 1 .. bbox_list.len() {
+            if is_merged_list[index_low_score] {
+                continue;
+            }
+            if mode == NmsMode::ClassAware && bbox_list[index_high_score].class_id != bbox_list[index_low_score].class_id {
+                continue;
+            }
+            if overlap(&bbox_list[index_high_score], &bbox_list[index_low_score]) > threshold {
+                is_merged_list[index_low_score] = true;
+            }
+        }
+    }
+    bbox_list_new
+}
+
+/* Post-process strategy to remove (or merge) overlapped boxes */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NmsMethod {
+    Hard,           /* greedy NMS with IoU */
+    SoftLinear,     /* Soft-NMS. score *= (1 - IoU) when IoU > threshold */
+    SoftGaussian,   /* Soft-NMS. score *= exp(-IoU^2 / sigma) */
+    Diou,           /* greedy NMS with DIoU */
+    Ciou,           /* greedy NMS with CIoU */
+    Wbf,            /* Weighted Boxes Fusion. overlapped boxes are averaged instead of removed */
+}
+
+impl Default for NmsMethod {
+    fn default() -> Self {
+        NmsMethod::Hard
+    }
+}
+
+impl std::str::FromStr for NmsMethod {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "hard" => Ok(NmsMethod::Hard),
+            "soft_linear" | "soft-linear" => Ok(NmsMethod::SoftLinear),
+            "soft_gaussian" | "soft-gaussian" => Ok(NmsMethod::SoftGaussian),
+            "diou" => Ok(NmsMethod::Diou),
+            "ciou" => Ok(NmsMethod::Ciou),
+            "wbf" => Ok(NmsMethod::Wbf),
+            _ => Err(format!("unknown nms method: {} (hard, soft_linear, soft_gaussian, diou, ciou or wbf)", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NmsParam {
+    pub method: NmsMethod,
+    pub mode: NmsMode,
+    pub iou_threshold: f32,
+    pub score_threshold: f32,       /* Soft-NMS drops a box when its decayed score becomes lower than this */
+    pub soft_nms_sigma: f32,
+    pub max_detections: Option<usize>,
+}
+
+pub fn apply_nms(bbox_list: &mut Vec::<BoundingBox>, param: &NmsParam) -> Vec::<BoundingBox> {
+    without_index(apply_nms_sorted(bbox_list, param))
+}
+
+/* The same as apply_nms, but each result comes with the index of its source box in bbox_list (which is not reordered) */
+/* Use the index to get data decoded with the box (mask coefficients, keypoints, etc.) */
+/* The source of a box fused by WBF is the highest score box of the cluster */
+pub fn apply_nms_indices(bbox_list: &[BoundingBox], param: &NmsParam) -> Vec::<(usize, BoundingBox)> {
+    /* The same order as sort_by_score */
+    let mut order_list: Vec::<usize> = (0 .. bbox_list.len()).filter(|index| !bbox_list[*index].score.is_nan()).collect();
+    order_list.sort_by(|a, b| bbox_list[*b].score.partial_cmp(&bbox_list[*a].score).unwrap_or(std::cmp::Ordering::Equal));
+    let mut bbox_list_sorted: Vec::<BoundingBox> = order_list.iter().map(|index| bbox_list[*index].clone()).collect();
+    apply_nms_sorted(&mut bbox_list_sorted, param).into_iter().map(|(index, bbox)| (order_list[index], bbox)).collect()
+}
+
+/* Each result has the index of the box in bbox_list (after sorted) */
+fn apply_nms_sorted(bbox_list: &mut Vec::<BoundingBox>, param: &NmsParam) -> Vec::<(usize, BoundingBox)> {
+    match param.method {
+        NmsMethod::Hard => greedy_nms(bbox_list, param.iou_threshold, param.mode, param.max_detections, calculate_iou),
+        NmsMethod::Diou => greedy_nms(bbox_list, param.iou_threshold, param.mode, param.max_detections, calculate_diou),
+        NmsMethod::Ciou => greedy_nms(bbox_list, param.iou_threshold, param.mode, param.max_detections, calculate_ciou),
+        NmsMethod::SoftLinear | NmsMethod::SoftGaussian => soft_nms_sorted(bbox_list, param),
+        NmsMethod::Wbf => weighted_boxes_fusion_sorted(bbox_list, param.iou_threshold, param.mode, param.max_detections),
+    }
+}
+
+/* Reference: https://arxiv.org/abs/1704.04503 */
+pub fn soft_nms(bbox_list: &mut Vec::<BoundingBox>, param: &NmsParam) -> Vec::<BoundingBox> {
+    without_index(soft_nms_sorted(bbox_list, param))
+}
+
+fn soft_nms_sorted(bbox_list: &mut Vec::<BoundingBox>, param: &NmsParam) -> Vec::<(usize, BoundingBox)> {
+    let mut bbox_list_new = Vec::<(usize, BoundingBox)>::new();
+    let max_detections = param.max_detections.unwrap_or(usize::MAX);
+    /* sigma <= 0 makes the score NaN (0 / 0) or 0, so it is clamped. ModelConfig::validate rejects such a value */
+    let sigma = if param.soft_nms_sigma > f32::EPSILON { param.soft_nms_sigma } else { f32::EPSILON };
+
+    sort_by_score(bbox_list);
+    let mut candidate_list: Vec::<(usize, BoundingBox)> = bbox_list.iter().cloned().enumerate().collect();
+    while !candidate_list.is_empty() && bbox_list_new.len() < max_detections {
+        /* The list is kept in descending order of score, so the first one is the highest */
+        let (index_high_score, bbox_high_score) = candidate_list.remove(0);
+        for (_, bbox) in candidate_list.iter_mut() {
+            if param.mode == NmsMode::ClassAware && bbox.class_id != bbox_high_score.class_id {
+                continue;
+            }
+            let iou = calculate_iou(&bbox_high_score, bbox);
+            bbox.score *= match param.method {
+                NmsMethod::SoftGaussian => (-iou * iou / sigma).exp(),
+                _ => if iou > param.iou_threshold { 1.0 - iou } else { 1.0 },
+            };
+        }
+        candidate_list.retain(|(_, bbox)| bbox.score >= param.score_threshold);
+        candidate_list.sort_by(|(_, a), (_, b)| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        bbox_list_new.push((index_high_score, bbox_high_score));
+    }
+    bbox_list_new
+}
+
+/* Boxes overlapping with a cluster (IoU > threshold) are fused into one box */
+/* Coordinates are averaged with score as weight, and score is the average of the cluster */
+/* Reference: https://arxiv.org/abs/1910.13302 */
+pub fn weighted_boxes_fusion(bbox_list: &mut Vec::<BoundingBox>, iou_threshold: f32, mode: NmsMode, max_detections: Option<usize>) -> Vec::<BoundingBox> {
+    without_index(weighted_boxes_fusion_sorted(bbox_list, iou_threshold, mode, max_detections))
+}
+
+/* Each result has the index of the first box (the highest score) of the cluster in bbox_list (after sorted) */
+fn weighted_boxes_fusion_sorted(bbox_list: &mut Vec::<BoundingBox>, iou_threshold: f32, mode: NmsMode, max_detections: Option<usize>) -> Vec::<(usize, BoundingBox)> {
+    sort_by_score(bbox_list);
+
+    let mut fused_list = Vec::<BoundingBox>::new();
+    let mut cluster_list = Vec::<Vec::<usize>>::new();
+    for (index, bbox) in bbox_list.iter().enumerate() {
+        let matched = fused_list.iter().enumerate()
+            .filter(|(_, fused)| mode == NmsMode::ClassAgnostic || fused.class_id == bbox.class_id)
+            .map(|(index_fused, fused)| (index_fused, calculate_iou(fused, bbox)))
+            .filter(|(_, iou)| *iou > iou_threshold)
+            .fold(None, |best: Option<(usize, f32)>, (index_fused, iou)| match best {
+                Some((_, best_iou)) if best_iou >= iou => best,
+                _ => Some((index_fused, iou)),
+            });
+        match matched {
+            Some((index_fused, _)) => {
+                cluster_list[index_fused].push(index);
+                fused_list[index_fused] = fuse(bbox_list, &cluster_list[index_fused]);
+            },
+            None => {
+                cluster_list.push(vec![index]);
+                fused_list.push(bbox.clone());
+            },
+        }
+    }
+
+    let mut fused_list: Vec::<(usize, BoundingBox)> = cluster_list.iter().map(|cluster| cluster[0]).zip(fused_list).collect();
+    fused_list.sort_by(|(_, a), (_, b)| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    fused_list.truncate(max_detections.unwrap_or(usize::MAX));
+    fused_list
+}
+
+/* The first box (the highest score) decides class and label */
+fn fuse(bbox_list: &[BoundingBox], index_list: &[usize]) -> BoundingBox {
+    let mut fused = bbox_list[index_list[0]].clone();
+    let score_sum: f32 = index_list.iter().map(|index| bbox_list[*index].score).sum();
+    let (mut x0, mut y0, mut x1, mut y1) = (0.0, 0.0, 0.0, 0.0);
+    for index in index_list.iter() {
+        let bbox = &bbox_list[*index];
+        let (bbox_x0, bbox_y0, bbox_x1, bbox_y1) = bbox.corners();
+        x0 += bbox_x0 * bbox.score;
+        y0 += bbox_y0 * bbox.score;
+        x1 += bbox_x1 * bbox.score;
+        y1 += bbox_y1 * bbox.score;
+    }
+    if score_sum > 0.0 {
+        fused.set_corners(x0 / score_sum, y0 / score_sum, x1 / score_sum, y1 / score_sum);
+    }
+    fused.score = score_sum / index_list.len() as f32;
+    fused
+}
+
+pub fn fit_in_screen(bbox: &mut BoundingBox, width: i32, height: i32) {
+    bbox.clamp(width as f32, height as f32);
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bbox(class_id: i32, score: f32, x: f32, y: f32, w: f32, h: f32) -> BoundingBox {
+        BoundingBox { class_id: class_id, label: class_id.to_string(), score: score, x: x, y: y, w: w, h: h }
+    }
+
+    fn scores(bbox_list: &[BoundingBox]) -> Vec::<f32> {
+        bbox_list.iter().map(|bbox| bbox.score).collect()
+    }
+
+    #[test]
+    fn iou() {
+        let a = bbox(0, 1.0, 0.0, 0.0, 10.0, 10.0);
+        assert_eq!(calculate_iou(&a, &a), 1.0);
+        assert_eq!(calculate_iou(&a, &bbox(0, 1.0, 5.0, 0.0, 10.0, 10.0)), 50.0 / 150.0);
+        assert_eq!(calculate_iou(&a, &bbox(0, 1.0, 20.0, 0.0, 10.0, 10.0)), 0.0);
+        assert_eq!(calculate_iou(&bbox(0, 1.0, 0.0, 0.0, 0.0, 0.0), &bbox(0, 1.0, 0.0, 0.0, 0.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn class_aware_and_agnostic() {
+        /* person and bicycle at the same place, and another person */
+        let bbox_list = vec![
+            bbox(1, 0.7, 1.0, 1.0, 10.0, 10.0),
+            bbox(0, 0.9, 0.0, 0.0, 10.0, 10.0),
+            bbox(0, 0.8, 50.0, 50.0, 10.0, 10.0),
+            bbox(0, 0.6, 0.5, 0.5, 10.0, 10.0),
+        ];
+        let result = batched_nms(&mut bbox_list.clone(), 0.5, NmsMode::ClassAware, None);
+        assert_eq!(scores(&result), vec![0.9, 0.8, 0.7]);
+        let result = batched_nms(&mut bbox_list.clone(), 0.5, NmsMode::ClassAgnostic, None);
+        assert_eq!(scores(&result), vec![0.9, 0.8]);
+        assert_eq!(scores(&nms(&mut bbox_list.clone(), 0.5)), vec![0.9, 0.8]);
+    }
+
+    #[test]
+    fn max_detections() {
+        let mut bbox_list: Vec::<BoundingBox> = (0..10).map(|i| bbox(0, i as f32 / 10.0, i as f32 * 20.0, 0.0, 10.0, 10.0)).collect();
+        let result = batched_nms(&mut bbox_list, 0.5, NmsMode::ClassAware, Some(3));
+        assert_eq!(scores(&result), vec![0.9, 0.8, 0.7]);
+        assert!(batched_nms(&mut bbox_list, 0.5, NmsMode::ClassAware, Some(0)).is_empty());
+    }
+
+    #[test]
+    fn ties() {
+        /* The same score: the earlier box in the input survives */
+        let mut bbox_list = vec![
+            bbox(0, 0.5, 0.0, 0.0, 10.0, 10.0),
+            bbox(2, 0.5, 1.0, 0.0, 10.0, 10.0),
+            bbox(3, 0.5, 2.0, 0.0, 10.0, 10.0),
+        ];
+        let result = batched_nms(&mut bbox_list, 0.5, NmsMode::ClassAgnostic, None);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].class_id, 0);
+
+        /* IoU equal to the threshold is not suppressed */
+        let mut bbox_list = vec![bbox(0, 0.9, 0.0, 0.0, 10.0, 10.0), bbox(0, 0.8, 5.0, 0.0, 10.0, 10.0)];
+        assert_eq!(batched_nms(&mut bbox_list, 50.0 / 150.0, NmsMode::ClassAgnostic, None).len(), 2);
+    }
+
+    #[test]
+    fn nan_score() {
+        let mut bbox_list = vec![
+            bbox(0, f32::NAN, 0.0, 0.0, 10.0, 10.0),
+            bbox(0, 0.3, 0.0, 0.0, 10.0, 10.0),
+            bbox(0, f32::NAN, 30.0, 0.0, 10.0, 10.0),
+            bbox(0, 0.4, 30.0, 0.0, 10.0, 10.0),
+        ];
+        let result = batched_nms(&mut bbox_list, 0.5, NmsMode::ClassAware, None);
+        assert_eq!(scores(&result), vec![0.4, 0.3]);
+
+        let mut bbox_list = vec![bbox(0, f32::NAN, 0.0, 0.0, 10.0, 10.0)];
+        assert!(nms(&mut bbox_list, 0.5).is_empty());
+    }
+
+    fn param(method: NmsMethod) -> NmsParam {
+        NmsParam { method: method, mode: NmsMode::ClassAware, iou_threshold: 0.3, score_threshold: 0.1, soft_nms_sigma: 0.5, max_detections: None }
+    }
+
+    #[test]
+    fn diou_ciou() {
+        let a = bbox(0, 1.0, 0.0, 0.0, 10.0, 10.0);
+        let b = bbox(0, 1.0, 5.0, 0.0, 10.0, 10.0);
+        /* centers are 5 apart, and the enclosing box is 15 x 10 */
+        assert!((calculate_diou(&a, &b) - (50.0 / 150.0 - 25.0 / 325.0)).abs() < 1e-6);
+        /* the same aspect ratio: CIoU == DIoU */
+        assert!((calculate_ciou(&a, &b) - calculate_diou(&a, &b)).abs() < 1e-6);
+        assert!(calculate_ciou(&a, &bbox(0, 1.0, 0.0, 0.0, 10.0, 5.0)) < calculate_diou(&a, &bbox(0, 1.0, 0.0, 0.0, 10.0, 5.0)));
+        assert_eq!(calculate_diou(&a, &a), 1.0);
+
+        /* a box containing the other at the same center: DIoU == IoU, so DIoU-NMS suppresses it like hard NMS */
+        let mut bbox_list = vec![a.clone(), bbox(0, 0.9, 1.0, 1.0, 8.0, 8.0)];
+        assert_eq!(apply_nms(&mut bbox_list, &param(NmsMethod::Diou)).len(), 1);
+        let mut bbox_list = vec![a.clone(), bbox(0, 0.9, 1.0, 1.0, 8.0, 8.0)];
+        assert_eq!(apply_nms(&mut bbox_list, &param(NmsMethod::Ciou)).len(), 1);
+    }
+
+    #[test]
+    fn soft_nms_decay() {
+        /* IoU = 50 / 150 */
+        let bbox_list = vec![bbox(0, 0.9, 0.0, 0.0, 10.0, 10.0), bbox(0, 0.8, 5.0, 0.0, 10.0, 10.0), bbox(1, 0.7, 5.0, 0.0, 10.0, 10.0)];
+        let iou = 50.0f32 / 150.0;
+
+        let result = apply_nms(&mut bbox_list.clone(), &param(NmsMethod::SoftLinear));
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[1].score, 0.7);
+        assert!((result[2].score - 0.8 * (1.0 - iou)).abs() < 1e-6);
+
+        let result = apply_nms(&mut bbox_list.clone(), &param(NmsMethod::SoftGaussian));
+        assert!((result[2].score - 0.8 * (-iou * iou / 0.5).exp()).abs() < 1e-6);
+
+        /* the decayed score is lower than score_threshold */
+        let mut strict = param(NmsMethod::SoftLinear);
+        strict.score_threshold = 0.6;
+        assert_eq!(scores(&apply_nms(&mut bbox_list.clone(), &strict)), vec![0.9, 0.7]);
+        strict.max_detections = Some(1);
+        assert_eq!(scores(&apply_nms(&mut bbox_list.clone(), &strict)), vec![0.9]);
+    }
+
+    #[test]
+    fn soft_nms_invalid_sigma() {
+        /* Overlapped boxes are suppressed like hard NMS, and separated boxes keep their score */
+        let bbox_list = vec![bbox(0, 0.9, 0.0, 0.0, 10.0, 10.0), bbox(0, 0.8, 5.0, 0.0, 10.0, 10.0), bbox(0, 0.7, 20.0, 0.0, 10.0, 10.0)];
+        for sigma in [0.0, -0.5, f32::NAN].iter() {
+            let mut gaussian = param(NmsMethod::SoftGaussian);
+            gaussian.soft_nms_sigma = *sigma;
+            assert_eq!(scores(&apply_nms(&mut bbox_list.clone(), &gaussian)), vec![0.9, 0.7]);
+        }
+    }
+
+    #[test]
+    fn wbf() {
+        let mut bbox_list = vec![
+            bbox(0, 0.75, 0.0, 0.0, 10.0, 10.0),
+            bbox(0, 0.25, 4.0, 0.0, 10.0, 10.0),
+            bbox(0, f32::NAN, 4.0, 0.0, 10.0, 10.0),
+            bbox(1, 0.5, 4.0, 0.0, 10.0, 10.0),
+            bbox(0, 0.6, 50.0, 50.0, 10.0, 10.0),
+        ];
+        let result = apply_nms(&mut bbox_list, &param(NmsMethod::Wbf));
+        assert_eq!(scores(&result), vec![0.6, 0.5, 0.5]);
+        let fused = result.iter().find(|bbox| bbox.class_id == 0 && bbox.x < 10.0).unwrap();
+        assert_eq!(fused.corners(), (1.0, 0.0, 11.0, 10.0));
+    }
+
+    #[test]
+    fn geometry() {
+        let mut a = bbox(0, 1.0, -5.0, 2.5, 20.0, 10.0);
+        assert_eq!(a.corners(), (-5.0, 2.5, 15.0, 12.5));
+        assert_eq!(a.center(), (5.0, 7.5));
+        assert_eq!(a.area(), 200.0);
+        assert_eq!(a.to_rect(), core::Rect::new(-5, 3, 20, 10));
+        a.clamp(10.0, 10.0);
+        assert_eq!(a.corners(), (0.0, 2.5, 10.0, 10.0));
+        a.scale(2.0, 0.5);
+        assert_eq!(a.corners(), (0.0, 1.25, 20.0, 5.0));
+
+        let mut outside = bbox(0, 1.0, 30.0, 30.0, 10.0, 10.0);
+        fit_in_screen(&mut outside, 20, 20);
+        assert_eq!(outside.area(), 0.0);
+    }
+
+    #[test]
+    fn nms_indices() {
+        /* Not sorted by score, and the source list is not reordered */
+        let bbox_list = vec![
+            bbox(0, 0.8, 1.0, 0.0, 10.0, 10.0),
+            bbox(0, f32::NAN, 0.0, 0.0, 10.0, 10.0),
+            bbox(0, 0.9, 0.0, 0.0, 10.0, 10.0),
+            bbox(1, 0.7, 1.0, 0.0, 10.0, 10.0),
+        ];
+        let indices = |method: NmsMethod| apply_nms_indices(&bbox_list, &param(method)).into_iter().map(|(index, _)| index).collect::<Vec::<usize>>();
+        assert_eq!(indices(NmsMethod::Hard), vec![2, 3]);
+        assert_eq!(indices(NmsMethod::Diou), vec![2, 3]);
+        /* Soft-NMS keeps the decayed box, and WBF refers to the highest score box of the cluster */
+        assert_eq!(indices(NmsMethod::SoftLinear), vec![2, 3, 0]);
+        assert_eq!(indices(NmsMethod::Wbf), vec![2, 3]);
+        assert!(bbox_list[1].score.is_nan());
+
+        /* The same boxes as apply_nms */
+        for method in [NmsMethod::Hard, NmsMethod::SoftGaussian, NmsMethod::Wbf].iter() {
+            let result = apply_nms_indices(&bbox_list, &param(*method));
+            assert_eq!(scores(&result.into_iter().map(|(_, bbox)| bbox).collect::<Vec::<BoundingBox>>()), scores(&apply_nms(&mut bbox_list.clone(), &param(*method))));
+        }
+    }
+}
@@ -0,0 +1,166 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+pub use clap::Parser;
+
+use crate::bounding_box::{NmsMethod, NmsMode};
+use crate::config::{EngineBuilder, FromModelConfig};
+use crate::error::Result;
+use crate::preprocess::ResizeMode;
+use crate::tracker::TrackerMode;
+
+
+/////////////////////////////////////////////////////////////////
+/* Command line options shared by pj_dnn_det_* binaries */
+#[derive(Parser, Debug, Clone)]
+#[clap(name = "pj_dnn_det", about = "Run object detection on an image, a video, a camera or a directory of images")]
+pub struct Args {
+    /// Input image file (default input when nothing is specified)
+    #[clap(long, group = "input")]
+    pub image: Option<String>,
+
+    /// Input video file
+    #[clap(long, group = "input")]
+    pub video: Option<String>,
+
+    /// Input camera index
+    #[clap(long, group = "input")]
+    pub camera: Option<i32>,
+
+    /// Input directory. All the image files in it are processed
+    #[clap(long, group = "input")]
+    pub dir: Option<String>,
+
+    /// Output file (image or video), or output directory when --dir is used
+    #[clap(long, short)]
+    pub output: Option<String>,
+
+    /// Model config file (.toml or .json)
+    #[clap(long)]
+    pub config: Option<String>,
+
+    /// Model file (overwrites the value in the config)
+    #[clap(long)]
+    pub model: Option<String>,
+
+    /// Label file (overwrites the value in the config)
+    #[clap(long)]
+    pub label: Option<String>,
+
+    /// Model input width (overwrites the value in the config)
+    #[clap(long)]
+    pub input_width: Option<i32>,
+
+    /// Model input height (overwrites the value in the config)
+    #[clap(long)]
+    pub input_height: Option<i32>,
+
+    /// Resize mode: stretch, letterbox or center_crop (overwrites the value in the config)
+    #[clap(long)]
+    pub resize_mode: Option<ResizeMode>,
+
+    /// Threshold of the detection score (overwrites score_threshold in the config, or score_class_threshold in YOLOX and YOLOv5)
+    #[clap(long)]
+    pub score_threshold: Option<f32>,
+
+    /// NMS IoU threshold (overwrites the value in the config)
+    #[clap(long)]
+    pub nms_iou_threshold: Option<f32>,
+
+    /// NMS mode: class_aware or class_agnostic (overwrites the value in the config)
+    #[clap(long)]
+    pub nms_mode: Option<NmsMode>,
+
+    /// NMS method: hard, soft_linear, soft_gaussian, diou, ciou or wbf (overwrites the value in the config)
+    #[clap(long)]
+    pub nms_method: Option<NmsMethod>,
+
+    /// Maximum number of detections per frame (overwrites the value in the config)
+    #[clap(long)]
+    pub max_detections: Option<usize>,
+
+    /// Track objects across frames and give them IDs: sort, byte_track (also uses low score boxes) or deep_sort (also uses appearance. only in projects which have a re-identification model). Only in detection projects
+    #[clap(long)]
+    pub tracker: Option<TrackerMode>,
+
+    /// Counting lines and zones file (.toml or .json) to count line crossings and objects in zones of tracked objects. Only in detection projects
+    #[clap(long)]
+    pub analytics: Option<String>,
+
+    /// Don't show any window
+    #[clap(long)]
+    pub headless: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    Image(String),
+    Video(String),
+    Camera(i32),
+    Directory(String),
+}
+
+impl Args {
+    pub fn input_source(&self, default_image: &str) -> InputSource {
+        if let Some(filename) = &self.video {
+            InputSource::Video(filename.clone())
+        } else if let Some(id) = self.camera {
+            InputSource::Camera(id)
+        } else if let Some(dirname) = &self.dir {
+            InputSource::Directory(dirname.clone())
+        } else if let Some(filename) = &self.image {
+            InputSource::Image(filename.clone())
+        } else {
+            InputSource::Image(default_image.to_string())
+        }
+    }
+
+    /* Config file first, then each option overwrites it */
+    pub fn engine_builder<E: FromModelConfig>(&self) -> Result<EngineBuilder<E>> {
+        let mut builder = EngineBuilder::<E>::new();
+        if let Some(filename) = &self.config {
+            builder = builder.config_file(filename)?;
+        }
+        if let Some(filename) = &self.model {
+            builder = builder.model_filename(filename);
+        }
+        if let Some(filename) = &self.label {
+            builder = builder.label_filename(filename);
+        }
+        if self.input_width.is_some() || self.input_height.is_some() {
+            let config = builder.config();
+            let (width, height) = (self.input_width.unwrap_or(config.input_width), self.input_height.unwrap_or(config.input_height));
+            builder = builder.input_size(width, height);
+        }
+        if let Some(mode) = self.resize_mode {
+            let pad_value = builder.config().pad_value;
+            builder = builder.resize_mode(mode, pad_value);
+        }
+        if let Some(threshold) = self.score_threshold {
+            builder = builder.detection_score_threshold(threshold);
+        }
+        if let Some(threshold) = self.nms_iou_threshold {
+            builder = builder.nms_iou_threshold(threshold);
+        }
+        if let Some(mode) = self.nms_mode {
+            builder = builder.nms_mode(mode);
+        }
+        if let Some(method) = self.nms_method {
+            builder = builder.nms_method(method);
+        }
+        if let Some(num) = self.max_detections {
+            builder = builder.max_detections(num);
+        }
+        Ok(builder)
+    }
+}
@@ -0,0 +1,463 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+use crate::bounding_box::{NmsMethod, NmsMode, NmsParam};
+use crate::error::{Error, Result};
+use crate::preprocess::ResizeMode;
+use crate::prior_box::PriorBoxConfig;
+
+
+/////////////////////////////////////////////////////////////////
+/* Runtime parameters of a DetectionEngine */
+/* Each engine provides its own default values, and a config file (.toml or .json) overwrites only the keys it has */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ModelConfig {
+    pub model_filename: String,
+    pub label_filename: Option<String>,
+    pub input_width: i32,
+    pub input_height: i32,
+    pub input_name: String,
+    pub output_names: Vec::<String>,
+    pub normalize_mean: (f64, f64, f64),
+    pub normalize_norm: (f64, f64, f64),
+    pub resize_mode: ResizeMode,
+    pub pad_value: (f64, f64, f64),         /* used for the margin in letterbox mode */
+    pub score_threshold: f32,
+    pub score_class_threshold: f32,     /* used only by engines which have box confidence and class confidence separately (e.g. YOLOX) */
+    pub nms_iou_threshold: f32,
+    pub nms_mode: NmsMode,
+    pub nms_method: NmsMethod,
+    pub soft_nms_sigma: f32,                /* used only by soft_gaussian */
+    pub max_detections: Option<usize>,      /* the number of boxes after NMS. no limit if not specified */
+    pub prior_box: Option<PriorBoxConfig>,    /* used only by SSD-style engines which decode boxes with prior boxes */
+}
+
+impl ModelConfig {
+    pub fn from_file(filename: &str, default: ModelConfig) -> Result<ModelConfig> {
+        let text = std::fs::read_to_string(filename).map_err(|e| Error::Config(format!("{}: {}", filename, e)))?;
+        let config = if filename.ends_with(".json") {
+            Self::from_json_str(&text, default)
+        } else {
+            Self::from_toml_str(&text, default)
+        };
+        config.map_err(|e| match e {
+            Error::Config(message) => Error::Config(format!("{}: {}", filename, message)),
+            e => e,
+        })
+    }
+
+    pub fn from_toml_str(text: &str, default: ModelConfig) -> Result<ModelConfig> {
+        let value: serde_json::Value = toml::from_str(text).map_err(|e| Error::Config(e.to_string()))?;
+        Self::merge(value, default)
+    }
+
+    pub fn from_json_str(text: &str, default: ModelConfig) -> Result<ModelConfig> {
+        let value: serde_json::Value = serde_json::from_str(text).map_err(|e| Error::Config(e.to_string()))?;
+        Self::merge(value, default)
+    }
+
+    /* Reject values which make the engine or NMS meaningless (e.g. NaN scores by soft_nms_sigma = 0) */
+    pub fn validate(&self) -> Result<()> {
+        if self.input_width <= 0 || self.input_height <= 0 {
+            return Err(Error::Config(format!("input size must be positive: {} x {}", self.input_width, self.input_height)));
+        }
+        if self.output_names.is_empty() {
+            return Err(Error::Config("output_names is empty".to_string()));
+        }
+        for (key, value) in [("score_threshold", self.score_threshold), ("score_class_threshold", self.score_class_threshold), ("nms_iou_threshold", self.nms_iou_threshold)].iter() {
+            if !(0.0..=1.0).contains(value) {
+                return Err(Error::Config(format!("{} must be in [0.0, 1.0]: {}", key, value)));
+            }
+        }
+        if self.soft_nms_sigma.is_nan() || self.soft_nms_sigma <= 0.0 {
+            return Err(Error::Config(format!("soft_nms_sigma must be positive: {}", self.soft_nms_sigma)));
+        }
+        Ok(())
+    }
+
+    /* Keys in key_list must keep the default value. Used to report options which the engine does not use, instead of ignoring them */
+    pub fn check_unchanged(&self, default: &ModelConfig, key_list: &[&str]) -> Result<()> {
+        let value = serde_json::to_value(self).map_err(|e| Error::Config(e.to_string()))?;
+        let value_default = serde_json::to_value(default).map_err(|e| Error::Config(e.to_string()))?;
+        for key in key_list.iter() {
+            if value.get(key) != value_default.get(key) {
+                return Err(Error::Config(format!("{} is not used by this engine", key)));
+            }
+        }
+        Ok(())
+    }
+
+    /* For engines which have box confidence and class confidence separately (e.g. YOLOX, YOLOv5) */
+    /* BoundingBox::score is compared with score_class_threshold. score_threshold (box confidence) is lowered to it, so that boxes whose score >= threshold are not dropped by it */
+    pub fn set_score_class_threshold(&mut self, threshold: f32) {
+        self.score_class_threshold = threshold;
+        self.score_threshold = self.score_threshold.min(threshold);
+    }
+
+    /* score_threshold is the threshold which is compared with the score in BoundingBox */
+    pub fn nms_param(&self, score_threshold: f32) -> NmsParam {
+        NmsParam {
+            method: self.nms_method,
+            mode: self.nms_mode,
+            iou_threshold: self.nms_iou_threshold,
+            score_threshold: score_threshold,
+            soft_nms_sigma: self.soft_nms_sigma,
+            max_detections: self.max_detections,
+        }
+    }
+
+    /* Keys in value overwrite the default. Unknown keys are reported as error */
+    fn merge(value: serde_json::Value, default: ModelConfig) -> Result<ModelConfig> {
+        let mut merged = serde_json::to_value(default).map_err(|e| Error::Config(e.to_string()))?;
+        match (merged.as_object_mut(), value) {
+            (Some(merged), serde_json::Value::Object(value)) => {
+                for (key, value) in value {
+                    merged.insert(key, value);
+                }
+            },
+            _ => return Err(Error::Config("config must be a table".to_string())),
+        }
+        let config: ModelConfig = serde_json::from_value(merged).map_err(|e| Error::Config(e.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+/* Implemented by engines which can be created from ModelConfig */
+pub trait FromModelConfig: Sized {
+    /* Values used when nothing is specified (the model parameters of each engine) */
+    fn default_config() -> ModelConfig;
+
+    /* Keys which the engine does not use (e.g. NMS keys of a classifier). Changing them is reported as error by EngineBuilder */
+    fn unused_keys() -> &'static [&'static str] {
+        &[]
+    }
+
+    /* Whether BoundingBox::score is compared with score_class_threshold instead of score_threshold */
+    /* true in engines which have box confidence and class confidence separately (e.g. YOLOX) */
+    fn uses_score_class_threshold() -> bool {
+        false
+    }
+
+    fn from_config(config: ModelConfig) -> Result<Self>;
+
+    /* Engine with the default config */
+    fn new() -> Result<Self> {
+        Self::builder().build()
+    }
+
+    /* Engine with the default config overwritten by a config file (.toml or .json) */
+    fn from_config_file(filename: &str) -> Result<Self> {
+        Self::builder().config_file(filename)?.build()
+    }
+
+    fn builder() -> EngineBuilder<Self> {
+        EngineBuilder::new()
+    }
+}
+
+/* e.g. DetectionEngine::builder().config_file("model_config.toml")?.score_threshold(0.5).build()? */
+pub struct EngineBuilder<E> {
+    config: ModelConfig,
+    engine: PhantomData<E>,
+}
+
+impl<E: FromModelConfig> EngineBuilder<E> {
+    pub fn new() -> Self {
+        EngineBuilder {
+            config: E::default_config(),
+            engine: PhantomData,
+        }
+    }
+
+    pub fn config_file(mut self, filename: &str) -> Result<Self> {
+        self.config = ModelConfig::from_file(filename, self.config)?;
+        Ok(self)
+    }
+
+    pub fn model_filename(mut self, filename: &str) -> Self {
+        self.config.model_filename = filename.to_string();
+        self
+    }
+
+    pub fn label_filename(mut self, filename: &str) -> Self {
+        self.config.label_filename = Some(filename.to_string());
+        self
+    }
+
+    pub fn input_size(mut self, width: i32, height: i32) -> Self {
+        self.config.input_width = width;
+        self.config.input_height = height;
+        self
+    }
+
+    pub fn input_name(mut self, name: &str) -> Self {
+        self.config.input_name = name.to_string();
+        self
+    }
+
+    pub fn output_names(mut self, name_list: &[&str]) -> Self {
+        self.config.output_names = name_list.iter().map(|name| name.to_string()).collect();
+        self
+    }
+
+    pub fn normalize(mut self, mean: (f64, f64, f64), norm: (f64, f64, f64)) -> Self {
+        self.config.normalize_mean = mean;
+        self.config.normalize_norm = norm;
+        self
+    }
+
+    pub fn resize_mode(mut self, mode: ResizeMode, pad_value: (f64, f64, f64)) -> Self {
+        self.config.resize_mode = mode;
+        self.config.pad_value = pad_value;
+        self
+    }
+
+    pub fn score_threshold(mut self, threshold: f32) -> Self {
+        self.config.score_threshold = threshold;
+        self
+    }
+
+    pub fn score_class_threshold(mut self, threshold: f32) -> Self {
+        self.config.score_class_threshold = threshold;
+        self
+    }
+
+    /* Threshold compared with BoundingBox::score (Detector::score_threshold), which --score-threshold sets */
+    /* It is score_class_threshold if the engine uses it, otherwise score_threshold */
+    pub fn detection_score_threshold(mut self, threshold: f32) -> Self {
+        if E::uses_score_class_threshold() {
+            self.config.set_score_class_threshold(threshold);
+        } else {
+            self.config.score_threshold = threshold;
+        }
+        self
+    }
+
+    pub fn nms_iou_threshold(mut self, threshold: f32) -> Self {
+        self.config.nms_iou_threshold = threshold;
+        self
+    }
+
+    pub fn nms_mode(mut self, mode: NmsMode) -> Self {
+        self.config.nms_mode = mode;
+        self
+    }
+
+    pub fn nms_method(mut self, method: NmsMethod) -> Self {
+        self.config.nms_method = method;
+        self
+    }
+
+    pub fn max_detections(mut self, num: usize) -> Self {
+        self.config.max_detections = Some(num);
+        self
+    }
+
+    pub fn prior_box(mut self, prior_box: PriorBoxConfig) -> Self {
+        self.config.prior_box = Some(prior_box);
+        self
+    }
+
+    pub fn config(&self) -> &ModelConfig {
+        &self.config
+    }
+
+    pub fn build(self) -> Result<E> {
+        self.config.validate()?;
+        self.config.check_unchanged(&E::default_config(), E::unused_keys())?;
+        E::from_config(self.config)
+    }
+}
+
+impl<E: FromModelConfig> Default for EngineBuilder<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_config() -> ModelConfig {
+        ModelConfig {
+            model_filename: "model.onnx".to_string(),
+            label_filename: Some("label.txt".to_string()),
+            input_width: 416,
+            input_height: 416,
+            input_name: "images".to_string(),
+            output_names: vec!["output".to_string()],
+            normalize_mean: (0.0, 0.0, 0.0),
+            normalize_norm: (1.0, 1.0, 1.0),
+            resize_mode: ResizeMode::Letterbox,
+            pad_value: (114.0, 114.0, 114.0),
+            score_threshold: 0.4,
+            score_class_threshold: 0.2,
+            nms_iou_threshold: 0.6,
+            nms_mode: NmsMode::ClassAware,
+            nms_method: NmsMethod::Hard,
+            soft_nms_sigma: 0.5,
+            max_detections: None,
+            prior_box: None,
+        }
+    }
+
+    struct DummyEngine {
+        config: ModelConfig,
+    }
+
+    impl FromModelConfig for DummyEngine {
+        fn default_config() -> ModelConfig {
+            default_config()
+        }
+
+        fn from_config(config: ModelConfig) -> Result<Self> {
+            Ok(DummyEngine { config: config })
+        }
+    }
+
+    /* An engine which has box confidence and class confidence separately (e.g. YOLOX) */
+    struct DummyTwoStageEngine {
+        config: ModelConfig,
+    }
+
+    impl FromModelConfig for DummyTwoStageEngine {
+        fn default_config() -> ModelConfig {
+            default_config()
+        }
+
+        fn uses_score_class_threshold() -> bool {
+            true
+        }
+
+        fn from_config(config: ModelConfig) -> Result<Self> {
+            Ok(DummyTwoStageEngine { config: config })
+        }
+    }
+
+    /* An engine without NMS (e.g. classifier) */
+    struct DummyClassifier;
+
+    impl FromModelConfig for DummyClassifier {
+        fn default_config() -> ModelConfig {
+            default_config()
+        }
+
+        fn unused_keys() -> &'static [&'static str] {
+            &["nms_iou_threshold", "nms_method", "max_detections"]
+        }
+
+        fn from_config(_config: ModelConfig) -> Result<Self> {
+            Ok(DummyClassifier)
+        }
+    }
+
+    fn temp_file(name: &str, text: &str) -> String {
+        let filename = std::env::temp_dir().join(format!("vision_core_config_{}_{}", std::process::id(), name)).to_string_lossy().to_string();
+        std::fs::write(&filename, text).unwrap();
+        filename
+    }
+
+    #[test]
+    fn merge_partial() {
+        let config = ModelConfig::from_toml_str("score_threshold = 0.5\ninput_width = 640\n", default_config()).unwrap();
+        assert_eq!(config.score_threshold, 0.5);
+        assert_eq!(config.input_width, 640);
+        assert_eq!(config.input_height, 416);
+        assert_eq!(config.model_filename, "model.onnx");
+
+        let config = ModelConfig::from_json_str(r#"{"nms_mode": "class_agnostic", "max_detections": 10}"#, default_config()).unwrap();
+        assert_eq!(config.nms_mode, NmsMode::ClassAgnostic);
+        assert_eq!(config.max_detections, Some(10));
+        assert_eq!(config.score_threshold, 0.4);
+    }
+
+    #[test]
+    fn reject_unknown_key() {
+        assert!(matches!(ModelConfig::from_toml_str("score_treshold = 0.5", default_config()), Err(Error::Config(_))));
+        assert!(matches!(ModelConfig::from_json_str(r#"{"score_treshold": 0.5}"#, default_config()), Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn dispatch_by_extension() {
+        let filename_json = temp_file("a.json", r#"{"input_height": 320}"#);
+        let filename_toml = temp_file("b.toml", "input_height = 288");
+        assert_eq!(ModelConfig::from_file(&filename_json, default_config()).unwrap().input_height, 320);
+        assert_eq!(ModelConfig::from_file(&filename_toml, default_config()).unwrap().input_height, 288);
+
+        /* JSON text in a .toml file is parsed as TOML */
+        let filename_wrong = temp_file("c.toml", r#"{"input_height": 320}"#);
+        assert!(matches!(ModelConfig::from_file(&filename_wrong, default_config()), Err(Error::Config(_))));
+        for filename in [filename_json, filename_toml, filename_wrong].iter() {
+            std::fs::remove_file(filename).unwrap();
+        }
+    }
+
+    #[test]
+    fn builder_override() {
+        let engine = EngineBuilder::<DummyEngine>::new()
+            .model_filename("other.onnx")
+            .input_size(320, 240)
+            .score_threshold(0.3)
+            .nms_method(NmsMethod::SoftGaussian)
+            .max_detections(5)
+            .build()
+            .unwrap();
+        assert_eq!(engine.config.model_filename, "other.onnx");
+        assert_eq!((engine.config.input_width, engine.config.input_height), (320, 240));
+        assert_eq!(engine.config.score_threshold, 0.3);
+        assert_eq!(engine.config.nms_method, NmsMethod::SoftGaussian);
+        assert_eq!(engine.config.max_detections, Some(5));
+        assert_eq!(engine.config.nms_iou_threshold, 0.6);
+
+        assert!(matches!(EngineBuilder::<DummyEngine>::new().score_threshold(1.5).build(), Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn detection_score_threshold() {
+        let engine = EngineBuilder::<DummyEngine>::new().detection_score_threshold(0.3).build().unwrap();
+        assert_eq!((engine.config.score_threshold, engine.config.score_class_threshold), (0.3, 0.2));
+
+        /* The box confidence threshold (0.4) is lowered not to drop boxes whose score >= 0.3 */
+        let engine = EngineBuilder::<DummyTwoStageEngine>::new().detection_score_threshold(0.3).build().unwrap();
+        assert_eq!((engine.config.score_threshold, engine.config.score_class_threshold), (0.3, 0.3));
+        let engine = EngineBuilder::<DummyTwoStageEngine>::new().detection_score_threshold(0.5).build().unwrap();
+        assert_eq!((engine.config.score_threshold, engine.config.score_class_threshold), (0.4, 0.5));
+    }
+
+    #[test]
+    fn reject_unused_key() {
+        assert!(EngineBuilder::<DummyClassifier>::new().score_threshold(0.3).build().is_ok());
+        assert!(matches!(EngineBuilder::<DummyClassifier>::new().nms_iou_threshold(0.3).build(), Err(Error::Config(_))));
+        assert!(matches!(EngineBuilder::<DummyClassifier>::new().max_detections(5).build(), Err(Error::Config(_))));
+        /* The same value as the default is not an error */
+        assert!(EngineBuilder::<DummyClassifier>::new().nms_method(NmsMethod::Hard).build().is_ok());
+    }
+
+    #[test]
+    fn reject_invalid_value() {
+        for text in ["input_width = 0", "input_height = -1", "score_threshold = 1.1", "score_class_threshold = -0.1",
+            "nms_iou_threshold = 2.0", "output_names = []", "soft_nms_sigma = 0.0", "soft_nms_sigma = -0.5"].iter() {
+            assert!(matches!(ModelConfig::from_toml_str(text, default_config()), Err(Error::Config(_))), "{}", text);
+        }
+        assert!(default_config().validate().is_ok());
+    }
+}
@@ -0,0 +1,216 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::core;
+
+use crate::bounding_box::BoundingBox;
+use crate::config::ModelConfig;
+use crate::error::Result;
+
+
+/////////////////////////////////////////////////////////////////
+/* Common interface of DetectionEngine in each pj_dnn_det_* project */
+/* Engines can be held as Box<dyn Detector> and chosen at runtime */
+pub trait Detector {
+    /* Run detection. Coordinates of the result are in the original image (mat) */
+    fn process(&mut self, mat: &core::Mat) -> Result<Vec::<BoundingBox>>;
+
+    /* Input size of the model */
+    fn input_size(&self) -> core::Size;
+
+    /* Labels indexed by BoundingBox::class_id */
+    fn label_list(&self) -> &[String];
+
+    fn model_name(&self) -> &str;
+
+    /* Threshold compared with BoundingBox::score. Boxes whose score is lower than this are not output */
+    /* This is the value --score-threshold sets. In engines which have box confidence and class confidence separately (e.g. YOLOX), it is score_class_threshold of ModelConfig */
+    /* (see FromModelConfig::uses_score_class_threshold) and the box confidence threshold is only lowered not to drop boxes whose score >= this */
+    fn score_threshold(&self) -> f32;
+
+    fn set_score_threshold(&mut self, threshold: f32);
+
+    /* Same as process, but with score_threshold instead of the current one */
+    fn process_with_score_threshold(&mut self, mat: &core::Mat, score_threshold: f32) -> Result<Vec::<BoundingBox>> {
+        let score_threshold_org = self.score_threshold();
+        self.set_score_threshold(score_threshold);
+        let result = self.process(mat);
+        self.set_score_threshold(score_threshold_org);
+        result
+    }
+
+    /* (high score boxes, low score boxes) for ByteTrack */
+    /* High: score >= score_threshold. Low: low_score_threshold <= score < score_threshold. NMS is done with both sets together */
+    fn process_high_low(&mut self, mat: &core::Mat, low_score_threshold: f32) -> Result<(Vec::<BoundingBox>, Vec::<BoundingBox>)> {
+        let score_threshold = self.score_threshold();
+        let bbox_list = self.process_with_score_threshold(mat, low_score_threshold.min(score_threshold))?;
+        Ok(bbox_list.into_iter().partition(|bbox| bbox.score >= score_threshold))
+    }
+}
+
+
+/* process_with_score_threshold of engines which compare BoundingBox::score with score_class_threshold (e.g. YOLOX, YOLOv5) */
+/* config_of returns ModelConfig of the engine. The thresholds are restored after processing */
+pub fn process_with_score_class_threshold<D: Detector>(detector: &mut D, config_of: fn(&mut D) -> &mut ModelConfig, mat: &core::Mat, score_threshold: f32) -> Result<Vec::<BoundingBox>> {
+    let config = config_of(detector);
+    let threshold_org = (config.score_threshold, config.score_class_threshold);
+    config.set_score_class_threshold(score_threshold);
+    let result = detector.process(mat);
+    let config = config_of(detector);
+    config.score_threshold = threshold_org.0;
+    config.score_class_threshold = threshold_org.1;
+    result
+}
+
+
+/* Common interface of re-identification engines (e.g. pj_dnn_reid_osnet) used by DeepSORT */
+pub trait EmbeddingExtractor {
+    /* Embedding of the crop of each box. Empty for a box which can't be cropped (e.g. out of the image) */
+    fn extract(&mut self, mat: &core::Mat, bbox_list: &[BoundingBox]) -> Result<Vec::<Vec::<f32>>>;
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bounding_box::{NmsMethod, NmsMode};
+    use crate::preprocess::ResizeMode;
+
+    /* Returns fixed boxes filtered by the threshold */
+    struct FixedDetector {
+        bbox_list: Vec::<BoundingBox>,
+        score_threshold: f32,
+    }
+
+    impl Detector for FixedDetector {
+        fn process(&mut self, _mat: &core::Mat) -> Result<Vec::<BoundingBox>> {
+            Ok(self.bbox_list.iter().filter(|bbox| bbox.score >= self.score_threshold).cloned().collect())
+        }
+
+        fn input_size(&self) -> core::Size {
+            core::Size::new(0, 0)
+        }
+
+        fn label_list(&self) -> &[String] {
+            &[]
+        }
+
+        fn model_name(&self) -> &str {
+            "fixed"
+        }
+
+        fn score_threshold(&self) -> f32 {
+            self.score_threshold
+        }
+
+        fn set_score_threshold(&mut self, threshold: f32) {
+            self.score_threshold = threshold;
+        }
+    }
+
+    /* Returns fixed boxes filtered by box confidence (score_threshold) and score (score_class_threshold) like YOLOX */
+    struct TwoStageDetector {
+        box_list: Vec::<(f32, BoundingBox)>,    /* (box confidence, box) */
+        config: ModelConfig,
+    }
+
+    impl Detector for TwoStageDetector {
+        fn process(&mut self, _mat: &core::Mat) -> Result<Vec::<BoundingBox>> {
+            Ok(self.box_list.iter()
+                .filter(|(box_confidence, bbox)| *box_confidence >= self.config.score_threshold && bbox.score >= self.config.score_class_threshold)
+                .map(|(_, bbox)| bbox.clone())
+                .collect())
+        }
+
+        fn input_size(&self) -> core::Size {
+            core::Size::new(0, 0)
+        }
+
+        fn label_list(&self) -> &[String] {
+            &[]
+        }
+
+        fn model_name(&self) -> &str {
+            "two stage"
+        }
+
+        fn score_threshold(&self) -> f32 {
+            self.config.score_class_threshold
+        }
+
+        fn set_score_threshold(&mut self, threshold: f32) {
+            self.config.score_class_threshold = threshold;
+        }
+
+        fn process_with_score_threshold(&mut self, mat: &core::Mat, score_threshold: f32) -> Result<Vec::<BoundingBox>> {
+            process_with_score_class_threshold(self, |detector| &mut detector.config, mat, score_threshold)
+        }
+    }
+
+    fn bbox(score: f32) -> BoundingBox {
+        BoundingBox { class_id: 0, label: "".to_string(), score: score, x: 0.0, y: 0.0, w: 1.0, h: 1.0 }
+    }
+
+    #[test]
+    fn high_and_low_score_boxes() {
+        let bbox_list = [0.9, 0.5, 0.3, 0.05].iter().map(|score| bbox(*score)).collect();
+        let mut detector = FixedDetector { bbox_list: bbox_list, score_threshold: 0.5 };
+        let mat = core::Mat::default();
+        assert_eq!(detector.process(&mat).unwrap().len(), 2);
+
+        let (bbox_high_list, bbox_low_list) = detector.process_high_low(&mat, 0.1).unwrap();
+        assert_eq!(bbox_high_list.iter().map(|bbox| bbox.score).collect::<Vec::<_>>(), vec![0.9, 0.5]);
+        assert_eq!(bbox_low_list.iter().map(|bbox| bbox.score).collect::<Vec::<_>>(), vec![0.3]);
+
+        /* The threshold is restored */
+        assert_eq!(detector.score_threshold(), 0.5);
+    }
+
+    #[test]
+    fn high_and_low_score_boxes_with_class_threshold() {
+        /* score_threshold (box confidence) 0.4, score_class_threshold 0.5 */
+        let config = ModelConfig {
+            model_filename: "model.onnx".to_string(),
+            label_filename: None,
+            input_width: 416,
+            input_height: 416,
+            input_name: "images".to_string(),
+            output_names: vec!["output".to_string()],
+            normalize_mean: (0.0, 0.0, 0.0),
+            normalize_norm: (1.0, 1.0, 1.0),
+            resize_mode: ResizeMode::Letterbox,
+            pad_value: (114.0, 114.0, 114.0),
+            score_threshold: 0.4,
+            score_class_threshold: 0.5,
+            nms_iou_threshold: 0.6,
+            nms_mode: NmsMode::ClassAware,
+            nms_method: NmsMethod::Hard,
+            soft_nms_sigma: 0.5,
+            max_detections: None,
+            prior_box: None,
+        };
+        let box_list = vec![(0.9, bbox(0.8)), (0.3, bbox(0.6)), (0.3, bbox(0.2)), (0.05, bbox(0.2))];
+        let mut detector = TwoStageDetector { box_list: box_list, config: config };
+        let mat = core::Mat::default();
+        assert_eq!(detector.process(&mat).unwrap().len(), 1);
+
+        /* High is split by the score (not by box confidence), and low boxes are not dropped by box confidence */
+        let (bbox_high_list, bbox_low_list) = detector.process_high_low(&mat, 0.1).unwrap();
+        assert_eq!(bbox_high_list.iter().map(|bbox| bbox.score).collect::<Vec::<_>>(), vec![0.8, 0.6]);
+        assert_eq!(bbox_low_list.iter().map(|bbox| bbox.score).collect::<Vec::<_>>(), vec![0.2]);
+
+        /* The thresholds are restored */
+        assert_eq!((detector.config.score_threshold, detector.config.score_class_threshold), (0.4, 0.5));
+    }
+}
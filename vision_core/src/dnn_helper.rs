@@ -0,0 +1,85 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::{prelude::*, core, dnn};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use crate::error::{Error, Result};
+
+
+/////////////////////////////////////////////////////////////////
+pub fn read_net(model_filename: &str) -> Result<dnn::Net> {
+    let load = || -> opencv::Result<dnn::Net> {
+        let mut net = dnn::read_net_from_onnx(model_filename)?;
+        net.set_preferable_backend(dnn::DNN_BACKEND_OPENCV)?;
+        net.set_preferable_target(dnn::DNN_TARGET_CPU)?;
+        // net.set_preferable_target(dnn::DNN_TARGET_OPENCL)?;
+        Ok(net)
+    };
+    load().map_err(|e| Error::ModelLoad { filename: model_filename.to_string(), source: e })
+}
+
+/* One label per line. Fails if the file has less than min_num labels */
+pub fn read_label_file(label_filename: &str, min_num: usize) -> Result<Vec::<String>> {
+    let to_error = |e: std::io::Error| Error::LabelLoad { filename: label_filename.to_string(), source: e };
+
+    let mut label_list = Vec::<String>::new();
+    let label_file = File::open(label_filename).map_err(to_error)?;
+    let reader = BufReader::new(label_file);
+    for line in reader.lines() {
+        label_list.push(line.map_err(to_error)?);
+    }
+
+    if label_list.len() < min_num {
+        let message = format!("{} labels are required, but only {} found", min_num, label_list.len());
+        return Err(to_error(std::io::Error::new(std::io::ErrorKind::InvalidData, message)));
+    }
+    Ok(label_list)
+}
+
+/* Convert to CV_32FC3 in [0.0, 1.0], then (value - mean) / norm for each channel */
+pub fn normalize(mat: &core::Mat, mean: &core::Scalar, norm: &core::Scalar) -> Result<core::Mat> {
+    let mut mat_normalized = Mat::default();
+    let mut mat_normalized_sub = Mat::default();
+    let mut mat_normalized_div = Mat::default();
+    mat.convert_to(&mut mat_normalized, core::CV_32FC3, 1.0 / 255.0, 0.0).map_err(Error::Preprocess)?;
+    core::subtract(&mat_normalized, mean, &mut mat_normalized_sub, &core::no_array(), -1).map_err(Error::Preprocess)?;
+    core::divide2(&mat_normalized_sub, norm, &mut mat_normalized_div, 1.0, -1).map_err(Error::Preprocess)?;
+    Ok(mat_normalized_div)
+}
+
+/* Output names in the form which Net::forward takes */
+pub fn output_blob_names(name_list: &[String]) -> core::Vector::<String> {
+    let mut out_blob_names = core::Vector::<String>::new();
+    for name in name_list.iter() {
+        out_blob_names.push(name);
+    }
+    out_blob_names
+}
+
+/* Copy output tensor (CV_32F) to vector, after checking the number of elements */
+pub fn output_to_vec(mat: &core::Mat, name: &str, expected: usize) -> Result<Vec::<f32>> {
+    let actual = mat.total();
+    if actual != expected {
+        return Err(Error::OutputShape { name: name.to_string(), expected: expected, actual: actual });
+    }
+    let ptr = mat.ptr(0).map_err(Error::Inference)?;
+    let data = unsafe {std::slice::from_raw_parts(ptr as *const f32, actual).to_vec()};
+    Ok(data)
+}
+
+/* Shape of output tensor (e.g. [1, 25200, 85]) */
+pub fn output_shape(mat: &core::Mat) -> Vec::<usize> {
+    mat.mat_size().iter().map(|v| *v as usize).collect()
+}
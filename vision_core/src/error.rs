@@ -0,0 +1,68 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use std::fmt;
+
+
+/////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+pub enum Error {
+    /* Failed to read the model file or to set up dnn::Net */
+    ModelLoad { filename: String, source: opencv::Error },
+    /* Failed to read the label file, or it doesn't have enough labels */
+    LabelLoad { filename: String, source: std::io::Error },
+    /* Failed in resize, normalize, blob creation, etc. (e.g. empty or malformed frame) */
+    Preprocess(opencv::Error),
+    /* Failed in set_input, forward, or in retrieving the output */
+    Inference(opencv::Error),
+    /* Failed in decoding the output into images (e.g. masks), or in drawing the result */
+    Postprocess(opencv::Error),
+    /* The output tensor doesn't have the number of elements the decoder expects */
+    OutputShape { name: String, expected: usize, actual: usize },
+    /* Failed to read the config file, or the config has invalid values */
+    Config(String),
+    /* Failed to read or write a NumPy file (.npy, .npz), or it has unsupported format */
+    Npy { filename: String, message: String },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ModelLoad { filename, source } => write!(f, "failed to load model ({}): {}", filename, source),
+            Error::LabelLoad { filename, source } => write!(f, "failed to load label ({}): {}", filename, source),
+            Error::Preprocess(source) => write!(f, "failed in preprocessing: {}", source),
+            Error::Inference(source) => write!(f, "failed in inference: {}", source),
+            Error::Postprocess(source) => write!(f, "failed in postprocessing: {}", source),
+            Error::OutputShape { name, expected, actual } => write!(f, "unexpected output shape ({}): expected {} elements, but got {}", name, expected, actual),
+            Error::Config(message) => write!(f, "invalid config: {}", message),
+            Error::Npy { filename, message } => write!(f, "failed in npy ({}): {}", filename, message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::ModelLoad { source, .. } => Some(source),
+            Error::LabelLoad { source, .. } => Some(source),
+            Error::Preprocess(source) => Some(source),
+            Error::Inference(source) => Some(source),
+            Error::Postprocess(source) => Some(source),
+            Error::OutputShape { .. } => None,
+            Error::Config(_) => None,
+            Error::Npy { .. } => None,
+        }
+    }
+}
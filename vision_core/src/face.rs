@@ -0,0 +1,130 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::{core, imgproc};
+
+use crate::bounding_box::BoundingBox;
+use crate::error::{Error, Result};
+use crate::pose::Keypoint;
+
+
+/////////////////////////////////////////////////////////////////
+/* Detection result of face detection engines. landmark_list is in the order of FACE_LANDMARK_NAME_LIST */
+#[derive(Debug, Clone, PartialEq)]
+pub struct FaceDetection {
+    pub bbox: BoundingBox,
+    pub landmark_list: Vec::<Keypoint>,
+}
+
+impl FaceDetection {
+    pub fn landmark(&self, name: &str) -> Option<&Keypoint> {
+        FACE_LANDMARK_NAME_LIST.iter().position(|n| *n == name).and_then(|index| self.landmark_list.get(index))
+    }
+}
+
+/* Five landmarks of RetinaFace / YuNet. left and right are as seen in the image (not of the person) */
+pub const FACE_LANDMARK_NAME_LIST: [&str; 5] = [
+    "left_eye", "right_eye", "nose", "left_mouth", "right_mouth",
+];
+
+/* Position of the five landmarks in 112x112 aligned face of ArcFace (InsightFace) */
+/* Reference: https://github.com/deepinsight/insightface/blob/master/python-package/insightface/utils/face_align.py */
+pub const ALIGNED_FACE_SIZE: (i32, i32) = (112, 112);
+pub const ALIGNED_FACE_LANDMARK_LIST: [(f32, f32); 5] = [
+    (38.2946, 51.6963), (73.5318, 51.5014), (56.0252, 71.7366), (41.5493, 92.3655), (70.7299, 92.2041),
+];
+
+
+/////////////////////////////////////////////////////////////////
+/* Least squares similarity transform (rotation, uniform scale and translation) from src_list to dst_list */
+/* Returns the 2x3 affine matrix, or None if src_list is degenerate (less than 2 points, or all the points are the same) */
+/* Reference: Umeyama, "Least-squares estimation of transformation parameters between two point patterns", 1991 */
+pub fn estimate_similarity_transform(src_list: &[(f32, f32)], dst_list: &[(f32, f32)]) -> Option<[[f64; 3]; 2]> {
+    assert_eq!(src_list.len(), dst_list.len());
+    if src_list.len() < 2 {
+        return None;
+    }
+    let num = src_list.len() as f64;
+    let mean = |point_list: &[(f32, f32)]| {
+        let (sum_x, sum_y) = point_list.iter().fold((0.0, 0.0), |(sum_x, sum_y), (x, y)| (sum_x + *x as f64, sum_y + *y as f64));
+        (sum_x / num, sum_y / num)
+    };
+    let (src_mean_x, src_mean_y) = mean(src_list);
+    let (dst_mean_x, dst_mean_y) = mean(dst_list);
+
+    /* dst = [a -b; b a] * src + t */
+    let mut src_variance = 0.0;
+    let mut sum_a = 0.0;
+    let mut sum_b = 0.0;
+    for ((src_x, src_y), (dst_x, dst_y)) in src_list.iter().zip(dst_list.iter()) {
+        let (sx, sy) = (*src_x as f64 - src_mean_x, *src_y as f64 - src_mean_y);
+        let (dx, dy) = (*dst_x as f64 - dst_mean_x, *dst_y as f64 - dst_mean_y);
+        src_variance += sx * sx + sy * sy;
+        sum_a += sx * dx + sy * dy;
+        sum_b += sx * dy - sy * dx;
+    }
+    if src_variance < 1e-12 {
+        return None;
+    }
+    let a = sum_a / src_variance;
+    let b = sum_b / src_variance;
+    let tx = dst_mean_x - (a * src_mean_x - b * src_mean_y);
+    let ty = dst_mean_y - (b * src_mean_x + a * src_mean_y);
+    Some([[a, -b, tx], [b, a, ty]])
+}
+
+/* Crop the face so that the landmarks fit to ALIGNED_FACE_LANDMARK_LIST (scaled to output_size) */
+/* The result is the input for face recognition models (e.g. ArcFace), and is also useful to blur / anonymize faces */
+pub fn align_face(mat: &core::Mat, landmark_list: &[Keypoint], output_size: core::Size) -> Result<core::Mat> {
+    if landmark_list.len() != ALIGNED_FACE_LANDMARK_LIST.len() {
+        return Err(Error::Config(format!("face alignment requires {} landmarks, but got {}", ALIGNED_FACE_LANDMARK_LIST.len(), landmark_list.len())));
+    }
+    let scale_x = output_size.width as f32 / ALIGNED_FACE_SIZE.0 as f32;
+    let scale_y = output_size.height as f32 / ALIGNED_FACE_SIZE.1 as f32;
+    let dst_list: Vec::<(f32, f32)> = ALIGNED_FACE_LANDMARK_LIST.iter().map(|(x, y)| (x * scale_x, y * scale_y)).collect();
+    let src_list: Vec::<(f32, f32)> = landmark_list.iter().map(|landmark| (landmark.x, landmark.y)).collect();
+    let matrix = estimate_similarity_transform(&src_list, &dst_list)
+        .ok_or_else(|| Error::Config("face alignment failed: landmarks are degenerate".to_string()))?;
+
+    let mat_matrix = core::Mat::from_slice_2d(&[matrix[0].to_vec(), matrix[1].to_vec()]).map_err(Error::Postprocess)?;
+    let mut mat_aligned = core::Mat::default();
+    imgproc::warp_affine(mat, &mut mat_aligned, &mat_matrix, output_size, imgproc::INTER_LINEAR, core::BORDER_CONSTANT, core::Scalar::default()).map_err(Error::Postprocess)?;
+    Ok(mat_aligned)
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn similarity_transform() {
+        /* dst = 2 * rot(90 deg) * src + (10, 20) */
+        let src_list = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (3.0, 2.0)];
+        let dst_list: Vec::<(f32, f32)> = src_list.iter().map(|(x, y)| (-2.0 * y + 10.0, 2.0 * x + 20.0)).collect();
+        let matrix = estimate_similarity_transform(&src_list, &dst_list).unwrap();
+        let expected = [[0.0, -2.0, 10.0], [2.0, 0.0, 20.0]];
+        for (row, expected_row) in matrix.iter().zip(expected.iter()) {
+            for (v, expected_v) in row.iter().zip(expected_row.iter()) {
+                assert!((v - expected_v).abs() < 1e-6, "{:?}", matrix);
+            }
+        }
+
+        /* Template itself is the identity */
+        let matrix = estimate_similarity_transform(&ALIGNED_FACE_LANDMARK_LIST, &ALIGNED_FACE_LANDMARK_LIST).unwrap();
+        assert!((matrix[0][0] - 1.0).abs() < 1e-6 && matrix[0][1].abs() < 1e-6 && matrix[0][2].abs() < 1e-4);
+
+        assert_eq!(estimate_similarity_transform(&[(1.0, 1.0), (1.0, 1.0)], &[(0.0, 0.0), (1.0, 1.0)]), None);
+    }
+}
@@ -0,0 +1,378 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::core;
+use serde::{Deserialize, Serialize};
+
+use crate::bounding_box::BoundingBox;
+
+
+/////////////////////////////////////////////////////////////////
+/* Simple polygon (edges don't cross each other) in pixel. Vertices can be either clockwise or counterclockwise */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Polygon {
+    pub point_list: Vec::<(f32, f32)>,
+}
+
+impl Polygon {
+    pub fn new(point_list: Vec::<(f32, f32)>) -> Self {
+        Polygon {
+            point_list: point_list,
+        }
+    }
+
+    pub fn from_bounding_box(bbox: &BoundingBox) -> Self {
+        let (x0, y0, x1, y1) = bbox.corners();
+        Polygon::new(vec![(x0, y0), (x1, y0), (x1, y1), (x0, y1)])
+    }
+
+    /* Positive when counterclockwise in the y-up coordinate (= clockwise on image) */
+    pub fn signed_area(&self) -> f32 {
+        let num = self.point_list.len();
+        if num < 3 {
+            return 0.0;
+        }
+        let mut area = 0.0;
+        for i in 0..num {
+            let (x0, y0) = self.point_list[i];
+            let (x1, y1) = self.point_list[(i + 1) % num];
+            area += x0 * y1 - x1 * y0;
+        }
+        area / 2.0
+    }
+
+    pub fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+
+    /* Center of mass of the area. The mean of the vertices if the area is 0 (e.g. a line), and (0, 0) if empty */
+    pub fn centroid(&self) -> (f32, f32) {
+        let num = self.point_list.len();
+        if num == 0 {
+            return (0.0, 0.0);
+        }
+        let area = self.signed_area();
+        if area == 0.0 {
+            let (sum_x, sum_y) = self.point_list.iter().fold((0.0, 0.0), |(sum_x, sum_y), (x, y)| (sum_x + x, sum_y + y));
+            return (sum_x / num as f32, sum_y / num as f32);
+        }
+        let (mut sum_x, mut sum_y) = (0.0, 0.0);
+        for i in 0..num {
+            let (x0, y0) = self.point_list[i];
+            let (x1, y1) = self.point_list[(i + 1) % num];
+            let cross = x0 * y1 - x1 * y0;
+            sum_x += (x0 + x1) * cross;
+            sum_y += (y0 + y1) * cross;
+        }
+        (sum_x / (6.0 * area), sum_y / (6.0 * area))
+    }
+
+    /* (x0, y0, x1, y1) */
+    pub fn bounds(&self) -> (f32, f32, f32, f32) {
+        self.point_list.iter().fold((f32::MAX, f32::MAX, f32::MIN, f32::MIN), |(x0, y0, x1, y1), (x, y)| (x0.min(*x), y0.min(*y), x1.max(*x), y1.max(*y)))
+    }
+
+    pub fn is_convex(&self) -> bool {
+        let num = self.point_list.len();
+        if num < 3 {
+            return false;
+        }
+        let mut sign = 0.0;
+        for i in 0..num {
+            let cross = cross(self.point_list[i], self.point_list[(i + 1) % num], self.point_list[(i + 2) % num]);
+            if cross != 0.0 {
+                if sign * cross < 0.0 {
+                    return false;
+                }
+                sign = cross;
+            }
+        }
+        true
+    }
+
+    /* Even-odd rule. A point exactly on an edge may be either inside or outside */
+    pub fn contains_point(&self, x: f32, y: f32) -> bool {
+        let num = self.point_list.len();
+        let mut is_inside = false;
+        let mut j = num.wrapping_sub(1);
+        for i in 0..num {
+            let (xi, yi) = self.point_list[i];
+            let (xj, yj) = self.point_list[j];
+            if (yi > y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi {
+                is_inside = !is_inside;
+            }
+            j = i;
+        }
+        is_inside
+    }
+
+    /* All vertices of other are inside, and no edges cross */
+    pub fn contains(&self, other: &Polygon) -> bool {
+        if other.point_list.is_empty() || !other.point_list.iter().all(|(x, y)| self.contains_point(*x, *y)) {
+            return false;
+        }
+        !self.edges().any(|(a0, a1)| other.edges().any(|(b0, b1)| segments_cross(a0, a1, b0, b1)))
+    }
+
+    pub fn intersection_area(&self, other: &Polygon) -> f32 {
+        /* Split self into triangles (convex), then clip other by each of them */
+        self.triangulate().iter()
+            .map(|triangle| Polygon::new(clip_by_convex(&other.point_list, triangle)).area())
+            .sum()
+    }
+
+    pub fn iou(&self, other: &Polygon) -> f32 {
+        let area_inter = self.intersection_area(other);
+        let area_sum = self.area() + other.area() - area_inter;
+        if area_sum <= 0.0 {
+            return 0.0;
+        }
+        (area_inter / area_sum).min(1.0)
+    }
+
+    /* Integer points for drawing */
+    pub fn to_cv_points(&self) -> core::Vector::<core::Point> {
+        self.point_list.iter().map(|(x, y)| core::Point::new(x.round() as i32, y.round() as i32)).collect()
+    }
+
+    fn edges(&self) -> impl Iterator<Item = ((f32, f32), (f32, f32))> + '_ {
+        let num = self.point_list.len();
+        (0..num).map(move |i| (self.point_list[i], self.point_list[(i + 1) % num]))
+    }
+
+    /* Ear clipping. Each triangle is counterclockwise (in the y-up coordinate) */
+    fn triangulate(&self) -> Vec::<[(f32, f32); 3]> {
+        let mut point_list = self.point_list.clone();
+        if self.signed_area() < 0.0 {
+            point_list.reverse();
+        }
+        let mut triangle_list = Vec::<[(f32, f32); 3]>::new();
+        while point_list.len() > 3 {
+            let num = point_list.len();
+            let ear = (0..num).find(|i| {
+                let (p0, p1, p2) = (point_list[(i + num - 1) % num], point_list[*i], point_list[(i + 1) % num]);
+                cross(p0, p1, p2) > 0.0 && !point_list.iter().any(|p| *p != p0 && *p != p1 && *p != p2 && is_in_triangle(*p, p0, p1, p2))
+            });
+            /* No ear is found only for degenerate (e.g. self-intersecting) polygons. Remove a vertex to go ahead */
+            let i = ear.unwrap_or(0);
+            if ear.is_some() {
+                triangle_list.push([point_list[(i + num - 1) % num], point_list[i], point_list[(i + 1) % num]]);
+            }
+            point_list.remove(i);
+        }
+        if point_list.len() == 3 && cross(point_list[0], point_list[1], point_list[2]) > 0.0 {
+            triangle_list.push([point_list[0], point_list[1], point_list[2]]);
+        }
+        triangle_list
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+/* Oriented box. angle is in degree, clockwise on image (the same as cv::RotatedRect) */
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RotatedRect {
+    pub cx: f32,
+    pub cy: f32,
+    pub w: f32,
+    pub h: f32,
+    pub angle: f32,
+}
+
+impl RotatedRect {
+    pub fn new(cx: f32, cy: f32, w: f32, h: f32, angle: f32) -> Self {
+        RotatedRect { cx: cx, cy: cy, w: w, h: h, angle: angle }
+    }
+
+    pub fn from_bounding_box(bbox: &BoundingBox) -> Self {
+        let (cx, cy) = bbox.center();
+        RotatedRect::new(cx, cy, bbox.w, bbox.h, 0.0)
+    }
+
+    /* top left, top right, bottom right, bottom left (before rotation) */
+    pub fn corners(&self) -> [(f32, f32); 4] {
+        let (sin, cos) = self.angle.to_radians().sin_cos();
+        let rotate = |dx: f32, dy: f32| (self.cx + dx * cos - dy * sin, self.cy + dx * sin + dy * cos);
+        let (half_w, half_h) = (self.w / 2.0, self.h / 2.0);
+        [rotate(-half_w, -half_h), rotate(half_w, -half_h), rotate(half_w, half_h), rotate(-half_w, half_h)]
+    }
+
+    pub fn to_polygon(&self) -> Polygon {
+        Polygon::new(self.corners().to_vec())
+    }
+
+    pub fn area(&self) -> f32 {
+        self.w.max(0.0) * self.h.max(0.0)
+    }
+
+    /* Axis aligned box which encloses the rotated rect */
+    pub fn bounds(&self) -> (f32, f32, f32, f32) {
+        self.to_polygon().bounds()
+    }
+
+    pub fn contains_point(&self, x: f32, y: f32) -> bool {
+        let (sin, cos) = self.angle.to_radians().sin_cos();
+        let (dx, dy) = (x - self.cx, y - self.cy);
+        let (local_x, local_y) = (dx * cos + dy * sin, -dx * sin + dy * cos);
+        local_x.abs() <= self.w / 2.0 && local_y.abs() <= self.h / 2.0
+    }
+
+    pub fn iou(&self, other: &RotatedRect) -> f32 {
+        self.to_polygon().iou(&other.to_polygon())
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+/* > 0 when p0 -> p1 -> p2 turns counterclockwise (in the y-up coordinate) */
+fn cross(p0: (f32, f32), p1: (f32, f32), p2: (f32, f32)) -> f32 {
+    (p1.0 - p0.0) * (p2.1 - p0.1) - (p1.1 - p0.1) * (p2.0 - p0.0)
+}
+
+fn is_in_triangle(p: (f32, f32), p0: (f32, f32), p1: (f32, f32), p2: (f32, f32)) -> bool {
+    cross(p0, p1, p) >= 0.0 && cross(p1, p2, p) >= 0.0 && cross(p2, p0, p) >= 0.0
+}
+
+/* Proper crossing only (touching at an end point is not a crossing) */
+fn segments_cross(a0: (f32, f32), a1: (f32, f32), b0: (f32, f32), b1: (f32, f32)) -> bool {
+    let d0 = cross(a0, a1, b0);
+    let d1 = cross(a0, a1, b1);
+    let d2 = cross(b0, b1, a0);
+    let d3 = cross(b0, b1, a1);
+    d0 * d1 < 0.0 && d2 * d3 < 0.0
+}
+
+/* Sutherland-Hodgman. clip must be convex and counterclockwise (in the y-up coordinate) */
+fn clip_by_convex(subject: &[(f32, f32)], clip: &[(f32, f32); 3]) -> Vec::<(f32, f32)> {
+    let mut output = subject.to_vec();
+    for i in 0..clip.len() {
+        if output.is_empty() {
+            break;
+        }
+        let (c0, c1) = (clip[i], clip[(i + 1) % clip.len()]);
+        let input = std::mem::take(&mut output);
+        for j in 0..input.len() {
+            let current = input[j];
+            let previous = input[(j + input.len() - 1) % input.len()];
+            let is_current_inside = cross(c0, c1, current) >= 0.0;
+            let is_previous_inside = cross(c0, c1, previous) >= 0.0;
+            if is_current_inside != is_previous_inside {
+                output.push(line_intersection(previous, current, c0, c1));
+            }
+            if is_current_inside {
+                output.push(current);
+            }
+        }
+    }
+    output
+}
+
+/* Intersection of segment p0-p1 and line c0-c1 (they are known to intersect) */
+fn line_intersection(p0: (f32, f32), p1: (f32, f32), c0: (f32, f32), c1: (f32, f32)) -> (f32, f32) {
+    let d0 = cross(c0, c1, p0);
+    let d1 = cross(c0, c1, p1);
+    let t = d0 / (d0 - d1);
+    (p0.0 + (p1.0 - p0.0) * t, p0.1 + (p1.1 - p0.1) * t)
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x: f32, y: f32, size: f32) -> Polygon {
+        Polygon::new(vec![(x, y), (x + size, y), (x + size, y + size), (x, y + size)])
+    }
+
+    fn assert_near(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn polygon_area_and_point() {
+        /* L shape (concave) */
+        let l_shape = Polygon::new(vec![(0.0, 0.0), (10.0, 0.0), (10.0, 5.0), (5.0, 5.0), (5.0, 10.0), (0.0, 10.0)]);
+        assert_near(l_shape.area(), 75.0);
+        assert!(!l_shape.is_convex());
+        assert!(square(0.0, 0.0, 1.0).is_convex());
+        assert!(l_shape.contains_point(2.0, 8.0));
+        assert!(l_shape.contains_point(8.0, 2.0));
+        assert!(!l_shape.contains_point(8.0, 8.0));
+        assert!(!l_shape.contains_point(-1.0, 2.0));
+        assert_eq!(l_shape.bounds(), (0.0, 0.0, 10.0, 10.0));
+    }
+
+    #[test]
+    fn polygon_centroid() {
+        /* L shape = 10x5 rect (center (5, 2.5)) + 5x5 rect (center (2.5, 7.5)), not the mean of the vertices (5, 5) */
+        let l_shape = Polygon::new(vec![(0.0, 0.0), (10.0, 0.0), (10.0, 5.0), (5.0, 5.0), (5.0, 10.0), (0.0, 10.0)]);
+        let (x, y) = l_shape.centroid();
+        assert_near(x, 312.5 / 75.0);
+        assert_near(y, 312.5 / 75.0);
+
+        /* The same for the opposite direction */
+        let (x, y) = Polygon::new(l_shape.point_list.iter().rev().cloned().collect()).centroid();
+        assert_near(x, 312.5 / 75.0);
+        assert_near(y, 312.5 / 75.0);
+
+        /* Area is 0 */
+        assert_eq!(Polygon::new(vec![(0.0, 0.0), (4.0, 2.0)]).centroid(), (2.0, 1.0));
+        assert_eq!(Polygon::new(vec![]).centroid(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn polygon_containment() {
+        let l_shape = Polygon::new(vec![(0.0, 0.0), (10.0, 0.0), (10.0, 5.0), (5.0, 5.0), (5.0, 10.0), (0.0, 10.0)]);
+        assert!(l_shape.contains(&square(1.0, 1.0, 3.0)));
+        assert!(!l_shape.contains(&square(4.0, 4.0, 3.0)));
+        /* all vertices are inside, but an edge goes through the notch */
+        assert!(!l_shape.contains(&Polygon::new(vec![(1.0, 9.0), (9.0, 1.0), (9.0, 4.0), (4.0, 9.0)])));
+    }
+
+    #[test]
+    fn polygon_iou() {
+        let a = square(0.0, 0.0, 10.0);
+        assert_near(a.iou(&a), 1.0);
+        assert_near(a.iou(&square(5.0, 0.0, 10.0)), 50.0 / 150.0);
+        assert_near(a.iou(&square(20.0, 0.0, 10.0)), 0.0);
+
+        /* concave x concave, in either order */
+        let l_shape = Polygon::new(vec![(0.0, 0.0), (10.0, 0.0), (10.0, 5.0), (5.0, 5.0), (5.0, 10.0), (0.0, 10.0)]);
+        let mut l_reversed = l_shape.clone();
+        l_reversed.point_list.reverse();
+        assert_near(l_shape.intersection_area(&l_reversed), 75.0);
+        assert_near(l_shape.intersection_area(&square(4.0, 4.0, 2.0)), 3.0);
+        assert_near(square(4.0, 4.0, 2.0).intersection_area(&l_shape), 3.0);
+    }
+
+    #[test]
+    fn rotated_rect() {
+        let rect = RotatedRect::new(0.0, 0.0, 4.0, 2.0, 90.0);
+        let (x0, y0, x1, y1) = rect.bounds();
+        assert_near(x0, -1.0);
+        assert_near(y0, -2.0);
+        assert_near(x1, 1.0);
+        assert_near(y1, 2.0);
+        assert_near(rect.to_polygon().area(), 8.0);
+        assert!(rect.contains_point(0.5, 1.5));
+        assert!(!rect.contains_point(1.5, 0.5));
+
+        /* a square and the same square rotated by 45 degree: intersection is an octagon */
+        let a = RotatedRect::new(0.0, 0.0, 2.0, 2.0, 0.0);
+        let b = RotatedRect::new(0.0, 0.0, 2.0, 2.0, 45.0);
+        let octagon = 8.0 * (2.0f32.sqrt() - 1.0);
+        assert_near(a.iou(&b), octagon / (8.0 - octagon));
+        assert_near(a.iou(&RotatedRect::new(0.0, 0.0, 2.0, 2.0, 90.0)), 1.0);
+    }
+}
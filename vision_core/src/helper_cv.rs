@@ -0,0 +1,265 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::{prelude::*, core, highgui, imgproc};
+use rand::prelude::*;
+
+use crate::analytics::Analytics;
+use crate::geometry::{Polygon, RotatedRect};
+use crate::mask::InstanceDetection;
+use crate::pose::Keypoint;
+
+
+/////////////////////////////////////////////////////////////////
+/* pos = left top */
+pub fn draw_text(mat: &mut core::Mat, text: &str, pos: core::Point, font_scale: f64, thickness: i32, color_front: core::Scalar, color_back: core::Scalar, is_text_on_rect: bool) -> opencv::Result<()> {
+    let mut pos = pos;
+    let mut base_line: i32 = 0;
+    let text_size: core::Size = imgproc::get_text_size(&text, highgui::QT_STYLE_NORMAL, font_scale, thickness, &mut base_line)?;
+    base_line += thickness;
+    pos.y += text_size.height;
+
+    if is_text_on_rect {
+        imgproc::rectangle(mat, core::Rect::new(pos.x, pos.y - text_size.height, text_size.width, base_line + text_size.height), color_back, -1, imgproc::LINE_8, 0)?;
+        imgproc::put_text(mat, &text, pos, highgui::QT_STYLE_NORMAL, font_scale, color_front, thickness, imgproc::LINE_8, false)?;
+    } else {
+        imgproc::put_text(mat, &text, pos, highgui::QT_STYLE_NORMAL, font_scale, color_back, thickness * 3, imgproc::LINE_8, false)?;
+        imgproc::put_text(mat, &text, pos, highgui::QT_STYLE_NORMAL, font_scale, color_front, thickness, imgproc::LINE_8, false)?;
+    }
+    Ok(())
+}
+
+
+/* thickness < 0 means filled */
+pub fn draw_polygon(mat: &mut core::Mat, polygon: &Polygon, color: core::Scalar, thickness: i32) -> opencv::Result<()> {
+    let mut point_list_list = core::Vector::<core::Vector::<core::Point>>::new();
+    point_list_list.push(polygon.to_cv_points());
+    if thickness < 0 {
+        imgproc::fill_poly(mat, &point_list_list, color, imgproc::LINE_8, 0, core::Point::new(0, 0))
+    } else {
+        imgproc::polylines(mat, &point_list_list, true, color, thickness, imgproc::LINE_8, 0)
+    }
+}
+
+
+pub fn draw_rotated_rect(mat: &mut core::Mat, rotated_rect: &RotatedRect, color: core::Scalar, thickness: i32) -> opencv::Result<()> {
+    draw_polygon(mat, &rotated_rect.to_polygon(), color, thickness)
+}
+
+
+/* Alpha-blend the mask of each instance with the color of its class. alpha = 0.0 (transparent) - 1.0 (opaque) */
+pub fn draw_instance_mask_list(mat: &mut core::Mat, detection_list: &[InstanceDetection], color_generator: &ColorGenerator, alpha: f64) -> opencv::Result<()> {
+    for detection in detection_list {
+        /* The mask must be in the image */
+        let mask = match &detection.mask {
+            Some(mask) if mask.rect.width > 0 && mask.rect.height > 0 && mask.rect.x >= 0 && mask.rect.y >= 0
+                && mask.rect.x + mask.rect.width <= mat.cols() && mask.rect.y + mask.rect.height <= mat.rows() => mask,
+            _ => continue,
+        };
+        let mut mat_roi = core::Mat::roi(mat, mask.rect)?;
+        let mat_color = core::Mat::new_size_with_default(core::Size::new(mask.rect.width, mask.rect.height), mat.typ(), color_generator.get(detection.bbox.class_id))?;
+        let mut mat_blended = core::Mat::default();
+        core::add_weighted(&mat_roi, 1.0 - alpha, &mat_color, alpha, 0.0, &mut mat_blended, -1)?;
+        mat_blended.copy_to_masked(&mut mat_roi, &mask.mat)?;
+    }
+    Ok(())
+}
+
+
+/* Alpha-blend the color of each class. class_map is CV_8UC1 of class id, and has the same size as mat */
+pub fn draw_class_map(mat: &mut core::Mat, class_map: &core::Mat, color_generator: &ColorGenerator, alpha: f64) -> opencv::Result<()> {
+    let mut mat_color = core::Mat::new_size_with_default(core::Size::new(mat.cols(), mat.rows()), mat.typ(), core::Scalar::default())?;
+    let mut min_value = 0.0;
+    let mut max_value = 0.0;
+    core::min_max_loc(class_map, Some(&mut min_value), Some(&mut max_value), None, None, &core::no_array())?;
+    for class_id in (min_value as i32) ..= (max_value as i32) {
+        let mut mask = core::Mat::default();
+        core::compare(class_map, &core::Scalar::all(class_id as f64), &mut mask, core::CMP_EQ)?;
+        mat_color.set_to(&color_generator.get(class_id), &mask)?;
+    }
+    let mut mat_blended = core::Mat::default();
+    core::add_weighted(mat, 1.0 - alpha, &mat_color, alpha, 0.0, &mut mat_blended, -1)?;
+    *mat = mat_blended;
+    Ok(())
+}
+
+
+/* Colormapped visualization (e.g. imgproc::COLORMAP_INFERNO) of depth_map (CV_32FC1 in [0.0, 1.0]). The result is CV_8UC3 */
+pub fn colorize_depth_map(depth_map: &core::Mat, colormap: i32) -> opencv::Result<core::Mat> {
+    let mut mat_depth_8u = core::Mat::default();
+    depth_map.convert_to(&mut mat_depth_8u, core::CV_8UC1, 255.0, 0.0)?;
+    let mut mat_color = core::Mat::default();
+    imgproc::apply_color_map(&mat_depth_8u, &mut mat_color, colormap)?;
+    Ok(mat_color)
+}
+
+
+/* Draw a line for each limb (pair of keypoint index) whose both keypoints have score >= score_threshold. Colors are different for each limb */
+pub fn draw_skeleton(mat: &mut core::Mat, keypoint_list: &[Keypoint], skeleton: &[(usize, usize)], score_threshold: f32, color_generator: &ColorGenerator, thickness: i32) -> opencv::Result<()> {
+    for (limb_index, (index0, index1)) in skeleton.iter().enumerate() {
+        if let (Some(keypoint0), Some(keypoint1)) = (keypoint_list.get(*index0), keypoint_list.get(*index1)) {
+            if keypoint0.score >= score_threshold && keypoint1.score >= score_threshold {
+                let point0 = core::Point::new(keypoint0.x.round() as i32, keypoint0.y.round() as i32);
+                let point1 = core::Point::new(keypoint1.x.round() as i32, keypoint1.y.round() as i32);
+                imgproc::line(mat, point0, point1, color_generator.get(limb_index as i32), thickness, imgproc::LINE_AA, 0)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+
+/* Draw a circle for each keypoint whose score >= score_threshold */
+pub fn draw_joints(mat: &mut core::Mat, keypoint_list: &[Keypoint], score_threshold: f32, color: core::Scalar, radius: i32) -> opencv::Result<()> {
+    for keypoint in keypoint_list.iter().filter(|keypoint| keypoint.score >= score_threshold) {
+        let center = core::Point::new(keypoint.x.round() as i32, keypoint.y.round() as i32);
+        imgproc::circle(mat, center, radius, color, -1, imgproc::LINE_AA, 0)?;
+    }
+    Ok(())
+}
+
+
+/* Counting lines (with an arrow to the forward side), zones, and the live counters of each class */
+pub fn draw_analytics(mat: &mut core::Mat, analytics: &Analytics, text_thickness: i32) -> opencv::Result<()> {
+    let color_line = core::Scalar::new(0., 200., 255., 255.);
+    let color_zone = core::Scalar::new(255., 200., 0., 255.);
+    let color_text = core::Scalar::new(255., 255., 255., 255.);
+    let to_point = |(x, y): (f32, f32)| core::Point::new(x.round() as i32, y.round() as i32);
+
+    for counter in analytics.line_counter_list() {
+        let line = &counter.line;
+        imgproc::line(mat, to_point(line.start), to_point(line.end), color_line, 2, imgproc::LINE_AA, 0)?;
+        let (dx, dy) = (line.end.0 - line.start.0, line.end.1 - line.start.1);
+        let length = (dx * dx + dy * dy).sqrt().max(1.0);
+        let middle = ((line.start.0 + line.end.0) / 2.0, (line.start.1 + line.end.1) / 2.0);
+        let arrow_end = (middle.0 - dy / length * 30.0, middle.1 + dx / length * 30.0);
+        imgproc::arrowed_line(mat, to_point(middle), to_point(arrow_end), color_line, 2, imgproc::LINE_AA, 0, 0.3)?;
+        let total = counter.total();
+        draw_text(mat, &format!("{}: {} / {}", line.name, total.forward, total.backward), to_point(line.start), 0.6, text_thickness, color_text, color_line, true)?;
+    }
+
+    for counter in analytics.zone_counter_list() {
+        draw_polygon(mat, &counter.zone.polygon, color_zone, 2)?;
+        let text = match counter.average_dwell_time() {
+            Some(average) => format!("{}: {} (avg {:.1} s)", counter.zone.name, counter.inside_count(), average),
+            None => format!("{}: {}", counter.zone.name, counter.inside_count()),
+        };
+        let (x0, y0, _, _) = counter.zone.polygon.bounds();
+        draw_text(mat, &text, to_point((x0, y0)), 0.6, text_thickness, color_text, color_zone, true)?;
+    }
+
+    /* Counters of each class at the left (below the FPS text) */
+    let mut pos = core::Point::new(0, 40);
+    for counter in analytics.line_counter_list() {
+        for (label, count) in counter.count_by_class.iter() {
+            draw_text(mat, &format!("{} {}: {} / {}", counter.line.name, label, count.forward, count.backward), pos, 0.6, text_thickness, color_text, color_line, true)?;
+            pos.y += 25;
+        }
+    }
+    for counter in analytics.zone_counter_list() {
+        for (label, count) in counter.entry_by_class.iter() {
+            draw_text(mat, &format!("{} {}: {} entered", counter.zone.name, label, count), pos, 0.6, text_thickness, color_text, color_zone, true)?;
+            pos.y += 25;
+        }
+    }
+    Ok(())
+}
+
+
+pub struct ColorGenerator {
+    color_list: Vec::<core::Scalar>,
+}
+
+impl ColorGenerator {
+    pub fn conv_hsl2rgb(h: u32, s: u32, l: u32) -> (u8, u8, u8) {
+        /* https://www.peko-step.com/tool/hslrgb.html */
+        
+        let h = h.min(360).max(0) as f32;   /* [0, 360] */
+        let s = s.min(100).max(0) as f32;   /* [0, 100] */
+        let l = l.min(100).max(0) as f32;   /* [0, 100] */
+        let max;
+        let min;
+        if l < 50.0 {
+            max = 2.55 * (l + l * (s / 100.0));
+            min = 2.55 * (l - l * (s / 100.0));
+        } else {
+            max = 2.55 * (l + (100.0 - l) * (s / 100.0));
+            min = 2.55 * (l - (100.0 - l) * (s / 100.0));
+        }
+        let red;
+        let green;
+        let blue;
+        if h < 60.0 {
+            red = max;
+            green = (h / 60.0) * (max - min) + min;
+            blue = min;
+        } else if h < 120.0 {
+            red = ((120.0 - h) / 60.0) * (max - min) + min;
+            green = max;
+            blue = min;
+        } else if h < 180.0 {
+            red = min;
+            green = max;
+            blue = ((h - 120.0)/ 60.0) * (max - min) + min;
+        } else if h < 240.0 {
+            red = min;
+            green = ((240.0 - h) / 60.0) * (max - min) + min;
+            blue = max;
+        } else if h < 300.0 {
+            red = ((h - 240.0) / 60.0) * (max - min) + min;
+            green = min;
+            blue = max;
+        } else {
+            red = max;
+            green = min;
+            blue = ((360.0- h) / 60.0) * (max - min) + min;
+        }
+        (red as u8, green as u8, blue as u8)
+    }
+
+    pub fn new(num: u32, lightness: u32) -> ColorGenerator {
+        let mut color_list = Vec::<core::Scalar>::new();
+        let mut rng = rand::thread_rng();
+        for i in 0 .. num {
+            /* Use HSL to get nice color */
+            // let h: u32 = (rng.gen_range(0..num) * 360) / num;
+            let h: u32 = 360 * (i + 1) / num;
+            let rgb = Self::conv_hsl2rgb(h, 100, lightness);
+            color_list.push(core::Scalar::new(rgb.2 as f64, rgb.1 as f64, rgb.0 as f64, 255.0));
+        }
+        color_list.shuffle(&mut rng);
+        
+        ColorGenerator{color_list: color_list}
+    }
+
+    /* Any id (including negative) is mapped to a color */
+    pub fn get(&self, id: i32) -> core::Scalar {
+        let index = id.rem_euclid(self.color_list.len() as i32);
+        self.color_list[index as usize]
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_of_negative_id() {
+        let color_generator = ColorGenerator::new(20, 30);
+        assert_eq!(color_generator.get(-1), color_generator.get(19));
+        assert_eq!(color_generator.get(-20), color_generator.get(0));
+        assert_eq!(color_generator.get(25), color_generator.get(5));
+    }
+}
@@ -0,0 +1,29 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+pub mod analytics;
+pub mod bounding_box;
+pub mod cli;
+pub mod config;
+pub mod detector;
+pub mod dnn_helper;
+pub mod error;
+pub mod face;
+pub mod geometry;
+pub mod helper_cv;
+pub mod mask;
+pub mod npy;
+pub mod pose;
+pub mod preprocess;
+pub mod prior_box;
+pub mod runner;
+pub mod tracker;
@@ -0,0 +1,191 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::{prelude::*, core};
+
+use crate::bounding_box::BoundingBox;
+use crate::error::{Error, Result};
+
+
+/////////////////////////////////////////////////////////////////
+/* Binary mask of an instance. mat is CV_8UC1 (0 or 255) whose size is the same as rect (in the original image) */
+pub struct InstanceMask {
+    pub rect: core::Rect,
+    pub mat: core::Mat,
+}
+
+impl InstanceMask {
+    /* data is row major, and has rect.width * rect.height elements */
+    pub fn from_vec(rect: core::Rect, data: &[u8]) -> Result<Self> {
+        let num_element = (rect.width.max(0) * rect.height.max(0)) as usize;
+        if data.len() != num_element {
+            return Err(Error::OutputShape { name: "mask".to_string(), expected: num_element, actual: data.len() });
+        }
+        let mut mat = core::Mat::new_rows_cols_with_default(rect.height, rect.width, core::CV_8UC1, core::Scalar::default()).map_err(Error::Postprocess)?;
+        if !data.is_empty() {
+            let ptr = mat.ptr_mut(0).map_err(Error::Postprocess)?;
+            unsafe {std::ptr::copy_nonoverlapping(data.as_ptr(), ptr, data.len())};
+        }
+        Ok(InstanceMask { rect: rect, mat: mat })
+    }
+}
+
+/* Detection result of instance segmentation engines */
+pub struct InstanceDetection {
+    pub bbox: BoundingBox,
+    pub mask: Option<InstanceMask>,
+}
+
+
+/////////////////////////////////////////////////////////////////
+/* Prototype masks ([num, height, width]) of YOLACT / YOLOv8-seg */
+/* mask of an instance = sigmoid(sum of coefficient[i] * prototype[i]) */
+pub struct PrototypeMask<'a> {
+    data: &'a [f32],
+    num: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<'a> PrototypeMask<'a> {
+    /* data must have num * height * width elements, and the prototype must not be empty */
+    pub fn new(data: &'a [f32], num: usize, width: usize, height: usize) -> Result<Self> {
+        if width == 0 || height == 0 || data.len() != num * width * height {
+            return Err(Error::OutputShape { name: "prototype".to_string(), expected: num * width * height, actual: data.len() });
+        }
+        Ok(PrototypeMask {
+            data: data,
+            num: num,
+            width: width,
+            height: height,
+        })
+    }
+
+    pub fn num(&self) -> usize {
+        self.num
+    }
+
+    /* Probability at each pixel of the prototype in [x0, x1) x [y0, y1) */
+    fn probability_map(&self, coefficient: &[f32], x0: usize, y0: usize, x1: usize, y1: usize) -> Vec::<f32> {
+        let plane_size = self.width * self.height;
+        let mut probability_list = Vec::<f32>::with_capacity((x1 - x0) * (y1 - y0));
+        for y in y0 .. y1 {
+            for x in x0 .. x1 {
+                let offset = y * self.width + x;
+                let logit: f32 = coefficient.iter().enumerate().map(|(i, c)| c * self.data[i * plane_size + offset]).sum();
+                probability_list.push(1.0 / (1.0 + (-logit).exp()));
+            }
+        }
+        probability_list
+    }
+
+    /* Binary mask (0 or 255, row major) of rect in the original image */
+    /* to_prototype converts a point in the original image into the prototype coordinate. Pixels outside of bbox are 0 */
+    pub fn decode(&self, coefficient: &[f32], bbox: &BoundingBox, rect: core::Rect, to_prototype: &dyn Fn(f32, f32) -> (f32, f32), threshold: f32) -> Result<Vec::<u8>> {
+        if coefficient.len() != self.num {
+            return Err(Error::OutputShape { name: "mask coefficient".to_string(), expected: self.num, actual: coefficient.len() });
+        }
+        let mut mask = vec![0; (rect.width.max(0) * rect.height.max(0)) as usize];
+        if mask.is_empty() {
+            return Ok(mask);
+        }
+
+        /* Only the region of rect in the prototype (+1 pixel for interpolation) is calculated */
+        let (px0, py0) = to_prototype(rect.x as f32, rect.y as f32);
+        let (px1, py1) = to_prototype((rect.x + rect.width) as f32, (rect.y + rect.height) as f32);
+        let clip = |v: f32, max: usize| (v.max(0.0) as usize).min(max);
+        let (x0, y0) = (clip(px0.floor() - 1.0, self.width - 1), clip(py0.floor() - 1.0, self.height - 1));
+        let (x1, y1) = (clip(px1.ceil() + 1.0, self.width - 1) + 1, clip(py1.ceil() + 1.0, self.height - 1) + 1);
+        let probability_map = self.probability_map(coefficient, x0, y0, x1, y1);
+        let map_width = x1 - x0;
+        let at = |x: usize, y: usize| probability_map[(y - y0) * map_width + (x - x0)];
+
+        let (bbox_x0, bbox_y0, bbox_x1, bbox_y1) = bbox.corners();
+        for y in 0 .. rect.height {
+            for x in 0 .. rect.width {
+                /* Center of the pixel */
+                let (image_x, image_y) = ((rect.x + x) as f32 + 0.5, (rect.y + y) as f32 + 0.5);
+                if image_x < bbox_x0 || image_x > bbox_x1 || image_y < bbox_y0 || image_y > bbox_y1 {
+                    continue;
+                }
+
+                /* Bilinear interpolation between the centers of the prototype pixels */
+                let (px, py) = to_prototype(image_x, image_y);
+                let px = (px - 0.5).clamp(x0 as f32, (x1 - 1) as f32);
+                let py = (py - 0.5).clamp(y0 as f32, (y1 - 1) as f32);
+                let (ix, iy) = (px.floor() as usize, py.floor() as usize);
+                let (ix_next, iy_next) = ((ix + 1).min(x1 - 1), (iy + 1).min(y1 - 1));
+                let (dx, dy) = (px - ix as f32, py - iy as f32);
+                let probability = at(ix, iy) * (1.0 - dx) * (1.0 - dy) + at(ix_next, iy) * dx * (1.0 - dy)
+                    + at(ix, iy_next) * (1.0 - dx) * dy + at(ix_next, iy_next) * dx * dy;
+                if probability > threshold {
+                    mask[(y * rect.width + x) as usize] = 255;
+                }
+            }
+        }
+        Ok(mask)
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_prototype() {
+        /* 2 prototypes of 4x4. #0 is positive in the left half, #1 is positive in the top half */
+        let mut data = vec![0.0; 2 * 16];
+        for y in 0 .. 4 {
+            for x in 0 .. 4 {
+                data[y * 4 + x] = if x < 2 { 10.0 } else { -10.0 };
+                data[16 + y * 4 + x] = if y < 2 { 10.0 } else { -10.0 };
+            }
+        }
+        let prototype = PrototypeMask::new(&data, 2, 4, 4).unwrap();
+        let bbox = BoundingBox { class_id: 0, label: "".to_string(), score: 1.0, x: 0.0, y: 0.0, w: 8.0, h: 8.0 };
+        let rect = core::Rect::new(0, 0, 8, 8);
+
+        /* The original image is 8x8 (2x of the prototype) */
+        let to_prototype = |x: f32, y: f32| (x / 2.0, y / 2.0);
+        let mask = prototype.decode(&[1.0, 0.0], &bbox, rect, &to_prototype, 0.5).unwrap();
+        for y in 0 .. 8 {
+            assert_eq!(&mask[y * 8 .. y * 8 + 8], &[255, 255, 255, 255, 0, 0, 0, 0]);
+        }
+
+        /* 0.5 * left + top: positive in the top half. Pixels out of bbox are 0 */
+        let bbox = BoundingBox { w: 6.0, ..bbox };
+        let mask = prototype.decode(&[0.5, 1.0], &bbox, rect, &to_prototype, 0.5).unwrap();
+        assert_eq!(&mask[0 .. 8], &[255, 255, 255, 255, 255, 255, 0, 0]);
+        assert_eq!(&mask[24 .. 32], &[255, 255, 255, 255, 255, 255, 0, 0]);
+        assert_eq!(&mask[32 .. 40], &[0; 8]);
+
+        /* Sub region */
+        let rect = core::Rect::new(2, 6, 4, 2);
+        let mask = prototype.decode(&[1.0, 0.0], &bbox, rect, &to_prototype, 0.5).unwrap();
+        assert_eq!(mask, vec![255, 255, 0, 0, 255, 255, 0, 0]);
+
+        /* Wrong number of coefficients */
+        assert!(prototype.decode(&[1.0], &bbox, rect, &to_prototype, 0.5).is_err());
+    }
+
+    #[test]
+    fn invalid_shape() {
+        /* Empty prototype, and data which doesn't match the shape */
+        assert!(PrototypeMask::new(&[], 2, 0, 4).is_err());
+        assert!(PrototypeMask::new(&[], 2, 4, 0).is_err());
+        assert!(PrototypeMask::new(&[0.0; 31], 2, 4, 4).is_err());
+        assert!(InstanceMask::from_vec(core::Rect::new(0, 0, 2, 2), &[0; 3]).is_err());
+    }
+}
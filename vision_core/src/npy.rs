@@ -0,0 +1,382 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::{prelude::*, core};
+use std::fs::File;
+use std::io::{Read, Write};
+
+use crate::error::{Error, Result};
+
+
+/////////////////////////////////////////////////////////////////
+/* NumPy .npy / .npz files */
+/* Only little endian, C order arrays of the types below are supported */
+/* Reference: https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html */
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+const NPY_ALIGN: usize = 64;
+
+pub trait NpyElement: Copy {
+    const TYPE_CODE: &'static str;      /* descr without byte order (e.g. "f4") */
+    fn from_le_slice(bytes: &[u8]) -> Self;
+    fn extend_le_bytes(self, buffer: &mut Vec::<u8>);
+}
+
+macro_rules! impl_npy_element {
+    ($t:ty, $code:expr) => {
+        impl NpyElement for $t {
+            const TYPE_CODE: &'static str = $code;
+            fn from_le_slice(bytes: &[u8]) -> Self {
+                let mut array = [0u8; std::mem::size_of::<$t>()];
+                array.copy_from_slice(bytes);
+                <$t>::from_le_bytes(array)
+            }
+            fn extend_le_bytes(self, buffer: &mut Vec::<u8>) {
+                buffer.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+    };
+}
+
+impl_npy_element!(u8, "u1");
+impl_npy_element!(i32, "i4");
+impl_npy_element!(i64, "i8");
+impl_npy_element!(f32, "f4");
+impl_npy_element!(f64, "f8");
+
+
+/////////////////////////////////////////////////////////////////
+#[derive(Debug, Clone, PartialEq)]
+pub struct NpyArray<T> {
+    pub shape: Vec::<usize>,
+    pub data: Vec::<T>,     /* C order */
+}
+
+impl<T: NpyElement> NpyArray<T> {
+    /* The number of elements of data must be the product of shape */
+    pub fn new(shape: Vec::<usize>, data: Vec::<T>) -> std::result::Result<Self, String> {
+        if num_element(&shape) != Some(data.len()) {
+            return Err(format!("shape {:?} doesn't match the number of elements ({})", shape, data.len()));
+        }
+        Ok(NpyArray {
+            shape: shape,
+            data: data,
+        })
+    }
+
+    pub fn read(filename: &str) -> Result<Self> {
+        let to_error = |message: String| Error::Npy { filename: filename.to_string(), message: message };
+        let bytes = std::fs::read(filename).map_err(|e| to_error(e.to_string()))?;
+        Self::from_bytes(&bytes).map_err(to_error)
+    }
+
+    pub fn write(&self, filename: &str) -> Result<()> {
+        std::fs::write(filename, self.to_bytes()).map_err(|e| Error::Npy { filename: filename.to_string(), message: e.to_string() })
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> std::result::Result<Self, String> {
+        if bytes.len() < 10 || &bytes[0..6] != NPY_MAGIC {
+            return Err("not a npy file".to_string());
+        }
+        let (header_len, header_start) = match bytes[6] {
+            1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10usize),
+            2 | 3 => {
+                if bytes.len() < 12 {
+                    return Err("truncated header".to_string());
+                }
+                (u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize, 12)
+            },
+            version => return Err(format!("unsupported version: {}", version)),
+        };
+        let data_start = header_start.checked_add(header_len).ok_or("invalid header length")?;
+        if bytes.len() < data_start {
+            return Err("truncated header".to_string());
+        }
+        let header = std::str::from_utf8(&bytes[header_start..data_start]).map_err(|e| e.to_string())?;
+        let shape = parse_header::<T>(header)?;
+
+        /* shape comes from the file, so the size is checked not to overflow */
+        let element_size = std::mem::size_of::<T>();
+        let num = num_element(&shape).ok_or_else(|| format!("shape {:?} is too large", shape))?;
+        let data_size = num.checked_mul(element_size).ok_or_else(|| format!("shape {:?} is too large", shape))?;
+        let body = &bytes[data_start..];
+        if body.len() < data_size {
+            return Err(format!("expected {} elements, but the file has only {} bytes of data", num, body.len()));
+        }
+        let data = body.chunks_exact(element_size).take(num).map(T::from_le_slice).collect();
+        Ok(NpyArray {
+            shape: shape,
+            data: data,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec::<u8> {
+        let shape = match self.shape.len() {
+            1 => format!("({},)", self.shape[0]),
+            _ => format!("({})", self.shape.iter().map(|v| v.to_string()).collect::<Vec::<String>>().join(", ")),
+        };
+        let mut header = format!("{{'descr': '{}{}', 'fortran_order': False, 'shape': {}, }}", if std::mem::size_of::<T>() == 1 { "|" } else { "<" }, T::TYPE_CODE, shape);
+
+        /* Pad with spaces so that the data starts at an aligned position. The header ends with '\n' */
+        let unpadded_len = NPY_MAGIC.len() + 2 + 2 + header.len() + 1;
+        header.push_str(&" ".repeat((NPY_ALIGN - unpadded_len % NPY_ALIGN) % NPY_ALIGN));
+        header.push('\n');
+
+        let mut bytes = Vec::<u8>::with_capacity(NPY_MAGIC.len() + 4 + header.len() + self.data.len() * std::mem::size_of::<T>());
+        bytes.extend_from_slice(NPY_MAGIC);
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        for v in self.data.iter() {
+            v.extend_le_bytes(&mut bytes);
+        }
+        bytes
+    }
+}
+
+impl NpyArray<f32> {
+    /* CV_32F Mat (any dims). Channel is added as the last axis when the Mat has more than one channel */
+    pub fn from_mat(mat: &core::Mat) -> std::result::Result<Self, String> {
+        if mat.depth() != core::CV_32F {
+            return Err(format!("only CV_32F Mat is supported (depth = {})", mat.depth()));
+        }
+        if !mat.is_continuous() {
+            return Err("Mat is not continuous".to_string());
+        }
+        let mut shape: Vec::<usize> = mat.mat_size().iter().map(|v| *v as usize).collect();
+        if mat.channels() > 1 {
+            shape.push(mat.channels() as usize);
+        }
+        let num = mat.total() * mat.channels() as usize;
+        let data = if num == 0 {
+            Vec::<f32>::new()
+        } else {
+            let ptr = mat.ptr(0).map_err(|e| e.to_string())?;
+            unsafe {std::slice::from_raw_parts(ptr as *const f32, num).to_vec()}
+        };
+        NpyArray::new(shape, data)
+    }
+
+    /* Single channel CV_32F Mat which has the same shape */
+    pub fn to_mat(&self) -> std::result::Result<core::Mat, String> {
+        let sizes: Vec::<i32> = match self.shape.len() {
+            0 => vec![1],
+            1 => vec![1, self.shape[0] as i32],     /* 1 x N, as Mat has at least 2 dims */
+            _ => self.shape.iter().map(|v| *v as i32).collect(),
+        };
+        let mut mat = core::Mat::new_nd_with_default(&sizes, core::CV_32F, core::Scalar::default()).map_err(|e| e.to_string())?;
+        if !self.data.is_empty() {
+            let ptr = mat.ptr_mut(0).map_err(|e| e.to_string())?;
+            unsafe {std::ptr::copy_nonoverlapping(self.data.as_ptr(), ptr as *mut f32, self.data.len())};
+        }
+        Ok(mat)
+    }
+}
+
+/* e.g. write_mat("output_0.npy", &output_blobs.get(0)?) to compare the tensor with the one from Python */
+pub fn write_mat(filename: &str, mat: &core::Mat) -> Result<()> {
+    let array = NpyArray::<f32>::from_mat(mat).map_err(|message| Error::Npy { filename: filename.to_string(), message: message })?;
+    array.write(filename)
+}
+
+pub fn read_mat(filename: &str) -> Result<core::Mat> {
+    NpyArray::<f32>::read(filename)?.to_mat().map_err(|message| Error::Npy { filename: filename.to_string(), message: message })
+}
+
+/* Product of shape. None on overflow */
+fn num_element(shape: &[usize]) -> Option<usize> {
+    shape.iter().try_fold(1usize, |num, v| num.checked_mul(*v))
+}
+
+/* Returns shape */
+fn parse_header<T: NpyElement>(header: &str) -> std::result::Result<Vec::<usize>, String> {
+    let value_of = |key: &str| -> std::result::Result<&str, String> {
+        let key = format!("'{}':", key);
+        let start = header.find(&key).ok_or(format!("{} is not found in header", key))? + key.len();
+        Ok(header[start..].trim_start())
+    };
+
+    let descr = value_of("descr")?;
+    let descr = descr.get(1..).and_then(|s| s.split('\'').next()).ok_or("invalid descr")?;
+    let (byte_order, type_code) = descr.split_at(1.min(descr.len()));
+    if type_code != T::TYPE_CODE || !(byte_order == "<" || byte_order == "|" || byte_order == "=") {
+        return Err(format!("dtype '{}' doesn't match the requested type '<{}'", descr, T::TYPE_CODE));
+    }
+
+    if value_of("fortran_order")?.starts_with("True") {
+        return Err("fortran order is not supported".to_string());
+    }
+
+    let shape = value_of("shape")?;
+    let end = shape.find(')').ok_or("invalid shape")?;
+    shape.get(1..end).ok_or("invalid shape")?
+        .split(',')
+        .map(|v| v.trim())
+        .filter(|v| !v.is_empty())
+        .map(|v| v.parse::<usize>().map_err(|e| format!("invalid shape ({}): {}", v, e)))
+        .collect()
+}
+
+
+/////////////////////////////////////////////////////////////////
+/* np.savez / np.savez_compressed. Arrays are accessed by the key (file name without ".npy") */
+pub struct NpzReader {
+    filename: String,
+    archive: zip::ZipArchive<File>,
+}
+
+impl NpzReader {
+    pub fn open(filename: &str) -> Result<Self> {
+        let to_error = |message: String| Error::Npy { filename: filename.to_string(), message: message };
+        let file = File::open(filename).map_err(|e| to_error(e.to_string()))?;
+        let archive = zip::ZipArchive::new(file).map_err(|e| to_error(e.to_string()))?;
+        Ok(NpzReader {
+            filename: filename.to_string(),
+            archive: archive,
+        })
+    }
+
+    pub fn names(&self) -> Vec::<String> {
+        self.archive.file_names().map(|name| name.trim_end_matches(".npy").to_string()).collect()
+    }
+
+    pub fn read<T: NpyElement>(&mut self, name: &str) -> Result<NpyArray<T>> {
+        let filename = &self.filename;
+        let to_error = |message: String| Error::Npy { filename: format!("{}:{}", filename, name), message: message };
+        let mut file = self.archive.by_name(&format!("{}.npy", name)).map_err(|e| to_error(e.to_string()))?;
+        let mut bytes = Vec::<u8>::new();
+        file.read_to_end(&mut bytes).map_err(|e| to_error(e.to_string()))?;
+        NpyArray::<T>::from_bytes(&bytes).map_err(to_error)
+    }
+}
+
+/* Arrays are stored without compression, as np.savez does */
+pub struct NpzWriter {
+    filename: String,
+    writer: zip::ZipWriter<File>,
+}
+
+impl NpzWriter {
+    pub fn create(filename: &str) -> Result<Self> {
+        let file = File::create(filename).map_err(|e| Error::Npy { filename: filename.to_string(), message: e.to_string() })?;
+        Ok(NpzWriter {
+            filename: filename.to_string(),
+            writer: zip::ZipWriter::new(file),
+        })
+    }
+
+    pub fn write<T: NpyElement>(&mut self, name: &str, array: &NpyArray<T>) -> Result<()> {
+        let filename = &self.filename;
+        let to_error = |message: String| Error::Npy { filename: format!("{}:{}", filename, name), message: message };
+        let options = zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Stored);
+        self.writer.start_file(format!("{}.npy", name), options).map_err(|e| to_error(e.to_string()))?;
+        self.writer.write_all(&array.to_bytes()).map_err(|e| to_error(e.to_string()))
+    }
+
+    pub fn finish(mut self) -> Result<()> {
+        self.writer.finish().map_err(|e| Error::Npy { filename: self.filename.clone(), message: e.to_string() })?;
+        Ok(())
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_filename(name: &str) -> String {
+        std::env::temp_dir().join(format!("vision_core_npy_{}_{}", std::process::id(), name)).to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn parse_numpy_output() {
+        /* np.save(f, np.arange(6, dtype=np.float32).reshape(2, 3)) */
+        let mut header = "{'descr': '<f4', 'fortran_order': False, 'shape': (2, 3), }".to_string();
+        header.push_str(&" ".repeat(128 - 10 - header.len() - 1));
+        header.push('\n');
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        for i in 0..6 {
+            bytes.extend_from_slice(&(i as f32).to_le_bytes());
+        }
+
+        let array = NpyArray::<f32>::from_bytes(&bytes).unwrap();
+        assert_eq!(array.shape, vec![2, 3]);
+        assert_eq!(array.data, vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(array.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let array = NpyArray::new(vec![5], vec![1i64, -2, 3, -4, 5]).unwrap();
+        let bytes = array.to_bytes();
+        assert_eq!((bytes.len() - array.data.len() * 8) % NPY_ALIGN, 0);
+        assert_eq!(NpyArray::<i64>::from_bytes(&bytes).unwrap(), array);
+
+        let scalar = NpyArray::new(vec![], vec![0.5f64]).unwrap();
+        assert_eq!(NpyArray::<f64>::from_bytes(&scalar.to_bytes()).unwrap(), scalar);
+
+        let image = NpyArray::new(vec![2, 2, 3], (0..12).map(|v| v as u8).collect()).unwrap();
+        assert!(String::from_utf8_lossy(&image.to_bytes()).contains("'descr': '|u1'"));
+        assert_eq!(NpyArray::<u8>::from_bytes(&image.to_bytes()).unwrap(), image);
+    }
+
+    #[test]
+    fn reject_unsupported() {
+        let array = NpyArray::new(vec![2], vec![1.0f32, 2.0]).unwrap();
+        assert!(NpyArray::<f64>::from_bytes(&array.to_bytes()).is_err());
+        let fortran = String::from_utf8_lossy(&array.to_bytes()).replace("False", "True ");
+        assert!(NpyArray::<f32>::from_bytes(fortran.as_bytes()).is_err());
+        assert!(NpyArray::<f32>::from_bytes(b"not a npy file").is_err());
+        assert!(NpyArray::<f32>::from_bytes(&array.to_bytes()[..array.to_bytes().len() - 1]).is_err());
+
+        /* The size of shape overflows usize */
+        for shape in [format!("({}, 4)", usize::MAX / 2), format!("({},)", usize::MAX / 2)].iter() {
+            let header = format!("{{'descr': '<f4', 'fortran_order': False, 'shape': {}, }}\n", shape);
+            let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+            bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+            bytes.extend_from_slice(header.as_bytes());
+            assert!(NpyArray::<f32>::from_bytes(&bytes).unwrap_err().contains("too large"));
+        }
+    }
+
+    #[test]
+    fn reject_shape_mismatch() {
+        assert!(NpyArray::new(vec![2, 3], vec![0.0f32; 5]).is_err());
+        assert!(NpyArray::new(vec![usize::MAX, 2], vec![0.0f32; 2]).is_err());
+        assert!(NpyArray::new(vec![0, 3], Vec::<f32>::new()).is_ok());
+    }
+
+    #[test]
+    fn npz_roundtrip() {
+        let filename = temp_filename("test.npz");
+        let boxes = NpyArray::new(vec![2, 4], vec![0.0f32, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7]).unwrap();
+        let ids = NpyArray::new(vec![2], vec![3i32, 7]).unwrap();
+
+        let mut writer = NpzWriter::create(&filename).unwrap();
+        writer.write("boxes", &boxes).unwrap();
+        writer.write("ids", &ids).unwrap();
+        writer.finish().unwrap();
+
+        let mut reader = NpzReader::open(&filename).unwrap();
+        let mut names = reader.names();
+        names.sort();
+        assert_eq!(names, vec!["boxes", "ids"]);
+        assert_eq!(reader.read::<f32>("boxes").unwrap(), boxes);
+        assert_eq!(reader.read::<i32>("ids").unwrap(), ids);
+        assert!(reader.read::<f32>("ids").is_err());
+        assert!(reader.read::<f32>("scores").is_err());
+        std::fs::remove_file(&filename).unwrap();
+    }
+}
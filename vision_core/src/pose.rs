@@ -0,0 +1,51 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use crate::bounding_box::BoundingBox;
+
+
+/////////////////////////////////////////////////////////////////
+/* Keypoint in pixel of the original image */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Keypoint {
+    pub x: f32,
+    pub y: f32,
+    pub score: f32,
+}
+
+/* Detection result of pose estimation engines. keypoint_list is in the order of COCO_KEYPOINT_NAME_LIST */
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoseDetection {
+    pub bbox: BoundingBox,
+    pub keypoint_list: Vec::<Keypoint>,
+}
+
+impl PoseDetection {
+    pub fn keypoint(&self, name: &str) -> Option<&Keypoint> {
+        COCO_KEYPOINT_NAME_LIST.iter().position(|n| *n == name).and_then(|index| self.keypoint_list.get(index))
+    }
+}
+
+pub const COCO_KEYPOINT_NAME_LIST: [&str; 17] = [
+    "nose", "left_eye", "right_eye", "left_ear", "right_ear",
+    "left_shoulder", "right_shoulder", "left_elbow", "right_elbow", "left_wrist", "right_wrist",
+    "left_hip", "right_hip", "left_knee", "right_knee", "left_ankle", "right_ankle",
+];
+
+/* Limbs as pairs of keypoint index */
+pub const COCO_SKELETON: [(usize, usize); 16] = [
+    (0, 1), (0, 2), (1, 3), (2, 4),                     /* face */
+    (5, 6), (5, 7), (7, 9), (6, 8), (8, 10),            /* arms */
+    (5, 11), (6, 12), (11, 12),                         /* body */
+    (11, 13), (13, 15), (12, 14), (14, 16),             /* legs */
+];
@@ -0,0 +1,280 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::{prelude::*, core, imgproc};
+use serde::{Deserialize, Serialize};
+
+use crate::bounding_box::BoundingBox;
+use crate::error::{Error, Result};
+
+
+/////////////////////////////////////////////////////////////////
+/* How an input image is fitted into the model input size */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResizeMode {
+    Stretch,        /* resize to the model size ignoring the aspect ratio */
+    Letterbox,      /* keep the aspect ratio, and pad the margin (centered) */
+    CenterCrop,     /* keep the aspect ratio, and crop the center */
+}
+
+impl Default for ResizeMode {
+    fn default() -> Self {
+        ResizeMode::Stretch
+    }
+}
+
+impl std::str::FromStr for ResizeMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "stretch" => Ok(ResizeMode::Stretch),
+            "letterbox" => Ok(ResizeMode::Letterbox),
+            "center_crop" | "center-crop" => Ok(ResizeMode::CenterCrop),
+            _ => Err(format!("unknown resize mode: {} (stretch, letterbox or center_crop)", s)),
+        }
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+/* model coordinate = original coordinate * scale + offset */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResizeTransform {
+    pub scale_x: f32,
+    pub scale_y: f32,
+    pub offset_x: f32,
+    pub offset_y: f32,
+}
+
+impl ResizeTransform {
+    pub fn to_original_point(&self, x: f32, y: f32) -> (f32, f32) {
+        ((x - self.offset_x) / self.scale_x, (y - self.offset_y) / self.scale_y)
+    }
+
+    /* (x, y, w, h) in the model input -> (x, y, w, h) in the original image */
+    pub fn to_original_rect(&self, x: f32, y: f32, w: f32, h: f32) -> (f32, f32, f32, f32) {
+        let (x, y) = self.to_original_point(x, y);
+        (x, y, w / self.scale_x, h / self.scale_y)
+    }
+
+    pub fn to_model_point(&self, x: f32, y: f32) -> (f32, f32) {
+        (x * self.scale_x + self.offset_x, y * self.scale_y + self.offset_y)
+    }
+
+    /* Transform from src_size to dst_size. The size of the resized image is rounded to integer */
+    pub fn new(src_size: core::Size, dst_size: core::Size, mode: ResizeMode) -> Self {
+        let scale_x = dst_size.width as f32 / src_size.width as f32;
+        let scale_y = dst_size.height as f32 / src_size.height as f32;
+        let scale = match mode {
+            ResizeMode::Stretch => return ResizeTransform { scale_x: scale_x, scale_y: scale_y, offset_x: 0.0, offset_y: 0.0 },
+            ResizeMode::Letterbox => scale_x.min(scale_y),
+            ResizeMode::CenterCrop => scale_x.max(scale_y),
+        };
+        let (resized_width, resized_height) = Self::scaled_size(src_size, scale);
+        ResizeTransform {
+            scale_x: resized_width as f32 / src_size.width as f32,
+            scale_y: resized_height as f32 / src_size.height as f32,
+            offset_x: ((dst_size.width - resized_width) / 2) as f32,
+            offset_y: ((dst_size.height - resized_height) / 2) as f32,
+        }
+    }
+
+    fn scaled_size(src_size: core::Size, scale: f32) -> (i32, i32) {
+        (((src_size.width as f32 * scale).round() as i32).max(1), ((src_size.height as f32 * scale).round() as i32).max(1))
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+/* Resize mat into dst_size. pad_value is used for the margin of Letterbox */
+pub fn resize(mat: &core::Mat, dst_size: core::Size, mode: ResizeMode, pad_value: (f64, f64, f64)) -> Result<(core::Mat, ResizeTransform)> {
+    let src_size = core::Size { width: mat.cols(), height: mat.rows() };
+    let transform = ResizeTransform::new(src_size, dst_size, mode);
+    let resized_size = match mode {
+        ResizeMode::Stretch => dst_size,
+        _ => core::Size {
+            width: (src_size.width as f32 * transform.scale_x).round() as i32,
+            height: (src_size.height as f32 * transform.scale_y).round() as i32,
+        },
+    };
+
+    let mut mat_resized = Mat::default();
+    imgproc::resize(mat, &mut mat_resized, resized_size, 0.0, 0.0, imgproc::INTER_LINEAR).map_err(Error::Preprocess)?;
+
+    let mat_dst = match mode {
+        ResizeMode::Stretch => mat_resized,
+        ResizeMode::Letterbox => {
+            let left = transform.offset_x as i32;
+            let top = transform.offset_y as i32;
+            let right = dst_size.width - resized_size.width - left;
+            let bottom = dst_size.height - resized_size.height - top;
+            let mut mat_padded = Mat::default();
+            core::copy_make_border(&mat_resized, &mut mat_padded, top, bottom, left, right, core::BORDER_CONSTANT, core::Scalar::from(pad_value)).map_err(Error::Preprocess)?;
+            mat_padded
+        },
+        ResizeMode::CenterCrop => {
+            let roi = core::Rect::new(-transform.offset_x as i32, -transform.offset_y as i32, dst_size.width, dst_size.height);
+            Mat::roi(&mat_resized, roi).and_then(|mat_cropped| mat_cropped.try_clone()).map_err(Error::Preprocess)?
+        },
+    };
+    Ok((mat_dst, transform))
+}
+
+
+
+/////////////////////////////////////////////////////////////////
+/* Rect of the crop: bbox expanded by margin (ratio to the box size) on each side, and clipped into the image. None if empty */
+pub fn crop_rect(bbox: &BoundingBox, margin: f32, image_width: i32, image_height: i32) -> Option<core::Rect> {
+    let mut bbox_crop = bbox.clone();
+    let (margin_x, margin_y) = (bbox.w * margin, bbox.h * margin);
+    let (x0, y0, x1, y1) = bbox.corners();
+    bbox_crop.set_corners(x0 - margin_x, y0 - margin_y, x1 + margin_x, y1 + margin_y);
+    bbox_crop.clamp(image_width as f32, image_height as f32);
+    let rect = bbox_crop.to_rect();
+    if rect.width > 0 && rect.height > 0 {
+        Some(rect)
+    } else {
+        None
+    }
+}
+
+/* Crop each box (ROI shares the data with mat), and give the crops to process in batches of batch_size */
+/* process returns one result per crop. The results are in the order of bbox_list, and None for an empty crop */
+pub fn process_crop_batch<T, F>(mat: &core::Mat, bbox_list: &[BoundingBox], margin: f32, batch_size: usize, mut process: F) -> Result<Vec::<Option<T>>>
+where
+    F: FnMut(&[core::Mat]) -> Result<Vec::<T>>,
+{
+    let mut index_list = Vec::<usize>::new();
+    let mut mat_crop_list = Vec::<core::Mat>::new();
+    for (index, bbox) in bbox_list.iter().enumerate() {
+        if let Some(rect) = crop_rect(bbox, margin, mat.cols(), mat.rows()) {
+            index_list.push(index);
+            mat_crop_list.push(core::Mat::roi(mat, rect).map_err(Error::Preprocess)?);
+        }
+    }
+
+    let mut result_list: Vec::<Option<T>> = (0 .. bbox_list.len()).map(|_| None).collect();
+    let batch_size = batch_size.max(1);
+    for (batch_index_list, batch_mat_list) in index_list.chunks(batch_size).zip(mat_crop_list.chunks(batch_size)) {
+        for (index, result) in batch_index_list.iter().zip(process(batch_mat_list)?) {
+            result_list[*index] = Some(result);
+        }
+    }
+    Ok(result_list)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SRC_SIZE: core::Size = core::Size { width: 640, height: 480 };
+    const DST_SIZE: core::Size = core::Size { width: 416, height: 416 };
+
+    fn assert_near(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-4, "{} != {}", actual, expected);
+    }
+
+    fn assert_round_trip(transform: &ResizeTransform) {
+        for (x, y) in [(0.0, 0.0), (100.0, 50.0), (415.0, 415.0)].iter() {
+            let (original_x, original_y) = transform.to_original_point(*x, *y);
+            let (model_x, model_y) = transform.to_model_point(original_x, original_y);
+            assert_near(model_x, *x);
+            assert_near(model_y, *y);
+        }
+    }
+
+    #[test]
+    fn transform_stretch() {
+        let transform = ResizeTransform::new(SRC_SIZE, DST_SIZE, ResizeMode::Stretch);
+        assert_near(transform.scale_x, 416.0 / 640.0);
+        assert_near(transform.scale_y, 416.0 / 480.0);
+        assert_eq!((transform.offset_x, transform.offset_y), (0.0, 0.0));
+        assert_round_trip(&transform);
+    }
+
+    #[test]
+    fn transform_letterbox() {
+        /* 640x480 -> 416x312, and 52 pixels margin at the top and bottom */
+        let transform = ResizeTransform::new(SRC_SIZE, DST_SIZE, ResizeMode::Letterbox);
+        assert_near(transform.scale_x, 416.0 / 640.0);
+        assert_near(transform.scale_y, 312.0 / 480.0);
+        assert_eq!((transform.offset_x, transform.offset_y), (0.0, 52.0));
+        assert_eq!(transform.to_original_point(0.0, 52.0), (0.0, 0.0));
+        assert_round_trip(&transform);
+    }
+
+    #[test]
+    fn transform_center_crop() {
+        /* 640x480 -> 555x416, and the center 416 pixels are cropped */
+        let transform = ResizeTransform::new(SRC_SIZE, DST_SIZE, ResizeMode::CenterCrop);
+        assert_near(transform.scale_x, 555.0 / 640.0);
+        assert_near(transform.scale_y, 416.0 / 480.0);
+        assert_eq!((transform.offset_x, transform.offset_y), (-69.0, 0.0));
+        assert_round_trip(&transform);
+    }
+
+    #[test]
+    fn crop_with_margin() {
+        let bbox = BoundingBox { class_id: 0, label: "Car".to_string(), score: 0.9, x: 100.0, y: 50.0, w: 40.0, h: 20.0 };
+        assert_eq!(crop_rect(&bbox, 0.0, 640, 480), Some(core::Rect::new(100, 50, 40, 20)));
+        assert_eq!(crop_rect(&bbox, 0.1, 640, 480), Some(core::Rect::new(96, 48, 48, 24)));
+
+        /* Clipped into the image */
+        let bbox = BoundingBox { x: 620.0, y: -10.0, ..bbox };
+        assert_eq!(crop_rect(&bbox, 0.5, 640, 480), Some(core::Rect::new(600, 0, 40, 20)));
+
+        /* Out of the image */
+        let bbox = BoundingBox { x: 700.0, ..bbox };
+        assert_eq!(crop_rect(&bbox, 0.1, 640, 480), None);
+    }
+
+    #[test]
+    fn crop_batch_order() {
+        let mat = Mat::new_rows_cols_with_default(SRC_SIZE.height, SRC_SIZE.width, core::CV_8UC3, core::Scalar::all(0.0)).unwrap();
+        let bbox = BoundingBox { class_id: 0, label: "person".to_string(), score: 0.9, x: 0.0, y: 0.0, w: 10.0, h: 20.0 };
+        let bbox_list = vec![
+            bbox.clone(),
+            BoundingBox { x: 700.0, ..bbox.clone() },   /* out of the image */
+            BoundingBox { w: 30.0, ..bbox.clone() },
+            BoundingBox { w: 40.0, ..bbox.clone() },
+        ];
+        let mut batch_size_list = Vec::<usize>::new();
+        let result_list = process_crop_batch(&mat, &bbox_list, 0.0, 2, |mat_list| {
+            batch_size_list.push(mat_list.len());
+            Ok(mat_list.iter().map(|mat| mat.cols()).collect())
+        }).unwrap();
+        assert_eq!(result_list, vec![Some(10), None, Some(30), Some(40)]);
+        assert_eq!(batch_size_list, vec![2, 1]);
+    }
+
+    #[test]
+    fn resize_size_and_pad() {
+        let color = core::Scalar::new(10.0, 20.0, 30.0, 0.0);
+        let mat = Mat::new_rows_cols_with_default(SRC_SIZE.height, SRC_SIZE.width, core::CV_8UC3, color).unwrap();
+        let pad_value = (114.0, 114.0, 114.0);
+        for mode in [ResizeMode::Stretch, ResizeMode::Letterbox, ResizeMode::CenterCrop].iter() {
+            let (mat_resized, _) = resize(&mat, DST_SIZE, *mode, pad_value).unwrap();
+            assert_eq!((mat_resized.cols(), mat_resized.rows()), (DST_SIZE.width, DST_SIZE.height));
+            assert_eq!(*mat_resized.at_2d::<core::Vec3b>(208, 208).unwrap(), core::Vec3b::from([10, 20, 30]));
+        }
+
+        let (mat_resized, _) = resize(&mat, DST_SIZE, ResizeMode::Letterbox, pad_value).unwrap();
+        assert_eq!(*mat_resized.at_2d::<core::Vec3b>(0, 0).unwrap(), core::Vec3b::from([114, 114, 114]));
+        assert_eq!(*mat_resized.at_2d::<core::Vec3b>(51, 208).unwrap(), core::Vec3b::from([114, 114, 114]));
+        assert_eq!(*mat_resized.at_2d::<core::Vec3b>(52, 208).unwrap(), core::Vec3b::from([10, 20, 30]));
+        assert_eq!(*mat_resized.at_2d::<core::Vec3b>(415, 208).unwrap(), core::Vec3b::from([114, 114, 114]));
+    }
+}
@@ -0,0 +1,291 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::npy::NpyArray;
+use crate::preprocess::ResizeTransform;
+
+
+/////////////////////////////////////////////////////////////////
+/* Prior (anchor) boxes of SSD-style models */
+/* Reference: */
+/*   https://docs.openvino.ai/latest/openvino_docs_ops_detection_PriorBoxClustered_1.html */
+/*   https://docs.openvino.ai/latest/openvino_docs_ops_detection_PriorBox_1.html */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PriorBoxConfig {
+    pub variance: (f32, f32, f32, f32),
+    #[serde(default = "PriorBoxConfig::default_offset")]
+    pub offset: f32,                    /* position of the box center in a cell (0.5 = center of the cell) */
+    #[serde(default)]
+    pub clip: bool,                     /* clip the box into [0.0, 1.0] */
+    #[serde(default)]
+    pub snap_to_pixel: bool,            /* truncate the box corners to integer pixel coordinates (toward zero) */
+    #[serde(default)]
+    pub filename: Option<String>,       /* .npy file of the prior box list (e.g. 0.npy dumped from the model). layers are not used if specified */
+    #[serde(default)]
+    pub layers: Vec::<PriorBoxLayer>,
+}
+
+/* One feature map. Boxes are generated in the order of box_sizes, then min_sizes (with max_sizes and aspect_ratios) */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PriorBoxLayer {
+    pub stride: i32,
+    #[serde(default)]
+    pub feature_map_size: Option<(i32, i32)>,   /* (width, height). ceil(input_size / stride) if not specified */
+    #[serde(default)]
+    pub box_sizes: Vec::<(f32, f32)>,           /* (width, height) in pixel (PriorBoxClustered) */
+    #[serde(default)]
+    pub min_sizes: Vec::<f32>,                  /* in pixel (PriorBox) */
+    #[serde(default)]
+    pub max_sizes: Vec::<f32>,                  /* empty, or the same length as min_sizes */
+    #[serde(default)]
+    pub aspect_ratios: Vec::<f32>,              /* width / height. 1.0 is always generated for each min_size */
+    #[serde(default)]
+    pub flip: bool,                             /* also generate 1 / aspect_ratio */
+}
+
+impl PriorBoxConfig {
+    fn default_offset() -> f32 {
+        0.5
+    }
+
+    /* Returns (x0, y0, x1, y1) in [0.0, 1.0] for each prior, in the order of layer -> y -> x -> box */
+    pub fn generate(&self, input_width: i32, input_height: i32) -> Result<Vec::<[f32; 4]>> {
+        if let Some(filename) = &self.filename {
+            return Self::load(filename);
+        }
+        if input_width <= 0 || input_height <= 0 {
+            return Err(Error::Config(format!("invalid input size for prior box: {} x {}", input_width, input_height)));
+        }
+        if self.layers.is_empty() {
+            return Err(Error::Config("prior_box.layers is empty".to_string()));
+        }
+
+        let mut prior_list = Vec::<[f32; 4]>::new();
+        for layer in self.layers.iter() {
+            let size_list = layer.size_list()?;
+            let (grid_w, grid_h) = layer.grid_size(input_width, input_height);
+            for grid_y in 0..grid_h {
+                for grid_x in 0..grid_w {
+                    let cx = (grid_x as f32 + self.offset) * layer.stride as f32;
+                    let cy = (grid_y as f32 + self.offset) * layer.stride as f32;
+                    for (w, h) in size_list.iter() {
+                        let mut corner = [cx - w / 2.0, cy - h / 2.0, cx + w / 2.0, cy + h / 2.0];
+                        if self.snap_to_pixel {
+                            for v in corner.iter_mut() {
+                                *v = v.trunc();
+                            }
+                        }
+                        let mut prior = [
+                            corner[0] / input_width as f32,
+                            corner[1] / input_height as f32,
+                            corner[2] / input_width as f32,
+                            corner[3] / input_height as f32,
+                        ];
+                        if self.clip {
+                            for v in prior.iter_mut() {
+                                *v = v.clamp(0.0, 1.0);
+                            }
+                        }
+                        prior_list.push(prior);
+                    }
+                }
+            }
+        }
+        Ok(prior_list)
+    }
+
+    /* Shape is [num_prior * 4], [num_prior, 4], or [2, num_prior * 4] (output of PriorBox layer. the second row is variance) */
+    fn load(filename: &str) -> Result<Vec::<[f32; 4]>> {
+        let array = NpyArray::<f32>::read(filename)?;
+        let shape: Vec::<usize> = array.shape.iter().cloned().skip_while(|v| *v == 1).collect();
+        let num = match shape.as_slice() {
+            [num] => *num,
+            [num_prior, 4] => num_prior * 4,
+            [2, num] => *num,
+            _ => 0,
+        };
+        if num == 0 || num % 4 != 0 {
+            return Err(Error::Npy { filename: filename.to_string(), message: format!("unexpected shape for prior box: {:?}", array.shape) });
+        }
+        Ok(array.data[..num].chunks_exact(4).map(|v| [v[0], v[1], v[2], v[3]]).collect())
+    }
+}
+
+impl PriorBoxLayer {
+    fn grid_size(&self, input_width: i32, input_height: i32) -> (i32, i32) {
+        match self.feature_map_size {
+            Some(size) => size,
+            None => ((input_width + self.stride - 1) / self.stride, (input_height + self.stride - 1) / self.stride),
+        }
+    }
+
+    /* (width, height) of each box in a cell */
+    fn size_list(&self) -> Result<Vec::<(f32, f32)>> {
+        if self.stride <= 0 {
+            return Err(Error::Config(format!("invalid prior box stride: {}", self.stride)));
+        }
+        if !self.max_sizes.is_empty() && self.max_sizes.len() != self.min_sizes.len() {
+            return Err(Error::Config(format!("prior box max_sizes ({}) must have the same length as min_sizes ({})", self.max_sizes.len(), self.min_sizes.len())));
+        }
+
+        let mut size_list = self.box_sizes.clone();
+        for (i, min_size) in self.min_sizes.iter().enumerate() {
+            size_list.push((*min_size, *min_size));
+            if let Some(max_size) = self.max_sizes.get(i) {
+                let size = (min_size * max_size).sqrt();
+                size_list.push((size, size));
+            }
+            for aspect_ratio in self.aspect_ratios.iter() {
+                if *aspect_ratio <= 0.0 || (aspect_ratio - 1.0).abs() < 1e-6 {
+                    continue;
+                }
+                let ratio = aspect_ratio.sqrt();
+                size_list.push((min_size * ratio, min_size / ratio));
+                if self.flip {
+                    size_list.push((min_size / ratio, min_size * ratio));
+                }
+            }
+        }
+        if size_list.is_empty() {
+            return Err(Error::Config(format!("prior box layer (stride = {}) has no box", self.stride)));
+        }
+        Ok(size_list)
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+/* Decodes the regression output of SSD-style models with the prior boxes */
+/* Reference: */
+/*   https://github.com/openvinotoolkit/openvino/blob/17091476d86cbb98392216fa3d4f0db90914449a/inference-engine/thirdparty/clDNN/src/impls/cpu/detection_output.cpp#L135 */
+/*   https://docs.openvino.ai/latest/openvino_docs_ops_detection_DetectionOutput_1.html */
+/*   https://github.com/biubug6/Pytorch_Retinaface/blob/master/utils/box_utils.py */
+pub struct PriorBoxDecoder {
+    prior_list: Vec::<[f32; 4]>,
+    variance: (f32, f32, f32, f32),
+    input_width: f32,
+    input_height: f32,
+}
+
+impl PriorBoxDecoder {
+    pub fn new(config: &PriorBoxConfig, input_width: i32, input_height: i32) -> Result<Self> {
+        Ok(Self::from_prior_list(config.generate(input_width, input_height)?, config.variance, input_width, input_height))
+    }
+
+    pub fn from_prior_list(prior_list: Vec::<[f32; 4]>, variance: (f32, f32, f32, f32), input_width: i32, input_height: i32) -> Self {
+        PriorBoxDecoder {
+            prior_list: prior_list,
+            variance: variance,
+            input_width: input_width as f32,
+            input_height: input_height as f32,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.prior_list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.prior_list.is_empty()
+    }
+
+    /* (cx, cy, w, h) of the prior in [0.0, 1.0] */
+    fn prior(&self, index: usize) -> (f32, f32, f32, f32) {
+        let [x0, y0, x1, y1] = self.prior_list[index];
+        ((x0 + x1) / 2.0, (y0 + y1) / 2.0, x1 - x0, y1 - y0)
+    }
+
+    /* delta is (dx, dy, dw, dh) for the prior of index. Returns (x, y, w, h) in the original image */
+    pub fn decode_box(&self, index: usize, delta: &[f32], transform: &ResizeTransform) -> (f32, f32, f32, f32) {
+        let (prior_cx, prior_cy, prior_w, prior_h) = self.prior(index);
+        let cx = self.variance.0 * delta[0] * prior_w + prior_cx;
+        let cy = self.variance.1 * delta[1] * prior_h + prior_cy;
+        let w = (delta[2] * self.variance.2).exp() * prior_w;
+        let h = (delta[3] * self.variance.3).exp() * prior_h;
+
+        /* [0.0, 1.0] -> model input -> original image */
+        transform.to_original_rect((cx - w / 2.0) * self.input_width, (cy - h / 2.0) * self.input_height, w * self.input_width, h * self.input_height)
+    }
+
+    /* delta is (dx, dy) of a point (e.g. landmark of RetinaFace) for the prior of index. Returns (x, y) in the original image */
+    pub fn decode_point(&self, index: usize, delta: &[f32], transform: &ResizeTransform) -> (f32, f32) {
+        let (prior_cx, prior_cy, prior_w, prior_h) = self.prior(index);
+        let x = self.variance.0 * delta[0] * prior_w + prior_cx;
+        let y = self.variance.1 * delta[1] * prior_h + prior_cy;
+        transform.to_original_point(x * self.input_width, y * self.input_height)
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_from_npy() {
+        let config = PriorBoxConfig {
+            variance: (0.1, 0.1, 0.2, 0.2),
+            offset: 0.5,
+            clip: true,
+            snap_to_pixel: false,
+            filename: None,
+            layers: vec![PriorBoxLayer {
+                stride: 32,
+                feature_map_size: None,
+                box_sizes: vec![],
+                min_sizes: vec![30.0],
+                max_sizes: vec![60.0],
+                aspect_ratios: vec![1.0, 2.0],
+                flip: true,
+            }],
+        };
+        let prior_list = config.generate(64, 96).unwrap();
+        assert_eq!(prior_list.len(), 2 * 3 * 4);
+        assert_eq!(prior_list[0], [1.0 / 64.0, 1.0 / 96.0, 31.0 / 64.0, 31.0 / 96.0]);
+
+        /* [1, 2, num_prior * 4] as dumped from PriorBox layer */
+        let filename = std::env::temp_dir().join(format!("vision_core_prior_box_{}.npy", std::process::id())).to_string_lossy().to_string();
+        let mut data: Vec::<f32> = prior_list.iter().flat_map(|v| v.iter().cloned()).collect();
+        data.extend(vec![0.1; prior_list.len() * 4]);
+        NpyArray::new(vec![1, 2, prior_list.len() * 4], data).unwrap().write(&filename).unwrap();
+
+        let config = PriorBoxConfig { filename: Some(filename.clone()), layers: vec![], ..config };
+        assert_eq!(config.generate(64, 96).unwrap(), prior_list);
+        std::fs::remove_file(&filename).unwrap();
+    }
+
+    #[test]
+    fn decode_with_prior() {
+        /* A prior of 20x40 at (50, 100) in 200x400 input, shown at half size in the original image */
+        let decoder = PriorBoxDecoder::from_prior_list(vec![[0.2, 0.2, 0.3, 0.3]], (0.1, 0.1, 0.2, 0.2), 200, 400);
+        let transform = ResizeTransform { scale_x: 2.0, scale_y: 2.0, offset_x: 0.0, offset_y: 0.0 };
+        let assert_rect = |(x, y, w, h): (f32, f32, f32, f32), expected: [f32; 4]| {
+            for (actual, expected) in [x, y, w, h].iter().zip(expected.iter()) {
+                assert!((actual - expected).abs() < 1e-4, "{} {}", actual, expected);
+            }
+        };
+        assert_eq!(decoder.len(), 1);
+        assert_rect(decoder.decode_box(0, &[0.0, 0.0, 0.0, 0.0], &transform), [20.0, 40.0, 10.0, 20.0]);
+
+        /* Shift by 0.5 prior size, and 2x size */
+        assert_rect(decoder.decode_box(0, &[5.0, -5.0, 2.0_f32.ln() * 5.0, 0.0], &transform), [20.0, 30.0, 20.0, 20.0]);
+        let (x, y) = decoder.decode_point(0, &[10.0, 0.0], &transform);
+        assert!((x - 35.0).abs() < 1e-4 && (y - 50.0).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,342 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use opencv::{prelude::*, core, highgui, imgcodecs, imgproc, videoio};
+use std::path::Path;
+
+use crate::analytics::Analytics;
+use crate::bounding_box::BoundingBox;
+use crate::cli::{Args, InputSource};
+use crate::detector::{Detector, EmbeddingExtractor};
+use crate::error::{Error, Result};
+use crate::helper_cv::*;
+use crate::tracker::{Tracker, TrackerConfig, TrackerMode, TrackedObject};
+
+
+/////////////////////////////////////////////////////////////////
+pub type AppResult = std::result::Result<(), Box<dyn std::error::Error>>;
+
+/* Process a frame and draw the result on it (text_thickness is for the drawing). Returns the number of detected objects */
+pub type FrameProcessor<'a> = dyn FnMut(&mut core::Mat, i32) -> Result<usize> + 'a;
+
+/* Same as FrameProcessor, with the timestamp [sec] of the frame in a video file (None for image, directory and camera) */
+pub type TimedFrameProcessor<'a> = dyn FnMut(&mut core::Mat, i32, Option<f64>) -> Result<usize> + 'a;
+
+const IMAGE_EXTENSION_LIST: [&str; 4] = ["jpg", "jpeg", "png", "bmp"];
+
+/* Run the detector on the input specified by args, and display / save the result */
+/* With --tracker, objects are tracked across frames and drawn with the color of their IDs */
+/* With --analytics, line crossings and objects in zones are counted (SORT is used if --tracker is not specified) */
+/* The time of a video file is calculated with the frame rate of the file. The others (directory, camera) use the time elapsed since the first frame */
+pub fn run(detector: &mut dyn Detector, args: &Args, default_image: &str) -> AppResult {
+    run_with_extractor(detector, None, args, default_image)
+}
+
+/* Same as run, with a re-identification engine which is required by --tracker deep_sort */
+pub fn run_with_extractor(detector: &mut dyn Detector, mut extractor: Option<&mut dyn EmbeddingExtractor>, args: &Args, default_image: &str) -> AppResult {
+    let mut analytics = match &args.analytics {
+        Some(filename) => Some(Analytics::from_config_file(filename)?),
+        None => None,
+    };
+    let tracker_mode = args.tracker.or_else(|| analytics.as_ref().map(|_| TrackerMode::Sort));
+    if tracker_mode == Some(TrackerMode::DeepSort) && extractor.is_none() {
+        return Err("deep_sort requires a re-identification model (see pj_dnn_reid_osnet)".into());
+    }
+    let color_generator = ColorGenerator::new(20, 30);
+    let mut tracker = tracker_mode.map(|mode| (mode, Tracker::new(TrackerConfig::default())));
+    let mut clock = FrameClock::new();
+    run_with_timestamp(&mut |mat: &mut core::Mat, text_thickness: i32, timestamp: Option<f64>| {
+        /* Taken before processing, so a frame which fails keeps its place in the timeline */
+        let timestamp = clock.timestamp(timestamp);
+        match tracker.as_mut() {
+            Some((mode, tracker)) => {
+                let object_list = match mode {
+                    TrackerMode::Sort => tracker.update(&detector.process(mat)?),
+                    TrackerMode::ByteTrack => {
+                        let (bbox_high_list, bbox_low_list) = detector.process_high_low(mat, tracker.config().low_score_threshold)?;
+                        tracker.update_with_low_score(&bbox_high_list, &bbox_low_list)
+                    },
+                    TrackerMode::DeepSort => {
+                        let bbox_list = detector.process(mat)?;
+                        let embedding_list = match extractor.as_mut() {
+                            Some(extractor) => extractor.extract(mat, &bbox_list)?,
+                            None => vec![],
+                        };
+                        tracker.update_with_embedding(&bbox_list, &embedding_list)
+                    },
+                };
+                draw_tracked_object_list(mat, &object_list, &color_generator, text_thickness).map_err(Error::Postprocess)?;
+                if let Some(analytics) = analytics.as_mut() {
+                    analytics.update(&object_list, timestamp);
+                    draw_analytics(mat, analytics, text_thickness).map_err(Error::Postprocess)?;
+                }
+                Ok(object_list.len())
+            },
+            None => {
+                let bbox_list = detector.process(mat)?;
+                draw_bounding_box_list(mat, &bbox_list, &color_generator, text_thickness).map_err(Error::Postprocess)?;
+                Ok(bbox_list.len())
+            },
+        }
+    }, args, default_image)
+}
+
+/* Timestamp [sec] of each frame. A video file gives the time in the file (calculated with its frame rate) */
+/* Otherwise (camera, directory), the time elapsed since the first frame is measured */
+pub struct FrameClock {
+    start: Option<std::time::Instant>,
+}
+
+impl FrameClock {
+    pub fn new() -> Self {
+        FrameClock {
+            start: None,
+        }
+    }
+
+    /* Called for every frame read. file_timestamp is the timestamp given by run_with_timestamp */
+    pub fn timestamp(&mut self, file_timestamp: Option<f64>) -> f64 {
+        match file_timestamp {
+            Some(timestamp) => timestamp,
+            None => self.start.get_or_insert_with(std::time::Instant::now).elapsed().as_secs_f64(),
+        }
+    }
+}
+
+impl Default for FrameClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/* Same as run, but with any processing (e.g. engines which output more than bounding boxes) */
+/* --tracker and --analytics need bounding boxes, so they are rejected instead of being ignored */
+pub fn run_with(processor: &mut FrameProcessor, args: &Args, default_image: &str) -> AppResult {
+    if args.tracker.is_some() || args.analytics.is_some() {
+        return Err("--tracker and --analytics are not supported in this project".into());
+    }
+    run_with_timestamp(&mut |mat: &mut core::Mat, text_thickness: i32, _timestamp: Option<f64>| processor(mat, text_thickness), args, default_image)
+}
+
+/* Same as run_with, but the processor also receives the timestamp of the frame (e.g. for analytics) */
+pub fn run_with_timestamp(processor: &mut TimedFrameProcessor, args: &Args, default_image: &str) -> AppResult {
+    match args.input_source(default_image) {
+        InputSource::Image(filename) => test_with_single_image(processor, &filename, args),
+        InputSource::Directory(dirname) => test_with_directory(processor, &dirname, args),
+        InputSource::Video(filename) => {
+            let cap = videoio::VideoCapture::from_file(&filename, videoio::CAP_ANY)?;
+            test_with_cap(processor, cap, true, args)
+        },
+        InputSource::Camera(id) => {
+            let cap = videoio::VideoCapture::new(id, videoio::CAP_ANY)?;
+            test_with_cap(processor, cap, false, args)
+        },
+    }
+}
+
+pub fn draw_bounding_box_list(mat: &mut core::Mat, bbox_list: &[BoundingBox], color_generator: &ColorGenerator, text_thickness: i32) -> opencv::Result<()> {
+    for bbox in bbox_list {
+        let color = color_generator.get(bbox.class_id);
+        let rect = bbox.to_rect();
+        imgproc::rectangle(mat, rect, color, 2, imgproc::LINE_8, 0)?;
+        draw_text(mat, &bbox.label, core::Point::new(rect.x, rect.y - 20), 0.6, text_thickness, core::Scalar::new(255., 255., 255., 255.), color, true)?;
+    }
+    Ok(())
+}
+
+/* Color is decided by track_id (not class_id), so each object keeps its color across frames */
+pub fn draw_tracked_object_list(mat: &mut core::Mat, object_list: &[TrackedObject], color_generator: &ColorGenerator, text_thickness: i32) -> opencv::Result<()> {
+    for object in object_list {
+        let color = color_generator.get(object.track_id);
+        let rect = object.bbox.to_rect();
+        imgproc::rectangle(mat, rect, color, 2, imgproc::LINE_8, 0)?;
+        draw_text(mat, &format!("{}: {}", object.track_id, object.bbox.label), core::Point::new(rect.x, rect.y - 20), 0.6, text_thickness, core::Scalar::new(255., 255., 255., 255.), color, true)?;
+    }
+    Ok(())
+}
+
+fn read_image(image_filename: &str) -> std::result::Result<core::Mat, Box<dyn std::error::Error>> {
+    let mat = imgcodecs::imread(image_filename, imgcodecs::IMREAD_COLOR)?;
+    if mat.empty() {
+        return Err(format!("Unable to read image: {}", image_filename).into());
+    }
+    Ok(mat)
+}
+
+fn test_with_single_image(processor: &mut TimedFrameProcessor, image_filename: &str, args: &Args) -> AppResult {
+    /* Read input image */
+    let mut mat_org = read_image(image_filename)?;
+
+    /* Run detection, and draw the result */
+    processor(&mut mat_org, 1, None)?;
+
+    if let Some(output_filename) = &args.output {
+        imgcodecs::imwrite(output_filename, &mat_org, &core::Vector::<i32>::new())?;
+    }
+
+    if !args.headless {
+        highgui::imshow("result", &mat_org)?;
+        highgui::wait_key(-1)?;
+    }
+    Ok(())
+}
+
+fn test_with_directory(processor: &mut TimedFrameProcessor, dirname: &str, args: &Args) -> AppResult {
+    let mut image_filename_list = Vec::<std::path::PathBuf>::new();
+    for entry in std::fs::read_dir(dirname)? {
+        let path = entry?.path();
+        let is_image = path.extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| IMAGE_EXTENSION_LIST.contains(&ext.to_lowercase().as_str()))
+            .unwrap_or(false);
+        if is_image {
+            image_filename_list.push(path);
+        }
+    }
+    image_filename_list.sort();
+
+    if let Some(output_dirname) = &args.output {
+        std::fs::create_dir_all(output_dirname)?;
+    }
+
+    for image_filename in image_filename_list {
+        let image_filename_str = image_filename.to_string_lossy();
+        let mut mat_org = match read_image(&image_filename_str) {
+            Ok(mat) => mat,
+            Err(e) => {
+                println!("{}", e);
+                continue;
+            }
+        };
+
+        let num_object = match processor(&mut mat_org, 1, None) {
+            Ok(num_object) => num_object,
+            Err(e) => {
+                println!("{}: {}", image_filename_str, e);
+                continue;
+            }
+        };
+        println!("{}: {} objects", image_filename_str, num_object);
+
+        if let (Some(output_dirname), Some(name)) = (&args.output, image_filename.file_name()) {
+            let output_filename = Path::new(output_dirname).join(name);
+            imgcodecs::imwrite(&output_filename.to_string_lossy(), &mat_org, &core::Vector::<i32>::new())?;
+        }
+
+        if !args.headless {
+            highgui::imshow("result", &mat_org)?;
+            let key = highgui::wait_key(1)? as u8 as char;
+            if key == 'q' || key as u8 == 27 {
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+/* is_file: the frame rate of the capture is reliable, and used for the timestamp of each frame */
+fn test_with_cap(processor: &mut TimedFrameProcessor, mut cap: videoio::VideoCapture, is_file: bool, args: &Args) -> AppResult {
+    if !videoio::VideoCapture::is_opened(&cap)? {
+        return Err("Unable to open capture".into());
+    }
+
+    /* Writer is created when the first frame arrives, to know the frame size */
+    let mut writer: Option<videoio::VideoWriter> = None;
+    let fps = match cap.get(videoio::CAP_PROP_FPS)? {
+        fps if fps > 0.0 => fps,
+        _ => 30.0,
+    };
+
+    let mut frame_index = 0;
+    let mut t_all_previous = std::time::Instant::now();
+    loop {
+        /* Read image */
+        let mut mat_org = Mat::default();
+        cap.read(&mut mat_org)?;
+        if mat_org.empty() {
+            break;
+        }
+        let timestamp = if is_file { Some(frame_index as f64 / fps) } else { None };
+        frame_index += 1;
+
+        /* Run detection, and draw the result */
+        let t_detection_start = std::time::Instant::now();
+        if let Err(e) = processor(&mut mat_org, 2, timestamp) {
+            /* Skip the frame, but keep running */
+            println!("{}", e);
+            continue;
+        }
+        let t_detection = t_detection_start.elapsed();
+
+        /* Calculate processing time */
+        let t_all = t_all_previous.elapsed();
+        t_all_previous = std::time::Instant::now();
+        let text = format!("FPS = {:5.1}, Process = {:.1} ms", 1.0 / t_all.as_secs_f32(), t_detection.as_secs_f32() * 1000.0);
+        draw_text(&mut mat_org, &text, core::Point::new(0, 0), 0.8, 2, core::Scalar::new(255., 0., 0., 255.), core::Scalar::new(200., 200., 200., 255.), true)?;
+
+        /* Save the result image */
+        if let Some(output_filename) = &args.output {
+            if writer.is_none() {
+                let fourcc = i32::from_le_bytes(*b"mp4v");
+                let size = core::Size { width: mat_org.cols(), height: mat_org.rows() };
+                writer = Some(videoio::VideoWriter::new(output_filename, fourcc, fps, size, true)?);
+            }
+            if let Some(writer) = writer.as_mut() {
+                writer.write(&mat_org)?;
+            }
+        }
+
+        /* Display the result image */
+        if !args.headless {
+            highgui::imshow("result", &mat_org)?;
+            let key = highgui::wait_key(1)? as u8 as char;
+            if key == 'q' || key as u8 == 27 {
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[test]
+    fn run_with_rejects_tracking() {
+        /* Rejected before reading the input */
+        let mut processor = |_mat: &mut core::Mat, _text_thickness: i32| Ok(0);
+        for option in [vec!["--tracker", "sort"], vec!["--analytics", "analytics.toml"]].iter() {
+            let args = Args::parse_from([&["test", "--headless"], option.as_slice()].concat());
+            assert!(run_with(&mut processor, &args, "not_exist.jpg").unwrap_err().to_string().contains("--tracker"));
+        }
+    }
+
+    #[test]
+    fn frame_clock() {
+        /* Camera and directory: measured from the first frame */
+        let mut clock = FrameClock::new();
+        let timestamp_first = clock.timestamp(None);
+        assert!(timestamp_first < 0.01);
+        std::thread::sleep(std::time::Duration::from_millis(30));
+        let timestamp_second = clock.timestamp(None);
+        assert!(timestamp_second >= 0.03 && timestamp_second > timestamp_first);
+
+        /* Video file: the time in the file */
+        let mut clock = FrameClock::new();
+        assert_eq!(clock.timestamp(Some(12.5)), 12.5);
+    }
+}
@@ -0,0 +1,825 @@
+/* Copyright 2021 iwatake2222
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+    http://www.apache.org/licenses/LICENSE-2.0
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+==============================================================================*/
+
+/////////////////////////////////////////////////////////////////
+use std::collections::VecDeque;
+
+use crate::bounding_box::{BoundingBox, calculate_iou};
+
+
+/////////////////////////////////////////////////////////////////
+/* Which tracking algorithm is used to give IDs to detected objects */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrackerMode {
+    Sort,       /* Kalman filter + Hungarian assignment on IoU */
+    ByteTrack,  /* SORT + second association with low score detections */
+    DeepSort,   /* appearance (re-identification embedding) association gated by the Kalman filter, then IoU */
+}
+
+impl std::str::FromStr for TrackerMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "sort" => Ok(TrackerMode::Sort),
+            "byte_track" | "byte-track" | "bytetrack" => Ok(TrackerMode::ByteTrack),
+            "deep_sort" | "deep-sort" | "deepsort" => Ok(TrackerMode::DeepSort),
+            _ => Err(format!("unknown tracker mode: {} (sort, byte_track or deep_sort)", s)),
+        }
+    }
+}
+
+/* Track birth / death and association parameters */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrackerConfig {
+    pub max_age: u32,           /* a track is deleted when it is not matched for more than this number of frames */
+    pub min_hits: u32,          /* a track is output after it is matched in this number of consecutive frames */
+    pub iou_threshold: f32,     /* a detection and a track are not matched if IoU < this */
+    pub class_aware: bool,      /* match only a detection and a track which have the same class_id */
+    pub low_score_threshold: f32,       /* ByteTrack: detections whose score >= this are used in the second association */
+    pub low_score_iou_threshold: f32,   /* ByteTrack: IoU threshold in the second association */
+    pub max_cosine_distance: f32,       /* DeepSORT: a detection and a track are not matched if the cosine distance of the embeddings > this */
+    pub gallery_size: usize,            /* DeepSORT: the number of embeddings each track keeps (the oldest one is removed) */
+}
+
+impl Default for TrackerConfig {
+    fn default() -> Self {
+        TrackerConfig {
+            max_age: 30,
+            min_hits: 3,
+            iou_threshold: 0.3,
+            class_aware: false,
+            low_score_threshold: 0.1,
+            low_score_iou_threshold: 0.5,
+            max_cosine_distance: 0.2,
+            gallery_size: 100,
+        }
+    }
+}
+
+/* Object with an ID which is stable across frames. bbox is the filtered position, and has the class and score of the latest detection */
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackedObject {
+    pub track_id: i32,
+    pub bbox: BoundingBox,
+}
+
+
+/////////////////////////////////////////////////////////////////
+/* Constant velocity Kalman filter on (cx, cy, aspect ratio (w / h), h) */
+/* State is (cx, cy, a, h, vcx, vcy, va, vh). Noise is proportional to h (the same as DeepSORT / ByteTrack) */
+#[derive(Debug, Clone, PartialEq)]
+pub struct KalmanFilter {
+    mean: [f64; 8],
+    covariance: [[f64; 8]; 8],
+}
+
+impl KalmanFilter {
+    const STD_WEIGHT_POSITION: f64 = 1.0 / 20.0;
+    const STD_WEIGHT_VELOCITY: f64 = 1.0 / 160.0;
+
+    /* Start with zero velocity and large uncertainty of the velocity */
+    pub fn new(bbox: &BoundingBox) -> Self {
+        let measurement = to_measurement(bbox);
+        let h = measurement[3];
+        let std_list = [
+            2.0 * Self::STD_WEIGHT_POSITION * h, 2.0 * Self::STD_WEIGHT_POSITION * h, 1e-2, 2.0 * Self::STD_WEIGHT_POSITION * h,
+            10.0 * Self::STD_WEIGHT_VELOCITY * h, 10.0 * Self::STD_WEIGHT_VELOCITY * h, 1e-5, 10.0 * Self::STD_WEIGHT_VELOCITY * h,
+        ];
+        let mut mean = [0.0; 8];
+        mean[.. 4].copy_from_slice(&measurement);
+        KalmanFilter {
+            mean: mean,
+            covariance: diagonal(&std_list),
+        }
+    }
+
+    /* Move the state by one frame */
+    pub fn predict(&mut self) {
+        let h = self.mean[3];
+        let std_list = [
+            Self::STD_WEIGHT_POSITION * h, Self::STD_WEIGHT_POSITION * h, 1e-2, Self::STD_WEIGHT_POSITION * h,
+            Self::STD_WEIGHT_VELOCITY * h, Self::STD_WEIGHT_VELOCITY * h, 1e-5, Self::STD_WEIGHT_VELOCITY * h,
+        ];
+        let motion = motion_matrix();
+        for i in 0 .. 4 {
+            self.mean[i] += self.mean[i + 4];
+        }
+        self.covariance = add(&multiply(&multiply(&motion, &self.covariance), &transpose(&motion)), &diagonal(&std_list));
+    }
+
+    /* Distribution in the measurement space: (mean, covariance) of (cx, cy, a, h) */
+    pub fn project(&self) -> ([f64; 4], [[f64; 4]; 4]) {
+        let h = self.mean[3];
+        let std_list = [Self::STD_WEIGHT_POSITION * h, Self::STD_WEIGHT_POSITION * h, 1e-1, Self::STD_WEIGHT_POSITION * h];
+        let mut mean = [0.0; 4];
+        mean.copy_from_slice(&self.mean[.. 4]);
+        let mut covariance = [[0.0; 4]; 4];
+        for (row, row_state) in covariance.iter_mut().zip(self.covariance.iter()) {
+            row.copy_from_slice(&row_state[.. 4]);
+        }
+        (mean, add(&covariance, &diagonal(&std_list)))
+    }
+
+    /* Correct the state with the detected box */
+    pub fn update(&mut self, bbox: &BoundingBox) {
+        let measurement = to_measurement(bbox);
+        let (projected_mean, projected_covariance) = self.project();
+        let projected_covariance_inv = match invert(&projected_covariance) {
+            Some(inv) => inv,
+            None => return,
+        };
+
+        /* K = P * H^T * S^-1. H picks the first 4 elements, so P * H^T is the first 4 columns of P */
+        let mut covariance_h = [[0.0; 4]; 8];
+        for (row, row_state) in covariance_h.iter_mut().zip(self.covariance.iter()) {
+            row.copy_from_slice(&row_state[.. 4]);
+        }
+        let gain = multiply(&covariance_h, &projected_covariance_inv);
+
+        let mut innovation = [0.0; 4];
+        for (i, v) in innovation.iter_mut().enumerate() {
+            *v = measurement[i] - projected_mean[i];
+        }
+        for (v, gain_row) in self.mean.iter_mut().zip(gain.iter()) {
+            *v += gain_row.iter().zip(innovation.iter()).map(|(g, d)| g * d).sum::<f64>();
+        }
+        let correction = multiply(&multiply(&gain, &projected_covariance), &transpose(&gain));
+        self.covariance = add(&self.covariance, &scale(&correction, -1.0));
+    }
+
+    /* Squared Mahalanobis distance between the state and the box in the measurement space */
+    /* Compare with CHI2_INV_95_4DOF to reject unlikely boxes */
+    pub fn gating_distance(&self, bbox: &BoundingBox) -> f32 {
+        let measurement = to_measurement(bbox);
+        let (projected_mean, projected_covariance) = self.project();
+        let projected_covariance_inv = match invert(&projected_covariance) {
+            Some(inv) => inv,
+            None => return f32::INFINITY,
+        };
+        let mut innovation = [0.0; 4];
+        for (i, v) in innovation.iter_mut().enumerate() {
+            *v = measurement[i] - projected_mean[i];
+        }
+        let distance: f64 = projected_covariance_inv.iter().zip(innovation.iter())
+            .map(|(row, d_row)| d_row * row.iter().zip(innovation.iter()).map(|(v, d)| v * d).sum::<f64>())
+            .sum();
+        distance as f32
+    }
+
+    /* Box of the current state. class_id, label and score are taken from template */
+    pub fn to_bbox(&self, template: &BoundingBox) -> BoundingBox {
+        let (cx, cy, a, h) = (self.mean[0] as f32, self.mean[1] as f32, self.mean[2] as f32, (self.mean[3] as f32).max(0.0));
+        let w = (a * h).max(0.0);
+        BoundingBox { x: cx - w / 2.0, y: cy - h / 2.0, w: w, h: h, ..template.clone() }
+    }
+}
+
+/* 95% quantile of the chi-square distribution with 4 degrees of freedom (cx, cy, a, h) */
+pub const CHI2_INV_95_4DOF: f32 = 9.4877;
+
+fn to_measurement(bbox: &BoundingBox) -> [f64; 4] {
+    let (cx, cy) = bbox.center();
+    let h = (bbox.h as f64).max(1e-3);
+    [cx as f64, cy as f64, bbox.w as f64 / h, h]
+}
+
+/* x' = x + v */
+fn motion_matrix() -> [[f64; 8]; 8] {
+    let mut motion = diagonal(&[1.0; 8]);
+    for (i, row) in motion.iter_mut().take(4).enumerate() {
+        row[i + 4] = 1.0;
+    }
+    motion
+}
+
+/* Diagonal matrix whose elements are the squares of std_list (or of 1.0 for identity) */
+fn diagonal<const N: usize>(std_list: &[f64; N]) -> [[f64; N]; N] {
+    let mut matrix = [[0.0; N]; N];
+    for (i, row) in matrix.iter_mut().enumerate() {
+        row[i] = std_list[i] * std_list[i];
+    }
+    matrix
+}
+
+fn multiply<const N: usize, const M: usize, const K: usize>(a: &[[f64; M]; N], b: &[[f64; K]; M]) -> [[f64; K]; N] {
+    let mut matrix = [[0.0; K]; N];
+    for (row, row_a) in matrix.iter_mut().zip(a.iter()) {
+        for (j, v) in row.iter_mut().enumerate() {
+            *v = row_a.iter().zip(b.iter()).map(|(x, row_b)| x * row_b[j]).sum();
+        }
+    }
+    matrix
+}
+
+fn transpose<const N: usize, const M: usize>(a: &[[f64; M]; N]) -> [[f64; N]; M] {
+    let mut matrix = [[0.0; N]; M];
+    for (i, row_a) in a.iter().enumerate() {
+        for (j, v) in row_a.iter().enumerate() {
+            matrix[j][i] = *v;
+        }
+    }
+    matrix
+}
+
+fn add<const N: usize, const M: usize>(a: &[[f64; M]; N], b: &[[f64; M]; N]) -> [[f64; M]; N] {
+    let mut matrix = *a;
+    for (row, row_b) in matrix.iter_mut().zip(b.iter()) {
+        for (v, v_b) in row.iter_mut().zip(row_b.iter()) {
+            *v += v_b;
+        }
+    }
+    matrix
+}
+
+fn scale<const N: usize, const M: usize>(a: &[[f64; M]; N], factor: f64) -> [[f64; M]; N] {
+    let mut matrix = *a;
+    for v in matrix.iter_mut().flat_map(|row| row.iter_mut()) {
+        *v *= factor;
+    }
+    matrix
+}
+
+/* Gauss-Jordan elimination with partial pivoting. None if singular */
+fn invert<const N: usize>(a: &[[f64; N]; N]) -> Option<[[f64; N]; N]> {
+    let mut matrix = *a;
+    let mut inverse = diagonal(&[1.0; N]);
+    for col in 0 .. N {
+        let pivot = (col .. N).max_by(|i, j| matrix[*i][col].abs().partial_cmp(&matrix[*j][col].abs()).unwrap_or(std::cmp::Ordering::Equal))?;
+        if matrix[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        matrix.swap(col, pivot);
+        inverse.swap(col, pivot);
+        let factor = 1.0 / matrix[col][col];
+        matrix[col] = matrix[col].map(|v| v * factor);
+        inverse[col] = inverse[col].map(|v| v * factor);
+        for row in 0 .. N {
+            if row == col {
+                continue;
+            }
+            let factor = matrix[row][col];
+            if factor == 0.0 {
+                continue;
+            }
+            let (pivot_row, pivot_row_inverse) = (matrix[col], inverse[col]);
+            for (v, pivot_v) in matrix[row].iter_mut().zip(pivot_row.iter()) {
+                *v -= factor * pivot_v;
+            }
+            for (v, pivot_v) in inverse[row].iter_mut().zip(pivot_row_inverse.iter()) {
+                *v -= factor * pivot_v;
+            }
+        }
+    }
+    Some(inverse)
+}
+
+
+/////////////////////////////////////////////////////////////////
+/* Minimum cost assignment (Hungarian algorithm, O(n^3)). cost_matrix is [row][col] and can be rectangular */
+/* Returns (row, col) pairs in ascending order of row. min(rows, cols) pairs are always returned. Non finite cost is treated as a very large cost */
+pub fn linear_assignment(cost_matrix: &[Vec::<f32>]) -> Vec::<(usize, usize)> {
+    let num_row = cost_matrix.len();
+    let num_col = cost_matrix.first().map(|row| row.len()).unwrap_or(0);
+    if num_row == 0 || num_col == 0 {
+        return vec![];
+    }
+
+    /* The algorithm requires rows <= cols */
+    let is_transposed = num_row > num_col;
+    let (n, m) = if is_transposed { (num_col, num_row) } else { (num_row, num_col) };
+    let cost = |i: usize, j: usize| {
+        let v = if is_transposed { cost_matrix[j][i] } else { cost_matrix[i][j] };
+        if v.is_finite() { v as f64 } else { 1e9 }
+    };
+
+    /* Potentials u, v and matching p (col -> row), 1-indexed with 0 as a dummy */
+    let mut u = vec![0.0; n + 1];
+    let mut v = vec![0.0; m + 1];
+    let mut p = vec![0usize; m + 1];
+    let mut way = vec![0usize; m + 1];
+    for i in 1 ..= n {
+        p[0] = i;
+        let mut j0 = 0;
+        let mut min_v = vec![f64::INFINITY; m + 1];
+        let mut used = vec![false; m + 1];
+        loop {
+            used[j0] = true;
+            let i0 = p[j0];
+            let mut delta = f64::INFINITY;
+            let mut j1 = 0;
+            for j in 1 ..= m {
+                if used[j] {
+                    continue;
+                }
+                let current = cost(i0 - 1, j - 1) - u[i0] - v[j];
+                if current < min_v[j] {
+                    min_v[j] = current;
+                    way[j] = j0;
+                }
+                if min_v[j] < delta {
+                    delta = min_v[j];
+                    j1 = j;
+                }
+            }
+            for j in 0 ..= m {
+                if used[j] {
+                    u[p[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min_v[j] -= delta;
+                }
+            }
+            j0 = j1;
+            if p[j0] == 0 {
+                break;
+            }
+        }
+        /* Augment along the path */
+        while j0 != 0 {
+            let j1 = way[j0];
+            p[j0] = p[j1];
+            j0 = j1;
+        }
+    }
+
+    let mut pair_list: Vec::<(usize, usize)> = (1 ..= m)
+        .filter(|j| p[*j] != 0)
+        .map(|j| if is_transposed { (j - 1, p[j] - 1) } else { (p[j] - 1, j - 1) })
+        .collect();
+    pair_list.sort_unstable();
+    pair_list
+}
+
+/* Match with cost_matrix ([track][detection]), and reject pairs whose cost > max_cost */
+/* Returns (matched (track, detection) pairs, unmatched tracks, unmatched detections) */
+pub fn match_with_cost(cost_matrix: &[Vec::<f32>], num_track: usize, num_detection: usize, max_cost: f32) -> (Vec::<(usize, usize)>, Vec::<usize>, Vec::<usize>) {
+    let mut is_track_matched = vec![false; num_track];
+    let mut is_detection_matched = vec![false; num_detection];
+    let mut matched_list = Vec::<(usize, usize)>::new();
+    for (track_index, detection_index) in linear_assignment(cost_matrix) {
+        if cost_matrix[track_index][detection_index] <= max_cost {
+            is_track_matched[track_index] = true;
+            is_detection_matched[detection_index] = true;
+            matched_list.push((track_index, detection_index));
+        }
+    }
+    let unmatched_track_list = (0 .. num_track).filter(|i| !is_track_matched[*i]).collect();
+    let unmatched_detection_list = (0 .. num_detection).filter(|i| !is_detection_matched[*i]).collect();
+    (matched_list, unmatched_track_list, unmatched_detection_list)
+}
+
+
+/* 1 - cosine similarity. 0.0 for the same direction, 2.0 for the opposite direction */
+pub fn cosine_distance(feature0: &[f32], feature1: &[f32]) -> f32 {
+    let dot: f32 = feature0.iter().zip(feature1.iter()).map(|(a, b)| a * b).sum();
+    let norm0 = feature0.iter().map(|v| v * v).sum::<f32>().sqrt();
+    let norm1 = feature1.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm0 <= 0.0 || norm1 <= 0.0 {
+        return 1.0;
+    }
+    1.0 - dot / (norm0 * norm1)
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[derive(Debug, Clone)]
+pub struct Track {
+    pub track_id: i32,
+    pub bbox: BoundingBox,          /* the latest detection */
+    pub hits: u32,                  /* the number of matched frames */
+    pub hit_streak: u32,            /* the number of consecutive matched frames */
+    pub age: u32,                   /* the number of frames since the birth */
+    pub time_since_update: u32,     /* the number of frames since the last match */
+    kalman_filter: KalmanFilter,
+    gallery: VecDeque::<Vec::<f32>>,    /* DeepSORT: embeddings of the matched detections (the newest is the last) */
+}
+
+impl Track {
+    fn new(track_id: i32, bbox: &BoundingBox) -> Self {
+        Track {
+            track_id: track_id,
+            bbox: bbox.clone(),
+            hits: 1,
+            hit_streak: 1,
+            age: 0,
+            time_since_update: 0,
+            kalman_filter: KalmanFilter::new(bbox),
+            gallery: VecDeque::<Vec::<f32>>::new(),
+        }
+    }
+
+    fn predict(&mut self) {
+        if self.time_since_update > 0 {
+            self.hit_streak = 0;
+        }
+        self.kalman_filter.predict();
+        self.age += 1;
+        self.time_since_update += 1;
+    }
+
+    fn update(&mut self, bbox: &BoundingBox) {
+        self.kalman_filter.update(bbox);
+        self.bbox = bbox.clone();
+        self.hits += 1;
+        self.hit_streak += 1;
+        self.time_since_update = 0;
+    }
+
+    fn add_embedding(&mut self, embedding: Option<&Vec::<f32>>, gallery_size: usize) {
+        if let Some(embedding) = embedding.filter(|embedding| !embedding.is_empty()) {
+            self.gallery.push_back(embedding.clone());
+            while self.gallery.len() > gallery_size {
+                self.gallery.pop_front();
+            }
+        }
+    }
+
+    pub fn gallery(&self) -> &VecDeque::<Vec::<f32>> {
+        &self.gallery
+    }
+
+    /* Filtered (or predicted if not matched in this frame) box */
+    pub fn state_bbox(&self) -> BoundingBox {
+        self.kalman_filter.to_bbox(&self.bbox)
+    }
+
+    pub fn kalman_filter(&self) -> &KalmanFilter {
+        &self.kalman_filter
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+/* SORT: Simple Online and Realtime Tracking, ByteTrack when low score detections are given, and DeepSORT when embeddings are given */
+/* Reference: https://arxiv.org/abs/1602.00763 , https://github.com/abewley/sort */
+/* Reference: https://arxiv.org/abs/2110.06864 , https://github.com/ifzhang/ByteTrack */
+/* Reference: https://arxiv.org/abs/1703.07402 , https://github.com/nwojke/deep_sort */
+pub struct Tracker {
+    config: TrackerConfig,
+    track_list: Vec::<Track>,
+    next_track_id: i32,
+    frame_count: u32,
+}
+
+impl Tracker {
+    pub fn new(config: TrackerConfig) -> Self {
+        Tracker {
+            config: config,
+            track_list: Vec::<Track>::new(),
+            next_track_id: 1,
+            frame_count: 0,
+        }
+    }
+
+    pub fn config(&self) -> &TrackerConfig {
+        &self.config
+    }
+
+    /* All the alive tracks including unconfirmed and lost ones */
+    pub fn track_list(&self) -> &[Track] {
+        &self.track_list
+    }
+
+    pub fn reset(&mut self) {
+        self.track_list.clear();
+        self.next_track_id = 1;
+        self.frame_count = 0;
+    }
+
+    /* Call once per frame (even if nothing is detected) with the detection result of the frame */
+    /* Returns the confirmed objects matched in this frame */
+    pub fn update(&mut self, bbox_list: &[BoundingBox]) -> Vec::<TrackedObject> {
+        self.update_with_low_score(bbox_list, &[])
+    }
+
+    /* ByteTrack. bbox_high_list is used in the same way as update. bbox_low_list (e.g. occluded objects) only keeps tracks alive */
+    /* Use Detector::process_high_low to get both lists */
+    pub fn update_with_low_score(&mut self, bbox_high_list: &[BoundingBox], bbox_low_list: &[BoundingBox]) -> Vec::<TrackedObject> {
+        self.predict();
+
+        /* First association: all the tracks and high score detections */
+        let track_index_list: Vec::<usize> = (0 .. self.track_list.len()).collect();
+        let detection_index_list: Vec::<usize> = (0 .. bbox_high_list.len()).collect();
+        let (unmatched_track_list, unmatched_detection_list) = self.associate_by_iou(&track_index_list, &detection_index_list, bbox_high_list, &[], self.config.iou_threshold);
+
+        /* Second association: tracks which were matched in the previous frame and low score detections */
+        let track_index_list: Vec::<usize> = unmatched_track_list.into_iter().filter(|index| self.track_list[*index].time_since_update == 1).collect();
+        let detection_index_list: Vec::<usize> = (0 .. bbox_low_list.len()).collect();
+        self.associate_by_iou(&track_index_list, &detection_index_list, bbox_low_list, &[], self.config.low_score_iou_threshold);
+
+        /* Birth (only from high score detections) and death */
+        self.start_and_delete_tracks(&unmatched_detection_list, bbox_high_list, &[]);
+        self.confirmed_object_list()
+    }
+
+    /* DeepSORT. embedding_list[i] is the re-identification feature of bbox_list[i] (empty if not available) */
+    /* Use EmbeddingExtractor (e.g. pj_dnn_reid_osnet) to get embedding_list */
+    pub fn update_with_embedding(&mut self, bbox_list: &[BoundingBox], embedding_list: &[Vec::<f32>]) -> Vec::<TrackedObject> {
+        self.predict();
+
+        /* First association: appearance (cosine distance to the gallery) gated by the motion (Mahalanobis distance) */
+        let track_index_list: Vec::<usize> = (0 .. self.track_list.len()).collect();
+        let detection_index_list: Vec::<usize> = (0 .. bbox_list.len()).collect();
+        let (unmatched_track_list, unmatched_detection_list) = self.associate_by_appearance(&track_index_list, &detection_index_list, bbox_list, embedding_list);
+
+        /* Second association: IoU for tracks which were matched in the previous frame (e.g. the appearance changed suddenly) */
+        let track_index_list: Vec::<usize> = unmatched_track_list.into_iter().filter(|index| self.track_list[*index].time_since_update == 1).collect();
+        let (_, unmatched_detection_list) = self.associate_by_iou(&track_index_list, &unmatched_detection_list, bbox_list, embedding_list, self.config.iou_threshold);
+
+        self.start_and_delete_tracks(&unmatched_detection_list, bbox_list, embedding_list);
+        self.confirmed_object_list()
+    }
+
+    fn predict(&mut self) {
+        self.frame_count += 1;
+        for track in self.track_list.iter_mut() {
+            track.predict();
+        }
+    }
+
+    /* Birth from the unmatched detections, and death of tracks which are not matched for more than max_age frames */
+    fn start_and_delete_tracks(&mut self, detection_index_list: &[usize], bbox_list: &[BoundingBox], embedding_list: &[Vec::<f32>]) {
+        for detection_index in detection_index_list {
+            let mut track = Track::new(self.next_track_id, &bbox_list[*detection_index]);
+            track.add_embedding(embedding_list.get(*detection_index), self.config.gallery_size);
+            self.track_list.push(track);
+            self.next_track_id += 1;
+        }
+        let max_age = self.config.max_age;
+        self.track_list.retain(|track| track.time_since_update <= max_age);
+    }
+
+    /* Match the tracks and the detections (indices of track_list and bbox_list) with cost_matrix ([track][detection]), and update the matched tracks */
+    /* Returns (unmatched tracks, unmatched detections) in indices of track_list and bbox_list */
+    fn assign(&mut self, track_index_list: &[usize], detection_index_list: &[usize], cost_matrix: &[Vec::<f32>], max_cost: f32, bbox_list: &[BoundingBox], embedding_list: &[Vec::<f32>]) -> (Vec::<usize>, Vec::<usize>) {
+        let (matched_list, unmatched_track_list, unmatched_detection_list) = match_with_cost(cost_matrix, track_index_list.len(), detection_index_list.len(), max_cost);
+        for (track_index, detection_index) in matched_list {
+            let detection_index = detection_index_list[detection_index];
+            let track = &mut self.track_list[track_index_list[track_index]];
+            track.update(&bbox_list[detection_index]);
+            track.add_embedding(embedding_list.get(detection_index), self.config.gallery_size);
+        }
+        (
+            unmatched_track_list.into_iter().map(|index| track_index_list[index]).collect(),
+            unmatched_detection_list.into_iter().map(|index| detection_index_list[index]).collect(),
+        )
+    }
+
+    fn associate_by_iou(&mut self, track_index_list: &[usize], detection_index_list: &[usize], bbox_list: &[BoundingBox], embedding_list: &[Vec::<f32>], iou_threshold: f32) -> (Vec::<usize>, Vec::<usize>) {
+        /* 1 - IoU. Pairs which can't be matched have the cost over 1 */
+        let cost_matrix: Vec::<Vec::<f32>> = track_index_list.iter().map(|track_index| {
+            let bbox_track = self.track_list[*track_index].state_bbox();
+            detection_index_list.iter().map(|detection_index| {
+                let bbox = &bbox_list[*detection_index];
+                if self.config.class_aware && bbox.class_id != bbox_track.class_id {
+                    2.0
+                } else {
+                    1.0 - calculate_iou(&bbox_track, bbox)
+                }
+            }).collect()
+        }).collect();
+        self.assign(track_index_list, detection_index_list, &cost_matrix, 1.0 - iou_threshold, bbox_list, embedding_list)
+    }
+
+    fn associate_by_appearance(&mut self, track_index_list: &[usize], detection_index_list: &[usize], bbox_list: &[BoundingBox], embedding_list: &[Vec::<f32>]) -> (Vec::<usize>, Vec::<usize>) {
+        /* The smallest cosine distance to the gallery. Pairs which are too far in the motion, or don't have embeddings have the cost over 2 */
+        const COST_INFEASIBLE: f32 = 3.0;
+        let cost_matrix: Vec::<Vec::<f32>> = track_index_list.iter().map(|track_index| {
+            let track = &self.track_list[*track_index];
+            detection_index_list.iter().map(|detection_index| {
+                let bbox = &bbox_list[*detection_index];
+                let embedding = match embedding_list.get(*detection_index) {
+                    Some(embedding) if !embedding.is_empty() => embedding,
+                    _ => return COST_INFEASIBLE,
+                };
+                if self.config.class_aware && bbox.class_id != track.bbox.class_id {
+                    return COST_INFEASIBLE;
+                }
+                if track.kalman_filter.gating_distance(bbox) > CHI2_INV_95_4DOF {
+                    return COST_INFEASIBLE;
+                }
+                track.gallery.iter().map(|feature| cosine_distance(feature, embedding)).fold(COST_INFEASIBLE, f32::min)
+            }).collect()
+        }).collect();
+        self.assign(track_index_list, detection_index_list, &cost_matrix, self.config.max_cosine_distance, bbox_list, embedding_list)
+    }
+
+    /* Tracks which are matched in this frame and have enough hits. Every track is output in the first min_hits frames */
+    fn confirmed_object_list(&self) -> Vec::<TrackedObject> {
+        self.track_list.iter()
+            .filter(|track| track.time_since_update == 0 && (track.hit_streak >= self.config.min_hits || self.frame_count <= self.config.min_hits))
+            .map(|track| TrackedObject { track_id: track.track_id, bbox: track.state_bbox() })
+            .collect()
+    }
+}
+
+
+/////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_bbox(x: f32, y: f32, w: f32, h: f32) -> BoundingBox {
+        BoundingBox { class_id: 0, label: "person".to_string(), score: 0.9, x: x, y: y, w: w, h: h }
+    }
+
+    #[test]
+    fn hungarian_assignment() {
+        /* Greedy would take (0, 0) = 1, but the optimum is 2 + 2 + 1 = 5 */
+        let cost_matrix = vec![
+            vec![1.0, 2.0, 9.0],
+            vec![2.0, 9.0, 9.0],
+            vec![9.0, 9.0, 1.0],
+        ];
+        assert_eq!(linear_assignment(&cost_matrix), vec![(0, 1), (1, 0), (2, 2)]);
+
+        /* Rectangular */
+        let cost_matrix = vec![vec![5.0, 1.0], vec![1.0, 5.0], vec![0.0, 0.5]];
+        assert_eq!(linear_assignment(&cost_matrix), vec![(0, 1), (2, 0)]);
+        let cost_matrix = vec![vec![5.0, 1.0, 0.0]];
+        assert_eq!(linear_assignment(&cost_matrix), vec![(0, 2)]);
+        assert_eq!(linear_assignment(&[]), vec![]);
+
+        let (matched_list, unmatched_track_list, unmatched_detection_list) = match_with_cost(&[vec![0.2, 0.9], vec![0.95, 0.99]], 2, 2, 0.7);
+        assert_eq!(matched_list, vec![(0, 0)]);
+        assert_eq!(unmatched_track_list, vec![1]);
+        assert_eq!(unmatched_detection_list, vec![1]);
+    }
+
+    #[test]
+    fn kalman_filter_constant_velocity() {
+        let mut kalman_filter = KalmanFilter::new(&make_bbox(0.0, 0.0, 50.0, 100.0));
+        for frame in 1 .. 20 {
+            kalman_filter.predict();
+            kalman_filter.update(&make_bbox(frame as f32 * 5.0, 0.0, 50.0, 100.0));
+        }
+        /* The velocity is learned, so the prediction follows the motion */
+        kalman_filter.predict();
+        let bbox = kalman_filter.to_bbox(&make_bbox(0.0, 0.0, 0.0, 0.0));
+        assert!((bbox.x - 100.0).abs() < 1.0, "{:?}", bbox);
+        assert!(bbox.y.abs() < 1.0 && (bbox.w - 50.0).abs() < 1.0 && (bbox.h - 100.0).abs() < 1.0, "{:?}", bbox);
+
+        /* Gating: the predicted position is likely, and a far position is not */
+        assert!(kalman_filter.gating_distance(&bbox) < 1.0);
+        assert!(kalman_filter.gating_distance(&BoundingBox { x: bbox.x + 200.0, ..bbox.clone() }) > CHI2_INV_95_4DOF);
+
+        let inverse = invert(&[[4.0, 7.0], [2.0, 6.0]]).unwrap();
+        assert!((inverse[0][0] - 0.6).abs() < 1e-9 && (inverse[0][1] + 0.7).abs() < 1e-9);
+        assert_eq!(invert(&[[1.0, 2.0], [2.0, 4.0]]), None);
+    }
+
+    #[test]
+    fn sort_keeps_id() {
+        let mut tracker = Tracker::new(TrackerConfig { max_age: 2, min_hits: 2, ..TrackerConfig::default() });
+
+        /* Two objects moving in opposite directions. The order of detections changes every frame */
+        let mut id_list = Vec::<(i32, i32)>::new();
+        for frame in 0 .. 6 {
+            let bbox_a = make_bbox(10.0 + frame as f32 * 4.0, 10.0, 40.0, 80.0);
+            let bbox_b = make_bbox(200.0 - frame as f32 * 4.0, 10.0, 40.0, 80.0);
+            let bbox_list = if frame % 2 == 0 { vec![bbox_a, bbox_b] } else { vec![bbox_b, bbox_a] };
+            let object_list = tracker.update(&bbox_list);
+            assert_eq!(object_list.len(), 2);
+            let id_a = object_list.iter().find(|object| object.bbox.x < 100.0).unwrap().track_id;
+            let id_b = object_list.iter().find(|object| object.bbox.x >= 100.0).unwrap().track_id;
+            id_list.push((id_a, id_b));
+        }
+        assert!(id_list.iter().all(|ids| *ids == (1, 2)), "{:?}", id_list);
+
+        /* A new object is not output until it gets min_hits */
+        let bbox_c = make_bbox(400.0, 300.0, 40.0, 80.0);
+        assert!(tracker.update(std::slice::from_ref(&bbox_c)).is_empty());
+        let object_list = tracker.update(std::slice::from_ref(&bbox_c));
+        assert_eq!(object_list.len(), 1);
+        assert_eq!(object_list[0].track_id, 3);
+
+        /* Lost tracks are deleted after max_age */
+        assert_eq!(tracker.track_list().len(), 3);
+        tracker.update(&[]);
+        tracker.update(&[]);
+        assert_eq!(tracker.track_list().len(), 1);
+        tracker.update(&[]);
+        tracker.update(&[]);
+        assert!(tracker.track_list().is_empty());
+    }
+
+    /* Scripted sequence: an object walks to the right and is occluded (low score) in frames 4 .. 8 */
+    fn occluded_sequence() -> Vec::<BoundingBox> {
+        (0 .. 12).map(|frame| {
+            let score = if (4 .. 8).contains(&frame) { 0.3 } else { 0.9 };
+            BoundingBox { score: score, ..make_bbox(20.0 + frame as f32 * 6.0, 40.0, 40.0, 80.0) }
+        }).collect()
+    }
+
+    #[test]
+    fn byte_track_keeps_occluded_object() {
+        let config = TrackerConfig { max_age: 2, min_hits: 2, ..TrackerConfig::default() };
+        let high_score_threshold = 0.5;
+        let split = |bbox: &BoundingBox| if bbox.score >= high_score_threshold { (vec![bbox.clone()], vec![]) } else { (vec![], vec![bbox.clone()]) };
+
+        /* ByteTrack: the low score boxes keep the track, so the ID doesn't change */
+        let mut tracker = Tracker::new(config);
+        let mut id_list = Vec::<Vec::<i32>>::new();
+        for bbox in occluded_sequence() {
+            let (bbox_high_list, bbox_low_list) = split(&bbox);
+            id_list.push(tracker.update_with_low_score(&bbox_high_list, &bbox_low_list).iter().map(|object| object.track_id).collect());
+        }
+        assert!(id_list.iter().all(|ids| *ids == vec![1]), "{:?}", id_list);
+
+        /* SORT (only high score boxes): the track is lost during the occlusion and a new ID is given after it */
+        let mut tracker = Tracker::new(config);
+        let mut id_list = Vec::<Vec::<i32>>::new();
+        for bbox in occluded_sequence() {
+            let (bbox_high_list, _) = split(&bbox);
+            id_list.push(tracker.update(&bbox_high_list).iter().map(|object| object.track_id).collect());
+        }
+        assert!(id_list[4 .. 8].iter().all(|ids| ids.is_empty()), "{:?}", id_list);
+        assert_eq!(id_list[11], vec![2]);
+    }
+
+    #[test]
+    fn byte_track_low_score_rules() {
+        let config = TrackerConfig { max_age: 5, min_hits: 1, ..TrackerConfig::default() };
+        let bbox = make_bbox(100.0, 100.0, 40.0, 80.0);
+        let bbox_low = BoundingBox { score: 0.2, ..bbox.clone() };
+
+        /* Low score boxes never start a track */
+        let mut tracker = Tracker::new(config);
+        assert!(tracker.update_with_low_score(&[], std::slice::from_ref(&bbox_low)).is_empty());
+        assert!(tracker.track_list().is_empty());
+
+        /* A low score box doesn't bring back a track which was already lost (it may be a false positive) */
+        tracker.update_with_low_score(std::slice::from_ref(&bbox), &[]);
+        tracker.update_with_low_score(&[], &[]);
+        assert!(tracker.update_with_low_score(&[], std::slice::from_ref(&bbox_low)).is_empty());
+
+        /* A high score box does */
+        let object_list = tracker.update_with_low_score(std::slice::from_ref(&bbox), &[]);
+        assert_eq!(object_list.len(), 1);
+        assert_eq!(object_list[0].track_id, 1);
+
+        /* Two objects crossing: the occluded one (low score) keeps its ID, and the other doesn't steal it */
+        let mut tracker = Tracker::new(config);
+        for frame in 0 .. 8 {
+            let bbox_a = make_bbox(20.0 + frame as f32 * 10.0, 40.0, 40.0, 80.0);
+            let bbox_b = make_bbox(160.0 - frame as f32 * 10.0, 60.0, 40.0, 80.0);
+            let (bbox_high_list, bbox_low_list) = if (3 .. 6).contains(&frame) {
+                (vec![bbox_b.clone()], vec![BoundingBox { score: 0.3, ..bbox_a.clone() }])
+            } else {
+                (vec![bbox_a.clone(), bbox_b.clone()], vec![])
+            };
+            let object_list = tracker.update_with_low_score(&bbox_high_list, &bbox_low_list);
+            let find_id = |bbox: &BoundingBox| object_list.iter()
+                .find(|object| (object.bbox.x - bbox.x).abs() < 5.0 && (object.bbox.y - bbox.y).abs() < 5.0)
+                .map(|object| object.track_id);
+            let (id_a, id_b) = (find_id(&bbox_a), find_id(&bbox_b));
+            assert_eq!((id_a, id_b), (Some(1), Some(2)), "frame {}", frame);
+        }
+    }
+
+    #[test]
+    fn deep_sort_uses_appearance() {
+        let config = TrackerConfig { max_age: 5, min_hits: 1, ..TrackerConfig::default() };
+        let embedding_a = vec![1.0, 0.0, 0.1];
+        let embedding_b = vec![0.0, 1.0, 0.1];
+        assert!(cosine_distance(&embedding_a, &embedding_a) < 1e-6);
+        assert!((cosine_distance(&[1.0, 0.0], &[-1.0, 0.0]) - 2.0).abs() < 1e-6);
+
+        /* Two tall objects side by side (IoU = 0.33) swap their positions, as when people cross */
+        let bbox_left = make_bbox(100.0, 50.0, 40.0, 200.0);
+        let bbox_right = make_bbox(120.0, 50.0, 40.0, 200.0);
+        let mut tracker_deep_sort = Tracker::new(config);
+        let mut tracker_sort = Tracker::new(config);
+        for _ in 0 .. 5 {
+            tracker_deep_sort.update_with_embedding(&[bbox_left.clone(), bbox_right.clone()], &[embedding_a.clone(), embedding_b.clone()]);
+            tracker_sort.update(&[bbox_left.clone(), bbox_right.clone()]);
+        }
+        let id_at_left = |object_list: &[TrackedObject]| object_list.iter().find(|object| object.bbox.x < 110.0).map(|object| object.track_id);
+
+        /* DeepSORT follows the appearance. SORT follows the position */
+        let object_list = tracker_deep_sort.update_with_embedding(&[bbox_left.clone(), bbox_right.clone()], &[embedding_b.clone(), embedding_a.clone()]);
+        assert_eq!(id_at_left(&object_list), Some(2));
+        let object_list = tracker_sort.update(&[bbox_left.clone(), bbox_right.clone()]);
+        assert_eq!(id_at_left(&object_list), Some(1));
+        assert_eq!(tracker_deep_sort.track_list()[0].gallery().len(), 6);
+
+        /* Detections without embeddings fall back to IoU */
+        let object_list = tracker_deep_sort.update_with_embedding(std::slice::from_ref(&bbox_left), &[]);
+        assert_eq!(id_at_left(&object_list), Some(2));
+
+        /* Gating: the same appearance far away is another object */
+        let bbox_far = make_bbox(600.0, 50.0, 40.0, 200.0);
+        let object_list = tracker_deep_sort.update_with_embedding(&[bbox_far], std::slice::from_ref(&embedding_a));
+        assert_eq!(object_list.iter().map(|object| object.track_id).collect::<Vec::<_>>(), vec![3]);
+    }
+}